@@ -193,7 +193,7 @@ fn maximum_diagnostics() {
             let content = format!("{:?}", m.content);
             content.contains("The number of diagnostics exceeds the number allowed by Biome")
                 && content.contains("Diagnostics not shown")
-                && content.contains("79")
+                && content.contains("31")
         }));
 
     assert_cli_snapshot(SnapshotPayload::new(
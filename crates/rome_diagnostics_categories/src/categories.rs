@@ -59,7 +59,6 @@ define_categories! {
     "lint/correctness/noGlobalObjectCalls": "https://biomejs.dev/linter/rules/no-global-object-calls",
     "lint/correctness/noInnerDeclarations": "https://biomejs.dev/linter/rules/no-inner-declarations",
     "lint/correctness/noInvalidConstructorSuper": "https://biomejs.dev/linter/rules/no-invalid-constructor-super",
-    "lint/correctness/useIsNan": "https://biomejs.dev/linter/rules/use-is-nan",
     "lint/correctness/noNewSymbol": "https://biomejs.dev/linter/rules/no-new-symbol",
     "lint/correctness/noPrecisionLoss": "https://biomejs.dev/linter/rules/no-precision-loss",
     "lint/correctness/noRenderReturnValue": "https://biomejs.dev/linter/rules/no-render-return-value",
@@ -76,40 +75,99 @@ define_categories! {
     "lint/correctness/noUnusedVariables": "https://biomejs.dev/linter/rules/no-unused-variables",
     "lint/correctness/noVoidElementsWithChildren": "https://biomejs.dev/linter/rules/no-void-elements-with-children",
     "lint/correctness/noVoidTypeReturn": "https://biomejs.dev/linter/rules/no-void-type-return",
+    "lint/correctness/useGetterReturn": "https://biomejs.dev/linter/rules/use-getter-return",
+    "lint/correctness/useIsNan": "https://biomejs.dev/linter/rules/use-is-nan",
     "lint/correctness/useValidForDirection": "https://biomejs.dev/linter/rules/use-valid-for-direction",
     "lint/correctness/useYield": "https://biomejs.dev/linter/rules/use-yield",
 
     // nursery
     "lint/nursery/noAccumulatingSpread": "https://biomejs.dev/linter/rules/no-accumulating-spread",
+    "lint/nursery/noAlert": "https://biomejs.dev/linter/rules/no-alert",
+    "lint/nursery/noAriaHiddenOnFocusable": "https://biomejs.dev/linter/rules/no-aria-hidden-on-focusable",
     "lint/nursery/noAriaUnsupportedElements": "https://biomejs.dev/linter/rules/no-aria-unsupported-elements",
+    "lint/nursery/noArrayConstructor": "https://biomejs.dev/linter/rules/no-array-constructor",
+    "lint/nursery/noAwaitInLoop": "https://biomejs.dev/linter/rules/no-await-in-loop",
     "lint/nursery/noBannedTypes": "https://biomejs.dev/linter/rules/no-banned-types",
+    "lint/nursery/noBarrelFile": "https://biomejs.dev/linter/rules/no-barrel-file",
     "lint/nursery/noConfusingArrow": "https://biomejs.dev/linter/rules/no-confusing-arrow",
+    "lint/nursery/noConfusingVoidType": "https://biomejs.dev/linter/rules/no-confusing-void-type",
+    "lint/nursery/noConsole": "https://biomejs.dev/linter/rules/no-console",
+    "lint/nursery/noConstantBinaryExpression": "https://biomejs.dev/linter/rules/no-constant-binary-expression",
     "lint/nursery/noConstantCondition": "https://biomejs.dev/linter/rules/no-constant-condition",
     "lint/nursery/noControlCharactersInRegex": "https://biomejs.dev/linter/rules/no-control-characters-in-regex",
+    "lint/nursery/noDefaultExport": "https://biomejs.dev/linter/rules/no-default-export",
+    "lint/nursery/noDocumentCookie": "https://biomejs.dev/linter/rules/no-document-cookie",
+    "lint/nursery/noDocumentWrite": "https://biomejs.dev/linter/rules/no-document-write",
+    "lint/nursery/noDuplicateImports": "https://biomejs.dev/linter/rules/no-duplicate-imports",
     "lint/nursery/noDuplicateJsonKeys": "https://biomejs.dev/linter/rules/no-duplicate-json-keys",
+    "lint/nursery/noEmptyBlockStatements": "https://biomejs.dev/linter/rules/no-empty-block-statements",
+    "lint/nursery/noEmptyCharacterClass": "https://biomejs.dev/linter/rules/no-empty-character-class",
     "lint/nursery/noExcessiveComplexity": "https://biomejs.dev/linter/rules/no-excessive-complexity",
     "lint/nursery/noFallthroughSwitchClause": "https://biomejs.dev/linter/rules/no-fallthrough-switch-clause",
+    "lint/nursery/noGlobalEval": "https://biomejs.dev/linter/rules/no-global-eval",
     "lint/nursery/noGlobalIsFinite": "https://biomejs.dev/linter/rules/no-global-is-finite",
     "lint/nursery/noGlobalIsNan": "https://biomejs.dev/linter/rules/no-global-is-nan",
+    "lint/nursery/noHeadImportInDocument": "https://biomejs.dev/linter/rules/no-head-import-in-document",
+    "lint/nursery/noImportTypeAsValue": "https://biomejs.dev/linter/rules/no-import-type-as-value",
+    "lint/nursery/noInteractiveElementToNoninteractiveRole": "https://biomejs.dev/linter/rules/no-interactive-element-to-noninteractive-role",
+    "lint/nursery/noLabelWithoutControl": "https://biomejs.dev/linter/rules/no-label-without-control",
+    "lint/nursery/noMisleadingCharacterClass": "https://biomejs.dev/linter/rules/no-misleading-character-class",
+    "lint/nursery/noMisrefactoredShorthandAssign": "https://biomejs.dev/linter/rules/no-misrefactored-shorthand-assign",
+    "lint/nursery/noNewNativeNonConstructor": "https://biomejs.dev/linter/rules/no-new-native-non-constructor",
+    "lint/nursery/noNonNullAssertedOptionalChain": "https://biomejs.dev/linter/rules/no-non-null-asserted-optional-chain",
     "lint/nursery/noNoninteractiveTabindex": "https://biomejs.dev/linter/rules/no-noninteractive-tabindex",
     "lint/nursery/noNonoctalDecimalEscape": "https://biomejs.dev/linter/rules/no-nonoctal-decimal-escape",
+    "lint/nursery/noProcessEnv": "https://biomejs.dev/linter/rules/no-process-env",
+    "lint/nursery/noReExportAll": "https://biomejs.dev/linter/rules/no-re-export-all",
     "lint/nursery/noRedundantRoles": "https://biomejs.dev/linter/rules/no-redundant-roles",
     "lint/nursery/noSelfAssign": "https://biomejs.dev/linter/rules/no-self-assign",
+    "lint/nursery/noStaticElementInteractions": "https://biomejs.dev/linter/rules/no-static-element-interactions",
     "lint/nursery/noStaticOnlyClass": "https://biomejs.dev/linter/rules/no-static-only-class",
+    "lint/nursery/noThenProperty": "https://biomejs.dev/linter/rules/no-then-property",
+    "lint/nursery/noThrowLiteral": "https://biomejs.dev/linter/rules/no-throw-literal",
     "lint/nursery/noUnsafeDeclarationMerging": "https://biomejs.dev/linter/rules/no-unsafe-declaration-merging",
+    "lint/nursery/noUselessBackreferenceInRegex": "https://biomejs.dev/linter/rules/no-useless-backreference-in-regex",
+    "lint/nursery/noUselessConcat": "https://biomejs.dev/linter/rules/no-useless-concat",
     "lint/nursery/noUselessEmptyExport": "https://biomejs.dev/linter/rules/no-useless-empty-export",
+    "lint/nursery/noUselessEscapeInRegex": "https://biomejs.dev/linter/rules/no-useless-escape-in-regex",
+    "lint/nursery/noUselessStringRaw": "https://biomejs.dev/linter/rules/no-useless-string-raw",
     "lint/nursery/noUselessThisAlias": "https://biomejs.dev/linter/rules/no-useless-this-alias",
     "lint/nursery/noVoid": "https://biomejs.dev/linter/rules/no-void",
     "lint/nursery/useAriaPropTypes": "https://biomejs.dev/linter/rules/use-aria-prop-types",
     "lint/nursery/useArrowFunction": "https://biomejs.dev/linter/rules/use-arrow-function",
+    "lint/nursery/useAsConstAssertion": "https://biomejs.dev/linter/rules/use-as-const-assertion",
+    "lint/nursery/useAtIndex": "https://biomejs.dev/linter/rules/use-at-index",
+    "lint/nursery/useConsistentArrayType": "https://biomejs.dev/linter/rules/use-consistent-array-type",
+    "lint/nursery/useConsistentBuiltinInstantiation": "https://biomejs.dev/linter/rules/use-consistent-builtin-instantiation",
+    "lint/nursery/useConsistentCurlyBraces": "https://biomejs.dev/linter/rules/use-consistent-curly-braces",
+    "lint/nursery/useConsistentMemberAccessibility": "https://biomejs.dev/linter/rules/use-consistent-member-accessibility",
+    "lint/nursery/useConsistentTypeDefinitions": "https://biomejs.dev/linter/rules/use-consistent-type-definitions",
+    "lint/nursery/useDateNow": "https://biomejs.dev/linter/rules/use-date-now",
+    "lint/nursery/useErrorCause": "https://biomejs.dev/linter/rules/use-error-cause",
     "lint/nursery/useExhaustiveDependencies": "https://biomejs.dev/linter/rules/use-exhaustive-dependencies",
-    "lint/nursery/useGetterReturn": "https://biomejs.dev/linter/rules/use-getter-return",
+    "lint/nursery/useExportsLast": "https://biomejs.dev/linter/rules/use-exports-last",
+    "lint/nursery/useFlatRegexFlags": "https://biomejs.dev/linter/rules/use-flat-regex-flags",
+    "lint/nursery/useFocusableInteractive": "https://biomejs.dev/linter/rules/use-focusable-interactive",
     "lint/nursery/useGroupedTypeImport": "https://biomejs.dev/linter/rules/use-grouped-type-import",
     "lint/nursery/useHookAtTopLevel": "https://biomejs.dev/linter/rules/use-hook-at-top-level",
+    "lint/nursery/useImportExtensions": "https://biomejs.dev/linter/rules/use-import-extensions",
+    "lint/nursery/useImportGrouping": "https://biomejs.dev/linter/rules/use-import-grouping",
     "lint/nursery/useImportRestrictions": "https://biomejs.dev/linter/rules/use-import-restrictions",
+    "lint/nursery/useImportType": "https://biomejs.dev/linter/rules/use-import-type",
     "lint/nursery/useIsArray": "https://biomejs.dev/linter/rules/use-is-array",
+    "lint/nursery/useJsxKeyInIterable": "https://biomejs.dev/linter/rules/use-jsx-key-in-iterable",
     "lint/nursery/useLiteralEnumMembers": "https://biomejs.dev/linter/rules/use-literal-enum-members",
     "lint/nursery/useNamingConvention": "https://biomejs.dev/linter/rules/use-naming-convention",
+    "lint/nursery/useNodeAssertStrict": "https://biomejs.dev/linter/rules/use-node-assert-strict",
+    "lint/nursery/useNodePrefixForBuiltins": "https://biomejs.dev/linter/rules/use-node-prefix-for-builtins",
+    "lint/nursery/useNumberNamespace": "https://biomejs.dev/linter/rules/use-number-namespace",
+    "lint/nursery/useNumericSeparators": "https://biomejs.dev/linter/rules/use-numeric-separators",
+    "lint/nursery/useObjectSpread": "https://biomejs.dev/linter/rules/use-object-spread",
+    "lint/nursery/useRegexLiterals": "https://biomejs.dev/linter/rules/use-regex-literals",
+    "lint/nursery/useShorthandFunctionType": "https://biomejs.dev/linter/rules/use-shorthand-function-type",
+    "lint/nursery/useThrowNewError": "https://biomejs.dev/linter/rules/use-throw-new-error",
+    "lint/nursery/useTrimStartEnd": "https://biomejs.dev/linter/rules/use-trim-start-end",
+    "lint/nursery/useValidAutocomplete": "https://biomejs.dev/linter/rules/use-valid-autocomplete",
 
     // performance
     "lint/performance/noDelete": "https://biomejs.dev/linter/rules/no-delete",
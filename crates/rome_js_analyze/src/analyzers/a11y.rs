@@ -14,7 +14,6 @@ pub(crate) mod use_anchor_content;
 pub(crate) mod use_heading_content;
 pub(crate) mod use_html_lang;
 pub(crate) mod use_iframe_title;
-pub(crate) mod use_key_with_click_events;
 pub(crate) mod use_key_with_mouse_events;
 pub(crate) mod use_media_caption;
 pub(crate) mod use_valid_anchor;
@@ -35,7 +34,6 @@ declare_group! {
             self :: use_heading_content :: UseHeadingContent ,
             self :: use_html_lang :: UseHtmlLang ,
             self :: use_iframe_title :: UseIframeTitle ,
-            self :: use_key_with_click_events :: UseKeyWithClickEvents ,
             self :: use_key_with_mouse_events :: UseKeyWithMouseEvents ,
             self :: use_media_caption :: UseMediaCaption ,
             self :: use_valid_anchor :: UseValidAnchor ,
@@ -2,7 +2,7 @@ use crate::JsRuleAction;
 use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
-use rome_js_syntax::{jsx_ext::AnyJsxElement, JsxAttribute};
+use rome_js_syntax::{jsx_ext::AnyJsxElement, static_value::StaticValue, JsxAttribute};
 use rome_rowan::{AstNode, BatchMutationExt};
 
 declare_rule! {
@@ -10,6 +10,8 @@ declare_rule! {
     ///
     /// Autofocusing elements can cause usability issues for sighted and non-sighted users, alike.
     ///
+    /// The rule reports both `autoFocus` and `autofocus`, unless their value is explicitly `{false}`.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -30,6 +32,10 @@ declare_rule! {
     /// <input autoFocus={undefined} />
     /// ```
     ///
+    /// ```jsx,expect_diagnostic
+    /// <input autofocus />
+    /// ```
+    ///
     /// ### Valid
     ///
     /// ```jsx
@@ -45,6 +51,10 @@ declare_rule! {
     ///```
     ///
     /// ```jsx
+    /// <input autoFocus={false} />
+    ///```
+    ///
+    /// ```jsx
     /// // `autoFocus` prop in user created component is valid
     /// <MyComponent autoFocus={true} />
     ///```
@@ -72,7 +82,14 @@ impl Rule for NoAutoFocus {
         if node.is_custom_component() {
             return None;
         }
-        node.find_attribute_by_name("autoFocus")
+        let attribute = node
+            .find_attribute_by_name("autoFocus")
+            .or_else(|| node.find_attribute_by_name("autofocus"))?;
+        let is_false = matches!(
+            attribute.as_static_value(),
+            Some(StaticValue::Boolean(value)) if value.text_trimmed() == "false"
+        );
+        (!is_false).then_some(attribute)
     }
 
     fn diagnostic(_ctx: &RuleContext<Self>, attr: &Self::State) -> Option<RuleDiagnostic> {
@@ -80,7 +97,7 @@ impl Rule for NoAutoFocus {
             rule_category!(),
             attr.syntax().text_trimmed_range(),
             markup! {
-                "Avoid the "<Emphasis>"autoFocus"</Emphasis>" attribute."
+                "Avoid the "<Emphasis>{attr.name_value_token()?.text_trimmed()}</Emphasis>" attribute."
             },
         ))
     }
@@ -97,7 +114,7 @@ impl Rule for NoAutoFocus {
         Some(JsRuleAction {
             category: ActionCategory::QuickFix,
             applicability: Applicability::MaybeIncorrect,
-            message: markup! { "Remove the "<Emphasis>"autoFocus"</Emphasis>" attribute." }
+            message: markup! { "Remove the "<Emphasis>{attr.name_value_token()?.text_trimmed()}</Emphasis>" attribute." }
                 .to_owned(),
             mutation,
         })
@@ -1,24 +1,24 @@
+use crate::utils::append_jsx_attribute;
 use crate::JsRuleAction;
 use rome_analyze::context::RuleContext;
 use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make::{
-    jsx_attribute, jsx_attribute_initializer_clause, jsx_attribute_list, jsx_ident, jsx_name,
-    jsx_string, token,
+    jsx_attribute, jsx_attribute_initializer_clause, jsx_ident, jsx_name, jsx_string, token,
 };
 use rome_js_syntax::jsx_ext::AnyJsxElement;
-use rome_js_syntax::{
-    AnyJsxAttribute, AnyJsxAttributeName, AnyJsxAttributeValue, JsxAttribute, JsxAttributeList, T,
-};
-use rome_rowan::{AstNode, AstNodeList, BatchMutationExt, TriviaPieceKind};
+use rome_js_syntax::{AnyJsxAttributeName, AnyJsxAttributeValue, JsxAttribute, T};
+use rome_rowan::{AstNode, BatchMutationExt, TriviaPieceKind};
 
 declare_rule! {
-    /// Disallow `target="_blank"` attribute without `rel="noreferrer"`
+    /// Disallow `target="_blank"` attribute without `rel="noopener"` or `rel="noreferrer"`
     ///
     /// When creating anchor `a` element, there are times when its link has to be opened in a new browser tab
-    /// via `target="_blank"` attribute. This attribute has to paired with `rel="noreferrer"` or you're incur
-    /// in a security issue.
+    /// via `target="_blank"` attribute. This attribute has to paired with `rel="noopener"` or `rel="noreferrer"`,
+    /// otherwise the opened page can access the original page through `window.opener`.
+    ///
+    /// The code fix adds `noopener` to the `rel` attribute, after its existing values.
     ///
     /// Refer to [the noreferrer documentation](https://html.spec.whatwg.org/multipage/links.html#link-type-noreferrer)
     /// and the [the noopener documentation](https://html.spec.whatwg.org/multipage/links.html#link-type-noopener)
@@ -32,11 +32,11 @@ declare_rule! {
     /// ```
     ///
     /// ```jsx,expect_diagnostic
-    /// <a href='http://external.link' target='_blank' rel="noopener">child</a>
+    /// <a href='http://external.link' target='_blank' rel="nofollow">child</a>
     /// ```
     ///
     /// ```jsx,expect_diagnostic
-    /// <a {...props} href='http://external.link' target='_blank' rel="noopener">child</a>
+    /// <a {...props} href='http://external.link' target='_blank' rel="nofollow">child</a>
     /// ```
     ///
     /// ### Valid
@@ -46,6 +46,10 @@ declare_rule! {
     /// ```
     ///
     /// ```jsx
+    /// <a href='http://external.link' rel='noopener' target='_blank'>child</a>
+    /// ```
+    ///
+    /// ```jsx
     /// <a href='http://external.link' target='_blank' rel="noopener" {...props}>child</a>
     /// ```
     pub(crate) NoBlankTarget {
@@ -88,7 +92,10 @@ impl Rule for NoBlankTarget {
                             .as_static_value()?
                             .text()
                             .split_ascii_whitespace()
-                            .any(|f| f == "noreferrer")
+                            .any(|value| {
+                                value.eq_ignore_ascii_case("noopener")
+                                    || value.eq_ignore_ascii_case("noreferrer")
+                            })
                             && !node.has_trailing_spread_prop(&target_attribute)
                             && !node.has_trailing_spread_prop(&rel_attribute))
                     {
@@ -101,45 +108,38 @@ impl Rule for NoBlankTarget {
         None
     }
 
-    fn action(
-        ctx: &RuleContext<Self>,
-        (target_attribute, rel_attribute): &Self::State,
-    ) -> Option<JsRuleAction> {
+    fn action(ctx: &RuleContext<Self>, (_, rel_attribute): &Self::State) -> Option<JsRuleAction> {
         let mut mutation = ctx.root().begin();
         let message = if let Some(rel_attribute) = rel_attribute {
             let prev_jsx_attribute = rel_attribute.initializer()?.value().ok()?;
             let prev_jsx_string = prev_jsx_attribute.as_jsx_string()?;
-            let new_text = format!(
-                "\"noreferrer {}\"",
-                prev_jsx_string.inner_string_text().ok()?.text()
-            );
+            let prev_text = prev_jsx_string.inner_string_text().ok()?;
+            let prev_text = prev_text.text().trim();
+            let new_text = if prev_text.is_empty() {
+                "\"noopener\"".to_string()
+            } else {
+                format!("\"{prev_text} noopener\"")
+            };
             mutation.replace_node(prev_jsx_string.clone(), jsx_string(jsx_ident(&new_text)));
 
             (markup! {
-                "Add the "<Emphasis>"\"noreferrer\""</Emphasis>" to the existing attribute."
+                "Add "<Emphasis>"\"noopener\""</Emphasis>" to the existing attribute."
             })
             .to_owned()
         } else {
-            let old_attribute_list = target_attribute
-                .syntax()
-                .ancestors()
-                .find_map(JsxAttributeList::cast)?;
-            let mut new_attribute_list: Vec<_> = old_attribute_list.iter().collect();
             let new_attribute = jsx_attribute(AnyJsxAttributeName::JsxName(jsx_name(
                 jsx_ident("rel").with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
             )))
             .with_initializer(jsx_attribute_initializer_clause(
                 token(T![=]),
-                AnyJsxAttributeValue::JsxString(jsx_string(jsx_ident("\"noreferrer\""))),
+                AnyJsxAttributeValue::JsxString(jsx_string(jsx_ident("\"noopener\""))),
             ))
             .build();
 
-            new_attribute_list.push(AnyJsxAttribute::JsxAttribute(new_attribute));
-
-            mutation.replace_node(old_attribute_list, jsx_attribute_list(new_attribute_list));
+            append_jsx_attribute(&mut mutation, ctx.query(), new_attribute)?;
 
             (markup! {
-                "Add the "<Emphasis>"rel=\"noreferrer\""</Emphasis>" attribute."
+                "Add the "<Emphasis>"rel=\"noopener\""</Emphasis>" attribute."
             })
             .to_owned()
         };
@@ -148,7 +148,7 @@ impl Rule for NoBlankTarget {
             mutation,
             message,
             category: ActionCategory::QuickFix,
-            applicability: Applicability::Always,
+            applicability: Applicability::MaybeIncorrect,
         })
     }
 
@@ -160,11 +160,11 @@ impl Rule for NoBlankTarget {
             rule_category!(),
             target_attribute.syntax().text_trimmed_range(),
             markup! {
-                "Avoid using "<Emphasis>"target=\"_blank\""</Emphasis>" without "<Emphasis>"rel=\"noreferrer\""</Emphasis>"."
+                "Avoid using "<Emphasis>"target=\"_blank\""</Emphasis>" without "<Emphasis>"rel=\"noopener\""</Emphasis>" or "<Emphasis>"rel=\"noreferrer\""</Emphasis>"."
             },
         ).note(
             markup!{
-                "Opening external links in new tabs without rel=\"noreferrer\" is a security risk. See \
+                "Opening external links in new tabs without rel=\"noopener\" is a security risk. See \
                 "<Hyperlink href="https://html.spec.whatwg.org/multipage/links.html#link-type-noopener">"the explanation"</Hyperlink>" for more details."
             }
         ))
@@ -1,12 +1,8 @@
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
 use rome_console::markup;
-use rome_diagnostics::Applicability;
 use rome_js_syntax::jsx_ext::AnyJsxElement;
 use rome_js_syntax::*;
-use rome_rowan::{AstNode, BatchMutationExt};
-
-use crate::JsRuleAction;
 
 declare_rule! {
     /// Enforces that no distracting elements are used.
@@ -14,6 +10,9 @@ declare_rule! {
     /// Elements that can be visually distracting can cause accessibility issues with visually impaired users.
     /// Such elements are most likely deprecated, and should be avoided.
     /// By default, the following elements are visually distracting: `<marquee>` and `<blink>`.
+    /// The name of the element is matched case-insensitively, custom components are ignored.
+    ///
+    /// The rule doesn't provide a code fix, because the replacement of these elements depends on their content.
     ///
     /// ## Examples
     ///
@@ -33,6 +32,10 @@ declare_rule! {
     /// <div />
     /// ```
     ///
+    /// ```jsx
+    /// <Marquee />
+    /// ```
+    ///
     /// ## Accessibility guidelines
     ///
     /// - [WCAG 2.2.2](https://www.w3.org/WAI/WCAG21/Understanding/pause-stop-hide)
@@ -52,18 +55,17 @@ impl Rule for NoDistractingElements {
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let element = ctx.query();
-        let name = element.name_value_token()?;
-        match name.text_trimmed() {
-            "marquee" | "blink" => Some(name),
-            _ => None,
-        }
+        let name = element.name().ok()?.as_jsx_name()?.value_token().ok()?;
+        let is_distracting = ["marquee", "blink"]
+            .iter()
+            .any(|distracting| name.text_trimmed().eq_ignore_ascii_case(distracting));
+        is_distracting.then_some(name)
     }
 
-    fn diagnostic(ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
-        let element = ctx.query();
+    fn diagnostic(_: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
         let diagnostic = RuleDiagnostic::new(
             rule_category!(),
-            element.range(),
+            name.text_trimmed_range(),
             markup! {"Don't use the '"{name.text_trimmed()}"' element."}.to_owned(),
         )
         .note(markup! {
@@ -72,17 +74,4 @@ impl Rule for NoDistractingElements {
 
         Some(diagnostic)
     }
-
-    fn action(ctx: &RuleContext<Self>, name: &Self::State) -> Option<JsRuleAction> {
-        let element = ctx.query();
-        let mut mutation = ctx.root().begin();
-        mutation.remove_node(element.clone());
-
-        Some(JsRuleAction {
-            category: ActionCategory::QuickFix,
-            applicability: Applicability::MaybeIncorrect,
-            message: markup! { "Remove the '"{name.text_trimmed()}"' element." }.to_owned(),
-            mutation,
-        })
-    }
 }
@@ -1,6 +1,6 @@
 use rome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
 use rome_console::markup;
-use rome_js_syntax::{jsx_ext::AnyJsxElement, JsxAttribute, JsxChildList, JsxElement};
+use rome_js_syntax::{jsx_ext::AnyJsxElement, JsxChildList, JsxElement};
 use rome_rowan::{AstNode, AstNodeList};
 
 declare_rule! {
@@ -8,8 +8,10 @@ declare_rule! {
     ///
     /// It is not possible to specify the `alt` attribute for the `svg` as for the `img`.
     /// To make svg accessible, the following methods are available:
-    /// - provide the `title` element as the first child to `svg`
-    /// - provide `role="img"` and `aria-label` or `aria-labelledby` to `svg`
+    /// - provide a non-empty `title` element as a descendant of `svg`
+    /// - provide a non-empty `aria-label` or `aria-labelledby` attribute to `svg`
+    ///
+    /// The decorative `svg` elements, hidden with `aria-hidden` or with the role `presentation` or `none`, are ignored.
     ///
     /// ## Examples
     ///
@@ -30,7 +32,7 @@ declare_rule! {
     /// <svg>foo</svg>
     /// ```
     ///
-    /// ```js
+    /// ```js,expect_diagnostic
     /// <svg role="img" aria-label="">
     ///     <span id="">Pass</span>
     /// </svg>
@@ -67,6 +69,12 @@ declare_rule! {
     /// </svg>
     /// ```
     ///
+    /// ```js
+    /// <svg aria-hidden="true">
+    ///     <circle />
+    /// </svg>
+    /// ```
+    ///
     /// ## Accessibility guidelines
     /// [Document Structure – SVG 1.1 (Second Edition)](https://www.w3.org/TR/SVG11/struct.html#DescriptionAndTitleElements)
     /// [ARIA: img role - Accessibility | MDN](https://developer.mozilla.org/en-US/docs/Web/Accessibility/ARIA/Roles/img_role)
@@ -93,44 +101,34 @@ impl Rule for NoSvgWithoutTitle {
             return None;
         }
 
-        // Checks if a `svg` element has a valid `title` element is in a childlist
-        let jsx_element = node.parent::<JsxElement>()?;
-        if let AnyJsxElement::JsxOpeningElement(_) = node {
-            let has_valid_title = has_valid_title_element(&jsx_element.children());
-            if has_valid_title.map_or(false, |bool| bool) {
-                return None;
-            }
+        // Decorative `svg` elements don't need an alternative text
+        let is_presentation = node
+            .find_attribute_by_name("role")
+            .and_then(|attribute| attribute.as_static_value())
+            .is_some_and(|role| matches!(role.text(), "presentation" | "none"));
+        if is_presentation || node.has_truthy_attribute("aria-hidden") {
+            return None;
         }
 
-        // Checks if a `svg` element has role='img' and title/aria-label/aria-labelledby attrigbute
-        let Some(role_attribute) = node.find_attribute_by_name("role") else {
-            return Some(());
-        };
-
-        let role_attribute_value = role_attribute.initializer()?.value().ok()?;
-        let Some(text) = role_attribute_value
-            .as_jsx_string()?
-            .inner_string_text()
-            .ok()
-        else {
-            return Some(());
-        };
-
-        if text.to_lowercase() == "img" {
-            let [aria_label, aria_labelledby] = node
-                .attributes()
-                .find_by_names(["aria-label", "aria-labelledby"]);
-
-            let jsx_child_list = jsx_element.children();
-            let is_valid = is_valid_attribute_value(aria_label, &jsx_child_list).unwrap_or(false)
-                || is_valid_attribute_value(aria_labelledby, &jsx_child_list).unwrap_or(false);
+        // Checks if a `svg` element has a non-empty `aria-label` or `aria-labelledby` attribute
+        let has_label = ["aria-label", "aria-labelledby"].into_iter().any(|name| {
+            node.find_attribute_by_name(name)
+                .and_then(|attribute| attribute.as_static_value())
+                .map_or(false, |value| !value.text().trim().is_empty())
+        });
+        if has_label {
+            return None;
+        }
 
-            if !is_valid {
-                return Some(());
+        // Checks if a `svg` element has a valid `title` element in its descendants
+        if let AnyJsxElement::JsxOpeningElement(_) = node {
+            let jsx_element = node.parent::<JsxElement>()?;
+            if has_valid_title_element(&jsx_element.children()) {
+                return None;
             }
-        };
+        }
 
-        None
+        Some(())
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
@@ -150,42 +148,31 @@ impl Rule for NoSvgWithoutTitle {
     }
 }
 
-/// Checks if the given attribute is attached to the `svg` element and the attribute value is used by the `id` of the childs element.
-fn is_valid_attribute_value(
-    attribute: Option<JsxAttribute>,
-    jsx_child_list: &JsxChildList,
-) -> Option<bool> {
-    let attribute_value = attribute?.initializer()?.value().ok()?;
-    let is_used_attribute = jsx_child_list
-        .iter()
-        .filter_map(|child| {
-            let jsx_element = child.as_jsx_element()?;
-            let opening_element = jsx_element.opening_element().ok()?;
-            let maybe_attribute = opening_element.find_attribute_by_name("id").ok()?;
-            let child_attribute_value = maybe_attribute?.initializer()?.value().ok()?;
-            let is_valid = attribute_value.as_static_value()?.text()
-                == child_attribute_value.as_static_value()?.text();
-            Some(is_valid)
-        })
-        .any(|x| x);
-    Some(is_used_attribute)
-}
-
-/// Checks if the given `JsxChildList` has a valid `title` element.
-fn has_valid_title_element(jsx_child_list: &JsxChildList) -> Option<bool> {
-    jsx_child_list
-        .iter()
-        .filter_map(|child| {
-            let jsx_element = child.as_jsx_element()?;
-            let opening_element = jsx_element.opening_element().ok()?;
-            let name = opening_element.name().ok()?;
-            let name = name.as_jsx_name()?.value_token().ok()?;
-            let has_title_name = name.text_trimmed() == "title";
-            if !has_title_name {
-                return has_valid_title_element(&jsx_element.children());
-            }
-            let is_empty_child = jsx_element.children().is_empty();
-            Some(has_title_name && !is_empty_child)
-        })
-        .next()
+/// Checks if the given `JsxChildList` has a non-empty `title` element in its descendants.
+fn has_valid_title_element(jsx_child_list: &JsxChildList) -> bool {
+    jsx_child_list.iter().any(|child| {
+        let Some(jsx_element) = child.as_jsx_element() else {
+            return false;
+        };
+        let is_title = jsx_element
+            .opening_element()
+            .ok()
+            .and_then(|opening_element| {
+                opening_element
+                    .name()
+                    .ok()?
+                    .as_jsx_name()?
+                    .value_token()
+                    .ok()
+            })
+            .is_some_and(|name| name.text_trimmed() == "title");
+        if is_title {
+            jsx_element
+                .children()
+                .iter()
+                .any(|child| child.is_accessible_node().unwrap_or(true))
+        } else {
+            has_valid_title_element(&jsx_element.children())
+        }
+    })
 }
@@ -39,6 +39,16 @@ declare_rule! {
     /// <input type="image" src="image.png" aria-labelledby="someId" />
     /// ```
     ///
+    /// An empty `alt` or the role `presentation` mark an image as decorative.
+    ///
+    /// ```jsx
+    /// <img src="divider.png" alt="" />
+    /// ```
+    ///
+    /// ```jsx
+    /// <img src="divider.png" role="presentation" />
+    /// ```
+    ///
     /// ## Accessibility guidelines
     ///
     /// - [WCAG 1.1.1](https://www.w3.org/WAI/WCAG21/Understanding/non-text-content.html)
@@ -79,7 +89,12 @@ impl Rule for UseAltText {
             return None;
         }
 
-        let has_alt = has_valid_alt_text(element);
+        // An element without `alt` can receive it from a spread attribute,
+        // or be marked as decorative by the `presentation` and `none` roles
+        let has_alt = has_valid_alt_text(element)
+            || (element.find_attribute_by_name("alt").is_none()
+                && (element.has_spread_prop() || has_presentation_role(element)));
+        let range = element.name().ok()?.range();
         let has_aria_label = has_valid_label(element, "aria-label");
         let has_aria_labelledby = has_valid_label(element, "aria-labelledby");
         match element.name_value_token()?.text_trimmed() {
@@ -90,26 +105,23 @@ impl Rule for UseAltText {
                     match element {
                         AnyJsxElement::JsxOpeningElement(opening_element) => {
                             if !opening_element.has_accessible_child() {
-                                return Some((
-                                    ValidatedElement::Object,
-                                    element.syntax().text_range(),
-                                ));
+                                return Some((ValidatedElement::Object, range));
                             }
                         }
                         AnyJsxElement::JsxSelfClosingElement(_) => {
-                            return Some((ValidatedElement::Object, element.syntax().text_range()));
+                            return Some((ValidatedElement::Object, range));
                         }
                     }
                 }
             }
             "img" => {
                 if !has_alt && !has_aria_label && !has_aria_labelledby {
-                    return Some((ValidatedElement::Img, element.syntax().text_range()));
+                    return Some((ValidatedElement::Img, range));
                 }
             }
             "area" => {
                 if !has_alt && !has_aria_label && !has_aria_labelledby {
-                    return Some((ValidatedElement::Area, element.syntax().text_range()));
+                    return Some((ValidatedElement::Area, range));
                 }
             }
             "input" => {
@@ -118,7 +130,7 @@ impl Rule for UseAltText {
                     && !has_aria_label
                     && !has_aria_labelledby
                 {
-                    return Some((ValidatedElement::Input, element.syntax().text_range()));
+                    return Some((ValidatedElement::Input, range));
                 }
             }
             _ => {}
@@ -165,6 +177,15 @@ fn has_valid_alt_text(element: &AnyJsxElement) -> bool {
         })
 }
 
+fn has_presentation_role(element: &AnyJsxElement) -> bool {
+    element
+        .find_attribute_by_name("role")
+        .and_then(|attribute| attribute.as_static_value())
+        .map_or(false, |value| {
+            matches!(value.text(), "presentation" | "none")
+        })
+}
+
 fn has_valid_label(element: &AnyJsxElement, name_to_lookup: &str) -> bool {
     element
         .find_attribute_by_name(name_to_lookup)
@@ -1,12 +1,16 @@
 use rome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
 use rome_console::markup;
-use rome_js_syntax::{jsx_ext::AnyJsxElement, JsxElement};
-use rome_rowan::AstNode;
+use rome_js_syntax::{jsx_ext::AnyJsxElement, AnyJsxChild, JsxChildList, JsxElement};
+use rome_rowan::{AstNode, AstNodeList};
 
 declare_rule! {
     /// Enforce that heading elements (h1, h2, etc.) have content and that the content is accessible to screen readers.
     /// Accessible means that it is not hidden using the aria-hidden prop.
     ///
+    /// The content of the nested HTML elements is checked recursively,
+    /// while a nested component is always considered as content.
+    /// A heading with an `aria-label` or a spread attribute is ignored.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -23,6 +27,10 @@ declare_rule! {
     /// <h1></h1>
     /// ```
     ///
+    /// ```jsx,expect_diagnostic
+    /// <h1><span></span></h1>
+    /// ```
+    ///
     /// ## Valid
     ///
     /// ```jsx
@@ -41,6 +49,10 @@ declare_rule! {
     /// <h1><div aria-hidden />visible content</h1>
     /// ```
     ///
+    /// ```jsx
+    /// <h1 aria-label="heading"><Icon /></h1>
+    /// ```
+    ///
     /// ## Accessibility guidelines
     ///
     /// - [WCAG 2.4.6](https://www.w3.org/TR/UNDERSTANDING-WCAG20/navigation-mechanisms-descriptive.html)
@@ -75,7 +87,8 @@ impl Rule for UseHeadingContent {
 
             match node {
                 AnyJsxElement::JsxOpeningElement(opening_element) => {
-                    if !opening_element.has_accessible_child() {
+                    let element = opening_element.parent::<JsxElement>()?;
+                    if !has_accessible_content(element.children()) {
                         return Some(());
                     }
                 }
@@ -87,15 +100,9 @@ impl Rule for UseHeadingContent {
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
-        let range = match ctx.query() {
-            AnyJsxElement::JsxOpeningElement(node) => {
-                node.parent::<JsxElement>()?.syntax().text_range()
-            }
-            AnyJsxElement::JsxSelfClosingElement(node) => node.syntax().text_trimmed_range(),
-        };
         Some(RuleDiagnostic::new(
             rule_category!(),
-            range,
+            ctx.query().range(),
             markup! {
                 "Provide screen reader accessible content when using "<Emphasis>"heading"</Emphasis>"  elements."
             },
@@ -109,15 +116,37 @@ impl Rule for UseHeadingContent {
 fn has_valid_heading_content(node: &AnyJsxElement) -> bool {
     node.find_attribute_by_name("dangerouslySetInnerHTML")
         .is_some()
-        || node
-            .find_attribute_by_name("children")
-            .map_or(false, |attribute| {
-                if attribute.initializer().is_none() {
-                    return false;
-                }
-                attribute
-                    .as_static_value()
-                    .map_or(true, |attribute| !attribute.is_falsy())
-            })
+        || ["children", "aria-label"].into_iter().any(|name| {
+            node.find_attribute_by_name(name)
+                .map_or(false, |attribute| {
+                    if attribute.initializer().is_none() {
+                        return false;
+                    }
+                    attribute
+                        .as_static_value()
+                        .map_or(true, |attribute| !attribute.is_falsy())
+                })
+        })
         || node.has_spread_prop()
 }
+
+/// Checks if the children contain content that is accessible to screen readers
+///
+/// The nested HTML elements provide content only if their own children do.
+fn has_accessible_content(children: JsxChildList) -> bool {
+    children.iter().any(|child| match &child {
+        AnyJsxChild::JsxElement(element) => {
+            let Ok(opening_element) = element.opening_element() else {
+                return true;
+            };
+            let opening_element = AnyJsxElement::from(opening_element);
+            if opening_element.is_custom_component() {
+                return true;
+            }
+            !opening_element.has_truthy_attribute("aria-hidden")
+                && (has_valid_heading_content(&opening_element)
+                    || has_accessible_content(element.children()))
+        }
+        _ => child.is_accessible_node().unwrap_or(true),
+    })
+}
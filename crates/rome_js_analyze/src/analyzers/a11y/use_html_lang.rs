@@ -6,6 +6,9 @@ use rome_rowan::AstNode;
 declare_rule! {
     /// Enforce that `html` element has `lang` attribute.
     ///
+    /// The rule is ignored when a spread attribute can provide the `lang` attribute.
+    /// It doesn't provide a code fix, because the language of the document is unknown.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -67,7 +70,7 @@ impl Rule for UseHtmlLang {
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let element = ctx.query();
-        let name = element.name().ok()?.name_value_token()?;
+        let name = element.name().ok()?.as_jsx_name()?.value_token().ok()?;
 
         if name.text_trimmed() == "html" {
             if let Some(lang_attribute) = element.find_attribute_by_name("lang") {
@@ -95,9 +98,13 @@ impl Rule for UseHtmlLang {
             }
         ).note(
             markup! {
-                "Setting a "<Emphasis>"lang"</Emphasis>" attribute on HTML document elements configures the language"
+                "Setting a "<Emphasis>"lang"</Emphasis>" attribute on HTML document elements configures the language "
                 "used by screen readers when no user default is specified."
             }
+        ).note(
+            markup! {
+                "Add the language of the document, for example "<Emphasis>"lang=\"en\""</Emphasis>" for a document in English."
+            }
         ))
     }
 }
@@ -43,6 +43,9 @@ declare_rule! {
     /// <a href="javascript:void(0)">navigate here</a>
     /// ```
     /// ```jsx,expect_diagnostic
+    /// <a href="#">navigate here</a>
+    /// ```
+    /// ```jsx,expect_diagnostic
     /// <a href="https://example.com" onClick={something}>navigate here</a>
     /// ```
     /// ### Valid
@@ -78,6 +81,8 @@ pub(crate) enum UseValidAnchorState {
     MissingHrefAttribute(TextRange),
     /// The value assigned to attribute `href` is not valid
     IncorrectHref(TextRange),
+    /// The value assigned to attribute `href` is `#` or a `javascript:` URL, which doesn't navigate
+    ActionHref(TextRange),
     /// The element has `href` and `onClick`
     CantBeAnchor(TextRange),
 }
@@ -90,7 +95,7 @@ impl UseValidAnchorState {
                     "Provide a "<Emphasis>"href"</Emphasis>" attribute for the "<Emphasis>"a"</Emphasis>" element."
                 }).to_owned()
             },
-            UseValidAnchorState::IncorrectHref(_) => {
+            UseValidAnchorState::IncorrectHref(_) | UseValidAnchorState::ActionHref(_) => {
                 (markup! {
                     "Provide a valid value for the attribute "<Emphasis>"href"</Emphasis>"."
                 }).to_owned()
@@ -113,6 +118,10 @@ impl UseValidAnchorState {
                 "The href attribute should be a valid a URL"
             })
             .to_owned(),
+            UseValidAnchorState::ActionHref(_) => (markup! {
+                "An "<Emphasis>"href"</Emphasis>" of "<Emphasis>"#"</Emphasis>" or a "<Emphasis>"javascript:"</Emphasis>" URL doesn't navigate. Use a "<Emphasis>"button"</Emphasis>" element for the actions instead."
+            })
+            .to_owned(),
             UseValidAnchorState::CantBeAnchor(_) => (markup! {
                 "Anchor elements should only be used for default sections or page navigation"
             })
@@ -124,7 +133,8 @@ impl UseValidAnchorState {
         match self {
             UseValidAnchorState::MissingHrefAttribute(range)
             | UseValidAnchorState::CantBeAnchor(range)
-            | UseValidAnchorState::IncorrectHref(range) => range,
+            | UseValidAnchorState::IncorrectHref(range)
+            | UseValidAnchorState::ActionHref(range) => range,
         }
     }
 }
@@ -157,14 +167,13 @@ impl Rule for UseValidAnchor {
                     }
 
                     let static_value = anchor_attribute.as_static_value()?;
-                    if static_value.as_string_constant().map_or(true, |const_str| {
-                        const_str.is_empty()
-                            || const_str == "#"
-                            || const_str.contains("javascript:")
-                    }) {
-                        return Some(UseValidAnchorState::IncorrectHref(
-                            anchor_attribute.syntax().text_trimmed_range(),
-                        ));
+                    let range = anchor_attribute.syntax().text_trimmed_range();
+                    match static_value.as_string_constant() {
+                        Some(href) if href == "#" || is_javascript_url(href) => {
+                            return Some(UseValidAnchorState::ActionHref(range));
+                        }
+                        Some(href) if !href.is_empty() => {}
+                        _ => return Some(UseValidAnchorState::IncorrectHref(range)),
                     }
                 }
                 (None, Some(on_click_attribute)) => {
@@ -197,3 +206,12 @@ impl Rule for UseValidAnchor {
         Some(diagnostic)
     }
 }
+
+/// Returns `true` if `href` is a `javascript:` URL.
+///
+/// Browsers ignore the leading whitespace and the case of the scheme.
+fn is_javascript_url(href: &str) -> bool {
+    href.trim_start()
+        .get(.."javascript:".len())
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case("javascript:"))
+}
@@ -55,6 +55,14 @@ declare_rule! {
     /// ```js
     /// /foo /
     ///```
+    ///
+    /// ```js
+    /// /foo  +bar/
+    ///```
+    ///
+    /// ```js
+    /// /foo[  ]bar/
+    ///```
     pub(crate) NoMultipleSpacesInRegularExpressionLiterals {
         version: "1.0.0",
         name: "noMultipleSpacesInRegularExpressionLiterals",
@@ -71,22 +79,13 @@ impl Rule for NoMultipleSpacesInRegularExpressionLiterals {
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let value_token = ctx.query().value_token().ok()?;
         let trimmed_text = value_token.text_trimmed();
-        let mut range_list = vec![];
-        let mut continue_white_space = false;
-        let mut last_white_index = 0;
-        for (i, ch) in trimmed_text.chars().enumerate() {
-            if ch == ' ' {
-                if !continue_white_space {
-                    continue_white_space = true;
-                    last_white_index = i;
-                }
-            } else if continue_white_space {
-                if i - last_white_index > 1 {
-                    range_list.push((last_white_index, i));
-                }
-                continue_white_space = false;
-            }
-        }
+        let end_slash_position = trimmed_text.rfind('/')?;
+        let pattern = trimmed_text.get(1..end_slash_position)?;
+        let range_list: Vec<_> = find_space_runs(pattern)
+            .into_iter()
+            // Take the opening slash into account
+            .map(|(start, end)| (start + 1, end + 1))
+            .collect();
         if !range_list.is_empty() {
             Some(range_list)
         } else {
@@ -146,3 +145,40 @@ impl Rule for NoMultipleSpacesInRegularExpressionLiterals {
         })
     }
 }
+
+/// Returns the byte ranges of the runs of two or more literal spaces in `pattern`.
+///
+/// The escaped spaces and the spaces of character classes are ignored.
+/// When a quantifier follows a run, the quantifier applies only to the last space,
+/// so this space isn't part of the run.
+fn find_space_runs(pattern: &str) -> Vec<(usize, usize)> {
+    let mut range_list = vec![];
+    let mut is_in_class = false;
+    let mut chars = pattern.char_indices().peekable();
+    while let Some((start, ch)) = chars.next() {
+        match ch {
+            '\\' => {
+                chars.next();
+            }
+            '[' => is_in_class = true,
+            ']' => is_in_class = false,
+            ' ' if !is_in_class => {
+                while chars.next_if(|(_, ch)| *ch == ' ').is_some() {}
+                let mut end = chars.peek().map_or(pattern.len(), |(end, _)| *end);
+                let is_quantified = match pattern[end..].chars().next() {
+                    Some('*' | '+' | '?') => true,
+                    Some('{') => pattern[end + 1..].starts_with(|ch: char| ch.is_ascii_digit()),
+                    _ => false,
+                };
+                if is_quantified {
+                    end -= 1;
+                }
+                if end - start > 1 {
+                    range_list.push((start, end));
+                }
+            }
+            _ => {}
+        }
+    }
+    range_list
+}
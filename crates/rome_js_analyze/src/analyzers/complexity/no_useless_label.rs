@@ -15,6 +15,10 @@ declare_rule! {
     ///
     /// If a loop contains no nested loops or switches, labeling the loop is unnecessary.
     ///
+    /// A `break` or `continue` that refers to the label of the nearest enclosing loop or switch
+    /// has the same effect without the label.
+    /// Labels of blocks and other statements are always required.
+    ///
     /// Source: https://eslint.org/docs/latest/rules/no-extra-label
     ///
     /// ## Examples
@@ -70,7 +74,12 @@ impl Rule for NoUselessLabel {
         let label = label_token.text_trimmed();
         for parent in stmt.syntax().ancestors() {
             if JsBreakableStatement::can_cast(parent.kind()) {
-                if let Some(labeled_stmt) = JsLabeledStatement::cast(parent.parent()?) {
+                // A statement can have several labels, e.g. `A: B: while (a) {}`
+                for labeled_stmt in parent
+                    .ancestors()
+                    .skip(1)
+                    .map_while(JsLabeledStatement::cast)
+                {
                     if labeled_stmt.label_token().ok()?.text_trimmed() == label {
                         return Some(());
                     }
@@ -3,8 +3,12 @@ use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
 use rome_console::markup;
 
 use rome_diagnostics::Applicability;
-use rome_js_syntax::TsTypeConstraintClause;
-use rome_rowan::{AstNode, BatchMutationExt};
+use rome_js_factory::make;
+use rome_js_syntax::{
+    JsArrowFunctionExpression, JsFileSource, TsTypeConstraintClause, TsTypeParameter,
+    TsTypeParameterList, TsTypeParameters, T,
+};
+use rome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
 
 use crate::JsRuleAction;
 
@@ -16,6 +20,10 @@ declare_rule! {
     /// All types are subtypes of `any` and `unknown`.
     /// It is thus useless to extend from `any` or `unknown`.
     ///
+    /// The code fix removes the constraint and keeps the default type of the type parameter.
+    /// In a TSX file, a trailing comma is added to the only type parameter of an arrow function,
+    /// because `<T>() => {}` would be parsed as a JSX element.
+    ///
     /// Source: https://typescript-eslint.io/rules/no-unnecessary-type-constraint/
     ///
     /// ## Examples
@@ -71,6 +79,8 @@ declare_rule! {
     /// interface Foo<T> {}
     ///
     /// type Bar<T> = {};
+    ///
+    /// type Baz<T = unknown> = {};
     ///```
     pub(crate) NoUselessTypeConstraint {
         version: "1.0.0",
@@ -115,11 +125,28 @@ impl Rule for NoUselessTypeConstraint {
     fn action(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<JsRuleAction> {
         let node = ctx.query();
         let mut mutation = ctx.root().begin();
-        mutation.remove_node(node.clone());
+        let type_parameter = node.parent::<TsTypeParameter>()?;
+        let type_parameter_list = type_parameter.parent::<TsTypeParameterList>()?;
+        let is_only_parameter_of_arrow = type_parameter_list.len() == 1
+            && type_parameter_list.trailing_separator().is_none()
+            && type_parameter_list
+                .parent::<TsTypeParameters>()
+                .and_then(|type_parameters| type_parameters.parent::<JsArrowFunctionExpression>())
+                .is_some();
+        if is_only_parameter_of_arrow && ctx.source_type::<JsFileSource>().variant().is_jsx() {
+            // `<T>() => {}` is a JSX element in a TSX file, `<T,>() => {}` is an arrow function
+            let new_type_parameter_list = make::ts_type_parameter_list(
+                [type_parameter.with_constraint(None).trim_end()?],
+                [make::token(T![,])],
+            );
+            mutation.replace_node(type_parameter_list, new_type_parameter_list);
+        } else {
+            mutation.remove_node(node.clone());
+        }
 
         Some(JsRuleAction {
             category: ActionCategory::QuickFix,
-            applicability: Applicability::MaybeIncorrect,
+            applicability: Applicability::Always,
             message: markup! { "Remove the constraint." }.to_owned(),
             mutation,
         })
@@ -71,7 +71,14 @@ impl Rule for UseFlatMap {
             let map_call_arguments = map_call.arguments().ok()?.args();
             let map_member_expression =
                 AnyJsMemberExpression::cast_ref(map_call.callee().ok()?.syntax())?;
-            if map_member_expression.member_name()?.text() == "map" && map_call_arguments.len() == 1
+            // Skip calls with a `thisArg` or with a spread argument that may include one.
+            if map_member_expression.member_name()?.text() == "map"
+                && map_call_arguments.len() == 1
+                && map_call_arguments
+                    .first()?
+                    .ok()?
+                    .as_any_js_expression()
+                    .is_some()
             {
                 return Some(map_call.clone());
             }
@@ -116,7 +123,7 @@ impl Rule for UseFlatMap {
             message: markup! {"Replace the chain with "<Emphasis>".flatMap()"</Emphasis>"."}
                 .to_owned(),
             category: ActionCategory::QuickFix,
-            applicability: Applicability::Always,
+            applicability: Applicability::MaybeIncorrect,
         })
     }
 }
@@ -17,6 +17,7 @@ pub(crate) mod no_unsafe_finally;
 pub(crate) mod no_unsafe_optional_chaining;
 pub(crate) mod no_unused_labels;
 pub(crate) mod no_void_type_return;
+pub(crate) mod use_getter_return;
 pub(crate) mod use_valid_for_direction;
 pub(crate) mod use_yield;
 
@@ -39,6 +40,7 @@ declare_group! {
             self :: no_unsafe_optional_chaining :: NoUnsafeOptionalChaining ,
             self :: no_unused_labels :: NoUnusedLabels ,
             self :: no_void_type_return :: NoVoidTypeReturn ,
+            self :: use_getter_return :: UseGetterReturn ,
             self :: use_valid_for_direction :: UseValidForDirection ,
             self :: use_yield :: UseYield ,
         ]
@@ -92,7 +92,7 @@ impl Rule for NoUnnecessaryContinue {
         let node = ctx.query();
         Some(RuleDiagnostic::new(
             rule_category!(),
-            node.range(),
+            node.continue_token().ok()?.text_trimmed_range(),
             markup! {
                 "Unnecessary continue statement"
             },
@@ -136,7 +136,7 @@ fn is_continue_un_necessary(node: &JsContinueStatement) -> Option<bool> {
     }
     let loop_stmt = loop_stmt?;
     if ancestors.is_empty() {
-        return Some(true);
+        return contains_parent_loop_label(syntax, loop_stmt);
     }
     Some(
         is_continue_last_statement(&ancestors, syntax.clone()).unwrap_or(false)
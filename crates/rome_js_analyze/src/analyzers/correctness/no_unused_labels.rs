@@ -20,6 +20,9 @@ declare_rule! {
     ///
     /// Labels that are declared and never used are most likely an error due to incomplete refactoring.
     ///
+    /// A label is used when a `break` or `continue` statement of the labeled statement refers to it.
+    /// Labels of an enclosing function are not visible, so they are never used by the statements of a nested function.
+    ///
     /// Source: https://eslint.org/docs/latest/rules/no-unused-labels
     ///
     /// ## Examples
@@ -151,13 +154,18 @@ impl Rule for NoUnusedLabels {
 
     fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
         let unused_label = ctx.query();
-        Some(RuleDiagnostic::new(
-            rule_category!(),
-            unused_label.label_token().ok()?.text_trimmed_range(),
-            markup! {
-                "Unused "<Emphasis>"label"</Emphasis>"."
-            },
-        ))
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                unused_label.label_token().ok()?.text_trimmed_range(),
+                markup! {
+                    "Unused "<Emphasis>"label"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The label is not used by any "<Emphasis>"break"</Emphasis>" or "<Emphasis>"continue"</Emphasis>" statement."
+            }),
+        )
     }
 
     fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
@@ -141,10 +141,16 @@ impl Rule for UseGetterReturn {
         let cfg = ctx.query();
         let diagnostic = match invalid_return {
             InvalidGetterReturn::MissingReturn => {
-                let getter_range = cfg.node.text_trimmed_range();
+                let get_token = if let Some(getter) = JsGetterClassMember::cast_ref(&cfg.node) {
+                    getter.get_token().ok()?
+                } else {
+                    JsGetterObjectMember::cast_ref(&cfg.node)?
+                        .get_token()
+                        .ok()?
+                };
                 RuleDiagnostic::new(
                     rule_category!(),
-                    getter_range,
+                    get_token.text_trimmed_range(),
                     markup! {
                         "This "<Emphasis>"getter"</Emphasis>" should "<Emphasis>"return"</Emphasis>" a value."
                     },
@@ -154,7 +160,7 @@ impl Rule for UseGetterReturn {
                 rule_category!(),
                 return_stmt_range,
                 markup! {
-                    "This "<Emphasis>"return"</Emphasis>" should return a value because it is located in a "<Emphasis>"return"</Emphasis>"."
+                    "This "<Emphasis>"return"</Emphasis>" should return a value because it is located in a "<Emphasis>"getter"</Emphasis>"."
                 },
             ),
         };
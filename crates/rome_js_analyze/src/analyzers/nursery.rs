@@ -2,39 +2,99 @@
 
 use rome_analyze::declare_group;
 
+pub(crate) mod no_aria_hidden_on_focusable;
+pub(crate) mod no_await_in_loop;
+pub(crate) mod no_barrel_file;
 pub(crate) mod no_confusing_arrow;
+pub(crate) mod no_confusing_void_type;
+pub(crate) mod no_constant_binary_expression;
 pub(crate) mod no_control_characters_in_regex;
+pub(crate) mod no_default_export;
+pub(crate) mod no_duplicate_imports;
+pub(crate) mod no_empty_block_statements;
+pub(crate) mod no_empty_character_class;
 pub(crate) mod no_excessive_complexity;
 pub(crate) mod no_fallthrough_switch_clause;
+pub(crate) mod no_misleading_character_class;
+pub(crate) mod no_misrefactored_shorthand_assign;
+pub(crate) mod no_non_null_asserted_optional_chain;
 pub(crate) mod no_nonoctal_decimal_escape;
+pub(crate) mod no_re_export_all;
 pub(crate) mod no_self_assign;
 pub(crate) mod no_static_only_class;
+pub(crate) mod no_then_property;
+pub(crate) mod no_useless_backreference_in_regex;
+pub(crate) mod no_useless_concat;
 pub(crate) mod no_useless_empty_export;
+pub(crate) mod no_useless_escape_in_regex;
 pub(crate) mod no_void;
 pub(crate) mod use_arrow_function;
-pub(crate) mod use_getter_return;
+pub(crate) mod use_as_const_assertion;
+pub(crate) mod use_at_index;
+pub(crate) mod use_consistent_array_type;
+pub(crate) mod use_consistent_curly_braces;
+pub(crate) mod use_consistent_member_accessibility;
+pub(crate) mod use_consistent_type_definitions;
+pub(crate) mod use_flat_regex_flags;
 pub(crate) mod use_grouped_type_import;
+pub(crate) mod use_import_extensions;
+pub(crate) mod use_import_grouping;
 pub(crate) mod use_import_restrictions;
+pub(crate) mod use_jsx_key_in_iterable;
 pub(crate) mod use_literal_enum_members;
+pub(crate) mod use_numeric_separators;
+pub(crate) mod use_shorthand_function_type;
+pub(crate) mod use_trim_start_end;
+pub(crate) mod use_valid_autocomplete;
 
 declare_group! {
     pub (crate) Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_aria_hidden_on_focusable :: NoAriaHiddenOnFocusable ,
+            self :: no_await_in_loop :: NoAwaitInLoop ,
+            self :: no_barrel_file :: NoBarrelFile ,
             self :: no_confusing_arrow :: NoConfusingArrow ,
+            self :: no_confusing_void_type :: NoConfusingVoidType ,
+            self :: no_constant_binary_expression :: NoConstantBinaryExpression ,
             self :: no_control_characters_in_regex :: NoControlCharactersInRegex ,
+            self :: no_default_export :: NoDefaultExport ,
+            self :: no_duplicate_imports :: NoDuplicateImports ,
+            self :: no_empty_block_statements :: NoEmptyBlockStatements ,
+            self :: no_empty_character_class :: NoEmptyCharacterClass ,
             self :: no_excessive_complexity :: NoExcessiveComplexity ,
             self :: no_fallthrough_switch_clause :: NoFallthroughSwitchClause ,
+            self :: no_misleading_character_class :: NoMisleadingCharacterClass ,
+            self :: no_misrefactored_shorthand_assign :: NoMisrefactoredShorthandAssign ,
+            self :: no_non_null_asserted_optional_chain :: NoNonNullAssertedOptionalChain ,
             self :: no_nonoctal_decimal_escape :: NoNonoctalDecimalEscape ,
+            self :: no_re_export_all :: NoReExportAll ,
             self :: no_self_assign :: NoSelfAssign ,
             self :: no_static_only_class :: NoStaticOnlyClass ,
+            self :: no_then_property :: NoThenProperty ,
+            self :: no_useless_backreference_in_regex :: NoUselessBackreferenceInRegex ,
+            self :: no_useless_concat :: NoUselessConcat ,
             self :: no_useless_empty_export :: NoUselessEmptyExport ,
+            self :: no_useless_escape_in_regex :: NoUselessEscapeInRegex ,
             self :: no_void :: NoVoid ,
             self :: use_arrow_function :: UseArrowFunction ,
-            self :: use_getter_return :: UseGetterReturn ,
+            self :: use_as_const_assertion :: UseAsConstAssertion ,
+            self :: use_at_index :: UseAtIndex ,
+            self :: use_consistent_array_type :: UseConsistentArrayType ,
+            self :: use_consistent_curly_braces :: UseConsistentCurlyBraces ,
+            self :: use_consistent_member_accessibility :: UseConsistentMemberAccessibility ,
+            self :: use_consistent_type_definitions :: UseConsistentTypeDefinitions ,
+            self :: use_flat_regex_flags :: UseFlatRegexFlags ,
             self :: use_grouped_type_import :: UseGroupedTypeImport ,
+            self :: use_import_extensions :: UseImportExtensions ,
+            self :: use_import_grouping :: UseImportGrouping ,
             self :: use_import_restrictions :: UseImportRestrictions ,
+            self :: use_jsx_key_in_iterable :: UseJsxKeyInIterable ,
             self :: use_literal_enum_members :: UseLiteralEnumMembers ,
+            self :: use_numeric_separators :: UseNumericSeparators ,
+            self :: use_shorthand_function_type :: UseShorthandFunctionType ,
+            self :: use_trim_start_end :: UseTrimStartEnd ,
+            self :: use_valid_autocomplete :: UseValidAutocomplete ,
         ]
      }
 }
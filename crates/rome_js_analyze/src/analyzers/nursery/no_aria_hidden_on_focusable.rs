@@ -0,0 +1,141 @@
+use crate::utils::aria::is_natively_focusable;
+use crate::JsRuleAction;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_syntax::{jsx_ext::AnyJsxElement, AnyJsExpression, AnyJsxAttributeValue, JsxAttribute};
+use rome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow `aria-hidden="true"` on focusable elements.
+    ///
+    /// An element hidden with `aria-hidden` is removed from the accessibility tree,
+    /// but a focusable element can still be reached with the keyboard.
+    /// The users of screen readers then land on an element that isn't announced.
+    ///
+    /// An element is focusable when it has a `tabIndex` greater than or equal to `0`,
+    /// or when it is natively focusable, such as `button`, `input` or `a` with a `href`,
+    /// unless its `tabIndex` is negative.
+    ///
+    /// Source: https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/docs/rules/no-aria-hidden-on-focusable.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <div aria-hidden="true" tabIndex="0" />
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <button aria-hidden>Submit</button>
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <a href="/" aria-hidden="true">Home</a>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <div aria-hidden="true" />
+    /// ```
+    ///
+    /// ```jsx
+    /// <button aria-hidden="true" tabIndex="-1">Submit</button>
+    /// ```
+    ///
+    /// ```jsx
+    /// <a aria-hidden="true">Home</a>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 4.1.2](https://www.w3.org/WAI/WCAG21/Understanding/name-role-value)
+    ///
+    pub(crate) NoAriaHiddenOnFocusable {
+        version: "next",
+        name: "noAriaHiddenOnFocusable",
+        recommended: false,
+    }
+}
+
+impl Rule for NoAriaHiddenOnFocusable {
+    type Query = Ast<AnyJsxElement>;
+    /// The `aria-hidden` attribute.
+    type State = JsxAttribute;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if !node.is_element() || !node.has_truthy_attribute("aria-hidden") {
+            return None;
+        }
+        let is_focusable = match node
+            .find_attribute_by_name("tabIndex")
+            .and_then(|tabindex| tabindex_value(&tabindex))
+        {
+            Some(tabindex) => tabindex >= 0,
+            // The `tabIndex` is missing or its value is unknown
+            None => is_natively_focusable(node),
+        };
+        if is_focusable {
+            node.find_attribute_by_name("aria-hidden")
+        } else {
+            None
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, attribute: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                attribute.range(),
+                markup! {
+                    "Don't use "<Emphasis>"aria-hidden"</Emphasis>" on a focusable element."
+                },
+            )
+            .note(markup! {
+                "The element can still be reached with the keyboard, but it is hidden from the screen readers."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, attribute: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        if attribute.syntax().has_trailing_comments() {
+            let prev_token = attribute.syntax().first_token()?.prev_token()?;
+            let new_token = prev_token
+                .append_trivia_pieces(attribute.syntax().last_trailing_trivia()?.pieces());
+            mutation.replace_token_discard_trivia(prev_token, new_token);
+        }
+        mutation.remove_node(attribute.clone());
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Remove the "<Emphasis>"aria-hidden"</Emphasis>" attribute." }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the value of the `tabIndex` attribute if it is a literal integer.
+fn tabindex_value(attribute: &JsxAttribute) -> Option<i32> {
+    let text = match attribute.initializer()?.value().ok()? {
+        AnyJsxAttributeValue::JsxString(string) => string.inner_string_text().ok()?.to_string(),
+        AnyJsxAttributeValue::JsxExpressionAttributeValue(value) => {
+            match value.expression().ok()? {
+                AnyJsExpression::JsUnaryExpression(expression)
+                    if expression.is_signed_numeric_literal().ok()? =>
+                {
+                    expression.text()
+                }
+                expression => expression.as_static_value()?.text().to_string(),
+            }
+        }
+        AnyJsxAttributeValue::AnyJsxTag(_) => return None,
+    };
+    text.trim().parse().ok()
+}
@@ -0,0 +1,191 @@
+use bpaf::Bpaf;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_js_syntax::{
+    AnyFunctionLike, JsAwaitExpression, JsDoWhileStatement, JsForInStatement, JsForOfStatement,
+    JsForStatement, JsWhileStatement,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow `await` inside loops.
+    ///
+    /// Awaiting in each iteration of a loop runs the asynchronous operations one after the other.
+    /// When the iterations don't depend on each other,
+    /// it's often faster to start all the operations and to await them together with `Promise.all()`.
+    ///
+    /// The rule reports `await` expressions in the body of `for`, `for...in`, `for...of`, `while`, and `do...while` loops,
+    /// as well as in the test and the update of `for`, `while`, and `do...while` loops.
+    /// `await` expressions inside a function declared in a loop are ignored,
+    /// and so are `for await...of` loops, which are meant to be sequential.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-await-in-loop
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// async function fetchAll(urls) {
+    ///     const responses = [];
+    ///     for (const url of urls) {
+    ///         responses.push(await fetch(url));
+    ///     }
+    ///     return responses;
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// async function poll() {
+    ///     while (await isPending()) {}
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function fetchAll(urls) {
+    ///     return await Promise.all(urls.map((url) => fetch(url)));
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// async function readAll(stream) {
+    ///     for await (const chunk of stream) {
+    ///         await write(chunk);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `ignoreForOf` to ignore `await` expressions in the body of `for...of` loops.
+    /// Its default value is `false`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "ignoreForOf": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoAwaitInLoop {
+        version: "next",
+        name: "noAwaitInLoop",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noAwaitInLoop`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AwaitInLoopOptions {
+    /// Whether `await` expressions in the body of `for...of` loops are ignored.
+    #[bpaf(hide)]
+    pub ignore_for_of: bool,
+}
+
+impl AwaitInLoopOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["ignoreForOf"];
+}
+
+// Required by [Bpaf].
+impl FromStr for AwaitInLoopOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for AwaitInLoopOptions {}
+impl VisitNode<JsonLanguage> for AwaitInLoopOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "ignoreForOf" {
+            self.ignore_for_of = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
+impl Rule for NoAwaitInLoop {
+    type Query = Ast<JsAwaitExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = AwaitInLoopOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let ignore_for_of = ctx.options().ignore_for_of;
+        let mut child = ctx.query().syntax().clone();
+        for ancestor in ctx.query().syntax().ancestors().skip(1) {
+            if AnyFunctionLike::can_cast(ancestor.kind()) {
+                return None;
+            }
+            let is_in_loop = if let Some(for_stmt) = JsForStatement::cast_ref(&ancestor) {
+                // The initializer is evaluated only once
+                for_stmt
+                    .initializer()
+                    .map_or(true, |initializer| initializer.syntax() != &child)
+            } else if let Some(for_of_stmt) = JsForOfStatement::cast_ref(&ancestor) {
+                // `for await...of` loops are sequential by design
+                if for_of_stmt.await_token().is_some() {
+                    return None;
+                }
+                !ignore_for_of && for_of_stmt.body().is_ok_and(|body| body.syntax() == &child)
+            } else if let Some(for_in_stmt) = JsForInStatement::cast_ref(&ancestor) {
+                for_in_stmt.body().is_ok_and(|body| body.syntax() == &child)
+            } else {
+                JsWhileStatement::can_cast(ancestor.kind())
+                    || JsDoWhileStatement::can_cast(ancestor.kind())
+            };
+            if is_in_loop {
+                return Some(());
+            }
+            child = ancestor;
+        }
+        None
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().await_token().ok()?.text_trimmed_range(),
+                markup! {
+                    "Avoid "<Emphasis>"await"</Emphasis>" inside a loop."
+                },
+            )
+            .note(markup! {
+                "Each iteration waits for the previous one to complete."
+            })
+            .note(markup! {
+                "If the iterations don't depend on each other, collect the promises and await them together with "<Emphasis>"Promise.all()"</Emphasis>"."
+            }),
+        )
+    }
+}
@@ -0,0 +1,178 @@
+use bpaf::Bpaf;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_js_syntax::{AnyJsExportClause, AnyJsModuleItem, JsExport, JsModule};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow modules that only re-export other modules.
+    ///
+    /// A barrel file is a module that only contains `export ... from` statements.
+    /// Importing a single name from a barrel file makes bundlers and test runners load
+    /// all the re-exported modules, which slows down builds and tests.
+    ///
+    /// The rule reports a module when all its statements are re-exports
+    /// and when it has more re-exports than the value of the option `threshold`.
+    /// Type-only re-exports such as `export type { A } from "./a"` are ignored,
+    /// because they are erased at compile time.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// export * from "./utils";
+    /// export { parse } from "./parser";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// export * from "./utils";
+    /// ```
+    ///
+    /// ```js
+    /// export { parse } from "./parser";
+    /// export const format = (value) => String(value);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `threshold` to change the number of re-exports that a barrel file can have.
+    /// Its default value is `1`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "threshold": 5
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoBarrelFile {
+        version: "next",
+        name: "noBarrelFile",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noBarrelFile`.
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BarrelFileOptions {
+    /// The number of re-exports that a barrel file can have.
+    #[bpaf(hide)]
+    pub threshold: u16,
+}
+
+impl BarrelFileOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["threshold"];
+}
+
+impl Default for BarrelFileOptions {
+    fn default() -> Self {
+        Self { threshold: 1 }
+    }
+}
+
+// Required by [Bpaf].
+impl FromStr for BarrelFileOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for BarrelFileOptions {}
+impl VisitNode<JsonLanguage> for BarrelFileOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "threshold" {
+            self.threshold = self.map_to_u16(&value, name_text, u16::MAX, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
+pub(crate) struct BarrelFile {
+    /// The first re-export of the module.
+    first_export: JsExport,
+    /// The number of re-exports of the module.
+    export_count: usize,
+}
+
+impl Rule for NoBarrelFile {
+    type Query = Ast<JsModule>;
+    type State = BarrelFile;
+    type Signals = Option<Self::State>;
+    type Options = BarrelFileOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut first_export = None;
+        let mut export_count = 0;
+        for item in ctx.query().items() {
+            let AnyJsModuleItem::JsExport(export) = item else {
+                return None;
+            };
+            let is_type_only = match export.export_clause().ok()? {
+                AnyJsExportClause::JsExportFromClause(clause) => clause.type_token().is_some(),
+                AnyJsExportClause::JsExportNamedFromClause(clause) => clause.type_token().is_some(),
+                _ => return None,
+            };
+            if !is_type_only {
+                export_count += 1;
+                first_export.get_or_insert(export);
+            }
+        }
+        if export_count <= usize::from(ctx.options().threshold) {
+            return None;
+        }
+        Some(BarrelFile {
+            first_export: first_export?,
+            export_count,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let export_count = state.export_count;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.first_export.range(),
+                markup! {
+                    "This module is a barrel file: it only contains "{export_count}" re-exports."
+                },
+            )
+            .note(markup! {
+                "Importing from a barrel file loads all the re-exported modules, which slows down bundlers and test runners."
+            })
+            .note(markup! {
+                "Import directly from the re-exported modules instead."
+            }),
+        )
+    }
+}
@@ -0,0 +1,192 @@
+use crate::JsRuleAction;
+use bpaf::Bpaf;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    AnyTsType, JsSyntaxKind, TsIntersectionTypeElementList, TsParenthesizedType,
+    TsUnionTypeVariantList, TsVoidType, T,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow `void` in unions, intersections, and type arguments.
+    ///
+    /// The `void` type means that the value of an expression is ignored,
+    /// such as the return value of a function.
+    /// In a union such as `number | void`, or in a type argument such as `Array<void>`,
+    /// `void` is confusing: the type of a value that can be missing is `undefined`.
+    ///
+    /// The rule allows `void` alone, such as the return type of a function.
+    ///
+    /// Source: https://typescript-eslint.io/rules/no-invalid-void-type
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// let value: number | void;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// function parse(input: string): Result<void> {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// function log(message: string): void {}
+    /// let value: number | undefined;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule doesn't fix the code by default,
+    /// because `undefined` isn't a drop-in replacement for `void` in every type.
+    /// Set the option `fixToUndefined` to `true` to suggest replacing `void` with `undefined`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "fixToUndefined": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoConfusingVoidType {
+        version: "next",
+        name: "noConfusingVoidType",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noConfusingVoidType`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConfusingVoidTypeOptions {
+    /// Whether the rule suggests replacing `void` with `undefined`.
+    #[bpaf(hide)]
+    pub fix_to_undefined: bool,
+}
+
+impl ConfusingVoidTypeOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["fixToUndefined"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ConfusingVoidTypeOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ConfusingVoidTypeOptions {}
+impl VisitNode<JsonLanguage> for ConfusingVoidTypeOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "fixToUndefined" {
+            self.fix_to_undefined = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum VoidTypeContext {
+    Union,
+    Intersection,
+    TypeArgument,
+}
+
+impl Rule for NoConfusingVoidType {
+    type Query = Ast<TsVoidType>;
+    type State = VoidTypeContext;
+    type Signals = Option<Self::State>;
+    type Options = ConfusingVoidTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut node = ctx.query().syntax().clone();
+        // `(void) | number`
+        while let Some(parent) = node
+            .parent()
+            .filter(|parent| TsParenthesizedType::can_cast(parent.kind()))
+        {
+            node = parent;
+        }
+        let parent = node.parent()?;
+        match parent.kind() {
+            JsSyntaxKind::TS_UNION_TYPE_VARIANT_LIST => {
+                // `type T = | void` is `void` alone
+                (TsUnionTypeVariantList::cast(parent)?.len() > 1).then_some(VoidTypeContext::Union)
+            }
+            JsSyntaxKind::TS_INTERSECTION_TYPE_ELEMENT_LIST => {
+                (TsIntersectionTypeElementList::cast(parent)?.len() > 1)
+                    .then_some(VoidTypeContext::Intersection)
+            }
+            JsSyntaxKind::TS_TYPE_ARGUMENT_LIST => Some(VoidTypeContext::TypeArgument),
+            _ => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let title = match state {
+            VoidTypeContext::Union => markup! {
+                <Emphasis>"void"</Emphasis>" is confusing inside a union type."
+            },
+            VoidTypeContext::Intersection => markup! {
+                <Emphasis>"void"</Emphasis>" is confusing inside an intersection type."
+            },
+            VoidTypeContext::TypeArgument => markup! {
+                <Emphasis>"void"</Emphasis>" is confusing as a type argument."
+            },
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), ctx.query().range(), title).note(markup! {
+                <Emphasis>"void"</Emphasis>" is only valid as a return type. Use "<Emphasis>"undefined"</Emphasis>" for a value that can be missing."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        if !ctx.options().fix_to_undefined {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyTsType::from(ctx.query().clone()),
+            AnyTsType::from(make::ts_undefined_type(make::token(T![undefined]))),
+        );
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>"undefined"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
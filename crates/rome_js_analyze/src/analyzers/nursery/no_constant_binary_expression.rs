@@ -0,0 +1,353 @@
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, AnyJsTemplateElement, JsBinaryExpression,
+    JsBinaryOperator, JsLogicalExpression, JsLogicalOperator, JsUnaryOperator,
+};
+use rome_rowan::{declare_node_union, AstNode, AstNodeList, TextRange};
+
+declare_rule! {
+    /// Disallow expressions where the operation doesn't affect the value.
+    ///
+    /// Some binary and logical expressions always evaluate to the same result
+    /// or short-circuit in the same way, regardless of the value of their operands.
+    /// This is often the sign of a logic mistake, such as a misunderstanding of operator precedence.
+    ///
+    /// The rule reports:
+    ///
+    /// - logical expressions `a || b` and `a && b` where `a` is always truthy or always falsy;
+    /// - logical expressions `a ?? b` where `a` is never nullish or always nullish;
+    /// - strict comparisons with a newly created object, array, function, or regular expression,
+    ///   which is never equal to another value, and loose comparisons of two of them;
+    /// - comparisons of `null` with an operand that is never nullish or always nullish;
+    /// - comparisons of two literals;
+    /// - strict comparisons of a negation, which is always a boolean, with a boolean literal.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-constant-binary-expression
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const value1 = {} || "default";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const value2 = +x ?? "default";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const isEmpty = x === [];
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const isNull = !x == null;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const isSame = "abc" === "ABC";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const isTruthy = !!x === true;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const value1 = x || "default";
+    /// const value2 = x ?? "default";
+    /// const isEmpty = x.length === 0;
+    /// const isNull = x == null;
+    /// const isTruthy = Boolean(x);
+    /// ```
+    ///
+    pub(crate) NoConstantBinaryExpression {
+        version: "next",
+        name: "noConstantBinaryExpression",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyJsBinaryOrLogicalExpression = JsBinaryExpression | JsLogicalExpression
+}
+
+pub(crate) enum ConstantBinaryExpression {
+    /// The left operand of `||` or `&&` is always truthy or always falsy.
+    ConstantTruthiness(TextRange),
+    /// The left operand of `??` is never nullish or always nullish.
+    ConstantNullishness(TextRange),
+    /// An operand of a strict equality comparison, or both operands of a loose one, is a newly created object.
+    NewlyCreatedObject(TextRange),
+    /// An operand compared with `null` is never nullish or always nullish.
+    ConstantNullComparison(TextRange),
+    /// Both operands of a comparison are literals.
+    LiteralComparison,
+    /// A negation, which is always a boolean, is strictly compared with a boolean literal.
+    RedundantBooleanComparison(TextRange),
+}
+
+impl Rule for NoConstantBinaryExpression {
+    type Query = Ast<AnyJsBinaryOrLogicalExpression>;
+    type State = ConstantBinaryExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        match ctx.query() {
+            AnyJsBinaryOrLogicalExpression::JsLogicalExpression(expr) => {
+                let left = expr.left().ok()?;
+                match expr.operator().ok()? {
+                    JsLogicalOperator::LogicalOr | JsLogicalOperator::LogicalAnd => {
+                        has_constant_truthiness(&left)
+                            .then(|| ConstantBinaryExpression::ConstantTruthiness(left.range()))
+                    }
+                    JsLogicalOperator::NullishCoalescing => has_constant_nullishness(&left)
+                        .then(|| ConstantBinaryExpression::ConstantNullishness(left.range())),
+                }
+            }
+            AnyJsBinaryOrLogicalExpression::JsBinaryExpression(expr) => {
+                let operator = expr.operator().ok()?;
+                if !matches!(
+                    operator,
+                    JsBinaryOperator::Equality
+                        | JsBinaryOperator::Inequality
+                        | JsBinaryOperator::StrictEquality
+                        | JsBinaryOperator::StrictInequality
+                ) {
+                    return None;
+                }
+                let left = expr.left().ok()?;
+                let right = expr.right().ok()?;
+                if is_literal(&left) && is_literal(&right) {
+                    return Some(ConstantBinaryExpression::LiteralComparison);
+                }
+                let is_strict = matches!(
+                    operator,
+                    JsBinaryOperator::StrictEquality | JsBinaryOperator::StrictInequality
+                );
+                for (operand, other) in [(&left, &right), (&right, &left)] {
+                    if is_strict && is_boolean_literal(other) && is_negation(operand) {
+                        return Some(ConstantBinaryExpression::RedundantBooleanComparison(
+                            operand.range(),
+                        ));
+                    }
+                    // `{} == x` converts the object to a primitive, so it can be equal to `x`
+                    if is_newly_created_object(operand)
+                        && (is_strict || is_newly_created_object(other))
+                    {
+                        return Some(ConstantBinaryExpression::NewlyCreatedObject(
+                            operand.range(),
+                        ));
+                    }
+                    if is_null(other) && has_constant_nullishness(operand) {
+                        return Some(ConstantBinaryExpression::ConstantNullComparison(
+                            operand.range(),
+                        ));
+                    }
+                }
+                None
+            }
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let diagnostic = match state {
+            ConstantBinaryExpression::ConstantTruthiness(range) => {
+                let AnyJsBinaryOrLogicalExpression::JsLogicalExpression(expr) = node else {
+                    return None;
+                };
+                let operator = expr.operator_token().ok()?;
+                let operator = operator.text_trimmed();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    node.range(),
+                    markup! {
+                        "Unexpected constant truthiness on the left-hand side of a "<Emphasis>{operator}</Emphasis>" expression."
+                    },
+                )
+                .detail(
+                    range,
+                    markup! {
+                        "This operand is always truthy or always falsy."
+                    },
+                )
+            }
+            ConstantBinaryExpression::ConstantNullishness(range) => RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "Unexpected constant nullishness on the left-hand side of a "<Emphasis>"??"</Emphasis>" expression."
+                },
+            )
+            .detail(
+                range,
+                markup! {
+                    "This operand is never nullish or always nullish."
+                },
+            ),
+            ConstantBinaryExpression::NewlyCreatedObject(range) => RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "Unexpected comparison to a newly created object."
+                },
+            )
+            .detail(
+                range,
+                markup! {
+                    "This value is a new object that is never equal to another value."
+                },
+            ),
+            ConstantBinaryExpression::ConstantNullComparison(range) => RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "This comparison with "<Emphasis>"null"</Emphasis>" always evaluates to the same value."
+                },
+            )
+            .detail(
+                range,
+                markup! {
+                    "This operand is never nullish or always nullish."
+                },
+            ),
+            ConstantBinaryExpression::LiteralComparison => RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "This comparison of two literals always evaluates to the same value."
+                },
+            ),
+            ConstantBinaryExpression::RedundantBooleanComparison(range) => RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "This comparison with a boolean literal is redundant."
+                },
+            )
+            .detail(
+                range,
+                markup! {
+                    "This operand is always a boolean."
+                },
+            )
+            .note(markup! {
+                "Use the operand or its negation directly."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns `true` if `expr` is always truthy or always falsy.
+fn has_constant_truthiness(expr: &AnyJsExpression) -> bool {
+    match expr.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(_)
+        | AnyJsExpression::JsArrayExpression(_)
+        | AnyJsExpression::JsArrowFunctionExpression(_)
+        | AnyJsExpression::JsClassExpression(_)
+        | AnyJsExpression::JsFunctionExpression(_)
+        | AnyJsExpression::JsNewExpression(_)
+        | AnyJsExpression::JsObjectExpression(_)
+        | AnyJsExpression::JsxTagExpression(_) => true,
+        AnyJsExpression::JsTemplateExpression(template) => {
+            // An empty template is always falsy, and a template with a chunk is always truthy.
+            template.tag().is_none() && {
+                let elements = template.elements();
+                elements.is_empty()
+                    || elements.iter().any(|element| {
+                        matches!(element, AnyJsTemplateElement::JsTemplateChunkElement(_))
+                    })
+            }
+        }
+        AnyJsExpression::JsUnaryExpression(unary) => match unary.operator() {
+            Ok(JsUnaryOperator::Void | JsUnaryOperator::Typeof) => true,
+            Ok(JsUnaryOperator::LogicalNot) => unary
+                .argument()
+                .is_ok_and(|argument| has_constant_truthiness(&argument)),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Returns `true` if `expr` is never nullish or always nullish.
+fn has_constant_nullishness(expr: &AnyJsExpression) -> bool {
+    match expr.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(_)
+        | AnyJsExpression::JsArrayExpression(_)
+        | AnyJsExpression::JsArrowFunctionExpression(_)
+        | AnyJsExpression::JsBinaryExpression(_)
+        | AnyJsExpression::JsClassExpression(_)
+        | AnyJsExpression::JsFunctionExpression(_)
+        | AnyJsExpression::JsInExpression(_)
+        | AnyJsExpression::JsInstanceofExpression(_)
+        | AnyJsExpression::JsNewExpression(_)
+        | AnyJsExpression::JsObjectExpression(_)
+        | AnyJsExpression::JsPostUpdateExpression(_)
+        | AnyJsExpression::JsPreUpdateExpression(_)
+        | AnyJsExpression::JsUnaryExpression(_)
+        | AnyJsExpression::JsxTagExpression(_) => true,
+        // A tag function can return any value
+        AnyJsExpression::JsTemplateExpression(template) => template.tag().is_none(),
+        _ => false,
+    }
+}
+
+/// Returns `true` if `expr` creates a new object that is never equal to another value.
+fn is_newly_created_object(expr: &AnyJsExpression) -> bool {
+    matches!(
+        expr.clone().omit_parentheses(),
+        AnyJsExpression::JsArrayExpression(_)
+            | AnyJsExpression::JsArrowFunctionExpression(_)
+            | AnyJsExpression::JsClassExpression(_)
+            | AnyJsExpression::JsFunctionExpression(_)
+            | AnyJsExpression::JsObjectExpression(_)
+            | AnyJsExpression::AnyJsLiteralExpression(
+                AnyJsLiteralExpression::JsRegexLiteralExpression(_)
+            )
+    )
+}
+
+/// Returns `true` if `expr` is a literal that is not a regular expression.
+fn is_literal(expr: &AnyJsExpression) -> bool {
+    matches!(
+        expr.clone().omit_parentheses(),
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsBigintLiteralExpression(_)
+                | AnyJsLiteralExpression::JsBooleanLiteralExpression(_)
+                | AnyJsLiteralExpression::JsNullLiteralExpression(_)
+                | AnyJsLiteralExpression::JsNumberLiteralExpression(_)
+                | AnyJsLiteralExpression::JsStringLiteralExpression(_)
+        )
+    )
+}
+
+fn is_boolean_literal(expr: &AnyJsExpression) -> bool {
+    matches!(
+        expr.clone().omit_parentheses(),
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsBooleanLiteralExpression(_)
+        )
+    )
+}
+
+fn is_negation(expr: &AnyJsExpression) -> bool {
+    matches!(
+        expr.clone().omit_parentheses(),
+        AnyJsExpression::JsUnaryExpression(unary)
+            if unary.operator() == Ok(JsUnaryOperator::LogicalNot)
+    )
+}
+
+fn is_null(expr: &AnyJsExpression) -> bool {
+    matches!(
+        expr.clone().omit_parentheses(),
+        AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsNullLiteralExpression(_))
+    )
+}
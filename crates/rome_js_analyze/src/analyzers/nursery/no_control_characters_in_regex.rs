@@ -19,10 +19,14 @@ declare_rule! {
     /// - Hexadecimal character escapes from `\x00` to `\x1F`
     /// - Unicode character escapes from `\u0000` to `\u001F`
     /// - Unicode code point escapes from `\u{0}` to `\u{1F}`
-    /// - Unescaped raw characters from U+0000 to U+001F
+    /// - Unescaped raw characters from U+0000 to U+001F, except the tab and the line terminators
     ///
     /// Control escapes such as `\t` and `\n` are allowed by this rule.
     ///
+    /// The pattern of `new RegExp()` is checked when it is a string literal.
+    /// The escape sequences of the string are interpreted first,
+    /// so both `new RegExp("\x0C")` and `new RegExp("\\x0C")` are reported.
+    ///
     /// Source: https://eslint.org/docs/latest/rules/no-control-regex
     ///
     /// ## Examples
@@ -130,7 +134,7 @@ fn add_control_character_to_vec(
 /// - Unicode character escapes from `\u0000` to `\u001F`.
 /// - Unicode code point escapes range from `\u{0}` to `\u{1F}`.
 ///     - The Unicode flag must be set as true in order for these Unicode code point escapes to work: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/unicode.
-/// - Unescaped raw characters from U+0000 to U+001F.
+/// - Unescaped raw characters from U+0000 to U+001F, except the tab and the line terminators.
 fn collect_control_characters(pattern: String, flags: Option<String>) -> Option<Vec<String>> {
     let mut control_characters: Vec<String> = Vec::new();
     let is_unicode_flag_set = flags.unwrap_or_default().contains('u');
@@ -157,9 +161,11 @@ fn collect_control_characters(pattern: String, flags: Option<String>) -> Option<
                     &mut control_characters,
                     decode_unicode_escape_to_code_point,
                 ),
-                Some('\\') => continue,
-                _ => break,
+                _ => continue,
             },
+            // Tabs and line terminators are common in the patterns created from strings
+            '\t' | '\n' | '\r' => continue,
+            c if c < '\u{20}' => control_characters.push(format!("\\x{:02X}", c as u32)),
             _ => continue,
         }
     }
@@ -265,5 +271,13 @@ mod tests {
             collect_control_characters(String::from("\\x20\\u0020\\u{20}\\t\\n"), None),
             None
         );
+        assert_eq!(
+            collect_control_characters(String::from("\\d\\x1F\u{1}"), None),
+            Some(vec![String::from("\\x1F"), String::from("\\x01")])
+        );
+        assert_eq!(
+            collect_control_characters(String::from("\t\n\r"), None),
+            None
+        );
     }
 }
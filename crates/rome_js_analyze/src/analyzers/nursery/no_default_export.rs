@@ -0,0 +1,216 @@
+use crate::JsRuleAction;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    inner_string_text, AnyJsDeclarationClause, AnyJsExportClause, AnyJsExportDefaultDeclaration,
+    JsExport, JsExportDefaultDeclarationClause, JsExportDefaultExpressionClause,
+    JsExportNamedFromSpecifier, JsExportNamedSpecifier, JsLiteralExportName, JsSyntaxToken,
+    TextRange,
+};
+use rome_rowan::{declare_node_union, AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow default exports.
+    ///
+    /// Default exports cannot be easily discovered inside an editor:
+    /// they cannot be suggested by the editor when the user tries to import a name.
+    /// The module that imports a default export must also choose a name for it,
+    /// so the same export can be imported under different names across a code base,
+    /// which makes renaming and searching harder.
+    ///
+    /// The rule reports `export default` declarations and expressions,
+    /// and the exports renamed to `default` such as `export { a as default }`.
+    ///
+    /// The code fix turns a named default function or class into a named export.
+    /// The modules that import the default export must then be updated.
+    ///
+    /// Source: https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-default-export.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// export default function f() {}
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// export default class {}
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// export default 1;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function f() {}
+    /// export { f as default };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// export function f() {}
+    /// export class C {}
+    /// export { f as g };
+    /// ```
+    ///
+    pub(crate) NoDefaultExport {
+        version: "next",
+        name: "noDefaultExport",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyJsDefaultExport =
+        JsExportDefaultDeclarationClause
+        | JsExportDefaultExpressionClause
+        | JsExportNamedSpecifier
+        | JsExportNamedFromSpecifier
+}
+
+impl Rule for NoDefaultExport {
+    type Query = Ast<AnyJsDefaultExport>;
+    /// The range of `export default`, or of the exported name `default`.
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        match ctx.query() {
+            AnyJsDefaultExport::JsExportDefaultDeclarationClause(clause) => {
+                export_default_range(&clause.parent::<JsExport>()?, &clause.default_token().ok()?)
+            }
+            AnyJsDefaultExport::JsExportDefaultExpressionClause(clause) => {
+                export_default_range(&clause.parent::<JsExport>()?, &clause.default_token().ok()?)
+            }
+            AnyJsDefaultExport::JsExportNamedSpecifier(specifier) => {
+                default_export_name_range(&specifier.exported_name().ok()?)
+            }
+            AnyJsDefaultExport::JsExportNamedFromSpecifier(specifier) => {
+                let exported_name = match specifier.export_as() {
+                    Some(export_as) => export_as.exported_name().ok()?,
+                    None => specifier.source_name().ok()?,
+                };
+                default_export_name_range(&exported_name)
+            }
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Avoid "<Emphasis>"default"</Emphasis>" exports."
+                },
+            )
+            .note(markup! {
+                "Default exports cannot be suggested by the editors, and can be imported under different names."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let AnyJsDefaultExport::JsExportDefaultDeclarationClause(clause) = ctx.query() else {
+            return None;
+        };
+        let declaration: AnyJsDeclarationClause = match clause.declaration().ok()? {
+            AnyJsExportDefaultDeclaration::JsFunctionExportDefaultDeclaration(function) => {
+                let mut new_function = make::js_function_declaration(
+                    function.function_token().ok()?,
+                    function.id()?,
+                    function.parameters().ok()?,
+                    function.body().ok()?,
+                );
+                if let Some(async_token) = function.async_token() {
+                    new_function = new_function.with_async_token(async_token);
+                }
+                if let Some(star_token) = function.star_token() {
+                    new_function = new_function.with_star_token(star_token);
+                }
+                if let Some(type_parameters) = function.type_parameters() {
+                    new_function = new_function.with_type_parameters(type_parameters);
+                }
+                if let Some(return_type_annotation) = function.return_type_annotation() {
+                    new_function = new_function.with_return_type_annotation(return_type_annotation);
+                }
+                new_function.build().into()
+            }
+            AnyJsExportDefaultDeclaration::JsClassExportDefaultDeclaration(class) => {
+                let mut new_class = make::js_class_declaration(
+                    class.decorators(),
+                    class.class_token().ok()?,
+                    class.id()?,
+                    class.l_curly_token().ok()?,
+                    class.members(),
+                    class.r_curly_token().ok()?,
+                );
+                if let Some(abstract_token) = class.abstract_token() {
+                    new_class = new_class.with_abstract_token(abstract_token);
+                }
+                if let Some(type_parameters) = class.type_parameters() {
+                    new_class = new_class.with_type_parameters(type_parameters);
+                }
+                if let Some(extends_clause) = class.extends_clause() {
+                    new_class = new_class.with_extends_clause(extends_clause);
+                }
+                if let Some(implements_clause) = class.implements_clause() {
+                    new_class = new_class.with_implements_clause(implements_clause);
+                }
+                new_class.build().into()
+            }
+            AnyJsExportDefaultDeclaration::TsDeclareFunctionExportDefaultDeclaration(function) => {
+                let mut new_function = make::ts_declare_function_declaration(
+                    function.function_token().ok()?,
+                    function.id()?,
+                    function.parameters().ok()?,
+                );
+                if let Some(async_token) = function.async_token() {
+                    new_function = new_function.with_async_token(async_token);
+                }
+                if let Some(type_parameters) = function.type_parameters() {
+                    new_function = new_function.with_type_parameters(type_parameters);
+                }
+                if let Some(return_type_annotation) = function.return_type_annotation() {
+                    new_function = new_function.with_return_type_annotation(return_type_annotation);
+                }
+                if let Some(semicolon_token) = function.semicolon_token() {
+                    new_function = new_function.with_semicolon_token(semicolon_token);
+                }
+                new_function.build().into()
+            }
+            AnyJsExportDefaultDeclaration::TsInterfaceDeclaration(interface) => interface.into(),
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsExportClause::from(clause.clone()),
+            AnyJsExportClause::AnyJsDeclarationClause(declaration),
+        );
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use a named export instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the range of `export default`.
+fn export_default_range(export: &JsExport, default_token: &JsSyntaxToken) -> Option<TextRange> {
+    Some(TextRange::new(
+        export.export_token().ok()?.text_trimmed_range().start(),
+        default_token.text_trimmed_range().end(),
+    ))
+}
+
+/// Returns the range of `name` if it is `default`.
+fn default_export_name_range(name: &JsLiteralExportName) -> Option<TextRange> {
+    let value = name.value().ok()?;
+    (inner_string_text(&value).text() == "default").then(|| value.text_trimmed_range())
+}
@@ -0,0 +1,349 @@
+use crate::JsRuleAction;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    AnyJsBinding, AnyJsImportClause, AnyJsNamedImport, AnyJsNamedImportSpecifier, JsImport,
+    JsLanguage, JsModule, JsSyntaxKind, JsSyntaxToken, TriviaPieceKind, T,
+};
+use rome_rowan::{AstNode, AstNodeList, AstSeparatedList, BatchMutationExt};
+use rustc_hash::FxHashMap;
+
+declare_rule! {
+    /// Disallow duplicate imports of the same module.
+    ///
+    /// The imports of a module should be grouped in a single `import` declaration.
+    ///
+    /// The rule reports the second and the later declarations that import the same module as an earlier one,
+    /// when both declarations can be merged.
+    /// A namespace import can't be merged with named imports, and a module has only one default export,
+    /// so these imports are ignored.
+    /// The type-only imports are only merged with other type-only imports,
+    /// and the imports without bindings, such as `import "polyfill"`, are only merged with each other.
+    /// The imports with attributes are ignored.
+    ///
+    /// The code fix moves the bindings of the duplicate import into the first one.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-duplicate-imports
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { merge } from "module";
+    /// import { find } from "module";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import defaultExport from "module";
+    /// import { find } from "module";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import defaultExport, { merge, find } from "module";
+    /// ```
+    ///
+    /// ```js
+    /// import * as namespace from "module";
+    /// import { merge } from "module";
+    /// ```
+    ///
+    /// ```ts
+    /// import type { Options } from "module";
+    /// import { merge } from "module";
+    /// ```
+    ///
+    pub(crate) NoDuplicateImports {
+        version: "next",
+        name: "noDuplicateImports",
+        recommended: false,
+    }
+}
+
+pub(crate) struct DuplicateImport {
+    /// The first import of the module.
+    first: JsImport,
+    /// The import that can be merged into `first`.
+    duplicate: JsImport,
+}
+
+impl Rule for NoDuplicateImports {
+    type Query = Ast<JsModule>;
+    type State = DuplicateImport;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut signals = Vec::new();
+        let mut imports_by_module: FxHashMap<(String, ImportKind), Vec<(JsImport, ImportParts)>> =
+            FxHashMap::default();
+        for import in ctx
+            .query()
+            .items()
+            .iter()
+            .filter_map(|item| item.as_js_import().cloned())
+        {
+            let Some((source, parts)) = ImportParts::from_import(&import) else {
+                continue;
+            };
+            let imports = imports_by_module.entry((source, parts.kind)).or_default();
+            match imports.iter().find(|(_, first)| first.can_merge(&parts)) {
+                Some((first, _)) => signals.push(DuplicateImport {
+                    first: first.clone(),
+                    duplicate: import,
+                }),
+                None => imports.push((import, parts)),
+            }
+        }
+        signals
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.duplicate.range(),
+                markup! {
+                    "This module is already imported."
+                },
+            )
+            .detail(
+                state.first.range(),
+                markup! {
+                    "The module is first imported here."
+                },
+            )
+            .note(markup! {
+                "Merge the imports of the same module in a single declaration."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let first_clause = state.first.import_clause().ok()?;
+        let (_, first) = ImportParts::from_import(&state.first)?;
+        let (_, duplicate) = ImportParts::from_import(&state.duplicate)?;
+        let mut mutation = ctx.root().begin();
+        if first.kind != ImportKind::Bare {
+            let merged = first.merge(duplicate).into_clause(&first_clause)?;
+            mutation.replace_node(first_clause, merged);
+        }
+        mutation.remove_node(state.duplicate.clone());
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Merge the duplicate import into the first one." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum ImportKind {
+    /// `import "module"`
+    Bare,
+    /// `import type { A } from "module"`
+    Type,
+    /// `import { a } from "module"`
+    Value,
+}
+
+/// The bindings of an import declaration.
+struct ImportParts {
+    kind: ImportKind,
+    default: Option<AnyJsBinding>,
+    namespace: Option<AnyJsBinding>,
+    named: Vec<AnyJsNamedImportSpecifier>,
+}
+
+impl ImportParts {
+    /// Returns the imported module and the bindings of `import`.
+    ///
+    /// Returns `None` for the imports with attributes and the imports with bogus nodes.
+    fn from_import(import: &JsImport) -> Option<(String, Self)> {
+        let mut parts = Self {
+            kind: ImportKind::Value,
+            default: None,
+            namespace: None,
+            named: Vec::new(),
+        };
+        let (type_token, source, assertion) = match import.import_clause().ok()? {
+            AnyJsImportClause::JsImportBareClause(clause) => {
+                parts.kind = ImportKind::Bare;
+                (None, clause.source(), clause.assertion())
+            }
+            AnyJsImportClause::JsImportDefaultClause(clause) => {
+                parts.default = Some(clause.local_name().ok()?);
+                (clause.type_token(), clause.source(), clause.assertion())
+            }
+            AnyJsImportClause::JsImportNamespaceClause(clause) => {
+                parts.namespace = Some(clause.local_name().ok()?);
+                (clause.type_token(), clause.source(), clause.assertion())
+            }
+            AnyJsImportClause::JsImportNamedClause(clause) => {
+                if let Some(default_specifier) = clause.default_specifier() {
+                    parts.default = Some(default_specifier.local_name().ok()?);
+                }
+                match clause.named_import().ok()? {
+                    AnyJsNamedImport::JsNamedImportSpecifiers(specifiers) => {
+                        for specifier in specifiers.specifiers().iter() {
+                            let specifier = specifier.ok()?;
+                            if matches!(
+                                specifier,
+                                AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_)
+                            ) {
+                                return None;
+                            }
+                            parts.named.push(specifier);
+                        }
+                    }
+                    AnyJsNamedImport::JsNamespaceImportSpecifier(specifier) => {
+                        parts.namespace = Some(specifier.local_name().ok()?);
+                    }
+                }
+                (clause.type_token(), clause.source(), clause.assertion())
+            }
+        };
+        if assertion.is_some() {
+            return None;
+        }
+        if type_token.is_some() {
+            parts.kind = ImportKind::Type;
+        }
+        let source = source.ok()?.inner_string_text().ok()?;
+        Some((source.text().to_string(), parts))
+    }
+
+    /// Checks if the bindings of `self` and `other` can be imported by a single declaration.
+    fn can_merge(&self, other: &Self) -> bool {
+        if self.kind != other.kind {
+            return false;
+        }
+        let has_default = self.default.is_some() || other.default.is_some();
+        let has_namespace = self.namespace.is_some() || other.namespace.is_some();
+        let has_named = !self.named.is_empty() || !other.named.is_empty();
+        if (self.default.is_some() && other.default.is_some())
+            || (self.namespace.is_some() && other.namespace.is_some())
+            || (has_namespace && has_named)
+        {
+            return false;
+        }
+        // A type-only import can't have both a default import and other bindings
+        !(self.kind == ImportKind::Type && has_default && (has_namespace || has_named))
+    }
+
+    fn merge(self, other: Self) -> Self {
+        let mut named = self.named;
+        named.extend(other.named);
+        Self {
+            kind: self.kind,
+            default: self.default.or(other.default),
+            namespace: self.namespace.or(other.namespace),
+            named,
+        }
+    }
+
+    /// Creates the clause that imports the bindings of `self`.
+    ///
+    /// The type token and the source of `original` are preserved.
+    fn into_clause(self, original: &AnyJsImportClause) -> Option<AnyJsImportClause> {
+        let (type_token, source) = match original {
+            AnyJsImportClause::JsImportBareClause(_) => return None,
+            AnyJsImportClause::JsImportDefaultClause(clause) => {
+                (clause.type_token(), clause.source().ok()?)
+            }
+            AnyJsImportClause::JsImportNamespaceClause(clause) => {
+                (clause.type_token(), clause.source().ok()?)
+            }
+            AnyJsImportClause::JsImportNamedClause(clause) => {
+                (clause.type_token(), clause.source().ok()?)
+            }
+        };
+        let default = self.default.and_then(trim_trivia);
+        let namespace = self.namespace.and_then(trim_trivia);
+        let clause = match (default, namespace, self.named.is_empty()) {
+            (Some(default), None, true) => {
+                let clause = make::js_import_default_clause(default, from_token(), source);
+                match type_token {
+                    Some(type_token) => clause.with_type_token(type_token).build(),
+                    None => clause.build(),
+                }
+                .into()
+            }
+            (None, Some(namespace), true) => {
+                let clause = make::js_import_namespace_clause(
+                    token_with_space(T![*]),
+                    token_with_space(T![as]),
+                    namespace,
+                    from_token(),
+                    source,
+                );
+                match type_token {
+                    Some(type_token) => clause.with_type_token(type_token).build(),
+                    None => clause.build(),
+                }
+                .into()
+            }
+            (default, namespace, _) => {
+                let named_import = match namespace {
+                    Some(namespace) => AnyJsNamedImport::JsNamespaceImportSpecifier(
+                        make::js_namespace_import_specifier(
+                            token_with_space(T![*]),
+                            token_with_space(T![as]),
+                            namespace,
+                        ),
+                    ),
+                    None => named_import_specifiers(self.named)?,
+                };
+                let clause = make::js_import_named_clause(named_import, from_token(), source);
+                let clause = match type_token {
+                    Some(type_token) => clause.with_type_token(type_token),
+                    None => clause,
+                };
+                match default {
+                    Some(default) => clause.with_default_specifier(
+                        make::js_default_import_specifier(default, token_with_space(T![,])),
+                    ),
+                    None => clause,
+                }
+                .build()
+                .into()
+            }
+        };
+        Some(clause)
+    }
+}
+
+/// Creates `{ a, b }` from the specifiers, removing their trivia.
+fn named_import_specifiers(specifiers: Vec<AnyJsNamedImportSpecifier>) -> Option<AnyJsNamedImport> {
+    let specifiers = specifiers
+        .into_iter()
+        .map(trim_trivia)
+        .collect::<Option<Vec<_>>>()?;
+    let separators: Vec<_> = (1..specifiers.len())
+        .map(|_| token_with_space(T![,]))
+        .collect();
+    Some(AnyJsNamedImport::JsNamedImportSpecifiers(
+        make::js_named_import_specifiers(
+            token_with_space(T!['{']),
+            make::js_named_import_specifier_list(specifiers, separators),
+            make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        ),
+    ))
+}
+
+fn token_with_space(kind: JsSyntaxKind) -> JsSyntaxToken {
+    make::token(kind).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+}
+
+fn from_token() -> JsSyntaxToken {
+    make::token_decorated_with_space(T![from])
+}
+
+fn trim_trivia<N: AstNode<Language = JsLanguage>>(node: N) -> Option<N> {
+    N::cast(node.into_syntax().trim_trivia()?)
+}
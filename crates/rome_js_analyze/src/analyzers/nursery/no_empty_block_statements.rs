@@ -0,0 +1,168 @@
+use bpaf::Bpaf;
+use rome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_js_syntax::{JsBlockStatement, JsCatchClause};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, AstNodeList, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow empty block statements.
+    ///
+    /// An empty block statement is usually a leftover of a refactoring, or code that was forgotten.
+    /// A block that contains a comment isn't empty: use a comment to explain why the block is empty.
+    ///
+    /// The bodies of functions and the `static` blocks of classes are not block statements,
+    /// so they are ignored.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-empty
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// if (foo) {}
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// try {
+    ///     doSomething();
+    /// } finally {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// if (foo) {
+    ///     // Nothing to do
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// try {
+    ///     doSomething();
+    /// } catch {}
+    /// ```
+    ///
+    /// ```js
+    /// function noop() {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// By default, the empty `catch` clauses are allowed,
+    /// because they are a common way to ignore an error.
+    /// Set the option `allowEmptyCatch` to `false` to report them too.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowEmptyCatch": false
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoEmptyBlockStatements {
+        version: "next",
+        name: "noEmptyBlockStatements",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noEmptyBlockStatements`.
+#[derive(Deserialize, Serialize, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EmptyBlockStatementsOptions {
+    /// Whether the body of a `catch` clause may be empty.
+    #[bpaf(hide)]
+    pub allow_empty_catch: bool,
+}
+
+impl EmptyBlockStatementsOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["allowEmptyCatch"];
+}
+
+impl Default for EmptyBlockStatementsOptions {
+    fn default() -> Self {
+        Self {
+            allow_empty_catch: true,
+        }
+    }
+}
+
+// Required by [Bpaf].
+impl FromStr for EmptyBlockStatementsOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for EmptyBlockStatementsOptions {}
+impl VisitNode<JsonLanguage> for EmptyBlockStatementsOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "allowEmptyCatch" {
+            self.allow_empty_catch = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
+impl Rule for NoEmptyBlockStatements {
+    type Query = Ast<JsBlockStatement>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = EmptyBlockStatementsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let block = ctx.query();
+        if !block.statements().is_empty()
+            || block.l_curly_token().ok()?.has_trailing_comments()
+            || block.r_curly_token().ok()?.has_leading_comments()
+        {
+            return None;
+        }
+        if ctx.options().allow_empty_catch && block.parent::<JsCatchClause>().is_some() {
+            return None;
+        }
+        Some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Unexpected empty block."
+                },
+            )
+            .note(markup! {
+                "Empty blocks are usually the result of an incomplete refactoring. Remove the empty block or add a comment inside it if it is intentional."
+            }),
+        )
+    }
+}
@@ -0,0 +1,125 @@
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::JsRegexLiteralExpression;
+use rome_rowan::AstNode;
+
+declare_rule! {
+    /// Disallow empty character classes in regular expression literals.
+    ///
+    /// The empty character class `[]` doesn't match any character,
+    /// so the regular expression that contains it never matches.
+    /// The negated empty character class `[^]` matches any character, including the line terminators.
+    /// Both are usually mistakes.
+    ///
+    /// The escaped brackets, such as in `/\[]/`, are not character classes.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-empty-character-class
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const regex = /^abc[]/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const regex = /[^]/;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const regex = /^abc[a-z]/;
+    /// const brackets = /\[]/;
+    /// const closingBracket = /[\]]/;
+    /// ```
+    ///
+    pub(crate) NoEmptyCharacterClass {
+        version: "next",
+        name: "noEmptyCharacterClass",
+        recommended: false,
+    }
+}
+
+pub(crate) enum EmptyCharacterClass {
+    /// `[]`
+    Empty,
+    /// `[^]`
+    Negated,
+}
+
+impl Rule for NoEmptyCharacterClass {
+    type Query = Ast<JsRegexLiteralExpression>;
+    type State = EmptyCharacterClass;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let value_token = ctx.query().value_token().ok()?;
+        let text = value_token.text_trimmed();
+        let end_slash_position = text.rfind('/')?;
+        let is_unicode_sets = text[end_slash_position..].contains('v');
+        find_empty_character_class(&text[..end_slash_position], is_unicode_sets)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            EmptyCharacterClass::Empty => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This regular expression contains an empty character class "<Emphasis>"[]"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "An empty character class doesn't match any character, so the regular expression never matches."
+            }),
+            EmptyCharacterClass::Negated => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This regular expression contains a negated empty character class "<Emphasis>"[^]"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "A negated empty character class matches any character. Use "<Emphasis>"[\\s\\S]"</Emphasis>" or the "<Emphasis>"s"</Emphasis>" flag with "<Emphasis>"."</Emphasis>" to make the intent explicit."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns the first empty character class of `pattern`.
+///
+/// `pattern` is the regex literal without its flags, starting with the opening slash.
+/// With the `v` flag, character classes can be nested.
+fn find_empty_character_class(pattern: &str, is_unicode_sets: bool) -> Option<EmptyCharacterClass> {
+    // The nesting level of character classes
+    let mut class_depth = 0u32;
+    let mut chars = pattern.chars().skip(1).peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' if class_depth == 0 || is_unicode_sets => {
+                class_depth += 1;
+                let is_negated = chars.next_if_eq(&'^').is_some();
+                if chars.peek() == Some(&']') {
+                    return Some(if is_negated {
+                        EmptyCharacterClass::Negated
+                    } else {
+                        EmptyCharacterClass::Empty
+                    });
+                }
+            }
+            ']' if class_depth > 0 => {
+                class_depth -= 1;
+            }
+            _ => {}
+        }
+    }
+    None
+}
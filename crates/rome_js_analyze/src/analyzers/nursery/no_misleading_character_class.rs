@@ -0,0 +1,338 @@
+use crate::JsRuleAction;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_syntax::{JsRegexLiteralExpression, JsSyntaxKind, JsSyntaxToken};
+use rome_rowan::{AstNode, BatchMutationExt};
+use std::iter::Peekable;
+use std::str::Chars;
+
+declare_rule! {
+    /// Disallow characters made of multiple code units in the character classes of regular expressions.
+    ///
+    /// Without the `u` flag, a regular expression matches UTF-16 code units, not characters.
+    /// A character outside of the Basic Multilingual Plane, such as most emojis, is a pair of code units:
+    /// in a character class, each unit of the pair is matched on its own.
+    /// Similarly, a character followed by a combining mark, such as `Á` written as `A` followed by U+0301,
+    /// is two characters, and a character class matches only one of them.
+    ///
+    /// The rule reports the regular expression literals without the `u` and `v` flags
+    /// whose character classes contain a surrogate pair, or a character followed by a combining mark.
+    /// The rule recognizes the marks of the Combining Diacritical Marks blocks and the variation selectors.
+    ///
+    /// The code fix adds the `u` flag to the regular expressions that contain a surrogate pair,
+    /// when the rule doesn't find a part of the pattern whose meaning depends on the flag.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-misleading-character-class
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// /^[👍]$/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /^[Á]$/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /^[\uD83D\uDC4D]$/;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /^[abc]$/;
+    /// /^[👍]$/u;
+    /// /^👍$/;
+    /// ```
+    ///
+    pub(crate) NoMisleadingCharacterClass {
+        version: "next",
+        name: "noMisleadingCharacterClass",
+        recommended: false,
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum MisleadingCharacter {
+    /// A character made of two UTF-16 code units
+    SurrogatePair,
+    /// A character followed by a combining mark
+    CombiningMark,
+}
+
+impl Rule for NoMisleadingCharacterClass {
+    type Query = Ast<JsRegexLiteralExpression>;
+    type State = MisleadingCharacter;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let value_token = ctx.query().value_token().ok()?;
+        let (pattern, flags) = split_regex(value_token.text_trimmed())?;
+        if flags.contains(['u', 'v']) {
+            return None;
+        }
+        find_misleading_character(pattern)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            MisleadingCharacter::SurrogatePair => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This character class contains a character made of a surrogate pair."
+                },
+            )
+            .note(markup! {
+                "Without the "<Emphasis>"u"</Emphasis>" flag, the character class matches each half of the pair on its own."
+            }),
+            MisleadingCharacter::CombiningMark => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This character class contains a character followed by a combining mark."
+                },
+            )
+            .note(markup! {
+                "The character class matches the character and the combining mark on their own, not the combined character."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        if *state != MisleadingCharacter::SurrogatePair {
+            return None;
+        }
+        let value_token = ctx.query().value_token().ok()?;
+        let text = value_token.text_trimmed();
+        let (pattern, flags) = split_regex(text)?;
+        if !can_add_unicode_flag(pattern, flags) {
+            return None;
+        }
+        let new_token = JsSyntaxToken::new_detached(
+            JsSyntaxKind::JS_REGEX_LITERAL,
+            &format!("{text}u"),
+            [],
+            [],
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(value_token, new_token);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Add the "<Emphasis>"u"</Emphasis>" flag." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Splits a regex literal into its pattern, without the slashes, and its flags.
+fn split_regex(text: &str) -> Option<(&str, &str)> {
+    let end_slash_position = text.rfind('/')?;
+    Some((
+        text.get(1..end_slash_position)?,
+        &text[end_slash_position + 1..],
+    ))
+}
+
+/// Returns the first misleading character of the character classes of `pattern`.
+fn find_misleading_character(pattern: &str) -> Option<MisleadingCharacter> {
+    let mut is_in_class = false;
+    // The previous character of the current character class
+    let mut previous: Option<u32> = None;
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if !is_in_class {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '[' => {
+                    is_in_class = true;
+                    chars.next_if_eq(&'^');
+                    previous = None;
+                }
+                _ => {}
+            }
+            continue;
+        }
+        let current = match c {
+            ']' => {
+                is_in_class = false;
+                continue;
+            }
+            '\\' => match chars.next() {
+                Some('u') => parse_hex(&mut chars, 4),
+                Some('x') => parse_hex(&mut chars, 2),
+                _ => None,
+            },
+            // The bounds of a range are not adjacent characters
+            '-' => None,
+            _ => Some(c as u32),
+        };
+        if let Some(current) = current {
+            if current > 0xFFFF
+                || (is_low_surrogate(current) && previous.is_some_and(is_high_surrogate))
+            {
+                return Some(MisleadingCharacter::SurrogatePair);
+            }
+            if is_combining_mark(current) && previous.is_some() {
+                return Some(MisleadingCharacter::CombiningMark);
+            }
+        }
+        previous = current;
+    }
+    None
+}
+
+/// Checks if adding the `u` flag keeps the meaning of the rest of `pattern`.
+///
+/// The check is conservative: the flag changes the case-insensitive matching,
+/// the dot and the negated character classes, and it rejects the escapes and the braces
+/// that are allowed without it.
+fn can_add_unicode_flag(pattern: &str, flags: &str) -> bool {
+    if flags.contains('i') {
+        return false;
+    }
+    let mut is_in_class = false;
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                let Some(escaped) = chars.next() else {
+                    return false;
+                };
+                let is_valid = match escaped {
+                    '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{'
+                    | '}' | '|' | '/' => true,
+                    'd' | 'D' | 'w' | 'W' | 's' | 'S' | 'b' | 'B' | 'n' | 'r' | 't' | 'f' | 'v' => {
+                        true
+                    }
+                    '-' => is_in_class,
+                    '0' => !chars.peek().is_some_and(char::is_ascii_digit),
+                    'c' => chars.next_if(char::is_ascii_alphabetic).is_some(),
+                    'x' => parse_hex(&mut chars, 2).is_some(),
+                    'u' => parse_hex(&mut chars, 4).is_some(),
+                    _ => false,
+                };
+                if !is_valid {
+                    return false;
+                }
+            }
+            '[' if !is_in_class => {
+                is_in_class = true;
+                if chars.next_if_eq(&'^').is_some() {
+                    return false;
+                }
+            }
+            ']' if is_in_class => {
+                is_in_class = false;
+            }
+            '.' | ']' | '}' if !is_in_class => return false,
+            '{' if !is_in_class => {
+                if !skip_quantifier_bounds(&mut chars) {
+                    return false;
+                }
+            }
+            // A quantifier applies to the whole character with the flag
+            _ if !is_in_class && c as u32 > 0xFFFF => return false,
+            _ => {}
+        }
+    }
+    true
+}
+
+/// Skips the bounds of a quantifier `{n}`, `{n,}` or `{n,m}`, after the opening brace.
+fn skip_quantifier_bounds(chars: &mut Peekable<Chars>) -> bool {
+    let mut has_min = false;
+    while chars.next_if(char::is_ascii_digit).is_some() {
+        has_min = true;
+    }
+    if chars.next_if_eq(&',').is_some() {
+        while chars.next_if(char::is_ascii_digit).is_some() {}
+    }
+    has_min && chars.next_if_eq(&'}').is_some()
+}
+
+/// Parses a code unit written with exactly `digits` hexadecimal digits.
+fn parse_hex(chars: &mut Peekable<Chars>, digits: u32) -> Option<u32> {
+    let mut value = 0;
+    for _ in 0..digits {
+        let digit = chars.next_if(char::is_ascii_hexdigit)?.to_digit(16)?;
+        value = value * 16 + digit;
+    }
+    Some(value)
+}
+
+fn is_high_surrogate(code_unit: u32) -> bool {
+    (0xD800..=0xDBFF).contains(&code_unit)
+}
+
+fn is_low_surrogate(code_unit: u32) -> bool {
+    (0xDC00..=0xDFFF).contains(&code_unit)
+}
+
+fn is_combining_mark(code_point: u32) -> bool {
+    matches!(
+        code_point,
+        // Combining Diacritical Marks
+        0x0300..=0x036F
+        // Combining Diacritical Marks Extended
+        | 0x1AB0..=0x1AFF
+        // Combining Diacritical Marks Supplement
+        | 0x1DC0..=0x1DFF
+        // Combining Diacritical Marks for Symbols
+        | 0x20D0..=0x20FF
+        // Variation Selectors
+        | 0xFE00..=0xFE0F
+        // Combining Half Marks
+        | 0xFE20..=0xFE2F
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_misleading_character() {
+        assert_eq!(
+            find_misleading_character("[👍]"),
+            Some(MisleadingCharacter::SurrogatePair)
+        );
+        assert_eq!(
+            find_misleading_character("[\\uD83D\\uDC4D]"),
+            Some(MisleadingCharacter::SurrogatePair)
+        );
+        assert_eq!(
+            find_misleading_character("[A\u{301}]"),
+            Some(MisleadingCharacter::CombiningMark)
+        );
+        assert_eq!(
+            find_misleading_character("[A\\u0301]"),
+            Some(MisleadingCharacter::CombiningMark)
+        );
+        assert_eq!(find_misleading_character("👍[a]"), None);
+        assert_eq!(find_misleading_character("\\[👍]"), None);
+        assert_eq!(find_misleading_character("[\\uD83D]\\uDC4D"), None);
+        assert_eq!(find_misleading_character("[\u{301}]"), None);
+    }
+
+    #[test]
+    fn test_can_add_unicode_flag() {
+        assert!(can_add_unicode_flag("^[👍]+$", "g"));
+        assert!(can_add_unicode_flag("[👍]{1,2}\\d\\u0041", ""));
+        assert!(!can_add_unicode_flag("[👍]", "i"));
+        assert!(!can_add_unicode_flag("[👍].", ""));
+        assert!(!can_add_unicode_flag("[^👍]", ""));
+        assert!(!can_add_unicode_flag("[👍]\\a", ""));
+        assert!(!can_add_unicode_flag("[👍]{", ""));
+        assert!(!can_add_unicode_flag("[👍]👍+", ""));
+    }
+}
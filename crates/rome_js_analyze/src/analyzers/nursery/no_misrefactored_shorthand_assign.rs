@@ -0,0 +1,145 @@
+use crate::JsRuleAction;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    AnyJsExpression, JsAssignmentExpression, JsAssignmentOperator, JsSyntaxToken, JsSyntaxTrivia,
+    JsUnaryExpression, JsUnaryOperator, T,
+};
+use rome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow a unary operator directly following the `=` of an assignment.
+    ///
+    /// `x =- y` is parsed as `x = -y`.
+    /// However, the spacing suggests that a compound assignment such as `x -= y` was intended.
+    ///
+    /// The rule reports assignments where the unary operator `-`, `+`, `~`, or `!`
+    /// directly follows `=`, while `=` is preceded by a whitespace.
+    ///
+    /// The code fix turns `=-` into `-=` and `=+` into `+=`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// x =- y;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// x =+ y;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// x =! y;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// x -= y;
+    /// x = -y;
+    /// x=-y;
+    /// ```
+    ///
+    pub(crate) NoMisrefactoredShorthandAssign {
+        version: "next",
+        name: "noMisrefactoredShorthandAssign",
+        recommended: false,
+    }
+}
+
+impl Rule for NoMisrefactoredShorthandAssign {
+    type Query = Ast<JsAssignmentExpression>;
+    /// The unary expression assigned.
+    type State = JsUnaryExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.operator().ok()? != JsAssignmentOperator::Assign {
+            return None;
+        }
+        let AnyJsExpression::JsUnaryExpression(unary) = node.right().ok()? else {
+            return None;
+        };
+        if !matches!(
+            unary.operator().ok()?,
+            JsUnaryOperator::Minus
+                | JsUnaryOperator::Plus
+                | JsUnaryOperator::BitwiseNot
+                | JsUnaryOperator::LogicalNot
+        ) {
+            return None;
+        }
+        let assign_token = node.operator_token().ok()?;
+        let unary_token = unary.operator_token().ok()?;
+        let is_spaced_before = has_whitespace(assign_token.leading_trivia())
+            || has_whitespace(node.left().ok()?.syntax().last_token()?.trailing_trivia());
+        let is_attached_after =
+            assign_token.trailing_trivia().is_empty() && unary_token.leading_trivia().is_empty();
+        (is_spaced_before && is_attached_after).then_some(unary)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, unary: &Self::State) -> Option<RuleDiagnostic> {
+        let unary_operator = unary.operator_token().ok()?;
+        let unary_operator = unary_operator.text_trimmed();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            ctx.query().operator_token().ok()?.text_trimmed_range(),
+            markup! {
+                "The operator "<Emphasis>{unary_operator}</Emphasis>" directly follows "<Emphasis>"="</Emphasis>"."
+            },
+        );
+        let diagnostic = match compound_operator(unary) {
+            Some(compound_operator) => diagnostic.note(markup! {
+                "Did you mean "<Emphasis>{compound_operator.text_trimmed()}</Emphasis>"? Otherwise, add a space between "<Emphasis>"="</Emphasis>" and "<Emphasis>{unary_operator}</Emphasis>"."
+            }),
+            None => diagnostic.note(markup! {
+                "Add a space between "<Emphasis>"="</Emphasis>" and "<Emphasis>{unary_operator}</Emphasis>" to make the intent clear."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, unary: &Self::State) -> Option<JsRuleAction> {
+        let assign_token = ctx.query().operator_token().ok()?;
+        let unary_token = unary.operator_token().ok()?;
+        let compound_operator = compound_operator(unary)?
+            .with_leading_trivia_pieces(assign_token.leading_trivia().pieces())
+            .with_trailing_trivia_pieces(unary_token.trailing_trivia().pieces());
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_discard_trivia(assign_token, compound_operator.clone());
+        mutation.replace_node_discard_trivia(
+            AnyJsExpression::from(unary.clone()),
+            unary.argument().ok()?,
+        );
+        let compound_operator = compound_operator.text_trimmed();
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>{compound_operator}</Emphasis>" instead." }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the compound assignment operator that was likely intended, if any.
+fn compound_operator(unary: &JsUnaryExpression) -> Option<JsSyntaxToken> {
+    match unary.operator().ok()? {
+        JsUnaryOperator::Minus => Some(make::token(T![-=])),
+        JsUnaryOperator::Plus => Some(make::token(T![+=])),
+        _ => None,
+    }
+}
+
+fn has_whitespace(trivia: JsSyntaxTrivia) -> bool {
+    trivia
+        .pieces()
+        .any(|piece| piece.is_whitespace() || piece.is_newline())
+}
@@ -0,0 +1,151 @@
+use crate::JsRuleAction;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_syntax::{AnyJsExpression, TsNonNullAssertionExpression};
+use rome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow non-null assertions after an optional chain.
+    ///
+    /// An optional chain evaluates to `undefined` when it short-circuits.
+    /// Asserting that the result of an optional chain isn't `null` or `undefined`
+    /// defeats the purpose of the optional chain, and hides a possible `undefined` value.
+    ///
+    /// A non-null assertion in the middle of an optional chain, such as in `a?.b!.c`,
+    /// doesn't apply to the short-circuited value, so it is ignored.
+    ///
+    /// Source: https://typescript-eslint.io/rules/no-non-null-asserted-optional-chain
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// obj?.prop!;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// (obj?.prop)!.length;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// obj?.method()!;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// obj?.prop;
+    /// obj!.prop;
+    /// obj?.prop!.length;
+    /// ```
+    ///
+    pub(crate) NoNonNullAssertedOptionalChain {
+        version: "next",
+        name: "noNonNullAssertedOptionalChain",
+        recommended: false,
+    }
+}
+
+impl Rule for NoNonNullAssertedOptionalChain {
+    type Query = Ast<TsNonNullAssertionExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let expression = node.expression().ok()?;
+        if let AnyJsExpression::JsParenthesizedExpression(_) = expression {
+            // The parentheses end the chain
+            return is_optional_chain(&expression.omit_parentheses()).then_some(());
+        }
+        if !is_optional_chain(&expression) {
+            return None;
+        }
+        // The non-null assertion is in the middle of the chain: `a?.b!.c`
+        let continues_chain = match node.parent::<AnyJsExpression>() {
+            Some(AnyJsExpression::JsStaticMemberExpression(member)) => {
+                member.object().ok()?.syntax() == node.syntax()
+            }
+            Some(AnyJsExpression::JsComputedMemberExpression(member)) => {
+                member.object().ok()?.syntax() == node.syntax()
+            }
+            Some(AnyJsExpression::JsCallExpression(call)) => {
+                call.callee().ok()?.syntax() == node.syntax()
+            }
+            _ => false,
+        };
+        (!continues_chain).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let excl_token = ctx.query().excl_token().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                excl_token.text_trimmed_range(),
+                markup! {
+                    "Forbidden non-null assertion after an optional chain."
+                },
+            )
+            .note(markup! {
+                "The optional chain can return "<Emphasis>"undefined"</Emphasis>", and the non-null assertion hides it."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let excl_token = node.excl_token().ok()?;
+        // Keep the comments attached to `!`
+        let trivia: Vec<_> = excl_token
+            .leading_trivia()
+            .pieces()
+            .chain(excl_token.trailing_trivia().pieces())
+            .collect();
+        let expression = node.expression().ok()?.append_trivia_pieces(trivia)?;
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(node.clone()), expression);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Remove the non-null assertion." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Checks if `expression` is part of an optional chain that can short-circuit.
+///
+/// The non-null assertions don't end the chain, but the parentheses do: `(a?.b).c` isn't an optional chain.
+fn is_optional_chain(expression: &AnyJsExpression) -> bool {
+    let mut current = Some(expression.clone());
+    while let Some(expression) = current {
+        current = match expression {
+            AnyJsExpression::JsCallExpression(call) => {
+                if call.is_optional() {
+                    return true;
+                }
+                call.callee().ok()
+            }
+            AnyJsExpression::JsStaticMemberExpression(member) => {
+                if member.is_optional() {
+                    return true;
+                }
+                member.object().ok()
+            }
+            AnyJsExpression::JsComputedMemberExpression(member) => {
+                if member.is_optional() {
+                    return true;
+                }
+                member.object().ok()
+            }
+            AnyJsExpression::TsNonNullAssertionExpression(assertion) => assertion.expression().ok(),
+            _ => return false,
+        }
+    }
+    false
+}
@@ -0,0 +1,145 @@
+use bpaf::Bpaf;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_js_syntax::JsExportFromClause;
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::SyntaxNode;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow `export * from` re-exports.
+    ///
+    /// Re-exporting all the exports of a module makes it hard for bundlers to eliminate unused code,
+    /// because the re-exported names are only known after resolving the other module.
+    /// It can also cause name collisions when several modules are re-exported from the same barrel file.
+    ///
+    /// Type-only re-exports such as `export type * from "./types"` are ignored,
+    /// because they are erased at compile time.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// export * from "./utils";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// export * as utils from "./utils";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// export { parse, stringify } from "./utils";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `allowNamedExportAll` to allow re-exporting all the exports of a module under a namespace,
+    /// such as `export * as utils from "./utils"`.
+    /// Its default value is `false`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowNamedExportAll": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoReExportAll {
+        version: "next",
+        name: "noReExportAll",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noReExportAll`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ReExportAllOptions {
+    /// Whether `export * as namespace from` re-exports are allowed.
+    #[bpaf(hide)]
+    pub allow_named_export_all: bool,
+}
+
+impl ReExportAllOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["allowNamedExportAll"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ReExportAllOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ReExportAllOptions {}
+impl VisitNode<JsonLanguage> for ReExportAllOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "allowNamedExportAll" {
+            self.allow_named_export_all = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
+impl Rule for NoReExportAll {
+    type Query = Ast<JsExportFromClause>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ReExportAllOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.type_token().is_some()
+            || (node.export_as().is_some() && ctx.options().allow_named_export_all)
+        {
+            return None;
+        }
+        Some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().star_token().ok()?.text_trimmed_range(),
+                markup! {
+                    "Avoid re-exporting all the exports of a module."
+                },
+            )
+            .note(markup! {
+                "Bundlers have to resolve the re-exported module to know which names are exported, which makes unused code harder to eliminate."
+            })
+            .note(markup! {
+                "Re-export the used names explicitly, for example with "<Emphasis>"export { name } from"</Emphasis>"."
+            }),
+        )
+    }
+}
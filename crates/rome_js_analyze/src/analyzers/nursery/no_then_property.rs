@@ -0,0 +1,140 @@
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::{
+    AnyJsClassMemberName, AnyJsObjectMemberName, JsGetterClassMember, JsGetterObjectMember,
+    JsLiteralMemberName, JsMethodClassMember, JsMethodObjectMember, JsPropertyClassMember,
+    JsPropertyObjectMember,
+};
+use rome_rowan::{declare_node_union, AstNode};
+
+declare_rule! {
+    /// Disallow `then` property.
+    ///
+    /// An object with a `then` method is a _thenable_:
+    /// `await` and `Promise.resolve()` call its `then` method
+    /// instead of treating the object as a regular value.
+    /// Defining a `then` property can thus lead to surprising behaviors.
+    ///
+    /// The rule reports properties, methods, and getters named `then`
+    /// in object literals and classes.
+    /// Computed member names are ignored.
+    ///
+    /// Source: https://github.com/sindresorhus/eslint-plugin-unicorn/blob/main/docs/rules/no-thenable.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const foo = {
+    ///     then() {}
+    /// };
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const foo = {
+    ///     then: () => {}
+    /// };
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// class Foo {
+    ///     then() {}
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const foo = {
+    ///     thenable: true
+    /// };
+    /// foo.then = () => {};
+    /// ```
+    ///
+    pub(crate) NoThenProperty {
+        version: "next",
+        name: "noThenProperty",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyThenableMember =
+        JsPropertyObjectMember
+        | JsMethodObjectMember
+        | JsGetterObjectMember
+        | JsPropertyClassMember
+        | JsMethodClassMember
+        | JsGetterClassMember
+}
+
+impl AnyThenableMember {
+    /// Returns the name of the member if it is not computed.
+    fn literal_name(&self) -> Option<JsLiteralMemberName> {
+        match self {
+            AnyThenableMember::JsPropertyObjectMember(member) => {
+                object_literal_name(member.name().ok()?)
+            }
+            AnyThenableMember::JsMethodObjectMember(member) => {
+                object_literal_name(member.name().ok()?)
+            }
+            AnyThenableMember::JsGetterObjectMember(member) => {
+                object_literal_name(member.name().ok()?)
+            }
+            AnyThenableMember::JsPropertyClassMember(member) => {
+                class_literal_name(member.name().ok()?)
+            }
+            AnyThenableMember::JsMethodClassMember(member) => {
+                class_literal_name(member.name().ok()?)
+            }
+            AnyThenableMember::JsGetterClassMember(member) => {
+                class_literal_name(member.name().ok()?)
+            }
+        }
+    }
+}
+
+impl Rule for NoThenProperty {
+    type Query = Ast<AnyThenableMember>;
+    /// The name of the member.
+    type State = JsLiteralMemberName;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let name = ctx.query().literal_name()?;
+        (name.name().ok()?.text() == "then").then_some(name)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                name.range(),
+                markup! {
+                    "Do not add a "<Emphasis>"then"</Emphasis>" property."
+                },
+            )
+            .note(markup! {
+                "An object with a "<Emphasis>"then"</Emphasis>" method is treated as a promise by "<Emphasis>"await"</Emphasis>" and "<Emphasis>"Promise.resolve()"</Emphasis>"."
+            }),
+        )
+    }
+}
+
+fn object_literal_name(name: AnyJsObjectMemberName) -> Option<JsLiteralMemberName> {
+    match name {
+        AnyJsObjectMemberName::JsLiteralMemberName(name) => Some(name),
+        AnyJsObjectMemberName::JsComputedMemberName(_) => None,
+    }
+}
+
+fn class_literal_name(name: AnyJsClassMemberName) -> Option<JsLiteralMemberName> {
+    match name {
+        AnyJsClassMemberName::JsLiteralMemberName(name) => Some(name),
+        AnyJsClassMemberName::JsComputedMemberName(_)
+        | AnyJsClassMemberName::JsPrivateClassMemberName(_) => None,
+    }
+}
@@ -0,0 +1,422 @@
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::JsRegexLiteralExpression;
+use rome_rowan::AstNode;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+declare_rule! {
+    /// Disallow backreferences that always match the empty string.
+    ///
+    /// A backreference matches the text matched by a capturing group.
+    /// When the group can't have matched anything at the time the backreference is matched,
+    /// the backreference matches the empty string, and it is useless.
+    ///
+    /// The rule reports the backreferences:
+    /// - inside the group they refer to, such as `/(a\1)/`;
+    /// - to a group in another alternative, such as `/(a)|\1/`;
+    /// - before the group they refer to, such as `/\1(a)/`,
+    ///   or after the group in a lookbehind, where the pattern is matched from right to left;
+    /// - to a group inside a negative lookaround, such as `/(?!(a))\1/`.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-useless-backreference
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// /(a\1)/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /(a)|\1b/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /\k<foo>(?<foo>a)/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /(?!(a))\1/;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /(a)\1/;
+    /// /(?<foo>a)\k<foo>/;
+    /// /(?<=\1(a))b/;
+    /// /(?:(a)|b)\1/;
+    /// ```
+    ///
+    pub(crate) NoUselessBackreferenceInRegex {
+        version: "next",
+        name: "noUselessBackreferenceInRegex",
+        recommended: false,
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum UselessBackreferenceKind {
+    /// The backreference is inside the group
+    Nested,
+    /// The backreference and the group are in different alternatives
+    Disjunctive,
+    /// The backreference is matched before the group
+    Forward,
+    /// The backreference is matched before the group, in a lookbehind
+    Backward,
+    /// The group is inside a negative lookaround that doesn't contain the backreference
+    IntoNegativeLookaround,
+}
+
+pub(crate) struct UselessBackreference {
+    kind: UselessBackreferenceKind,
+    /// The text of the backreference, such as `\1`
+    text: String,
+}
+
+impl Rule for NoUselessBackreferenceInRegex {
+    type Query = Ast<JsRegexLiteralExpression>;
+    type State = UselessBackreference;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Ok(value_token) = ctx.query().value_token() else {
+            return Vec::new();
+        };
+        let text = value_token.text_trimmed();
+        let Some(end_slash_position) = text.rfind('/') else {
+            return Vec::new();
+        };
+        let is_unicode_sets = text[end_slash_position..].contains('v');
+        let Some(pattern) = text.get(1..end_slash_position) else {
+            return Vec::new();
+        };
+        RegexTree::parse(pattern, is_unicode_sets)
+            .find_useless_backreferences()
+            .into_iter()
+            .map(|(kind, range)| UselessBackreference {
+                kind,
+                text: pattern[range.0..range.1].to_string(),
+            })
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let backreference = &state.text;
+        let title = match state.kind {
+            UselessBackreferenceKind::Nested => markup! {
+                "The backreference "<Emphasis>{backreference}</Emphasis>" is inside the group it refers to."
+            },
+            UselessBackreferenceKind::Disjunctive => markup! {
+                "The backreference "<Emphasis>{backreference}</Emphasis>" refers to a group in another alternative."
+            },
+            UselessBackreferenceKind::Forward => markup! {
+                "The backreference "<Emphasis>{backreference}</Emphasis>" appears before the group it refers to."
+            },
+            UselessBackreferenceKind::Backward => markup! {
+                "The backreference "<Emphasis>{backreference}</Emphasis>" appears after the group it refers to, in a lookbehind."
+            },
+            UselessBackreferenceKind::IntoNegativeLookaround => markup! {
+                "The backreference "<Emphasis>{backreference}</Emphasis>" refers to a group inside a negative lookaround."
+            },
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), ctx.query().range(), title).note(markup! {
+                "The group can't have matched when the backreference is matched, so the backreference always matches the empty string."
+            }),
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum GroupKind {
+    /// The whole pattern
+    Pattern,
+    Capturing,
+    NonCapturing,
+    Lookahead {
+        is_negative: bool,
+    },
+    Lookbehind {
+        is_negative: bool,
+    },
+}
+
+#[derive(Debug)]
+enum RegexNode {
+    Group {
+        kind: GroupKind,
+        parent: Option<usize>,
+    },
+    /// An alternative of a group
+    Alternative { parent: usize },
+}
+
+#[derive(Debug)]
+enum BackreferenceTarget {
+    Index(u32),
+    Name(String),
+}
+
+#[derive(Debug)]
+struct Backreference {
+    target: BackreferenceTarget,
+    /// The alternative that contains the backreference
+    alternative: usize,
+    /// The byte range of the backreference in the pattern
+    range: (usize, usize),
+}
+
+#[derive(Debug)]
+struct CapturingGroup {
+    name: Option<String>,
+    node: usize,
+    /// The byte range of the group in the pattern
+    range: (usize, usize),
+}
+
+/// The groups, the alternatives and the backreferences of a regex pattern.
+#[derive(Debug, Default)]
+struct RegexTree {
+    nodes: Vec<RegexNode>,
+    /// The capturing groups in the order of their opening parentheses
+    capturing_groups: Vec<CapturingGroup>,
+    backreferences: Vec<Backreference>,
+}
+
+impl RegexTree {
+    fn parse(pattern: &str, is_unicode_sets: bool) -> Self {
+        let mut tree = Self::default();
+        tree.nodes.push(RegexNode::Group {
+            kind: GroupKind::Pattern,
+            parent: None,
+        });
+        let mut chars = pattern.char_indices().peekable();
+        tree.parse_alternatives(&mut chars, 0, pattern, is_unicode_sets);
+        tree
+    }
+
+    /// Parses the alternatives of `group` until its closing parenthesis.
+    fn parse_alternatives(
+        &mut self,
+        chars: &mut Peekable<CharIndices>,
+        group: usize,
+        pattern: &str,
+        is_unicode_sets: bool,
+    ) {
+        let mut alternative = self.push_node(RegexNode::Alternative { parent: group });
+        while let Some((start, c)) = chars.next() {
+            match c {
+                '|' => {
+                    alternative = self.push_node(RegexNode::Alternative { parent: group });
+                }
+                ')' => return,
+                '(' => {
+                    let (kind, name) = if chars.next_if(|(_, c)| *c == '?').is_some() {
+                        match chars.next().map(|(_, c)| c) {
+                            Some('=') => (GroupKind::Lookahead { is_negative: false }, None),
+                            Some('!') => (GroupKind::Lookahead { is_negative: true }, None),
+                            Some('<') => match chars.peek().map(|(_, c)| *c) {
+                                Some('=') => {
+                                    chars.next();
+                                    (GroupKind::Lookbehind { is_negative: false }, None)
+                                }
+                                Some('!') => {
+                                    chars.next();
+                                    (GroupKind::Lookbehind { is_negative: true }, None)
+                                }
+                                _ => (GroupKind::Capturing, Some(parse_group_name(chars))),
+                            },
+                            _ => (GroupKind::NonCapturing, None),
+                        }
+                    } else {
+                        (GroupKind::Capturing, None)
+                    };
+                    let node = self.push_node(RegexNode::Group {
+                        kind,
+                        parent: Some(alternative),
+                    });
+                    let capturing_group_index = (kind == GroupKind::Capturing).then(|| {
+                        self.capturing_groups.push(CapturingGroup {
+                            name,
+                            node,
+                            range: (start, pattern.len()),
+                        });
+                        self.capturing_groups.len() - 1
+                    });
+                    self.parse_alternatives(chars, node, pattern, is_unicode_sets);
+                    if let Some(index) = capturing_group_index {
+                        let end = chars.peek().map_or(pattern.len(), |(end, _)| *end);
+                        self.capturing_groups[index].range.1 = end;
+                    }
+                }
+                '[' => skip_character_class(chars, is_unicode_sets),
+                '\\' => match chars.next() {
+                    Some((_, digit @ '1'..='9')) => {
+                        let mut index = digit.to_digit(10).unwrap_or_default();
+                        while let Some((_, digit)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                            index = index
+                                .saturating_mul(10)
+                                .saturating_add(digit.to_digit(10).unwrap_or_default());
+                        }
+                        let end = chars.peek().map_or(pattern.len(), |(end, _)| *end);
+                        self.backreferences.push(Backreference {
+                            target: BackreferenceTarget::Index(index),
+                            alternative,
+                            range: (start, end),
+                        });
+                    }
+                    Some((_, 'k')) if chars.next_if(|(_, c)| *c == '<').is_some() => {
+                        let name = parse_group_name(chars);
+                        let end = chars.peek().map_or(pattern.len(), |(end, _)| *end);
+                        self.backreferences.push(Backreference {
+                            target: BackreferenceTarget::Name(name),
+                            alternative,
+                            range: (start, end),
+                        });
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+    }
+
+    fn push_node(&mut self, node: RegexNode) -> usize {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    fn parent(&self, node: usize) -> Option<usize> {
+        match self.nodes[node] {
+            RegexNode::Group { parent, .. } => parent,
+            RegexNode::Alternative { parent } => Some(parent),
+        }
+    }
+
+    fn group_kind(&self, node: usize) -> Option<GroupKind> {
+        match self.nodes[node] {
+            RegexNode::Group { kind, .. } => Some(kind),
+            RegexNode::Alternative { .. } => None,
+        }
+    }
+
+    /// Returns the nodes from the root of the tree to `node`.
+    fn path(&self, node: usize) -> Vec<usize> {
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(parent) = self.parent(current) {
+            path.push(parent);
+            current = parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Returns the ranges of the useless backreferences, and why they are useless.
+    fn find_useless_backreferences(&self) -> Vec<(UselessBackreferenceKind, (usize, usize))> {
+        self.backreferences
+            .iter()
+            .filter_map(|backreference| {
+                let group = match &backreference.target {
+                    BackreferenceTarget::Index(index) => {
+                        // Without a group with this index, this is an octal escape
+                        self.capturing_groups
+                            .get(usize::try_from(*index).ok()?.checked_sub(1)?)?
+                    }
+                    BackreferenceTarget::Name(name) => self
+                        .capturing_groups
+                        .iter()
+                        .find(|group| group.name.as_ref() == Some(name))?,
+                };
+                let kind = self.useless_backreference_kind(backreference, group)?;
+                Some((kind, backreference.range))
+            })
+            .collect()
+    }
+
+    fn useless_backreference_kind(
+        &self,
+        backreference: &Backreference,
+        group: &CapturingGroup,
+    ) -> Option<UselessBackreferenceKind> {
+        let backreference_path = self.path(backreference.alternative);
+        let group_path = self.path(group.node);
+        if backreference_path.contains(&group.node) {
+            return Some(UselessBackreferenceKind::Nested);
+        }
+        let common_length = backreference_path
+            .iter()
+            .zip(&group_path)
+            .take_while(|(a, b)| a == b)
+            .count();
+        let common_ancestor = backreference_path[common_length - 1];
+        if self.group_kind(common_ancestor).is_some() {
+            // The paths go through different alternatives of the common group
+            return Some(UselessBackreferenceKind::Disjunctive);
+        }
+        // The innermost lookaround that contains both sets the direction of the matching
+        let is_in_lookbehind = backreference_path[..common_length]
+            .iter()
+            .rev()
+            .find_map(|node| match self.group_kind(*node) {
+                Some(GroupKind::Lookahead { .. }) => Some(false),
+                Some(GroupKind::Lookbehind { .. }) => Some(true),
+                _ => None,
+            })
+            .unwrap_or(false);
+        let is_before_group = backreference.range.0 < group.range.0;
+        if !is_in_lookbehind && is_before_group {
+            return Some(UselessBackreferenceKind::Forward);
+        }
+        if is_in_lookbehind && !is_before_group {
+            return Some(UselessBackreferenceKind::Backward);
+        }
+        let is_group_in_negative_lookaround = group_path[common_length..].iter().any(|node| {
+            matches!(
+                self.group_kind(*node),
+                Some(
+                    GroupKind::Lookahead { is_negative: true }
+                        | GroupKind::Lookbehind { is_negative: true }
+                )
+            )
+        });
+        is_group_in_negative_lookaround.then_some(UselessBackreferenceKind::IntoNegativeLookaround)
+    }
+}
+
+/// Parses a group name, after its opening angle bracket.
+fn parse_group_name(chars: &mut Peekable<CharIndices>) -> String {
+    chars
+        .by_ref()
+        .map(|(_, c)| c)
+        .take_while(|c| *c != '>')
+        .collect()
+}
+
+/// Skips a character class, after its opening bracket.
+///
+/// With the `v` flag, character classes can be nested.
+fn skip_character_class(chars: &mut Peekable<CharIndices>, is_unicode_sets: bool) {
+    let mut class_depth = 1u32;
+    while let Some((_, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' if is_unicode_sets => class_depth += 1,
+            ']' => {
+                class_depth -= 1;
+                if class_depth == 0 {
+                    return;
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use crate::JsRuleAction;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, JsBinaryExpression, JsBinaryOperator,
+    JsParenthesizedExpression, JsStringLiteralExpression, JsSyntaxKind, JsSyntaxToken,
+};
+use rome_rowan::{AstNode, BatchMutationExt, Direction};
+
+declare_rule! {
+    /// Disallow the concatenation of string literals.
+    ///
+    /// Concatenating two string literals is useless:
+    /// the literals can be combined into a single string literal.
+    ///
+    /// Concatenations that span several lines or that contain comments are ignored,
+    /// because they are often used to split long strings.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-useless-concat
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const a = "a" + "b";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const a = "a" + 'b' + "c";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const a = "a" + b;
+    /// const c = "a" + `b`;
+    /// const d = "a" +
+    ///     "b";
+    /// ```
+    ///
+    pub(crate) NoUselessConcat {
+        version: "next",
+        name: "noUselessConcat",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUselessConcat {
+    type Query = Ast<JsBinaryExpression>;
+    /// The string literals to merge, in source order.
+    type State = Vec<JsStringLiteralExpression>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        // Only report the outermost concatenation of string literals.
+        let parent = node
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .find(|x| !JsParenthesizedExpression::can_cast(x.kind()))
+            .and_then(JsBinaryExpression::cast);
+        if parent.is_some_and(|parent| collect_string_literals(&parent.into()).is_some()) {
+            return None;
+        }
+        let literals = collect_string_literals(&node.clone().into())?;
+        (!has_inner_newline_or_comments(node)).then_some(literals)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Useless concatenation of string literals."
+                },
+            )
+            .note(markup! {
+                "Combine the string literals into a single string literal."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, literals: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let tokens = literals
+            .iter()
+            .map(|literal| literal.value_token().ok())
+            .collect::<Option<Vec<_>>>()?;
+        let quote = dominant_quote(&tokens);
+        let mut text = String::new();
+        text.push(quote);
+        for token in &tokens {
+            let token_text = token.text_trimmed();
+            // Remove the quotes
+            let content = &token_text[1..token_text.len() - 1];
+            requote(content, quote, &mut text);
+        }
+        text.push(quote);
+        let literal = make::js_string_literal_expression(JsSyntaxToken::new_detached(
+            JsSyntaxKind::JS_STRING_LITERAL,
+            &text,
+            [],
+            [],
+        ));
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsExpression::JsBinaryExpression(node.clone()),
+            AnyJsExpression::AnyJsLiteralExpression(
+                AnyJsLiteralExpression::JsStringLiteralExpression(literal),
+            ),
+        );
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: markup! { "Combine the string literals." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the string literals of `expr` if `expr` is a concatenation of string literals.
+fn collect_string_literals(expr: &AnyJsExpression) -> Option<Vec<JsStringLiteralExpression>> {
+    fn collect(expr: AnyJsExpression, literals: &mut Vec<JsStringLiteralExpression>) -> Option<()> {
+        match expr.omit_parentheses() {
+            AnyJsExpression::JsBinaryExpression(expr)
+                if expr.operator().ok()? == JsBinaryOperator::Plus =>
+            {
+                collect(expr.left().ok()?, literals)?;
+                collect(expr.right().ok()?, literals)
+            }
+            AnyJsExpression::AnyJsLiteralExpression(
+                AnyJsLiteralExpression::JsStringLiteralExpression(literal),
+            ) => {
+                literals.push(literal);
+                Some(())
+            }
+            _ => None,
+        }
+    }
+    let mut literals = Vec::new();
+    collect(expr.clone(), &mut literals)?;
+    // A single literal is not a concatenation.
+    (literals.len() > 1).then_some(literals)
+}
+
+/// Returns `true` if a newline or a comment is found between the first and the last token of `node`.
+fn has_inner_newline_or_comments(node: &JsBinaryExpression) -> bool {
+    let syntax = node.syntax();
+    let (Some(first), Some(last)) = (syntax.first_token(), syntax.last_token()) else {
+        return false;
+    };
+    syntax.descendants_tokens(Direction::Next).any(|token| {
+        (token != first && (token.has_leading_newline() || token.has_leading_comments()))
+            || (token != last && token.has_trailing_comments())
+    })
+}
+
+/// Returns the quote used by most of the string literals.
+/// In case of tie, the quote of the first literal is returned.
+fn dominant_quote(tokens: &[JsSyntaxToken]) -> char {
+    let double_quotes = tokens
+        .iter()
+        .filter(|token| token.text_trimmed().starts_with('"'))
+        .count();
+    let single_quotes = tokens.len() - double_quotes;
+    match double_quotes.cmp(&single_quotes) {
+        std::cmp::Ordering::Greater => '"',
+        std::cmp::Ordering::Less => '\'',
+        std::cmp::Ordering::Equal => tokens
+            .first()
+            .and_then(|token| token.text_trimmed().chars().next())
+            .unwrap_or('"'),
+    }
+}
+
+/// Appends to `result` the content of a string literal so that it can be enclosed by `quote`.
+///
+/// Unescaped occurrences of `quote` are escaped,
+/// and escaped occurrences of the other quote are unescaped.
+fn requote(content: &str, quote: char, result: &mut String) {
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some(next @ ('"' | '\'')) if next != quote => {
+                    result.push(next);
+                }
+                Some(next) => {
+                    result.push(c);
+                    result.push(next);
+                }
+                None => result.push(c),
+            }
+        } else {
+            if c == quote {
+                result.push('\\');
+            }
+            result.push(c);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::requote;
+
+    #[test]
+    fn requote_content() {
+        let mut result = String::new();
+        requote(r#"a"b\'c\"d\\"#, '"', &mut result);
+        assert_eq!(result, r#"a\"b'c\"d\\"#);
+
+        let mut result = String::new();
+        requote(r#"a'b\"c\'d"#, '\'', &mut result);
+        assert_eq!(result, r#"a\'b"c\'d"#);
+    }
+}
@@ -1,7 +1,10 @@
 use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_diagnostics::Applicability;
-use rome_js_syntax::{AnyJsModuleItem, JsExport, JsModuleItemList, JsSyntaxToken};
+use rome_js_syntax::{
+    AnyJsModuleItem, AnyJsStatement, AnyTsModuleReference, JsExport, JsModuleItemList,
+    JsSyntaxToken,
+};
 use rome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
 
 use crate::JsRuleAction;
@@ -18,6 +21,7 @@ declare_rule! {
     /// > whose contents are available in the global scope.
     ///
     /// However, an `export {}` statement does nothing if there are any other top-level import or export in the file.
+    /// In TypeScript, `import x = require("module")` is also an import.
     ///
     /// Source: https://typescript-eslint.io/rules/no-useless-empty-export/
     ///
@@ -63,6 +67,16 @@ impl Rule for NoUselessEmptyExport {
             let mut ignore_empty_export = true;
             for module_item in module_item_list {
                 match module_item {
+                    // `import x = require("module")`
+                    AnyJsModuleItem::AnyJsStatement(AnyJsStatement::TsImportEqualsDeclaration(
+                        import,
+                    )) => {
+                        if let Ok(AnyTsModuleReference::TsExternalModuleReference(_)) =
+                            import.module_reference()
+                        {
+                            return import.import_token().ok();
+                        }
+                    }
                     AnyJsModuleItem::AnyJsStatement(_) => {}
                     AnyJsModuleItem::JsImport(import) => return import.import_token().ok(),
                     AnyJsModuleItem::JsExport(export) => {
@@ -0,0 +1,175 @@
+use crate::JsRuleAction;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_syntax::{JsRegexLiteralExpression, JsSyntaxKind, JsSyntaxToken};
+use rome_rowan::BatchMutationExt;
+
+declare_rule! {
+    /// Disallow unnecessary escapes in regular expression literals.
+    ///
+    /// Escaping a character that has no special meaning doesn't change the regular expression.
+    /// It only makes it harder to read.
+    ///
+    /// Outside of a character class, the rule reports escaped characters
+    /// that are not syntax characters such as `.`, `*`, `(`, or `/`.
+    /// Inside a character class, only `\`, `]`, `-`, and a leading `^` need to be escaped.
+    /// Escaped letters and digits are never reported,
+    /// because they form escape sequences such as `\d` or `\u0041`.
+    ///
+    /// The code fix removes the unnecessary backslashes.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-useless-escape
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const regex = /a\-b/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const regex = /a\;b/;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const regex = /[\.]/;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const regex = /a\.b\/c/;
+    /// const range = /[a\-z]/;
+    /// const digits = /\d+/;
+    /// ```
+    ///
+    pub(crate) NoUselessEscapeInRegex {
+        version: "next",
+        name: "noUselessEscapeInRegex",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUselessEscapeInRegex {
+    type Query = Ast<JsRegexLiteralExpression>;
+    /// The byte offsets of the useless backslashes in the regex literal token.
+    type State = Vec<usize>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let value_token = ctx.query().value_token().ok()?;
+        let text = value_token.text_trimmed();
+        let end_slash_position = text.rfind('/')?;
+        let is_unicode_sets = text[end_slash_position..].contains('v');
+        let useless_escapes = useless_escapes(&text[..end_slash_position], is_unicode_sets);
+        (!useless_escapes.is_empty()).then_some(useless_escapes)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let value_token = ctx.query().value_token().ok()?;
+        let text = value_token.text_trimmed();
+        let escaped_characters = state
+            .iter()
+            .filter_map(|&position| text[position + 1..].chars().next())
+            .collect::<String>();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                value_token.text_trimmed_range(),
+                markup! {
+                    "This regular expression contains unnecessary escapes."
+                },
+            )
+            .note(markup! {
+                "The following characters don't need to be escaped: "<Emphasis>{escaped_characters}</Emphasis>
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let value_token = ctx.query().value_token().ok()?;
+        let text = value_token.text_trimmed();
+        let mut new_text = String::with_capacity(text.len());
+        let mut previous_end = 0;
+        for &position in state {
+            new_text.push_str(&text[previous_end..position]);
+            previous_end = position + 1;
+        }
+        new_text.push_str(&text[previous_end..]);
+        let new_token =
+            JsSyntaxToken::new_detached(JsSyntaxKind::JS_REGEX_LITERAL, &new_text, [], []);
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token(value_token, new_token);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Remove the unnecessary escapes." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the byte offsets of the useless backslashes in `pattern`.
+///
+/// `pattern` is the regex literal without its flags, starting with the opening slash.
+/// With the `v` flag, character classes can be nested and
+/// any ASCII punctuator may have a meaning inside them.
+fn useless_escapes(pattern: &str, is_unicode_sets: bool) -> Vec<usize> {
+    let mut result = Vec::new();
+    // The nesting level of character classes
+    let mut class_depth = 0u32;
+    // `true` if the current character is the first one of a character class
+    let mut is_class_start = false;
+    let mut chars = pattern.char_indices().skip(1);
+    while let Some((position, c)) = chars.next() {
+        let was_class_start = is_class_start;
+        is_class_start = false;
+        match c {
+            '\\' => {
+                let Some((_, escaped)) = chars.next() else {
+                    break;
+                };
+                let is_useful = escaped.is_ascii_alphanumeric()
+                    || if class_depth == 0 {
+                        matches!(
+                            escaped,
+                            '^' | '$'
+                                | '\\'
+                                | '.'
+                                | '*'
+                                | '+'
+                                | '?'
+                                | '('
+                                | ')'
+                                | '['
+                                | ']'
+                                | '{'
+                                | '}'
+                                | '|'
+                                | '/'
+                        )
+                    } else if is_unicode_sets {
+                        escaped.is_ascii_punctuation()
+                    } else {
+                        matches!(escaped, '\\' | ']' | '-') || (escaped == '^' && was_class_start)
+                    };
+                if !is_useful {
+                    result.push(position);
+                }
+            }
+            '[' if class_depth == 0 || is_unicode_sets => {
+                class_depth += 1;
+                is_class_start = true;
+            }
+            ']' if class_depth > 0 => {
+                class_depth -= 1;
+            }
+            _ => {}
+        }
+    }
+    result
+}
@@ -0,0 +1,160 @@
+use crate::JsRuleAction;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    AnyJsExpression, JsArrowFunctionExpression, JsExpressionStatement, JsFileSource,
+    OperatorPrecedence, TsTypeAssertionExpression, T,
+};
+use rome_rowan::{AstNode, BatchMutationExt};
+use std::iter;
+
+declare_rule! {
+    /// Enforce the use of `as` for type assertions instead of the angle-bracket syntax.
+    ///
+    /// TypeScript has two syntaxes for type assertions: `<Type>value` and `value as Type`.
+    /// The angle-bracket syntax can't be used in TSX files, where it conflicts with JSX,
+    /// and it is easily confused with the type arguments of a generic function.
+    /// The `as` syntax works everywhere, including for `const` assertions such as `value as const`.
+    ///
+    /// The code fix rewrites the assertion with `as`,
+    /// and adds parentheses when the precedence of the surrounding expression requires them.
+    ///
+    /// Source: https://typescript-eslint.io/rules/consistent-type-assertions
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// const value = <const>["a", "b"];
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// const length = (<string>value).length;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// const value = ["a", "b"] as const;
+    /// const length = (value as string).length;
+    /// ```
+    ///
+    pub(crate) UseAsConstAssertion {
+        version: "next",
+        name: "useAsConstAssertion",
+        recommended: false,
+    }
+}
+
+impl Rule for UseAsConstAssertion {
+    type Query = Ast<TsTypeAssertionExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        // The angle-bracket assertions are parsed as JSX in TSX files
+        if ctx.source_type::<JsFileSource>().variant().is_jsx() {
+            return None;
+        }
+        Some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Use "<Emphasis>"as"</Emphasis>" instead of the angle-bracket syntax for this type assertion."
+                },
+            )
+            .note(markup! {
+                "The angle-bracket syntax can't be used in TSX files, and it looks like the type arguments of a generic function."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let expression = node
+            .expression()
+            .ok()?
+            .trim_start()?
+            .with_trailing_trivia_pieces(iter::empty())?;
+        let ty = node.ty().ok()?.trim()?;
+        let as_expression = AnyJsExpression::from(make::ts_as_expression(
+            expression,
+            make::token_decorated_with_space(T![as]),
+            ty,
+        ));
+        let new_expression = if needs_parentheses(node) {
+            AnyJsExpression::from(make::js_parenthesized_expression(
+                make::token(T!['(']),
+                as_expression,
+                make::token(T![')']),
+            ))
+        } else {
+            as_expression
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(node.clone()), new_expression);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>"as"</Emphasis>"." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Checks if `value as Type` needs parentheses where `<Type>value` is.
+fn needs_parentheses(node: &TsTypeAssertionExpression) -> bool {
+    match node.parent::<AnyJsExpression>() {
+        // `a * <T>b` is `a * (b as T)`, not `a * b as T`
+        Some(
+            parent @ (AnyJsExpression::JsBinaryExpression(_)
+            | AnyJsExpression::JsInExpression(_)
+            | AnyJsExpression::JsInstanceofExpression(_)
+            | AnyJsExpression::TsAsExpression(_)
+            | AnyJsExpression::TsSatisfiesExpression(_)),
+        ) => parent.precedence().map_or(true, |precedence| {
+            precedence >= OperatorPrecedence::Relational
+        }),
+        // `!<T>a` is `!(a as T)`, not `!a as T`
+        Some(AnyJsExpression::JsUnaryExpression(_) | AnyJsExpression::JsAwaitExpression(_)) => true,
+        _ => starts_with_curly_or_declaration(node),
+    }
+}
+
+/// Checks if `value as Type` would start a statement or the body of an arrow function
+/// with a token that changes its meaning, such as `{` in `<T>{}`.
+fn starts_with_curly_or_declaration(node: &TsTypeAssertionExpression) -> bool {
+    let Some(first_token) = node
+        .expression()
+        .ok()
+        .and_then(|expression| expression.syntax().first_token())
+    else {
+        return false;
+    };
+    let start = node.syntax().text_trimmed_range().start();
+    for ancestor in node.syntax().ancestors().skip(1) {
+        if JsExpressionStatement::can_cast(ancestor.kind()) {
+            return matches!(first_token.kind(), T!['{'] | T![function] | T![class]);
+        }
+        if ancestor.text_trimmed_range().start() != start {
+            // The assertion starts the body of an arrow function: `() => <T>{}`
+            return first_token.kind() == T!['{']
+                && JsArrowFunctionExpression::cast(ancestor)
+                    .and_then(|arrow| arrow.body().ok())
+                    .map_or(false, |body| {
+                        body.syntax().text_trimmed_range().start() == start
+                    });
+        }
+    }
+    false
+}
@@ -0,0 +1,185 @@
+use crate::utils::is_side_effect_free;
+use crate::JsRuleAction;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    numbers::parse_js_number, AnyJsCallArgument, AnyJsExpression, AnyJsLiteralExpression,
+    JsBinaryOperator, JsCallExpression, JsComputedMemberExpression, JsNewExpression, JsSyntaxToken,
+    JsUnaryExpression, JsUnaryOperator, T,
+};
+use rome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Prefer `.at()` over indexing from the end with `.length`.
+    ///
+    /// `array.at(-1)` accesses the last element of `array` like `array[array.length - 1]` does,
+    /// without repeating `array`.
+    ///
+    /// The rule reports computed member accesses `object[object.length - N]`
+    /// where `N` is a positive integer literal.
+    /// The object must be an identifier, `this`, or a member access on them,
+    /// because repeating an expression with side effects is not the same as using it once.
+    ///
+    /// The code fix rewrites the access to `object.at(-N)`.
+    /// Because `at()` is only available on arrays, strings, and typed arrays,
+    /// the code fix is not always safe.
+    ///
+    /// Source: https://github.com/sindresorhus/eslint-plugin-unicorn/blob/main/docs/rules/prefer-at.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const last = array[array.length - 1];
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const secondToLast = this.items[this.items.length - 2];
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const last = array.at(-1);
+    /// const first = array[0];
+    /// const item = array[other.length - 1];
+    /// ```
+    ///
+    pub(crate) UseAtIndex {
+        version: "next",
+        name: "useAtIndex",
+        recommended: false,
+    }
+}
+
+impl Rule for UseAtIndex {
+    type Query = Ast<JsComputedMemberExpression>;
+    /// The integer literal `N` of `object[object.length - N]`.
+    type State = JsSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.is_optional_chain() || !is_replaceable_by_call(node) {
+            return None;
+        }
+        let object = node.object().ok()?;
+        if !is_side_effect_free(&object) {
+            return None;
+        }
+        let AnyJsExpression::JsBinaryExpression(index) = node.member().ok()?.omit_parentheses()
+        else {
+            return None;
+        };
+        if index.operator().ok()? != JsBinaryOperator::Minus {
+            return None;
+        }
+        let AnyJsExpression::JsStaticMemberExpression(length) = index.left().ok()? else {
+            return None;
+        };
+        if length.is_optional_chain()
+            || length
+                .member()
+                .ok()?
+                .as_js_name()?
+                .value_token()
+                .ok()?
+                .text_trimmed()
+                != "length"
+            || length.object().ok()?.syntax().text_trimmed() != object.syntax().text_trimmed()
+        {
+            return None;
+        }
+        let AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsNumberLiteralExpression(offset),
+        ) = index.right().ok()?
+        else {
+            return None;
+        };
+        let offset = offset.value_token().ok()?;
+        // `object.at(-0)` returns the first element instead of `undefined`.
+        let value = parse_js_number(offset.text_trimmed())?;
+        (value > 0.0 && value.fract() == 0.0).then_some(offset)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, offset: &Self::State) -> Option<RuleDiagnostic> {
+        let offset = offset.text_trimmed();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Prefer "<Emphasis>".at(-"{offset}")"</Emphasis>" over indexing from the end with "<Emphasis>".length"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                <Emphasis>".at()"</Emphasis>" accepts negative indexes that count back from the end."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, offset: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let negative_offset = make::js_unary_expression(
+            make::token(T![-]),
+            AnyJsExpression::AnyJsLiteralExpression(
+                make::js_number_literal_expression(make::js_number_literal(offset.text_trimmed()))
+                    .into(),
+            ),
+        );
+        let call = make::js_call_expression(
+            make::js_static_member_expression(
+                node.object().ok()?.trim_end()?,
+                make::token(T![.]),
+                make::js_name(make::ident("at")).into(),
+            )
+            .into(),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list(
+                    [AnyJsCallArgument::AnyJsExpression(negative_offset.into())],
+                    [],
+                ),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(node.clone()), call.into());
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>".at()"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if replacing `node` by a call doesn't change the meaning of its parent.
+///
+/// A call is not a valid `delete` operand, `new object.at(-1)()` is parsed differently,
+/// and calling `object[index]()` passes `object` as `this`.
+fn is_replaceable_by_call(node: &JsComputedMemberExpression) -> bool {
+    let Some(parent) = node.syntax().parent() else {
+        return true;
+    };
+    if let Some(call) = JsCallExpression::cast_ref(&parent) {
+        return call
+            .callee()
+            .is_ok_and(|callee| callee.syntax() != node.syntax());
+    }
+    if let Some(new_expression) = JsNewExpression::cast_ref(&parent) {
+        return new_expression
+            .callee()
+            .is_ok_and(|callee| callee.syntax() != node.syntax());
+    }
+    if let Some(unary) = JsUnaryExpression::cast_ref(&parent) {
+        return unary.operator().ok() != Some(JsUnaryOperator::Delete);
+    }
+    true
+}
@@ -0,0 +1,343 @@
+use crate::utils::has_inner_comments;
+use crate::JsRuleAction;
+use bpaf::Bpaf;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    AnyTsType, TriviaPieceKind, TsArrayType, TsReferenceType, TsTypeOperatorType, T,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{declare_node_union, AstNode, AstSeparatedList, BatchMutationExt, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Require consistently using either `T[]` or `Array<T>`
+    ///
+    /// TypeScript provides two equivalent ways to write an array type:
+    /// the shorthand syntax `T[]` and the generic syntax `Array<T>`.
+    /// Similarly, `readonly T[]` is equivalent to `ReadonlyArray<T>`.
+    /// This rule enforces the use of one of these syntaxes.
+    ///
+    /// When converting to the shorthand syntax, union, intersection, function, and other element types
+    /// are wrapped in parentheses to preserve their meaning.
+    /// `Array` and `ReadonlyArray` types with zero or several type arguments are ignored.
+    ///
+    /// Source: https://typescript-eslint.io/rules/array-type
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// let invalid: Array<foo>;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// let invalid: Array<string | number>;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// let invalid: ReadonlyArray<string>;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// let valid: foo[];
+    /// let valid: (string | number)[];
+    /// let valid: readonly string[];
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `syntax` to choose the enforced syntax.
+    /// It accepts the following values:
+    ///
+    /// - `"shorthand"` enforces the use of `T[]` and `readonly T[]`. This is the default.
+    /// - `"generic"` enforces the use of `Array<T>` and `ReadonlyArray<T>`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "syntax": "generic"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseConsistentArrayType {
+        version: "next",
+        name: "useConsistentArrayType",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyTsArrayTypeSyntax = TsArrayType | TsReferenceType
+}
+
+/// Options for the rule `useConsistentArrayType`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConsistentArrayTypeOptions {
+    /// The array syntax to enforce.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub syntax: ArrayTypeSyntax,
+}
+
+impl ConsistentArrayTypeOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["syntax"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ConsistentArrayTypeOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ConsistentArrayTypeOptions {}
+impl VisitNode<JsonLanguage> for ConsistentArrayTypeOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "syntax" {
+            let mut syntax = ArrayTypeSyntax::default();
+            self.map_to_known_string(&value, name_text, &mut syntax, diagnostics)?;
+            self.syntax = syntax;
+        }
+        Some(())
+    }
+}
+
+/// Supported syntaxes for array types.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ArrayTypeSyntax {
+    /// `T[]` and `readonly T[]`
+    #[default]
+    Shorthand,
+    /// `Array<T>` and `ReadonlyArray<T>`
+    Generic,
+}
+
+impl ArrayTypeSyntax {
+    pub const KNOWN_VALUES: &'static [&'static str] = &["shorthand", "generic"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ArrayTypeSyntax {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(ArrayTypeSyntax::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for ArrayTypeSyntax {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, Self::KNOWN_VALUES, diagnostics)?;
+        match node.inner_string_text().ok()?.text() {
+            "shorthand" => *self = Self::Shorthand,
+            "generic" => *self = Self::Generic,
+            _ => (),
+        }
+        Some(())
+    }
+}
+
+pub(crate) struct ArrayTypeReplacement {
+    /// The type to replace.
+    node: AnyTsType,
+    /// The replacing type, written with the enforced syntax.
+    replacement: AnyTsType,
+    is_readonly: bool,
+}
+
+impl Rule for UseConsistentArrayType {
+    type Query = Ast<AnyTsArrayTypeSyntax>;
+    type State = ArrayTypeReplacement;
+    type Signals = Option<Self::State>;
+    type Options = ConsistentArrayTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        match (ctx.query(), ctx.options().syntax) {
+            (AnyTsArrayTypeSyntax::TsReferenceType(ty), ArrayTypeSyntax::Shorthand) => {
+                to_shorthand_syntax(ty)
+            }
+            (AnyTsArrayTypeSyntax::TsArrayType(ty), ArrayTypeSyntax::Generic) => {
+                to_generic_syntax(ty)
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let message = match (ctx.options().syntax, state.is_readonly) {
+            (ArrayTypeSyntax::Shorthand, false) => markup! {
+                "Use "<Emphasis>"T[]"</Emphasis>" instead of "<Emphasis>"Array<T>"</Emphasis>"."
+            },
+            (ArrayTypeSyntax::Shorthand, true) => markup! {
+                "Use "<Emphasis>"readonly T[]"</Emphasis>" instead of "<Emphasis>"ReadonlyArray<T>"</Emphasis>"."
+            },
+            (ArrayTypeSyntax::Generic, false) => markup! {
+                "Use "<Emphasis>"Array<T>"</Emphasis>" instead of "<Emphasis>"T[]"</Emphasis>"."
+            },
+            (ArrayTypeSyntax::Generic, true) => markup! {
+                "Use "<Emphasis>"ReadonlyArray<T>"</Emphasis>" instead of "<Emphasis>"readonly T[]"</Emphasis>"."
+            },
+        };
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            state.node.range(),
+            message,
+        ))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        // Don't lose the comments inside the type
+        if has_inner_comments(state.node.syntax()) {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(state.node.clone(), state.replacement.clone());
+        let message = match (ctx.options().syntax, state.is_readonly) {
+            (ArrayTypeSyntax::Shorthand, false) => {
+                markup! { "Use "<Emphasis>"T[]"</Emphasis>"." }
+            }
+            (ArrayTypeSyntax::Shorthand, true) => {
+                markup! { "Use "<Emphasis>"readonly T[]"</Emphasis>"." }
+            }
+            (ArrayTypeSyntax::Generic, false) => {
+                markup! { "Use "<Emphasis>"Array<T>"</Emphasis>"." }
+            }
+            (ArrayTypeSyntax::Generic, true) => {
+                markup! { "Use "<Emphasis>"ReadonlyArray<T>"</Emphasis>"." }
+            }
+        };
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: message.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Converts `Array<T>` to `T[]`, and `ReadonlyArray<T>` to `readonly T[]`.
+fn to_shorthand_syntax(ty: &TsReferenceType) -> Option<ArrayTypeReplacement> {
+    let name = ty.name().ok()?;
+    let is_readonly = match name
+        .as_js_reference_identifier()?
+        .value_token()
+        .ok()?
+        .text_trimmed()
+    {
+        "Array" => false,
+        "ReadonlyArray" => true,
+        _ => return None,
+    };
+    let type_arguments = ty.type_arguments()?.ts_type_argument_list();
+    if type_arguments.len() != 1 {
+        return None;
+    }
+    let element_type = type_arguments.first()?.ok()?.trim()?;
+    let element_type = if needs_parentheses(&element_type) {
+        make::ts_parenthesized_type(make::token(T!['(']), element_type, make::token(T![')'])).into()
+    } else {
+        element_type
+    };
+    let mut replacement: AnyTsType =
+        make::ts_array_type(element_type, make::token(T!['[']), make::token(T![']'])).into();
+    if is_readonly {
+        replacement = make::ts_type_operator_type(
+            make::token(T![readonly]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            replacement,
+        )
+        .into();
+    }
+    Some(ArrayTypeReplacement {
+        node: ty.clone().into(),
+        replacement,
+        is_readonly,
+    })
+}
+
+/// Converts `T[]` to `Array<T>`, and `readonly T[]` to `ReadonlyArray<T>`.
+fn to_generic_syntax(ty: &TsArrayType) -> Option<ArrayTypeReplacement> {
+    let readonly_type = ty.parent::<TsTypeOperatorType>().filter(|parent| {
+        parent
+            .operator_token()
+            .is_ok_and(|operator| operator.kind() == T![readonly])
+    });
+    let mut element_type = ty.element_type().ok()?;
+    while let AnyTsType::TsParenthesizedType(parenthesized) = element_type {
+        element_type = parenthesized.ty().ok()?;
+    }
+    let name = if readonly_type.is_some() {
+        "ReadonlyArray"
+    } else {
+        "Array"
+    };
+    let replacement =
+        make::ts_reference_type(make::js_reference_identifier(make::ident(name)).into())
+            .with_type_arguments(make::ts_type_arguments(
+                make::token(T![<]),
+                make::ts_type_argument_list(
+                    [element_type.with_leading_trivia_pieces([])?.trim_end()?],
+                    [],
+                ),
+                make::token(T![>]),
+            ))
+            .build()
+            .into();
+    Some(ArrayTypeReplacement {
+        is_readonly: readonly_type.is_some(),
+        node: readonly_type.map_or_else(|| ty.clone().into(), AnyTsType::from),
+        replacement,
+    })
+}
+
+/// Returns `true` if `ty` must be parenthesized when it is the element type of `T[]`.
+fn needs_parentheses(ty: &AnyTsType) -> bool {
+    matches!(
+        ty,
+        AnyTsType::TsConditionalType(_)
+            | AnyTsType::TsConstructorType(_)
+            | AnyTsType::TsFunctionType(_)
+            | AnyTsType::TsInferType(_)
+            | AnyTsType::TsIntersectionType(_)
+            | AnyTsType::TsTypeOperatorType(_)
+            | AnyTsType::TsUnionType(_)
+    )
+}
@@ -0,0 +1,354 @@
+use crate::JsRuleAction;
+use bpaf::Bpaf;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, AnyJsxAttributeValue, AnyJsxChild,
+    JsStringLiteralExpression, JsSyntaxKind, JsSyntaxToken, JsxAttributeInitializerClause,
+    JsxExpressionAttributeValue, JsxExpressionChild, JsxString, JsxText, T,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{declare_node_union, AstNode, BatchMutationExt, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Enforce the consistent use of curly braces around the strings of JSX attributes and children.
+    ///
+    /// By default, the rule reports the string literals wrapped in curly braces,
+    /// such as `<a href={"/home"}>{"Home"}</a>`, because they can be written without the braces.
+    ///
+    /// The strings that cannot be written as is without changing their meaning are ignored:
+    /// the strings that contain escape sequences or `&`,
+    /// and the children that are empty or that start or end with whitespace, such as `{" "}`.
+    /// The code fix escapes the characters `{`, `}`, `<`, and `>` of the children with HTML entities.
+    ///
+    /// Source: https://github.com/jsx-eslint/eslint-plugin-react/blob/master/docs/rules/jsx-curly-brace-presence.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <a href={"/home"}>Home</a>
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <p>{"Hello"}</p>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <a href="/home">Home</a>
+    /// ```
+    ///
+    /// ```jsx
+    /// <p>Hello{" "}{name}</p>
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `braces` to choose the enforced style.
+    /// It accepts the following values:
+    ///
+    /// - `"never"` reports the strings wrapped in curly braces. This is the default.
+    /// - `"always"` reports the strings of attributes and the texts of children that are not wrapped in curly braces.
+    ///   The texts that span several lines are ignored.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "braces": "always"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseConsistentCurlyBraces {
+        version: "next",
+        name: "useConsistentCurlyBraces",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useConsistentCurlyBraces`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConsistentCurlyBracesOptions {
+    /// Whether the strings must be wrapped in curly braces.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub braces: CurlyBraces,
+}
+
+impl ConsistentCurlyBracesOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["braces"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ConsistentCurlyBracesOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ConsistentCurlyBracesOptions {}
+impl VisitNode<JsonLanguage> for ConsistentCurlyBracesOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "braces" {
+            let mut braces = CurlyBraces::default();
+            self.map_to_known_string(&value, name_text, &mut braces, diagnostics)?;
+            self.braces = braces;
+        }
+        Some(())
+    }
+}
+
+/// Whether the strings of JSX attributes and children are wrapped in curly braces.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum CurlyBraces {
+    /// `<a href="/home">Home</a>`
+    #[default]
+    Never,
+    /// `<a href={"/home"}>{"Home"}</a>`
+    Always,
+}
+
+impl CurlyBraces {
+    pub const KNOWN_VALUES: &'static [&'static str] = &["never", "always"];
+}
+
+// Required by [Bpaf].
+impl FromStr for CurlyBraces {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(CurlyBraces::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for CurlyBraces {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, Self::KNOWN_VALUES, diagnostics)?;
+        match node.inner_string_text().ok()?.text() {
+            "never" => *self = Self::Never,
+            "always" => *self = Self::Always,
+            _ => (),
+        }
+        Some(())
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyJsxStringValue =
+        JsxExpressionAttributeValue
+        | JsxExpressionChild
+        | JsxString
+        | JsxText
+}
+
+impl Rule for UseConsistentCurlyBraces {
+    type Query = Ast<AnyJsxStringValue>;
+    /// The replacing token.
+    type State = JsSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = ConsistentCurlyBracesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        match (ctx.query(), ctx.options().braces) {
+            (AnyJsxStringValue::JsxExpressionAttributeValue(value), CurlyBraces::Never) => {
+                let string = string_literal(value.expression().ok()?)?;
+                let text = string.inner_string_text().ok()?;
+                if !is_plain_string(text.text()) {
+                    return None;
+                }
+                // The quotes of the string are kept: it cannot contain them without escapes.
+                Some(JsSyntaxToken::new_detached(
+                    JsSyntaxKind::JSX_STRING_LITERAL,
+                    string.value_token().ok()?.text_trimmed(),
+                    [],
+                    [],
+                ))
+            }
+            (AnyJsxStringValue::JsxExpressionChild(child), CurlyBraces::Never) => {
+                let string = string_literal(child.expression()?)?;
+                let text = string.inner_string_text().ok()?;
+                let text = text.text();
+                if !is_plain_string(text) || text.is_empty() || text.trim() != text {
+                    return None;
+                }
+                Some(JsSyntaxToken::new_detached(
+                    JsSyntaxKind::JSX_TEXT_LITERAL,
+                    &escape_jsx_text(text),
+                    [],
+                    [],
+                ))
+            }
+            (AnyJsxStringValue::JsxString(string), CurlyBraces::Always) => {
+                string.parent::<JsxAttributeInitializerClause>()?;
+                let text = string.inner_string_text().ok()?;
+                if !is_plain_string(text.text()) || text.contains('\n') {
+                    return None;
+                }
+                Some(JsSyntaxToken::new_detached(
+                    JsSyntaxKind::JS_STRING_LITERAL,
+                    string.value_token().ok()?.text_trimmed(),
+                    [],
+                    [],
+                ))
+            }
+            (AnyJsxStringValue::JsxText(text), CurlyBraces::Always) => {
+                let value = text.value_token().ok()?;
+                let text = value.text();
+                if !is_plain_string(text) || text.trim().is_empty() || text.contains(['\n', '\r']) {
+                    return None;
+                }
+                let quote = if !text.contains('"') {
+                    '"'
+                } else if !text.contains('\'') {
+                    '\''
+                } else {
+                    return None;
+                };
+                Some(JsSyntaxToken::new_detached(
+                    JsSyntaxKind::JS_STRING_LITERAL,
+                    &format!("{quote}{text}{quote}"),
+                    [],
+                    [],
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let message = match node {
+            AnyJsxStringValue::JsxExpressionAttributeValue(_)
+            | AnyJsxStringValue::JsxExpressionChild(_) => markup! {
+                "This string doesn't need to be wrapped in curly braces."
+            },
+            AnyJsxStringValue::JsxString(_) | AnyJsxStringValue::JsxText(_) => markup! {
+                "This string should be wrapped in curly braces."
+            },
+        };
+        Some(RuleDiagnostic::new(rule_category!(), node.range(), message))
+    }
+
+    fn action(ctx: &RuleContext<Self>, token: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let message = match ctx.query() {
+            AnyJsxStringValue::JsxExpressionAttributeValue(value) => {
+                mutation.replace_node(
+                    AnyJsxAttributeValue::from(value.clone()),
+                    AnyJsxAttributeValue::from(make::jsx_string(token.clone())),
+                );
+                markup! { "Remove the curly braces." }
+            }
+            AnyJsxStringValue::JsxExpressionChild(child) => {
+                mutation.replace_node(
+                    AnyJsxChild::from(child.clone()),
+                    AnyJsxChild::from(make::jsx_text(token.clone())),
+                );
+                markup! { "Remove the curly braces." }
+            }
+            AnyJsxStringValue::JsxString(string) => {
+                mutation.replace_node(
+                    AnyJsxAttributeValue::from(string.clone()),
+                    AnyJsxAttributeValue::from(make::jsx_expression_attribute_value(
+                        make::token(T!['{']),
+                        string_expression(token.clone()),
+                        make::token(T!['}']),
+                    )),
+                );
+                markup! { "Wrap the string in curly braces." }
+            }
+            AnyJsxStringValue::JsxText(text) => {
+                mutation.replace_node(
+                    AnyJsxChild::from(text.clone()),
+                    AnyJsxChild::from(
+                        make::jsx_expression_child(make::token(T!['{']), make::token(T!['}']))
+                            .with_expression(string_expression(token.clone()))
+                            .build(),
+                    ),
+                );
+                markup! { "Wrap the string in curly braces." }
+            }
+        };
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: message.to_owned(),
+            mutation,
+        })
+    }
+}
+
+fn string_literal(expression: AnyJsExpression) -> Option<JsStringLiteralExpression> {
+    match expression {
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsStringLiteralExpression(string),
+        ) => Some(string),
+        _ => None,
+    }
+}
+
+fn string_expression(token: JsSyntaxToken) -> AnyJsExpression {
+    AnyJsExpression::AnyJsLiteralExpression(AnyJsLiteralExpression::JsStringLiteralExpression(
+        make::js_string_literal_expression(token),
+    ))
+}
+
+/// Returns `true` if `text` has the same meaning in a JavaScript string and in JSX.
+///
+/// JavaScript strings interpret escape sequences, while JSX interprets HTML entities.
+fn is_plain_string(text: &str) -> bool {
+    !text.contains(['\\', '&'])
+}
+
+/// Escapes the characters of `text` that cannot appear in a JSX text.
+fn escape_jsx_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '{' => escaped.push_str("&#123;"),
+            '}' => escaped.push_str("&#125;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
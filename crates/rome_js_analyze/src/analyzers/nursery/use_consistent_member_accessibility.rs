@@ -0,0 +1,361 @@
+use crate::JsRuleAction;
+use bpaf::Bpaf;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    AnyJsClassMemberName, JsFileSource, JsGetterClassMember, JsMethodClassMember,
+    JsPropertyClassMember, JsSetterClassMember, JsSyntaxKind, JsSyntaxNode, TextRange,
+    TriviaPieceKind, TsAccessibilityModifier, TsPropertyParameter, T,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{declare_node_union, AstNode, BatchMutationExt, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Require consistent accessibility modifiers on class members.
+    ///
+    /// TypeScript class members are public by default.
+    /// Some teams prefer to always write the accessibility modifier of a member,
+    /// while other teams prefer to never write the redundant `public` modifier.
+    ///
+    /// The rule checks class properties, methods, getters, setters,
+    /// and the parameter properties of constructors.
+    /// Members with a private name such as `#x` are ignored.
+    /// Only TypeScript files are checked.
+    ///
+    /// Source: https://typescript-eslint.io/rules/explicit-member-accessibility
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Person {
+    ///     name: string;
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Person {
+    ///     constructor(readonly name: string) {}
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// class Person {
+    ///     public name: string;
+    ///     #age: number;
+    ///     constructor(private readonly id: string) {}
+    ///     protected greet() {}
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `accessibility` to choose which accessibility modifiers are required:
+    ///
+    /// - `"explicit"` (default) requires an accessibility modifier on every member;
+    /// - `"noPublic"` disallows the redundant `public` modifier.
+    ///   The parameter properties of constructors are ignored,
+    ///   because removing their `public` modifier turns them into regular parameters.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "accessibility": "noPublic"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseConsistentMemberAccessibility {
+        version: "next",
+        name: "useConsistentMemberAccessibility",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useConsistentMemberAccessibility`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConsistentMemberAccessibilityOptions {
+    /// The accessibility modifiers to enforce.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub accessibility: Accessibility,
+}
+
+impl ConsistentMemberAccessibilityOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["accessibility"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ConsistentMemberAccessibilityOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ConsistentMemberAccessibilityOptions {}
+impl VisitNode<JsonLanguage> for ConsistentMemberAccessibilityOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "accessibility" {
+            let mut accessibility = Accessibility::default();
+            self.map_to_known_string(&value, name_text, &mut accessibility, diagnostics)?;
+            self.accessibility = accessibility;
+        }
+        Some(())
+    }
+}
+
+/// Supported accessibility requirements.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum Accessibility {
+    /// Every member has an accessibility modifier.
+    #[default]
+    Explicit,
+    /// No member has the `public` modifier.
+    NoPublic,
+}
+
+impl Accessibility {
+    pub const KNOWN_VALUES: &'static [&'static str] = &["explicit", "noPublic"];
+}
+
+// Required by [Bpaf].
+impl FromStr for Accessibility {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Accessibility::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for Accessibility {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, Self::KNOWN_VALUES, diagnostics)?;
+        match node.inner_string_text().ok()?.text() {
+            "explicit" => *self = Self::Explicit,
+            "noPublic" => *self = Self::NoPublic,
+            _ => (),
+        }
+        Some(())
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyMemberWithAccessibility =
+        JsPropertyClassMember
+        | JsMethodClassMember
+        | JsGetterClassMember
+        | JsSetterClassMember
+        | TsPropertyParameter
+}
+
+impl AnyMemberWithAccessibility {
+    /// Returns the list of modifiers of the member.
+    fn modifiers(&self) -> JsSyntaxNode {
+        match self {
+            Self::JsPropertyClassMember(member) => member.modifiers().into_syntax(),
+            Self::JsMethodClassMember(member) => member.modifiers().into_syntax(),
+            Self::JsGetterClassMember(member) => member.modifiers().into_syntax(),
+            Self::JsSetterClassMember(member) => member.modifiers().into_syntax(),
+            Self::TsPropertyParameter(member) => member.modifiers().into_syntax(),
+        }
+    }
+
+    /// Returns the name of the member, or `None` if the member has a private name.
+    fn name(&self) -> Option<JsSyntaxNode> {
+        let name = match self {
+            Self::JsPropertyClassMember(member) => member.name().ok()?,
+            Self::JsMethodClassMember(member) => member.name().ok()?,
+            Self::JsGetterClassMember(member) => member.name().ok()?,
+            Self::JsSetterClassMember(member) => member.name().ok()?,
+            Self::TsPropertyParameter(member) => {
+                let parameter = member.formal_parameter().ok()?;
+                return Some(match parameter.as_js_formal_parameter() {
+                    Some(parameter) => parameter.binding().ok()?.into_syntax(),
+                    None => parameter.into_syntax(),
+                });
+            }
+        };
+        if matches!(name, AnyJsClassMemberName::JsPrivateClassMemberName(_)) {
+            return None;
+        }
+        Some(name.into_syntax())
+    }
+}
+
+pub(crate) enum AccessibilityIssue {
+    /// The member has no accessibility modifier.
+    /// The range is the range of the member name.
+    MissingAccessibility(TextRange),
+    /// The member has a redundant `public` modifier.
+    RedundantPublic(TsAccessibilityModifier),
+}
+
+impl Rule for UseConsistentMemberAccessibility {
+    type Query = Ast<AnyMemberWithAccessibility>;
+    type State = AccessibilityIssue;
+    type Signals = Option<Self::State>;
+    type Options = ConsistentMemberAccessibilityOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx.source_type::<JsFileSource>().language().is_typescript() {
+            return None;
+        }
+        let node = ctx.query();
+        let accessibility_modifier = node
+            .modifiers()
+            .children()
+            .find_map(TsAccessibilityModifier::cast);
+        match ctx.options().accessibility {
+            Accessibility::Explicit => {
+                if accessibility_modifier.is_some() {
+                    return None;
+                }
+                let name = node.name()?;
+                Some(AccessibilityIssue::MissingAccessibility(
+                    name.text_trimmed_range(),
+                ))
+            }
+            Accessibility::NoPublic => {
+                // Removing `public` turns a parameter property into a regular parameter
+                if matches!(node, AnyMemberWithAccessibility::TsPropertyParameter(_)) {
+                    return None;
+                }
+                let accessibility_modifier = accessibility_modifier?;
+                (accessibility_modifier.modifier_token().ok()?.kind() == T![public])
+                    .then_some(AccessibilityIssue::RedundantPublic(accessibility_modifier))
+            }
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            AccessibilityIssue::MissingAccessibility(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This member has no accessibility modifier."
+                },
+            )
+            .note(markup! {
+                "Add "<Emphasis>"public"</Emphasis>", "<Emphasis>"protected"</Emphasis>", or "<Emphasis>"private"</Emphasis>" to make its accessibility explicit."
+            }),
+            AccessibilityIssue::RedundantPublic(modifier) => RuleDiagnostic::new(
+                rule_category!(),
+                modifier.range(),
+                markup! {
+                    "The "<Emphasis>"public"</Emphasis>" modifier is redundant."
+                },
+            )
+            .note(markup! {
+                "Class members are public by default."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let message = match state {
+            AccessibilityIssue::MissingAccessibility(_) => {
+                let modifiers = ctx.query().modifiers();
+                // Accessibility modifiers follow the decorators
+                let index = modifiers
+                    .children()
+                    .take_while(|modifier| modifier.kind() == JsSyntaxKind::JS_DECORATOR)
+                    .count();
+                let next_token = match index.checked_sub(1) {
+                    Some(last_decorator_index) => modifiers
+                        .children()
+                        .nth(last_decorator_index)?
+                        .last_token()?
+                        .next_token()?,
+                    None => modifiers
+                        .first_token()
+                        .or_else(|| ctx.query().syntax().first_token())?,
+                };
+                let public_modifier = make::ts_accessibility_modifier(
+                    make::token(T![public])
+                        .with_leading_trivia_pieces(next_token.leading_trivia().pieces())
+                        .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                );
+                let new_modifiers = match modifiers.children().nth(index) {
+                    Some(next_modifier) => modifiers.clone().splice_slots(
+                        index..=index,
+                        [
+                            Some(public_modifier.into_syntax().into()),
+                            Some(next_modifier.with_leading_trivia_pieces([])?.into()),
+                        ],
+                    ),
+                    None => {
+                        mutation.replace_element_discard_trivia(
+                            next_token.clone().into(),
+                            next_token.with_leading_trivia_pieces([]).into(),
+                        );
+                        modifiers.clone().splice_slots(
+                            index..index,
+                            [Some(public_modifier.into_syntax().into())],
+                        )
+                    }
+                };
+                mutation.replace_element_discard_trivia(modifiers.into(), new_modifiers.into());
+                markup! { "Add the "<Emphasis>"public"</Emphasis>" modifier." }
+            }
+            AccessibilityIssue::RedundantPublic(modifier) => {
+                let public_token = modifier.modifier_token().ok()?;
+                let next_token = public_token.next_token()?;
+                mutation.replace_element_discard_trivia(
+                    next_token.clone().into(),
+                    next_token
+                        .with_leading_trivia_pieces(public_token.leading_trivia().pieces())
+                        .into(),
+                );
+                mutation.remove_node(modifier.clone());
+                markup! { "Remove the "<Emphasis>"public"</Emphasis>" modifier." }
+            }
+        };
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: message.to_owned(),
+            mutation,
+        })
+    }
+}
@@ -0,0 +1,326 @@
+use crate::JsRuleAction;
+use bpaf::Bpaf;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    AnyTsType, JsExportDefaultDeclarationClause, TriviaPieceKind, TsExternalModuleDeclaration,
+    TsGlobalDeclaration, TsInterfaceDeclaration, TsTypeAliasDeclaration, T,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{declare_node_union, AstNode, BatchMutationExt, SyntaxNode, TextRange};
+use serde::{Deserialize, Serialize};
+use std::iter;
+use std::str::FromStr;
+
+declare_rule! {
+    /// Enforce the use of either `interface` or `type` to define object types.
+    ///
+    /// TypeScript can define the shape of an object with an `interface` or with a `type` alias.
+    /// Both are mostly interchangeable: using only one of them makes the codebase more consistent.
+    ///
+    /// By default, the rule reports the `type` aliases of object types,
+    /// and its code fix turns them into interfaces.
+    /// A `type` alias of another type, such as a union or a mapped type, can't be an interface and is ignored.
+    ///
+    /// Source: https://typescript-eslint.io/rules/consistent-type-definitions
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// type Point = { x: number; y: number; };
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// type Box<T> = { value: T };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// interface Point { x: number; y: number; }
+    /// type Id = string | number;
+    /// type Flags<T> = { [K in keyof T]: boolean };
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `style` to choose how object types are defined:
+    ///
+    /// - `"interface"` (default) reports the `type` aliases of object types;
+    /// - `"type"` reports the interfaces.
+    ///
+    /// With `"type"`, the code fix turns an interface into a `type` alias,
+    /// and the interfaces it extends into an intersection: `interface A extends B { }` becomes `type A = B & { };`.
+    /// The interfaces exported by default and the interfaces that augment a module
+    /// can't be turned into a `type` alias, so they are reported without a code fix.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "style": "type"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseConsistentTypeDefinitions {
+        version: "next",
+        name: "useConsistentTypeDefinitions",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useConsistentTypeDefinitions`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConsistentTypeDefinitionsOptions {
+    /// How object types are defined.
+    #[bpaf(hide)]
+    pub style: TypeDefinitionStyle,
+}
+
+impl ConsistentTypeDefinitionsOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["style"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ConsistentTypeDefinitionsOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ConsistentTypeDefinitionsOptions {}
+impl VisitNode<JsonLanguage> for ConsistentTypeDefinitionsOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "style" {
+            let mut style = TypeDefinitionStyle::default();
+            self.map_to_known_string(&value, name_text, &mut style, diagnostics)?;
+            self.style = style;
+        }
+        Some(())
+    }
+}
+
+/// Supported ways of defining object types.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum TypeDefinitionStyle {
+    /// `interface A { }`
+    #[default]
+    Interface,
+    /// `type A = { };`
+    Type,
+}
+
+impl TypeDefinitionStyle {
+    pub const KNOWN_VALUES: &'static [&'static str] = &["interface", "type"];
+}
+
+// Required by [Bpaf].
+impl FromStr for TypeDefinitionStyle {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(TypeDefinitionStyle::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for TypeDefinitionStyle {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, Self::KNOWN_VALUES, diagnostics)?;
+        match node.inner_string_text().ok()?.text() {
+            "interface" => *self = Self::Interface,
+            "type" => *self = Self::Type,
+            _ => (),
+        }
+        Some(())
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyTsTypeDefinition = TsTypeAliasDeclaration | TsInterfaceDeclaration
+}
+
+impl AnyTsTypeDefinition {
+    /// Returns the range of the name of the defined type.
+    fn name_range(&self) -> Option<TextRange> {
+        let name = match self {
+            AnyTsTypeDefinition::TsTypeAliasDeclaration(alias) => alias.binding_identifier(),
+            AnyTsTypeDefinition::TsInterfaceDeclaration(interface) => interface.id(),
+        };
+        Some(name.ok()?.range())
+    }
+}
+
+impl Rule for UseConsistentTypeDefinitions {
+    type Query = Ast<AnyTsTypeDefinition>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ConsistentTypeDefinitionsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        match (ctx.query(), ctx.options().style) {
+            (
+                AnyTsTypeDefinition::TsTypeAliasDeclaration(alias),
+                TypeDefinitionStyle::Interface,
+            ) => {
+                // Only the aliases of object types can be interfaces
+                matches!(alias.ty().ok()?, AnyTsType::TsObjectType(_)).then_some(())
+            }
+            (AnyTsTypeDefinition::TsInterfaceDeclaration(_), TypeDefinitionStyle::Type) => Some(()),
+            _ => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let title = match node {
+            AnyTsTypeDefinition::TsTypeAliasDeclaration(_) => markup! {
+                "Use an "<Emphasis>"interface"</Emphasis>" instead of a "<Emphasis>"type"</Emphasis>" alias to define this object type."
+            },
+            AnyTsTypeDefinition::TsInterfaceDeclaration(_) => markup! {
+                "Use a "<Emphasis>"type"</Emphasis>" alias instead of an "<Emphasis>"interface"</Emphasis>" to define this object type."
+            },
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), node.name_range()?, title).note(markup! {
+                "Defining all the object types in the same way makes the code more consistent."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let (new_node, message) = match node {
+            AnyTsTypeDefinition::TsTypeAliasDeclaration(alias) => (
+                alias_to_interface(alias)?.into_syntax(),
+                markup! { "Use an "<Emphasis>"interface"</Emphasis>"." },
+            ),
+            AnyTsTypeDefinition::TsInterfaceDeclaration(interface) => (
+                interface_to_alias(interface)?.into_syntax(),
+                markup! { "Use a "<Emphasis>"type"</Emphasis>" alias." },
+            ),
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.replace_element(node.syntax().clone().into(), new_node.into());
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: message.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Turns `type A<T> = { };` into `interface A<T> { }`.
+fn alias_to_interface(alias: &TsTypeAliasDeclaration) -> Option<TsInterfaceDeclaration> {
+    let AnyTsType::TsObjectType(object) = alias.ty().ok()? else {
+        return None;
+    };
+    let interface_token =
+        make::token(T![interface]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+    let mut builder = make::ts_interface_declaration(
+        interface_token,
+        alias.binding_identifier().ok()?,
+        object.l_curly_token().ok()?,
+        object.members(),
+        object.r_curly_token().ok()?,
+    );
+    if let Some(type_parameters) = alias.type_parameters() {
+        builder = builder.with_type_parameters(type_parameters);
+    }
+    Some(builder.build())
+}
+
+/// Turns `interface A<T> extends B { }` into `type A<T> = B & { };`.
+fn interface_to_alias(interface: &TsInterfaceDeclaration) -> Option<TsTypeAliasDeclaration> {
+    let parent_kind = interface.syntax().parent()?.kind();
+    // `export default type` doesn't exist
+    if JsExportDefaultDeclarationClause::can_cast(parent_kind) {
+        return None;
+    }
+    // The interfaces of `declare global { }` and `declare module "mod" { }`
+    // are merged with existing interfaces
+    if interface.syntax().ancestors().any(|ancestor| {
+        TsGlobalDeclaration::can_cast(ancestor.kind())
+            || TsExternalModuleDeclaration::can_cast(ancestor.kind())
+    }) {
+        return None;
+    }
+    // The trailing trivia of `}` is moved after `;`
+    let object = AnyTsType::from(
+        make::ts_object_type(
+            interface.l_curly_token().ok()?,
+            interface.members(),
+            interface.r_curly_token().ok()?,
+        )
+        .trim_start()?
+        .with_trailing_trivia_pieces(iter::empty())?,
+    );
+    let mut types = Vec::new();
+    if let Some(extends_clause) = interface.extends_clause() {
+        for extended in extends_clause.types() {
+            let extended = extended.ok()?.trim()?;
+            let mut reference = make::ts_reference_type(extended.name().ok()?);
+            if let Some(type_arguments) = extended.type_arguments() {
+                reference = reference.with_type_arguments(type_arguments);
+            }
+            types.push(AnyTsType::from(reference.build()));
+        }
+    }
+    let ty = if types.is_empty() {
+        object
+    } else {
+        types.push(object);
+        let separators = (1..types.len()).map(|_| make::token_decorated_with_space(T![&]));
+        AnyTsType::from(
+            make::ts_intersection_type(make::ts_intersection_type_element_list(types, separators))
+                .build(),
+        )
+    };
+    let type_token =
+        make::token(T![type]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+    let mut builder = make::ts_type_alias_declaration(
+        type_token,
+        interface.id().ok()?.trim_end()?,
+        make::token_decorated_with_space(T![=]),
+        ty,
+    )
+    .with_semicolon_token(make::token(T![;]));
+    if let Some(type_parameters) = interface.type_parameters() {
+        builder = builder.with_type_parameters(type_parameters.trim_end()?);
+    }
+    Some(builder.build())
+}
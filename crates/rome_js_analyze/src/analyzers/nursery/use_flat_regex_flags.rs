@@ -0,0 +1,157 @@
+use crate::JsRuleAction;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_syntax::{JsRegexLiteralExpression, JsSyntaxKind, JsSyntaxToken};
+use rome_rowan::{BatchMutationExt, TextRange, TextSize};
+
+declare_rule! {
+    /// Enforce sorted flags without duplicates in regular expression literals.
+    ///
+    /// The flags of a regular expression can be written in any order,
+    /// but sorting them in the order `d`, `g`, `i`, `m`, `s`, `u`, `v`, `y`
+    /// makes the flags of different regular expressions easier to compare.
+    ///
+    /// The same flag can't appear twice: a regular expression with a duplicate flag is a syntax error.
+    ///
+    /// The code fix sorts the flags and removes the duplicates.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// /abc/ig;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// /abc/ymg;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /abc/;
+    /// /abc/gi;
+    /// /abc/gmy;
+    /// ```
+    ///
+    pub(crate) UseFlatRegexFlags {
+        version: "next",
+        name: "useFlatRegexFlags",
+        recommended: false,
+    }
+}
+
+/// The flags in their canonical order.
+const SORTED_FLAGS: &str = "dgimsuvy";
+
+pub(crate) enum RegexFlagsIssue {
+    /// The first flag that appears more than once
+    Duplicate(char),
+    /// The flags are not sorted
+    Unsorted,
+}
+
+pub(crate) struct RegexFlagsState {
+    issue: RegexFlagsIssue,
+    /// The range of the flags in the literal
+    range: TextRange,
+    /// The sorted flags, without duplicates
+    flags: String,
+}
+
+impl Rule for UseFlatRegexFlags {
+    type Query = Ast<JsRegexLiteralExpression>;
+    type State = RegexFlagsState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let value_token = ctx.query().value_token().ok()?;
+        let text = value_token.text_trimmed();
+        let flags_start = text.rfind('/')? + 1;
+        let flags = &text[flags_start..];
+        // The unknown flags are already reported by the parser
+        if !flags.chars().all(|flag| SORTED_FLAGS.contains(flag)) {
+            return None;
+        }
+        let sorted_flags: String = SORTED_FLAGS
+            .chars()
+            .filter(|flag| flags.contains(*flag))
+            .collect();
+        if flags == sorted_flags {
+            return None;
+        }
+        let duplicate = flags
+            .char_indices()
+            .find(|(index, flag)| flags[..*index].contains(*flag))
+            .map(|(_, flag)| flag);
+        let issue = match duplicate {
+            Some(flag) => RegexFlagsIssue::Duplicate(flag),
+            None => RegexFlagsIssue::Unsorted,
+        };
+        let token_start = value_token.text_trimmed_range().start();
+        let range = TextRange::new(
+            token_start + TextSize::from(flags_start as u32),
+            token_start + TextSize::from(text.len() as u32),
+        );
+        Some(RegexFlagsState {
+            issue,
+            range,
+            flags: sorted_flags,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state.issue {
+            RegexFlagsIssue::Duplicate(flag) => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The flag "<Emphasis>{flag.to_string()}</Emphasis>" is used more than once."
+                },
+            )
+            .note(markup! {
+                "A regular expression with a duplicate flag is a syntax error."
+            }),
+            RegexFlagsIssue::Unsorted => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The flags of this regular expression are not sorted."
+                },
+            )
+            .note(markup! {
+                "Sort the flags in the order "<Emphasis>{SORTED_FLAGS}</Emphasis>"."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let value_token = ctx.query().value_token().ok()?;
+        let text = value_token.text_trimmed();
+        let pattern = &text[..text.rfind('/')? + 1];
+        let new_token = JsSyntaxToken::new_detached(
+            JsSyntaxKind::JS_REGEX_LITERAL,
+            &format!("{pattern}{}", state.flags),
+            [],
+            [],
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(value_token, new_token);
+        let message = match state.issue {
+            RegexFlagsIssue::Duplicate(_) => {
+                markup! { "Remove the duplicate flags and sort the flags." }
+            }
+            RegexFlagsIssue::Unsorted => markup! { "Sort the flags." },
+        };
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: message.to_owned(),
+            mutation,
+        })
+    }
+}
@@ -0,0 +1,198 @@
+use crate::JsRuleAction;
+use bpaf::Bpaf;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_syntax::{inner_string_text, JsModuleSource, JsSyntaxKind, JsSyntaxToken};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, BatchMutationExt, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Enforce file extensions in relative imports.
+    ///
+    /// The ECMAScript modules resolve the specifiers as URLs,
+    /// so a relative import must include the extension of the imported file.
+    ///
+    /// The rule reports the `import` and `export ... from` declarations
+    /// whose specifier starts with `./` or `../` and whose last segment has no extension.
+    /// The specifiers that end with `/`, `.` or `..` are directories and are ignored.
+    ///
+    /// The analyzer doesn't read the file system,
+    /// so the code fix appends the first extension of the option `suggestedExtensions`, which is `.js` by default.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { sum } from "./math";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// export * from "../utils/strings";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { sum } from "./math.js";
+    /// import data from "../data.json";
+    /// import lodash from "lodash";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `suggestedExtensions` to change the extension appended by the code fix.
+    /// Only the first extension is used.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "suggestedExtensions": [".mjs"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseImportExtensions {
+        version: "next",
+        name: "useImportExtensions",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useImportExtensions`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ImportExtensionsOptions {
+    /// The extensions appended by the code fix. Only the first one is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide, argument::<String>("EXTENSION"), many, optional)]
+    pub suggested_extensions: Option<Vec<String>>,
+}
+
+impl ImportExtensionsOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["suggestedExtensions"];
+
+    /// Returns the extension to append, with a leading dot.
+    fn suggested_extension(&self) -> String {
+        let extension = self
+            .suggested_extensions
+            .as_ref()
+            .and_then(|extensions| extensions.first())
+            .map_or("js", |extension| extension.trim_start_matches('.'));
+        format!(".{extension}")
+    }
+}
+
+// Required by [Bpaf].
+impl FromStr for ImportExtensionsOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ImportExtensionsOptions {}
+impl VisitNode<JsonLanguage> for ImportExtensionsOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "suggestedExtensions" {
+            self.suggested_extensions =
+                self.map_to_array_of_strings(&value, name_text, diagnostics);
+        }
+        Some(())
+    }
+}
+
+impl Rule for UseImportExtensions {
+    type Query = Ast<JsModuleSource>;
+    /// The string literal of the module specifier.
+    type State = JsSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = ImportExtensionsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let source = ctx.query();
+        // Ignore `declare module "./name" {}`
+        if source.syntax().parent()?.kind() == JsSyntaxKind::TS_EXTERNAL_MODULE_DECLARATION {
+            return None;
+        }
+        let specifier = source.value_token().ok()?;
+        is_relative_without_extension(inner_string_text(&specifier).text()).then_some(specifier)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, specifier: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                specifier.text_trimmed_range(),
+                markup! {
+                    "The relative import "<Emphasis>{inner_string_text(specifier).text()}</Emphasis>" doesn't have a file extension."
+                },
+            )
+            .note(markup! {
+                "The ECMAScript modules require the extension of the imported file."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, specifier: &Self::State) -> Option<JsRuleAction> {
+        let extension = ctx.options().suggested_extension();
+        // Preserve the quotes of the original string
+        let quote = specifier.text_trimmed().chars().next()?;
+        let path = inner_string_text(specifier);
+        let new_specifier = JsSyntaxToken::new_detached(
+            JsSyntaxKind::JS_STRING_LITERAL,
+            &format!("{quote}{}{extension}{quote}", path.text()),
+            [],
+            [],
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(specifier.clone(), new_specifier);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Add the extension "<Emphasis>{extension}</Emphasis>"." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `specifier` is a relative path to a file without extension.
+fn is_relative_without_extension(specifier: &str) -> bool {
+    if !specifier.starts_with("./") && !specifier.starts_with("../") {
+        return false;
+    }
+    let Some(file_name) = specifier.rsplit('/').next() else {
+        return false;
+    };
+    if matches!(file_name, "" | "." | "..") {
+        return false;
+    }
+    // `.eslintrc` is a file without extension, `name.` is not a valid extension
+    !file_name
+        .rfind('.')
+        .is_some_and(|index| index > 0 && index < file_name.len() - 1)
+}
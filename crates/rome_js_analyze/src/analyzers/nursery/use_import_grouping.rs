@@ -0,0 +1,416 @@
+use crate::utils::{has_empty_line, NODE_BUILTIN_MODULES};
+use crate::JsRuleAction;
+use bpaf::Bpaf;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_syntax::{AnyJsImportClause, AnyJsModuleItem, JsImport, JsModule, TriviaPieceKind};
+use rome_json_syntax::{AnyJsonValue, JsonArrayValue, JsonLanguage};
+use rome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Enforce the grouping of the imports by kind.
+    ///
+    /// The imports are grouped in the following order:
+    /// the built-in modules of Node.js, the external packages, and the relative imports.
+    /// A blank line separates two groups.
+    ///
+    /// The imports without bindings, such as `import "./polyfill.js"`, run code when they are evaluated,
+    /// so they are never moved: the imports before and after them are checked separately.
+    /// The imports that follow another statement are also checked separately.
+    ///
+    /// The code fix reorders the imports, keeping the comments that precede an import attached to it,
+    /// and inserts the blank lines between the groups.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { sum } from "./math.js";
+    /// import fs from "node:fs";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import fs from "node:fs";
+    /// import lodash from "lodash";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import fs from "node:fs";
+    /// import path from "path";
+    ///
+    /// import lodash from "lodash";
+    ///
+    /// import { sum } from "./math.js";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `groups` to change the order of the groups.
+    /// The accepted groups are `"builtin"`, `"external"` and `"relative"`.
+    /// The groups that are not listed come last, in their default order.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "groups": ["external", "builtin", "relative"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseImportGrouping {
+        version: "next",
+        name: "useImportGrouping",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useImportGrouping`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ImportGroupingOptions {
+    /// The order of the groups: `"builtin"`, `"external"` and `"relative"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide, argument::<String>("GROUP"), many, optional)]
+    pub groups: Option<Vec<String>>,
+}
+
+impl ImportGroupingOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["groups"];
+
+    /// Returns the position of `group` in the enforced order.
+    fn rank(&self, group: ImportGroup) -> usize {
+        match &self.groups {
+            Some(groups) => groups
+                .iter()
+                .position(|name| name == group.name())
+                .unwrap_or(groups.len() + group as usize),
+            None => group as usize,
+        }
+    }
+}
+
+// Required by [Bpaf].
+impl FromStr for ImportGroupingOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ImportGroupingOptions {}
+impl VisitNode<JsonLanguage> for ImportGroupingOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "groups" {
+            if let AnyJsonValue::JsonArrayValue(array) = &value {
+                if !has_only_known_groups(array, diagnostics) {
+                    return None;
+                }
+            }
+            self.groups = self.map_to_array_of_strings(&value, name_text, diagnostics);
+        }
+        Some(())
+    }
+}
+
+fn has_only_known_groups(
+    array: &JsonArrayValue,
+    diagnostics: &mut Vec<DeserializationDiagnostic>,
+) -> bool {
+    // Visit every element to report all the unknown groups
+    let unknown_groups = array
+        .elements()
+        .iter()
+        .flatten()
+        .filter(|element| {
+            with_only_known_variants(element.syntax(), ImportGroup::KNOWN_VALUES, diagnostics)
+                .is_none()
+        })
+        .count();
+    unknown_groups == 0
+}
+
+/// The kinds of imports.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum ImportGroup {
+    /// `node:fs`, `fs`
+    Builtin,
+    /// `lodash`, `@scope/package`
+    External,
+    /// `./math.js`, `../utils.js`
+    Relative,
+}
+
+impl ImportGroup {
+    const KNOWN_VALUES: &'static [&'static str] = &["builtin", "external", "relative"];
+
+    fn from_specifier(specifier: &str) -> Self {
+        if specifier.starts_with("node:") || NODE_BUILTIN_MODULES.contains(&specifier) {
+            Self::Builtin
+        } else if specifier.starts_with('.') {
+            Self::Relative
+        } else {
+            Self::External
+        }
+    }
+
+    /// The name of the group in the options.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Builtin => "builtin",
+            Self::External => "external",
+            Self::Relative => "relative",
+        }
+    }
+
+    /// The name of the group in the diagnostics.
+    fn description(self) -> &'static str {
+        match self {
+            Self::Builtin => "Node.js built-in",
+            Self::External => "external",
+            Self::Relative => "relative",
+        }
+    }
+}
+
+pub(crate) enum MisgroupedImport {
+    /// The import must come before the imports of the group `expected_after`.
+    Order {
+        index: usize,
+        expected_after: ImportGroup,
+    },
+    /// The import starts a new group but it isn't preceded by a blank line.
+    MissingBlankLine { index: usize },
+}
+
+pub(crate) struct ImportSequence {
+    /// The imports checked together, in source order, with their group.
+    imports: Vec<(JsImport, ImportGroup)>,
+    misgrouped: MisgroupedImport,
+}
+
+impl Rule for UseImportGrouping {
+    type Query = Ast<JsModule>;
+    type State = ImportSequence;
+    type Signals = Option<Self::State>;
+    type Options = ImportGroupingOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let mut imports = Vec::new();
+        for item in ctx.query().items() {
+            if let AnyJsModuleItem::JsImport(import) = item {
+                if let Some(group) = import_group(&import) {
+                    imports.push((import, group));
+                    continue;
+                }
+            }
+            // The imports without bindings and the other items end the current sequence
+            if let Some(misgrouped) = find_misgrouped_import(&imports, options) {
+                return Some(ImportSequence {
+                    imports,
+                    misgrouped,
+                });
+            }
+            imports.clear();
+        }
+        let misgrouped = find_misgrouped_import(&imports, options)?;
+        Some(ImportSequence {
+            imports,
+            misgrouped,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state.misgrouped {
+            MisgroupedImport::Order {
+                index,
+                expected_after,
+            } => {
+                let (import, group) = &state.imports[index];
+                let group = group.description();
+                let expected_after = expected_after.description();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    import.range(),
+                    markup! {
+                        "The "<Emphasis>{group}</Emphasis>" imports must come before the "<Emphasis>{expected_after}</Emphasis>" imports."
+                    },
+                )
+            }
+            MisgroupedImport::MissingBlankLine { index } => {
+                let (import, group) = &state.imports[index];
+                let previous_group = state.imports[index - 1].1.description();
+                let group = group.description();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    import.range(),
+                    markup! {
+                        "The "<Emphasis>{group}</Emphasis>" imports must be separated from the "<Emphasis>{previous_group}</Emphasis>" imports by a blank line."
+                    },
+                )
+            }
+        };
+        Some(diagnostic.note(markup! {
+            "The imports are grouped by kind, and a blank line separates the groups."
+        }))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let options = ctx.options();
+        let imports = &state.imports;
+        let mut sorted: Vec<_> = imports.iter().collect();
+        sorted.sort_by_key(|(_, group)| options.rank(*group));
+
+        let first_token = imports.first()?.0.import_token().ok()?;
+        let newline = first_token
+            .leading_trivia()
+            .pieces()
+            .find(|piece| piece.is_newline())
+            .map_or("\n".to_string(), |piece| piece.text().to_string());
+        let (group_leading_trivia, _) = split_leading_trivia(&imports.first()?.0)?;
+
+        let mut mutation = ctx.root().begin();
+        let mut previous_group = None;
+        for ((old_import, _), (import, group)) in imports.iter().zip(sorted) {
+            let (detached, attached) = split_leading_trivia(import)?;
+            let mut leading_trivia = match previous_group {
+                None => group_leading_trivia.clone(),
+                Some(previous_group) if previous_group == *group => {
+                    vec![(TriviaPieceKind::Newline, newline.clone())]
+                }
+                Some(_) => vec![
+                    (TriviaPieceKind::Newline, newline.clone()),
+                    (TriviaPieceKind::Newline, newline.clone()),
+                ],
+            };
+            // The detached trivia of the first import is the leading trivia of the sequence,
+            // the detached comments of the other imports move with them
+            if import != &imports.first()?.0 {
+                leading_trivia.extend(
+                    detached
+                        .into_iter()
+                        .skip_while(|(kind, _)| kind.is_newline() || kind.is_whitespace()),
+                );
+            }
+            leading_trivia.extend(attached);
+            previous_group = Some(*group);
+
+            let token = import.import_token().ok()?;
+            let new_token = token.with_leading_trivia(
+                leading_trivia
+                    .iter()
+                    .map(|(kind, text)| (*kind, text.as_str()))
+                    .collect::<Vec<_>>(),
+            );
+            let new_import = import.clone().with_import_token(new_token);
+            mutation.replace_node_discard_trivia(old_import.clone(), new_import);
+        }
+
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Group the imports." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the group of `import`, or `None` if it has no bindings.
+fn import_group(import: &JsImport) -> Option<ImportGroup> {
+    let source = match import.import_clause().ok()? {
+        AnyJsImportClause::JsImportBareClause(_) => return None,
+        AnyJsImportClause::JsImportDefaultClause(clause) => clause.source(),
+        AnyJsImportClause::JsImportNamedClause(clause) => clause.source(),
+        AnyJsImportClause::JsImportNamespaceClause(clause) => clause.source(),
+    };
+    let specifier = source.ok()?.inner_string_text().ok()?;
+    Some(ImportGroup::from_specifier(specifier.text()))
+}
+
+fn find_misgrouped_import(
+    imports: &[(JsImport, ImportGroup)],
+    options: &ImportGroupingOptions,
+) -> Option<MisgroupedImport> {
+    let mut last_group = imports.first()?.1;
+    for (index, window) in imports.windows(2).enumerate() {
+        let [(_, previous_group), (import, group)] = window else {
+            continue;
+        };
+        let index = index + 1;
+        if options.rank(*group) < options.rank(last_group) {
+            return Some(MisgroupedImport::Order {
+                index,
+                expected_after: last_group,
+            });
+        }
+        if group != previous_group && !has_empty_line(import.import_token().ok()?.leading_trivia())
+        {
+            return Some(MisgroupedImport::MissingBlankLine { index });
+        }
+        last_group = *group;
+    }
+    None
+}
+
+type TriviaPieces = Vec<(TriviaPieceKind, String)>;
+
+/// Splits the leading trivia of `import` in two parts:
+/// the trivia detached from the import, which ends with the last blank line
+/// or with the whitespace that precedes the first comment,
+/// and the comments attached to the import.
+fn split_leading_trivia(import: &JsImport) -> Option<(TriviaPieces, TriviaPieces)> {
+    let token = import.import_token().ok()?;
+    let pieces: TriviaPieces = token
+        .leading_trivia()
+        .pieces()
+        .map(|piece| (piece.kind(), piece.text().to_string()))
+        .collect();
+    // The index that follows the last blank line
+    let mut after_blank_line = 0;
+    let mut was_newline = false;
+    for (index, (kind, _)) in pieces.iter().enumerate() {
+        if kind.is_whitespace() {
+            continue;
+        }
+        if kind.is_newline() && was_newline {
+            after_blank_line = index + 1;
+        }
+        was_newline = kind.is_newline();
+    }
+    let split = after_blank_line
+        + pieces[after_blank_line..]
+            .iter()
+            .take_while(|(kind, _)| kind.is_newline() || kind.is_whitespace())
+            .count();
+    let attached = pieces[split..].to_vec();
+    let mut detached = pieces;
+    detached.truncate(split);
+    Some((detached, attached))
+}
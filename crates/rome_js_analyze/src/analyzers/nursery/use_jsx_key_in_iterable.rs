@@ -0,0 +1,185 @@
+use crate::control_flow::AnyJsControlFlowRoot;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::{
+    AnyJsExpression, AnyJsFunctionBody, AnyJsMemberExpression, AnyJsxAttribute, AnyJsxTag,
+    JsCallExpression, JsReturnStatement, JsxAttributeList,
+};
+use rome_rowan::{AstNode, AstNodeList, AstSeparatedList, WalkEvent};
+
+declare_rule! {
+    /// Require a `key` property on the JSX elements returned by the callback of `map`.
+    ///
+    /// React uses the `key` property to identify the elements of a list between two renders.
+    /// Without a key, React can mix up the state of the elements when the list changes.
+    ///
+    /// The rule checks the elements returned by the callback of a `.map()` call,
+    /// both from the expression body of an arrow function and from the `return` statements of a function body.
+    /// The elements that have a spread attribute are ignored, because the spread object can contain the key.
+    ///
+    /// The shorthand fragment syntax `<></>` cannot have a key.
+    /// Use `<Fragment key={...}></Fragment>` instead.
+    ///
+    /// Source: https://github.com/jsx-eslint/eslint-plugin-react/blob/master/docs/rules/jsx-key.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// items.map((item) => <li>{item.name}</li>);
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// items.map((item) => {
+    ///     return <li>{item.name}</li>;
+    /// });
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// items.map((item) => <>{item.name}</>);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// items.map((item) => <li key={item.id}>{item.name}</li>);
+    /// ```
+    ///
+    /// ```jsx
+    /// items.map((item) => <Item {...item} />);
+    /// ```
+    ///
+    pub(crate) UseJsxKeyInIterable {
+        version: "next",
+        name: "useJsxKeyInIterable",
+        recommended: false,
+    }
+}
+
+impl Rule for UseJsxKeyInIterable {
+    type Query = Ast<JsCallExpression>;
+    /// A returned element without a key.
+    type State = AnyJsxTag;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        map_callback_results(ctx.query())
+            .into_iter()
+            .flat_map(returned_tags)
+            .filter(|tag| !has_key(tag))
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, tag: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match tag {
+            AnyJsxTag::JsxFragment(_) => RuleDiagnostic::new(
+                rule_category!(),
+                tag.range(),
+                markup! {
+                    "This fragment is returned by a "<Emphasis>"map"</Emphasis>" callback, but the shorthand fragment syntax cannot have a "<Emphasis>"key"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Use "<Emphasis>"<Fragment key={...}>"</Emphasis>" instead."
+            }),
+            _ => RuleDiagnostic::new(
+                rule_category!(),
+                tag.range(),
+                markup! {
+                    "This element is returned by a "<Emphasis>"map"</Emphasis>" callback, but it has no "<Emphasis>"key"</Emphasis>" property."
+                },
+            )
+            .note(markup! {
+                "React uses the "<Emphasis>"key"</Emphasis>" property to identify the elements of a list between renders."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns the expressions returned by the callback of `call` if it is a call to `map`.
+fn map_callback_results(call: &JsCallExpression) -> Vec<AnyJsExpression> {
+    let callback = call.callee().ok().and_then(|callee| {
+        let callee = AnyJsMemberExpression::cast_ref(callee.omit_parentheses().syntax())?;
+        if callee.member_name()?.text() != "map" {
+            return None;
+        }
+        let argument = call.arguments().ok()?.args().first()?.ok()?;
+        Some(argument.as_any_js_expression()?.clone().omit_parentheses())
+    });
+    let body = match callback {
+        Some(AnyJsExpression::JsArrowFunctionExpression(function)) => function.body().ok(),
+        Some(AnyJsExpression::JsFunctionExpression(function)) => {
+            function.body().ok().map(AnyJsFunctionBody::JsFunctionBody)
+        }
+        _ => None,
+    };
+    match body {
+        Some(AnyJsFunctionBody::AnyJsExpression(expression)) => vec![expression],
+        Some(AnyJsFunctionBody::JsFunctionBody(body)) => {
+            let mut results = Vec::new();
+            let mut iter = body.syntax().preorder();
+            while let Some(event) = iter.next() {
+                let WalkEvent::Enter(node) = event else {
+                    continue;
+                };
+                if AnyJsControlFlowRoot::can_cast(node.kind()) {
+                    iter.skip_subtree();
+                } else if let Some(argument) =
+                    JsReturnStatement::cast(node).and_then(|statement| statement.argument())
+                {
+                    results.push(argument);
+                }
+            }
+            results
+        }
+        None => Vec::new(),
+    }
+}
+
+/// Returns the JSX tags that `expression` can evaluate to.
+fn returned_tags(expression: AnyJsExpression) -> Vec<AnyJsxTag> {
+    match expression.omit_parentheses() {
+        AnyJsExpression::JsxTagExpression(expression) => {
+            expression.tag().ok().into_iter().collect()
+        }
+        AnyJsExpression::JsConditionalExpression(expression) => {
+            [expression.consequent().ok(), expression.alternate().ok()]
+                .into_iter()
+                .flatten()
+                .flat_map(returned_tags)
+                .collect()
+        }
+        AnyJsExpression::JsLogicalExpression(expression) => expression
+            .right()
+            .ok()
+            .into_iter()
+            .flat_map(returned_tags)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns `true` if `tag` has a `key` attribute, or a spread attribute that can contain it.
+fn has_key(tag: &AnyJsxTag) -> bool {
+    let attributes = match tag {
+        AnyJsxTag::JsxElement(element) => element
+            .opening_element()
+            .map(|opening| opening.attributes()),
+        AnyJsxTag::JsxSelfClosingElement(element) => Ok(element.attributes()),
+        AnyJsxTag::JsxFragment(_) => return false,
+    };
+    attributes.is_ok_and(|attributes| has_key_attribute(&attributes))
+}
+
+fn has_key_attribute(attributes: &JsxAttributeList) -> bool {
+    attributes.iter().any(|attribute| match attribute {
+        AnyJsxAttribute::JsxAttribute(attribute) => attribute
+            .name_value_token()
+            .is_some_and(|name| name.text_trimmed() == "key"),
+        AnyJsxAttribute::JsxSpreadAttribute(_) => true,
+    })
+}
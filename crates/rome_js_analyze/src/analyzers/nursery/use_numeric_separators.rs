@@ -0,0 +1,305 @@
+use crate::JsRuleAction;
+use bpaf::Bpaf;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_syntax::{JsBigintLiteralExpression, JsNumberLiteralExpression, JsSyntaxToken};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{declare_node_union, AstNode, BatchMutationExt, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Enforce the use of numeric separators in long numeric literals.
+    ///
+    /// Long sequences of digits are hard to read.
+    /// Since ES2021, the digits of a numeric literal can be grouped with the underscore (`_`) separator.
+    ///
+    /// Decimal numbers are grouped by three digits:
+    /// the integer part from the right and the fractional part from the left.
+    /// Hexadecimal and octal literals are grouped by four digits, and binary literals by eight digits.
+    /// Numeric literals that already contain a separator are ignored.
+    ///
+    /// Source: https://github.com/sindresorhus/eslint-plugin-unicorn/blob/main/docs/rules/numeric-separators-style.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const budget = 1000000;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const pi = 3.14159265;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const mask = 0xFFFFFFFF;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const big = 9007199254740993n;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const budget = 1_000_000;
+    /// const small = 1000;
+    /// const mask = 0xFFFF_FFFF;
+    /// const custom = 10_00_000;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `minimumDigits` to change the number of digits
+    /// that a part of a numeric literal must have to require separators.
+    /// Its default value is `5`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "minimumDigits": 7
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseNumericSeparators {
+        version: "next",
+        name: "useNumericSeparators",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyJsNumericLiteral = JsNumberLiteralExpression | JsBigintLiteralExpression
+}
+
+impl AnyJsNumericLiteral {
+    fn value_token(&self) -> Option<JsSyntaxToken> {
+        match self {
+            AnyJsNumericLiteral::JsNumberLiteralExpression(literal) => literal.value_token().ok(),
+            AnyJsNumericLiteral::JsBigintLiteralExpression(literal) => literal.value_token().ok(),
+        }
+    }
+}
+
+/// Options for the rule `useNumericSeparators`.
+#[derive(Deserialize, Serialize, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NumericSeparatorsOptions {
+    /// The minimum number of digits that a part of a numeric literal must have to require separators.
+    #[bpaf(hide)]
+    pub minimum_digits: u8,
+}
+
+impl NumericSeparatorsOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["minimumDigits"];
+}
+
+impl Default for NumericSeparatorsOptions {
+    fn default() -> Self {
+        Self { minimum_digits: 5 }
+    }
+}
+
+// Required by [Bpaf].
+impl FromStr for NumericSeparatorsOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for NumericSeparatorsOptions {}
+impl VisitNode<JsonLanguage> for NumericSeparatorsOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "minimumDigits" {
+            self.minimum_digits = self.map_to_u8(&value, name_text, u8::MAX, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
+impl Rule for UseNumericSeparators {
+    type Query = Ast<AnyJsNumericLiteral>;
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = NumericSeparatorsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let token = ctx.query().value_token()?;
+        let minimum_digits = ctx.options().minimum_digits as usize;
+        let text = token.text_trimmed();
+        let grouped = group_digits(text, minimum_digits)?;
+        (grouped != text).then_some(grouped)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This numeric literal is hard to read."
+                },
+            )
+            .note(markup! {
+                "Group its digits with numeric separators ("<Emphasis>"_"</Emphasis>")."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, grouped: &Self::State) -> Option<JsRuleAction> {
+        let token = ctx.query().value_token()?;
+        let mut mutation = ctx.root().begin();
+        let new_token = JsSyntaxToken::new_detached(token.kind(), grouped, [], []);
+        mutation.replace_token_transfer_trivia(token, new_token);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Add numeric separators." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `text` with its digits grouped by numeric separators.
+///
+/// Returns `None` if the literal cannot or should not be grouped:
+/// literals that already contain a separator and legacy octal literals.
+fn group_digits(text: &str, minimum_digits: usize) -> Option<String> {
+    if text.contains('_') {
+        return None;
+    }
+    let (text, suffix) = match text.strip_suffix('n') {
+        Some(text) => (text, "n"),
+        None => (text, ""),
+    };
+    let bytes = text.as_bytes();
+    if bytes.len() > 1 && bytes[0] == b'0' {
+        let group_size = match bytes[1] {
+            b'x' | b'X' | b'o' | b'O' => 4,
+            b'b' | b'B' => 8,
+            // Legacy octal literals and decimal literals with a leading zero
+            // don't support numeric separators.
+            b'0'..=b'9' => return None,
+            _ => 0,
+        };
+        if group_size != 0 {
+            let (prefix, digits) = text.split_at(2);
+            let digits = if digits.len() >= minimum_digits {
+                group_from_right(digits, group_size)
+            } else {
+                digits.to_string()
+            };
+            return Some(format!("{prefix}{digits}{suffix}"));
+        }
+    }
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(index) => text.split_at(index),
+        None => (text, ""),
+    };
+    let (integer, fraction) = match mantissa.find('.') {
+        Some(index) => (&mantissa[..index], Some(&mantissa[index + 1..])),
+        None => (mantissa, None),
+    };
+    let mut result = if integer.len() >= minimum_digits {
+        group_from_right(integer, 3)
+    } else {
+        integer.to_string()
+    };
+    if let Some(fraction) = fraction {
+        result.push('.');
+        if fraction.len() >= minimum_digits {
+            result.push_str(&group_from_left(fraction, 3));
+        } else {
+            result.push_str(fraction);
+        }
+    }
+    result.push_str(exponent);
+    result.push_str(suffix);
+    Some(result)
+}
+
+/// Inserts a separator every `group_size` digits, starting from the last digit.
+fn group_from_right(digits: &str, group_size: usize) -> String {
+    let mut result = String::with_capacity(digits.len() + digits.len() / group_size);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % group_size == 0 {
+            result.push('_');
+        }
+        result.push(digit);
+    }
+    result
+}
+
+/// Inserts a separator every `group_size` digits, starting from the first digit.
+fn group_from_left(digits: &str, group_size: usize) -> String {
+    let mut result = String::with_capacity(digits.len() + digits.len() / group_size);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && index % group_size == 0 {
+            result.push('_');
+        }
+        result.push(digit);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::group_digits;
+
+    #[test]
+    fn groups_decimal_literals() {
+        assert_eq!(group_digits("1000000", 5).as_deref(), Some("1_000_000"));
+        assert_eq!(
+            group_digits("1234.56789", 5).as_deref(),
+            Some("1234.567_89")
+        );
+        assert_eq!(group_digits("12345e10", 5).as_deref(), Some("12_345e10"));
+        assert_eq!(group_digits("1000", 5).as_deref(), Some("1000"));
+        assert_eq!(group_digits("1_000_000", 5), None);
+        assert_eq!(group_digits("0777777", 5), None);
+    }
+
+    #[test]
+    fn groups_non_decimal_literals() {
+        assert_eq!(
+            group_digits("0xFFFFFFFF", 5).as_deref(),
+            Some("0xFFFF_FFFF")
+        );
+        assert_eq!(group_digits("0o7777777", 5).as_deref(), Some("0o777_7777"));
+        assert_eq!(
+            group_digits("0b1111000011110000", 5).as_deref(),
+            Some("0b11110000_11110000")
+        );
+        assert_eq!(
+            group_digits("123456789n", 5).as_deref(),
+            Some("123_456_789n")
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use crate::JsRuleAction;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    AnyTsReturnType, AnyTsTypeMember, JsSyntaxKind, TriviaPieceKind, TsCallSignatureTypeMember,
+    TsInterfaceDeclaration, T,
+};
+use rome_rowan::{AstNode, AstNodeList, BatchMutationExt};
+
+declare_rule! {
+    /// Enforce using a function type instead of an interface with a single call signature.
+    ///
+    /// An interface whose only member is a call signature describes a function.
+    /// The type alias `type Callback = (x: number) => void` is shorter and clearer than
+    /// `interface Callback { (x: number): void }`.
+    ///
+    /// Interfaces that extend other interfaces are ignored,
+    /// because the call signature is merged with the inherited members.
+    /// Call signatures that return `this` are also ignored,
+    /// because `this` has a different meaning in a function type.
+    ///
+    /// Source: https://typescript-eslint.io/rules/prefer-function-type
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// interface Callback {
+    ///     (x: number): void;
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// export interface Comparator<T> {
+    ///     (a: T, b: T): number;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// type Callback = (x: number) => void;
+    /// interface Overloaded {
+    ///     (x: number): number;
+    ///     (x: string): string;
+    /// }
+    /// interface Extended extends Base {
+    ///     (x: number): void;
+    /// }
+    /// ```
+    ///
+    pub(crate) UseShorthandFunctionType {
+        version: "next",
+        name: "useShorthandFunctionType",
+        recommended: false,
+    }
+}
+
+impl Rule for UseShorthandFunctionType {
+    type Query = Ast<TsInterfaceDeclaration>;
+    /// The only call signature of the interface.
+    type State = TsCallSignatureTypeMember;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.extends_clause().is_some() {
+            return None;
+        }
+        let members = node.members();
+        if members.len() != 1 {
+            return None;
+        }
+        let AnyTsTypeMember::TsCallSignatureTypeMember(call_signature) = members.first()? else {
+            return None;
+        };
+        let returns_this = call_signature
+            .return_type_annotation()
+            .and_then(|annotation| annotation.ty().ok())
+            .is_some_and(|return_type| {
+                return_type
+                    .syntax()
+                    .descendants()
+                    .any(|descendant| descendant.kind() == JsSyntaxKind::TS_THIS_TYPE)
+            });
+        (!returns_this).then_some(call_signature)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let id = ctx.query().id().ok()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                id.range(),
+                markup! {
+                    "The interface "<Emphasis>{id.text()}</Emphasis>" only has a call signature."
+                },
+            )
+            .note(markup! {
+                "A function type is shorter and clearer."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, call_signature: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        // `export default` cannot be followed by a type alias
+        if node.syntax().parent().is_some_and(|parent| {
+            parent.kind() == JsSyntaxKind::JS_EXPORT_DEFAULT_DECLARATION_CLAUSE
+        }) {
+            return None;
+        }
+        // A function type requires a return type
+        let return_type = call_signature.return_type_annotation()?.ty().ok()?;
+        let mut function_type = make::ts_function_type(
+            call_signature.parameters().ok()?.trim()?,
+            make::token(T![=>])
+                .with_leading_trivia([(TriviaPieceKind::Whitespace, " ")])
+                .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            AnyTsReturnType::cast(return_type.into_syntax().trim_trailing_trivia()?)?,
+        );
+        if let Some(type_parameters) = call_signature.type_parameters() {
+            function_type = function_type.with_type_parameters(type_parameters.trim()?);
+        }
+        let mut type_alias = make::ts_type_alias_declaration(
+            make::token(T![type]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            node.id().ok()?.trim()?,
+            make::token(T![=])
+                .with_leading_trivia([(TriviaPieceKind::Whitespace, " ")])
+                .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            function_type.build().into(),
+        )
+        .with_semicolon_token(make::token(T![;]));
+        if let Some(type_parameters) = node.type_parameters() {
+            type_alias = type_alias.with_type_parameters(type_parameters.trim()?);
+        }
+        let mut mutation = ctx.root().begin();
+        mutation.replace_element(
+            node.syntax().clone().into(),
+            type_alias.build().into_syntax().into(),
+        );
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use a function type instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
@@ -0,0 +1,104 @@
+use crate::JsRuleAction;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{AnyJsExpression, JsCallExpression, JsSyntaxToken};
+use rome_rowan::{AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Enforce the use of `trimStart()` and `trimEnd()` over `trimLeft()` and `trimRight()`.
+    ///
+    /// `String.prototype.trimLeft()` and `String.prototype.trimRight()` are deprecated aliases
+    /// of `String.prototype.trimStart()` and `String.prototype.trimEnd()`.
+    ///
+    /// The code fix renames the method.
+    /// Because the receiver may not be a string, the code fix is not always safe.
+    ///
+    /// Source: https://github.com/sindresorhus/eslint-plugin-unicorn/blob/main/docs/rules/prefer-string-trim-start-end.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const foo = bar.trimLeft();
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const foo = bar.trimRight();
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const foo = bar.trimStart();
+    /// const baz = bar.trimEnd();
+    /// ```
+    ///
+    pub(crate) UseTrimStartEnd {
+        version: "next",
+        name: "useTrimStartEnd",
+        recommended: false,
+    }
+}
+
+impl Rule for UseTrimStartEnd {
+    type Query = Ast<JsCallExpression>;
+    /// The name token of the called method.
+    type State = JsSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.type_arguments().is_some() || !node.arguments().ok()?.args().is_empty() {
+            return None;
+        }
+        let AnyJsExpression::JsStaticMemberExpression(callee) =
+            node.callee().ok()?.omit_parentheses()
+        else {
+            return None;
+        };
+        let name = callee.member().ok()?.as_js_name()?.value_token().ok()?;
+        matches!(name.text_trimmed(), "trimLeft" | "trimRight").then_some(name)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        let name_text = name.text_trimmed();
+        let replacement = replacement(name_text)?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                name.text_trimmed_range(),
+                markup! {
+                    "Use "<Emphasis>{replacement}</Emphasis>" instead of "<Emphasis>{name_text}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                <Emphasis>{name_text}</Emphasis>" is a deprecated alias of "<Emphasis>{replacement}</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, name: &Self::State) -> Option<JsRuleAction> {
+        let replacement = replacement(name.text_trimmed())?;
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token(name.clone(), make::ident(replacement));
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>{replacement}</Emphasis>" instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+fn replacement(name: &str) -> Option<&'static str> {
+    match name {
+        "trimLeft" => Some("trimStart"),
+        "trimRight" => Some("trimEnd"),
+        _ => None,
+    }
+}
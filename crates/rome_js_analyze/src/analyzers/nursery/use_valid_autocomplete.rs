@@ -0,0 +1,203 @@
+use crate::utils::edit_distance;
+use rome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::{jsx_ext::AnyJsxElement, AnyJsxAttributeValue, JsxAttribute, TextRange};
+use rome_rowan::AstNode;
+
+declare_rule! {
+    /// Enforce that the `autocomplete` attribute contains valid tokens.
+    ///
+    /// The browsers ignore the `autocomplete` attribute when one of its tokens is unknown,
+    /// so the users cannot rely on the autofill of their browser or of their password manager.
+    ///
+    /// The tokens are checked against the [autofill field names](https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#autofill)
+    /// of the HTML specification, ignoring the case.
+    /// The values computed by an expression and the custom components are ignored.
+    /// When an invalid token is close to a valid one, the diagnostic suggests it.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <input autoComplete="emial" />
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <input autoComplete="billing incorrect" />
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <input autoComplete="email" />
+    /// ```
+    ///
+    /// ```jsx
+    /// <input autoComplete="section-login shipping street-address" />
+    /// ```
+    ///
+    /// ```jsx
+    /// <input autoComplete={autoComplete} />
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 1.3.5](https://www.w3.org/WAI/WCAG21/Understanding/identify-input-purpose)
+    ///
+    pub(crate) UseValidAutocomplete {
+        version: "next",
+        name: "useValidAutocomplete",
+        recommended: false,
+    }
+}
+
+/// The tokens of the `autocomplete` attribute, except the `section-*` tokens.
+const AUTOCOMPLETE_TOKENS: [&str; 64] = [
+    "off",
+    "on",
+    "shipping",
+    "billing",
+    "home",
+    "work",
+    "mobile",
+    "fax",
+    "pager",
+    "webauthn",
+    "name",
+    "honorific-prefix",
+    "given-name",
+    "additional-name",
+    "family-name",
+    "honorific-suffix",
+    "nickname",
+    "username",
+    "new-password",
+    "current-password",
+    "one-time-code",
+    "organization-title",
+    "organization",
+    "street-address",
+    "address-line1",
+    "address-line2",
+    "address-line3",
+    "address-level4",
+    "address-level3",
+    "address-level2",
+    "address-level1",
+    "country",
+    "country-name",
+    "postal-code",
+    "cc-name",
+    "cc-given-name",
+    "cc-additional-name",
+    "cc-family-name",
+    "cc-number",
+    "cc-exp",
+    "cc-exp-month",
+    "cc-exp-year",
+    "cc-csc",
+    "cc-type",
+    "transaction-currency",
+    "transaction-amount",
+    "language",
+    "bday",
+    "bday-day",
+    "bday-month",
+    "bday-year",
+    "sex",
+    "url",
+    "photo",
+    "tel",
+    "tel-country-code",
+    "tel-national",
+    "tel-area-code",
+    "tel-local",
+    "tel-local-prefix",
+    "tel-local-suffix",
+    "tel-extension",
+    "email",
+    "impp",
+];
+
+pub(crate) struct InvalidToken {
+    /// The range of the attribute value.
+    range: TextRange,
+    token: String,
+    /// A valid token close to the invalid one.
+    suggestion: Option<&'static str>,
+}
+
+impl Rule for UseValidAutocomplete {
+    type Query = Ast<JsxAttribute>;
+    type State = InvalidToken;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let attribute = ctx.query();
+        let name = attribute.name_value_token()?;
+        if !matches!(name.text_trimmed(), "autoComplete" | "autocomplete") {
+            return None;
+        }
+        let element = attribute
+            .syntax()
+            .ancestors()
+            .find_map(AnyJsxElement::cast)?;
+        if !element.is_element() {
+            return None;
+        }
+        // Don't check the values computed by an expression
+        let AnyJsxAttributeValue::JsxString(value) = attribute.initializer()?.value().ok()? else {
+            return None;
+        };
+        let text = value.inner_string_text().ok()?;
+        let token = text
+            .text()
+            .split_ascii_whitespace()
+            .find(|token| !is_valid_token(token))?;
+        Some(InvalidToken {
+            range: value.range(),
+            token: token.to_string(),
+            suggestion: closest_token(token),
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let token = &state.token;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.range,
+            markup! {
+                "The token "<Emphasis>{token}</Emphasis>" isn't a valid value of "<Emphasis>"autocomplete"</Emphasis>"."
+            },
+        )
+        .note(markup! {
+            "The browsers ignore the "<Emphasis>"autocomplete"</Emphasis>" attribute when one of its tokens is invalid."
+        });
+        let diagnostic = match state.suggestion {
+            Some(suggestion) => diagnostic.note(markup! {
+                "Did you mean "<Emphasis>{suggestion}</Emphasis>"?"
+            }),
+            None => diagnostic,
+        };
+        Some(diagnostic)
+    }
+}
+
+fn is_valid_token(token: &str) -> bool {
+    let token = token.to_ascii_lowercase();
+    token.starts_with("section-") || AUTOCOMPLETE_TOKENS.contains(&token.as_str())
+}
+
+/// Returns the valid token that is the closest to `token`, if it is close enough.
+fn closest_token(token: &str) -> Option<&'static str> {
+    let token = token.to_ascii_lowercase();
+    let max_distance = (token.len() / 3).clamp(1, 2);
+    AUTOCOMPLETE_TOKENS
+        .iter()
+        .map(|candidate| (edit_distance(&token, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
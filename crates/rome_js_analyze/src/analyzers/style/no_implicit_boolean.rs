@@ -1,11 +1,18 @@
+use bpaf::Bpaf;
 use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
 use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
 use rome_js_syntax::{
-    AnyJsLiteralExpression, AnyJsxAttributeValue, JsSyntaxKind, JsxAttribute, JsxAttributeFields, T,
+    AnyJsExpression, AnyJsLiteralExpression, AnyJsxAttributeValue, JsSyntaxKind, JsxAttribute,
+    JsxAttributeFields, T,
 };
-use rome_rowan::{AstNode, AstNodeExt, BatchMutationExt};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, AstNodeExt, BatchMutationExt, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 use crate::JsRuleAction;
 
@@ -41,6 +48,27 @@ declare_rule! {
     /// ```jsx
     /// <input disabled='false' />
     ///```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `explicitValues` to choose the enforced style.
+    /// It accepts the following values:
+    ///
+    /// - `"always"` reports the attributes without a value, such as `<input disabled />`. This is the default.
+    /// - `"never"` reports the attributes with the value `{true}`, such as `<input disabled={true} />`.
+    ///
+    /// Use the option `ignoredAttributes` to ignore some attributes, such as `key` or `ref`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "explicitValues": "never",
+    ///         "ignoredAttributes": ["key", "ref"]
+    ///     }
+    /// }
+    /// ```
+    ///
     pub(crate) NoImplicitBoolean {
         version: "1.0.0",
         name: "noImplicitBoolean",
@@ -48,31 +76,167 @@ declare_rule! {
     }
 }
 
+/// Options for the rule `noImplicitBoolean`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ImplicitBooleanOptions {
+    /// Whether the value `true` must be written explicitly.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub explicit_values: ExplicitValues,
+    /// The attributes that are ignored.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide, argument::<String>("NAME"), many, optional)]
+    pub ignored_attributes: Option<Vec<String>>,
+}
+
+impl ImplicitBooleanOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["explicitValues", "ignoredAttributes"];
+
+    fn is_ignored(&self, name: &str) -> bool {
+        self.ignored_attributes
+            .as_ref()
+            .is_some_and(|ignored_attributes| {
+                ignored_attributes.iter().any(|ignored| ignored == name)
+            })
+    }
+}
+
+// Required by [Bpaf].
+impl FromStr for ImplicitBooleanOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ImplicitBooleanOptions {}
+impl VisitNode<JsonLanguage> for ImplicitBooleanOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        match name_text {
+            "explicitValues" => {
+                let mut explicit_values = ExplicitValues::default();
+                self.map_to_known_string(&value, name_text, &mut explicit_values, diagnostics)?;
+                self.explicit_values = explicit_values;
+            }
+            "ignoredAttributes" => {
+                self.ignored_attributes =
+                    self.map_to_array_of_strings(&value, name_text, diagnostics);
+            }
+            _ => {}
+        }
+        Some(())
+    }
+}
+
+/// Whether the value `true` of JSX attributes is written explicitly.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ExplicitValues {
+    /// `<input disabled={true} />`
+    #[default]
+    Always,
+    /// `<input disabled />`
+    Never,
+}
+
+impl ExplicitValues {
+    pub const KNOWN_VALUES: &'static [&'static str] = &["always", "never"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ExplicitValues {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(ExplicitValues::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for ExplicitValues {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, Self::KNOWN_VALUES, diagnostics)?;
+        match node.inner_string_text().ok()?.text() {
+            "always" => *self = Self::Always,
+            "never" => *self = Self::Never,
+            _ => (),
+        }
+        Some(())
+    }
+}
+
 impl Rule for NoImplicitBoolean {
     type Query = Ast<JsxAttribute>;
     type State = ();
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = ImplicitBooleanOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let n = ctx.query();
+        let options = ctx.options();
+
+        if options.is_ignored(n.name_value_token()?.text_trimmed()) {
+            return None;
+        }
 
-        match n.initializer() {
-            Some(_) => None,
-            None => Some(()),
+        match (n.initializer(), options.explicit_values) {
+            (None, ExplicitValues::Always) => Some(()),
+            (Some(initializer), ExplicitValues::Never) => {
+                let value = initializer.value().ok()?;
+                let expression = value
+                    .as_jsx_expression_attribute_value()?
+                    .expression()
+                    .ok()?;
+                match expression {
+                    AnyJsExpression::AnyJsLiteralExpression(
+                        AnyJsLiteralExpression::JsBooleanLiteralExpression(literal),
+                    ) if literal.value_token().ok()?.kind() == T![true] => Some(()),
+                    _ => None,
+                }
+            }
+            _ => None,
         }
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
         let n = ctx.query();
+        let message = match ctx.options().explicit_values {
+            ExplicitValues::Always => markup! {
+                "Use explicit boolean values for boolean JSX props."
+            },
+            ExplicitValues::Never => markup! {
+                "Use implicit "<Emphasis>"true"</Emphasis>" values for boolean JSX props."
+            },
+        };
 
         Some(RuleDiagnostic::new(
             rule_category!(),
-            n.range(),
-            markup! {
-                "Use explicit boolean values for boolean JSX props."
-            }
-            .to_owned(),
+            n.name().ok()?.range(),
+            message.to_owned(),
         ))
     }
 
@@ -80,6 +244,24 @@ impl Rule for NoImplicitBoolean {
         let n = ctx.query();
         let mut mutation = ctx.root().begin();
 
+        if ctx.options().explicit_values == ExplicitValues::Never {
+            // <div disabled={true} /**test*/ /> ->    <div disabled /**test*/ />
+            let trailing_trivia = n.initializer()?.syntax().last_trailing_trivia()?;
+            let next_name = n
+                .name()
+                .ok()?
+                .append_trivia_pieces(trailing_trivia.pieces())?;
+            mutation.replace_node(n.clone(), make::jsx_attribute(next_name).build());
+
+            return Some(JsRuleAction {
+                category: ActionCategory::QuickFix,
+                applicability: Applicability::Always,
+                message: markup! { "Remove the explicit `true` literal for this attribute" }
+                    .to_owned(),
+                mutation,
+            });
+        }
+
         let JsxAttributeFields {
             name,
             initializer: _,
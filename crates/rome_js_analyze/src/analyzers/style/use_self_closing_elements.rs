@@ -1,15 +1,28 @@
+use bpaf::Bpaf;
 use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
 use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
-use rome_js_syntax::{AnyJsxTag, JsSyntaxToken, JsxElement, JsxOpeningElementFields, T};
-use rome_rowan::{AstNode, AstNodeList, BatchMutationExt, TriviaPiece};
+use rome_js_syntax::{
+    AnyJsxElementName, AnyJsxTag, JsSyntaxToken, JsxElement, JsxOpeningElementFields, T,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, AstNodeList, BatchMutationExt, SyntaxNode, TriviaPiece};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
+use crate::utils::is_rendered;
 use crate::JsRuleAction;
 
 declare_rule! {
     /// Prevent extra closing tags for components without children
     ///
+    /// An element is considered without children when its only children are
+    /// whitespace that spans several lines or empty expressions such as `{}`,
+    /// because they are not rendered.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -51,6 +64,21 @@ declare_rule! {
     /// ```js
     /// <Foo.bar>child</Foo.bar>
     ///```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `ignoreHtmlElements` to allow the closing tags of HTML elements such as `<div></div>`.
+    /// Its default value is `false`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "ignoreHtmlElements": true
+    ///     }
+    /// }
+    /// ```
+    ///
     pub(crate) UseSelfClosingElements {
         version: "1.0.0",
         name: "useSelfClosingElements",
@@ -58,14 +86,76 @@ declare_rule! {
     }
 }
 
+/// Options for the rule `useSelfClosingElements`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SelfClosingElementsOptions {
+    /// Whether the HTML elements can have a closing tag without children.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub ignore_html_elements: bool,
+}
+
+impl SelfClosingElementsOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["ignoreHtmlElements"];
+}
+
+// Required by [Bpaf].
+impl FromStr for SelfClosingElementsOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for SelfClosingElementsOptions {}
+impl VisitNode<JsonLanguage> for SelfClosingElementsOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "ignoreHtmlElements" {
+            self.ignore_html_elements = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
 impl Rule for UseSelfClosingElements {
     type Query = Ast<JsxElement>;
     type State = ();
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = SelfClosingElementsOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
-        if ctx.query().children().is_empty() {
+        let element = ctx.query();
+        if ctx.options().ignore_html_elements {
+            let name = element.opening_element().ok()?.name().ok()?;
+            if matches!(name, AnyJsxElementName::JsxName(_)) {
+                return None;
+            }
+        }
+        if element
+            .children()
+            .iter()
+            // The children that contain a comment can't be removed
+            .all(|child| !is_rendered(&child) && !child.syntax().has_comments_descendants())
+        {
             Some(())
         } else {
             None
@@ -75,7 +165,7 @@ impl Rule for UseSelfClosingElements {
     fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
         Some(RuleDiagnostic::new(
             rule_category!(),
-            ctx.query().range(),
+            ctx.query().opening_element().ok()?.range(),
             markup! {
                 "JSX elements without children should be marked as self-closing. In JSX, it is valid for any element to be self-closing."
             },
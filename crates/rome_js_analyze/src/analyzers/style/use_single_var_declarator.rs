@@ -68,12 +68,12 @@ impl Rule for UseSingleVarDeclarator {
         Some((await_token, kind, declarators, semicolon_token))
     }
 
-    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
-        let node = ctx.query();
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (_, kind, _, _) = state;
 
         Some(RuleDiagnostic::new(
             rule_category!(),
-            node.range(),
+            kind.text_trimmed_range(),
             "Declare variables separately",
         ))
     }
@@ -133,10 +133,22 @@ impl Rule for UseSingleVarDeclarator {
         let next_parent = prev_parent.clone().splice_slots(
             index..=index,
             declarators
-                .iter()
+                .elements()
                 .enumerate()
-                .filter_map(|(index, declarator)| {
-                    let mut declarator = declarator.ok()?;
+                .filter_map(|(index, element)| {
+                    let mut declarator = element.node().ok()?.clone();
+                    // The comments following a separator describe the previous declarator
+                    let separator_comments = element
+                        .trailing_separator()
+                        .ok()?
+                        .filter(|separator| separator.has_trailing_comments())
+                        .map(|separator| {
+                            let mut pieces: Vec<_> = separator.trailing_trivia().pieces().collect();
+                            while pieces.last().is_some_and(|piece| piece.is_whitespace()) {
+                                pieces.pop();
+                            }
+                            pieces
+                        });
 
                     // Remove the leading trivia for the declarators
                     let first_token = declarator.syntax().first_token()?;
@@ -209,16 +221,33 @@ impl Rule for UseSingleVarDeclarator {
                             variable_declaration.with_await_token(await_token.clone());
                     }
 
-                    let mut builder = make::js_variable_statement(variable_declaration.build());
-
                     let semicolon_token = if index + 1 == declarators_len {
-                        last_semicolon_token
+                        last_semicolon_token.cloned()
                     } else {
-                        remaining_semicolon_token.as_ref()
+                        remaining_semicolon_token.clone()
                     };
 
+                    let (variable_declaration, semicolon_token) =
+                        match (separator_comments, semicolon_token) {
+                            (Some(comments), Some(semicolon_token)) => (
+                                variable_declaration.build(),
+                                Some(semicolon_token.with_trailing_trivia_pieces(comments)),
+                            ),
+                            (Some(comments), None) => (
+                                variable_declaration
+                                    .build()
+                                    .append_trivia_pieces(comments)?,
+                                None,
+                            ),
+                            (None, semicolon_token) => {
+                                (variable_declaration.build(), semicolon_token)
+                            }
+                        };
+
+                    let mut builder = make::js_variable_statement(variable_declaration);
+
                     if let Some(semicolon_token) = semicolon_token {
-                        builder = builder.with_semicolon_token(semicolon_token.clone());
+                        builder = builder.with_semicolon_token(semicolon_token);
                     }
 
                     Some(Some(builder.build().into_syntax().into()))
@@ -9,7 +9,6 @@ pub(crate) mod no_compare_neg_zero;
 pub(crate) mod no_confusing_labels;
 pub(crate) mod no_const_enum;
 pub(crate) mod no_debugger;
-pub(crate) mod no_double_equals;
 pub(crate) mod no_duplicate_case;
 pub(crate) mod no_duplicate_class_members;
 pub(crate) mod no_duplicate_jsx_props;
@@ -38,7 +37,6 @@ declare_group! {
             self :: no_confusing_labels :: NoConfusingLabels ,
             self :: no_const_enum :: NoConstEnum ,
             self :: no_debugger :: NoDebugger ,
-            self :: no_double_equals :: NoDoubleEquals ,
             self :: no_duplicate_case :: NoDuplicateCase ,
             self :: no_duplicate_class_members :: NoDuplicateClassMembers ,
             self :: no_duplicate_jsx_props :: NoDuplicateJsxProps ,
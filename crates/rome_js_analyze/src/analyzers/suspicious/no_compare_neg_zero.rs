@@ -3,21 +3,27 @@ use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_factory::make;
 use rome_js_syntax::{
-    AnyJsExpression, AnyJsLiteralExpression, JsBinaryExpression, JsSyntaxKind, JsUnaryOperator,
+    numbers::parse_js_number, AnyJsCallArgument, AnyJsExpression, AnyJsLiteralExpression,
+    JsBinaryExpression, JsUnaryOperator, T,
 };
-use rome_rowan::{AstNode, BatchMutationExt, SyntaxToken};
+use rome_rowan::{AstNode, BatchMutationExt, TriviaPieceKind};
 
 use crate::JsRuleAction;
 
 pub struct NoCompareNegZeroState {
     operator_kind: &'static str,
-    left_need_replaced: bool,
-    right_need_replaced: bool,
 }
 
 declare_rule! {
     /// Disallow comparing against `-0`
     ///
+    /// Comparing against `-0` with the comparison operators also succeeds for `+0`.
+    /// To check whether a value is `-0`, use `Object.is(x, -0)`.
+    ///
+    /// For the equality operators, the rule suggests using `Object.is`.
+    /// Note that this code fix changes the behavior of the code.
+    /// The comparisons that use the ordering operators have no code fix.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -26,10 +32,15 @@ declare_rule! {
     /// (1 >= -0)
     /// ```
     ///
+    /// ```js,expect_diagnostic
+    /// (x === -0)
+    /// ```
+    ///
     /// ### Valid
     ///
     /// ```js
     /// (1 >= 0)
+    /// Object.is(x, -0)
     ///```
     pub(crate) NoCompareNegZero {
         version: "1.0.0",
@@ -61,11 +72,7 @@ impl Rule for NoCompareNegZero {
             // always success in to_string, you could look at our test case `noCompareNegZero.js`
             let operator_kind = op.kind().to_string().unwrap();
 
-            Some(NoCompareNegZeroState {
-                operator_kind,
-                left_need_replaced: is_left_neg_zero,
-                right_need_replaced: is_right_neg_zero,
-            })
+            Some(NoCompareNegZeroState { operator_kind })
         } else {
             None
         }
@@ -76,57 +83,76 @@ impl Rule for NoCompareNegZero {
 
         Some(RuleDiagnostic::new(
             rule_category!(),
-            node.range(),
+            node.operator_token().ok()?.text_trimmed_range(),
             markup! {
                 "Do not use the "{state.operator_kind}" operator to compare against -0."
             },
         ))
     }
-    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
-        let node = ctx.query();
-        let mut mutation = ctx.root().begin();
-
-        if state.left_need_replaced {
-            mutation.replace_node(
-                node.left().ok()?,
-                AnyJsExpression::AnyJsLiteralExpression(
-                    AnyJsLiteralExpression::JsNumberLiteralExpression(
-                        make::js_number_literal_expression(SyntaxToken::new_detached(
-                            JsSyntaxKind::JS_NUMBER_LITERAL,
-                            "0",
-                            [],
-                            [],
-                        )),
-                    ),
-                ),
-            );
-        }
 
-        if state.right_need_replaced {
-            mutation.replace_node(
-                node.right().ok()?,
-                AnyJsExpression::AnyJsLiteralExpression(
-                    AnyJsLiteralExpression::JsNumberLiteralExpression(
-                        make::js_number_literal_expression(SyntaxToken::new_detached(
-                            JsSyntaxKind::JS_NUMBER_LITERAL,
-                            "0",
-                            [],
-                            [],
-                        )),
-                    ),
-                ),
-            );
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        match state.operator_kind {
+            "==" | "===" => use_object_is(ctx, false),
+            "!=" | "!==" => use_object_is(ctx, true),
+            _ => None,
         }
+    }
+}
 
-        Some(JsRuleAction {
-            category: ActionCategory::QuickFix,
-            applicability: Applicability::Always,
-            message: markup! { "Replace -0 with 0" }.to_owned(),
-            mutation,
-        })
+/// Rewrites `x === -0` to `Object.is(x, -0)`, and `x !== -0` to `!Object.is(x, -0)`.
+fn use_object_is(ctx: &RuleContext<NoCompareNegZero>, is_negated: bool) -> Option<JsRuleAction> {
+    let node = ctx.query();
+    let operator = node.operator_token().ok()?;
+    // Don't lose the comments attached to the operator
+    if operator.has_leading_comments() || operator.has_trailing_comments() {
+        return None;
     }
+    let left = node
+        .left()
+        .ok()?
+        .with_leading_trivia_pieces([])?
+        .trim_end()?;
+    let right = node
+        .right()
+        .ok()?
+        .trim_start()?
+        .with_trailing_trivia_pieces([])?;
+    let object_is = make::js_static_member_expression(
+        make::js_identifier_expression(make::js_reference_identifier(make::ident("Object"))).into(),
+        make::token(T![.]),
+        make::js_name(make::ident("is")).into(),
+    );
+    let arguments = make::js_call_arguments(
+        make::token(T!['(']),
+        make::js_call_argument_list(
+            [
+                AnyJsCallArgument::AnyJsExpression(left),
+                AnyJsCallArgument::AnyJsExpression(right),
+            ],
+            [make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])],
+        ),
+        make::token(T![')']),
+    );
+    let mut new_node: AnyJsExpression = make::js_call_expression(object_is.into(), arguments)
+        .build()
+        .into();
+    if is_negated {
+        new_node = make::js_unary_expression(make::token(T![!]), new_node).into();
+    }
+
+    let mut mutation = ctx.root().begin();
+    mutation.replace_node(AnyJsExpression::JsBinaryExpression(node.clone()), new_node);
+
+    Some(JsRuleAction {
+        category: ActionCategory::QuickFix,
+        applicability: Applicability::MaybeIncorrect,
+        message: markup! { "Use "<Emphasis>"Object.is"</Emphasis>" instead." }.to_owned(),
+        mutation,
+    })
 }
 
+/// Returns `true` if `node` is the negation of a numeric literal equal to zero,
+/// such as `-0`, `-0.0`, or `-0x0`.
 fn is_neg_zero(node: &AnyJsExpression) -> Option<bool> {
     match node {
         AnyJsExpression::JsUnaryExpression(expr) => {
@@ -139,7 +165,7 @@ fn is_neg_zero(node: &AnyJsExpression) -> Option<bool> {
                 AnyJsLiteralExpression::JsNumberLiteralExpression(expr),
             ) = argument
             {
-                Some(expr.value_token().ok()?.text_trimmed() == "0")
+                Some(parse_js_number(expr.value_token().ok()?.text_trimmed())? == 0.0)
             } else {
                 Some(false)
             }
@@ -2,13 +2,19 @@ use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Ast, Rule
 use rome_console::markup;
 use rome_diagnostics::Applicability;
 use rome_js_syntax::JsDebuggerStatement;
-use rome_rowan::{AstNode, BatchMutationExt};
+use rome_rowan::BatchMutationExt;
 
 use crate::{utils, JsRuleAction};
 
 declare_rule! {
     /// Disallow the use of `debugger`
     ///
+    /// `debugger` statements are leftovers of debugging sessions and should not ship to production.
+    ///
+    /// The code fix removes the statement.
+    /// When the statement is the body of a control flow statement without braces,
+    /// such as `if (a) debugger`, it is replaced by an empty statement.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -45,7 +51,7 @@ impl Rule for NoDebugger {
 
         Some(RuleDiagnostic::new(
             rule_category!(),
-            node.syntax().text_trimmed_range(),
+            node.debugger_token().ok()?.text_trimmed_range(),
             markup! {
                 "This is an unexpected use of the "<Emphasis>"debugger"</Emphasis>" statement."
             }
@@ -61,7 +67,7 @@ impl Rule for NoDebugger {
 
         Some(JsRuleAction {
             category: ActionCategory::QuickFix,
-            applicability: Applicability::MaybeIncorrect,
+            applicability: Applicability::Always,
             message: markup! { "Remove debugger statement" }.to_owned(),
             mutation,
         })
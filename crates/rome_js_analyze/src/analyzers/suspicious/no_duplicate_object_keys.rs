@@ -1,24 +1,22 @@
-use crate::utils::batch::JsBatchMutation;
 use rome_analyze::context::RuleContext;
 use rome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
-use rome_console::markup;
 use rome_js_syntax::{
     AnyJsObjectMember, JsGetterObjectMember, JsObjectExpression, JsSetterObjectMember,
 };
 use rome_js_syntax::{
     JsMethodObjectMember, JsPropertyObjectMember, JsShorthandPropertyObjectMember, TextRange,
 };
-use rome_rowan::{AstNode, BatchMutationExt, TokenText};
-use std::cmp::Ordering;
+use rome_rowan::{AstNode, TokenText};
 use std::collections::HashMap;
 use std::fmt::Display;
 
-use crate::JsRuleAction;
-
 declare_rule! {
     /// Prevents object literals having more than one property declaration for the same name.
     /// If an object property with the same name is defined multiple times (except when combining a getter with a setter), only the last definition makes it into the object and previous definitions are ignored, which is likely a mistake.
     ///
+    /// The rule reports every duplicate after the first definition of a property.
+    /// Properties with a computed name are ignored.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -106,18 +104,6 @@ impl MemberDefinition {
             MemberDefinition::ShorthandProperty(shorthand_property) => shorthand_property.range(),
         }
     }
-
-    fn node(&self) -> AnyJsObjectMember {
-        match self {
-            MemberDefinition::Getter(getter) => AnyJsObjectMember::from(getter.clone()),
-            MemberDefinition::Setter(setter) => AnyJsObjectMember::from(setter.clone()),
-            MemberDefinition::Method(method) => AnyJsObjectMember::from(method.clone()),
-            MemberDefinition::Property(property) => AnyJsObjectMember::from(property.clone()),
-            MemberDefinition::ShorthandProperty(shorthand_property) => {
-                AnyJsObjectMember::from(shorthand_property.clone())
-            }
-        }
-    }
 }
 impl Display for MemberDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -159,8 +145,7 @@ impl TryFrom<AnyJsObjectMember> for MemberDefinition {
     }
 }
 
-/// A descriptor for a property that is, as far as we can tell from statically analyzing the object expression,
-/// not overwritten by another object member and will make it into the object.
+/// A descriptor for the first definition of a property, as far as we can tell from statically analyzing the object expression.
 #[derive(Clone)]
 enum DefinedProperty {
     Get(TextRange),
@@ -184,6 +169,22 @@ impl From<MemberDefinition> for DefinedProperty {
 
 pub(crate) struct PropertyConflict(DefinedProperty, MemberDefinition);
 impl DefinedProperty {
+    /// Returns the range and the kind of the member that first defines the property.
+    fn first_definition(&self) -> (TextRange, &'static str) {
+        match self {
+            DefinedProperty::Get(range) => (*range, "getter"),
+            DefinedProperty::Set(range) => (*range, "setter"),
+            DefinedProperty::Value(range) => (*range, "value"),
+            DefinedProperty::GetSet(get_range, set_range) => {
+                if get_range.start() < set_range.start() {
+                    (*get_range, "getter")
+                } else {
+                    (*set_range, "setter")
+                }
+            }
+        }
+    }
+
     fn extend_with(
         &self,
         member_definition: MemberDefinition,
@@ -223,8 +224,6 @@ impl Rule for NoDuplicateObjectKeys {
             .into_iter()
             .flatten()
             .filter_map(|member| MemberDefinition::try_from(member).ok())
-            // Note that we iterate from last to first property, so that we highlight properties being overwritten as problems and not those that take effect.
-            .rev()
         {
             if let Some(member_name) = member_definition.name() {
                 match defined_properties.remove(&member_name) {
@@ -239,6 +238,7 @@ impl Rule for NoDuplicateObjectKeys {
                             }
                             Err(conflict) => {
                                 signals.push(conflict);
+                                // Keep the first definition, so that every duplicate points back at it.
                                 defined_properties.insert(member_name, defined_property);
                             }
                         }
@@ -254,63 +254,18 @@ impl Rule for NoDuplicateObjectKeys {
         _ctx: &RuleContext<Self>,
         PropertyConflict(defined_property, member_definition): &Self::State,
     ) -> Option<RuleDiagnostic> {
-        let mut diagnostic = RuleDiagnostic::new(
-            rule_category!(),
-            member_definition.range(),
-            format!(
-                "This {} is later overwritten by an object member with the same name.",
-                member_definition
-            ),
-        );
-        diagnostic = match defined_property {
-            DefinedProperty::Get(range) => {
-                diagnostic.detail(range, "Overwritten with this getter.")
-            }
-            DefinedProperty::Set(range) => {
-                diagnostic.detail(range, "Overwritten with this setter.")
-            }
-            DefinedProperty::Value(range) => {
-                diagnostic.detail(range, "Overwritten with this value.")
-            }
-            DefinedProperty::GetSet(get_range, set_range) => match member_definition {
-                MemberDefinition::Getter(_) => {
-                    diagnostic.detail(get_range, "Overwritten with this getter.")
-                }
-                MemberDefinition::Setter(_) => {
-                    diagnostic.detail(set_range, "Overwritten with this setter.")
-                }
-                MemberDefinition::Method(_)
-                | MemberDefinition::Property(_)
-                | MemberDefinition::ShorthandProperty(_) => match get_range.ordering(*set_range) {
-                    Ordering::Less => diagnostic.detail(set_range, "Overwritten with this setter."),
-                    Ordering::Greater => {
-                        diagnostic.detail(get_range, "Overwritten with this getter.")
-                    }
-                    Ordering::Equal => {
-                        panic!(
-                            "The ranges of the property getter and property setter cannot overlap."
-                        )
-                    }
-                },
-            },
-        };
-        diagnostic = diagnostic.note("If an object property with the same name is defined multiple times (except when combining a getter with a setter), only the last definition makes it into the object and previous definitions are ignored.");
-
-        Some(diagnostic)
-    }
-
-    fn action(
-        ctx: &RuleContext<Self>,
-        PropertyConflict(_, member_definition): &Self::State,
-    ) -> Option<JsRuleAction> {
-        let mut batch = ctx.root().begin();
-        batch.remove_js_object_member(&member_definition.node());
-        Some(JsRuleAction {
-            category: rome_analyze::ActionCategory::QuickFix,
-            // The property initialization could contain side effects
-            applicability: rome_diagnostics::Applicability::MaybeIncorrect,
-            message: markup!("Remove this " {member_definition.to_string()}).to_owned(),
-            mutation: batch,
-        })
+        let (first_range, first_kind) = defined_property.first_definition();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                member_definition.range(),
+                format!(
+                    "This {} duplicates an earlier object member with the same name.",
+                    member_definition
+                ),
+            )
+            .detail(first_range, format!("First defined with this {first_kind}."))
+            .note("If an object property with the same name is defined multiple times (except when combining a getter with a setter), only the last definition makes it into the object and previous definitions are ignored."),
+        )
     }
 }
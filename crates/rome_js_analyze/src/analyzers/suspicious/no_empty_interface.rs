@@ -8,15 +8,20 @@ use rome_js_factory::{
     syntax::{AnyTsType, T},
 };
 use rome_js_syntax::{
-    AnyJsDeclarationClause, TriviaPieceKind, TsInterfaceDeclaration, TsTypeAliasDeclaration,
+    AnyJsDeclarationClause, JsExportDefaultDeclarationClause, TriviaPieceKind,
+    TsInterfaceDeclaration, TsTypeAliasDeclaration,
 };
-use rome_rowan::{AstNode, AstNodeList, BatchMutationExt};
+use rome_rowan::{AstNode, AstNodeList, AstSeparatedList, BatchMutationExt};
 
 declare_rule! {
     /// Disallow the declaration of empty interfaces.
     ///
     /// > An empty interface in TypeScript does very little: any non-nullable value is assignable to `{}`. Using an empty interface is often a sign of programmer error, such as misunderstanding the concept of `{}` or forgetting to fill in fields.
     ///
+    /// An empty interface that extends other interfaces is equivalent to their intersection.
+    /// The code fix turns it into a type alias such as `type A = B & C`.
+    /// No code fix is suggested for an empty interface that extends nothing.
+    ///
     /// Source: https://typescript-eslint.io/rules/no-empty-interface
     ///
     /// ## Examples
@@ -31,14 +36,16 @@ declare_rule! {
     /// interface A extends B {}
     /// ```
     ///
+    /// ```ts,expect_diagnostic
+    /// // A === B & C
+    /// interface A extends B, C {}
+    /// ```
+    ///
     /// ### Valid
     /// ```ts
     /// interface A {
     ///   prop: string;
     /// }
-    ///
-    /// // The interface can be used as an union type.
-    /// interface A extends B, C {}
     /// ```
     ///
     pub(crate) NoEmptyInterface {
@@ -49,18 +56,22 @@ declare_rule! {
 }
 
 pub enum DiagnosticMessage {
-    NoEmptyInterface,
-    NoEmptyInterfaceWithSuper,
+    WithoutSuper,
+    WithSuper,
+    WithSupers,
 }
 
 impl DiagnosticMessage {
     /// Convert a [DiagnosticMessage] to a string
     fn as_str(&self) -> &'static str {
         match self {
-            Self::NoEmptyInterface => "An empty interface is equivalent to '{}'.",
-            Self::NoEmptyInterfaceWithSuper => {
+            Self::WithoutSuper => "An empty interface is equivalent to '{}'.",
+            Self::WithSuper => {
                 "An interface declaring no members is equivalent to its supertype."
             }
+            Self::WithSupers => {
+                "An interface declaring no members is equivalent to the intersection of its supertypes."
+            }
         }
     }
 
@@ -68,28 +79,43 @@ impl DiagnosticMessage {
     /// replace it on the rule action
     fn fix_with(&self, node: &TsInterfaceDeclaration) -> Option<TsTypeAliasDeclaration> {
         match self {
-            Self::NoEmptyInterface => make_type_alias_from_interface(
-                node,
-                AnyTsType::from(make::ts_object_type(
-                    make::token(T!['{']),
-                    make::ts_type_member_list([]),
-                    make::token(T!['}']),
-                )),
-            ),
-            Self::NoEmptyInterfaceWithSuper => {
-                let super_interface = node.extends_clause()?.types().into_iter().next()?.ok()?;
-                let type_arguments = super_interface.type_arguments();
-                let ts_reference_type = make::ts_reference_type(super_interface.name().ok()?);
-
-                let ts_reference_type = if type_arguments.is_some() {
-                    ts_reference_type
-                        .with_type_arguments(type_arguments?)
-                        .build()
+            // `{}` would have the same issue
+            Self::WithoutSuper => None,
+            Self::WithSuper | Self::WithSupers => {
+                let super_types = node
+                    .extends_clause()?
+                    .types()
+                    .iter()
+                    .map(|super_interface| {
+                        let super_interface = super_interface.ok()?.trim()?;
+                        let ts_reference_type =
+                            make::ts_reference_type(super_interface.name().ok()?);
+                        let ts_reference_type = match super_interface.type_arguments() {
+                            Some(type_arguments) => ts_reference_type
+                                .with_type_arguments(type_arguments)
+                                .build(),
+                            None => ts_reference_type.build(),
+                        };
+                        Some(AnyTsType::from(ts_reference_type))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                let ts_type = if super_types.len() == 1 {
+                    super_types.into_iter().next()?
                 } else {
-                    ts_reference_type.build()
+                    let separators = (1..super_types.len()).map(|_| {
+                        make::token(T![&])
+                            .with_leading_trivia([(TriviaPieceKind::Whitespace, " ")])
+                            .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+                    });
+                    AnyTsType::from(
+                        make::ts_intersection_type(make::ts_intersection_type_element_list(
+                            super_types,
+                            separators,
+                        ))
+                        .build(),
+                    )
                 };
-
-                make_type_alias_from_interface(node, AnyTsType::from(ts_reference_type))
+                make_type_alias_from_interface(node, ts_type)
             }
         }
     }
@@ -103,26 +129,28 @@ impl Rule for NoEmptyInterface {
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
-        let has_no_members = node.members().is_empty();
+        if !node.members().is_empty() {
+            return None;
+        }
         let extends_clause_count = if let Some(extends_clause) = node.extends_clause() {
             extends_clause.types().into_iter().count()
         } else {
             0
         };
 
-        if extends_clause_count == 0 && has_no_members {
-            return Some(DiagnosticMessage::NoEmptyInterface);
-        }
-
-        if extends_clause_count == 1 && has_no_members {
-            return Some(DiagnosticMessage::NoEmptyInterfaceWithSuper);
+        match extends_clause_count {
+            0 => Some(DiagnosticMessage::WithoutSuper),
+            1 => Some(DiagnosticMessage::WithSuper),
+            _ => Some(DiagnosticMessage::WithSupers),
         }
-
-        None
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
-        let diagnostic = RuleDiagnostic::new(rule_category!(), ctx.query().range(), state.as_str());
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            ctx.query().id().ok()?.range(),
+            state.as_str(),
+        );
 
         Some(diagnostic)
     }
@@ -130,6 +158,10 @@ impl Rule for NoEmptyInterface {
     fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
         let mut mutation = ctx.root().begin();
         let node = ctx.query();
+        // `export default` cannot be followed by a type alias
+        if node.parent::<JsExportDefaultDeclarationClause>().is_some() {
+            return None;
+        }
 
         mutation.replace_node(
             AnyJsDeclarationClause::from(node.clone()),
@@ -1,8 +1,20 @@
+use crate::JsRuleAction;
+use bpaf::Bpaf;
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
 use rome_console::markup;
-use rome_js_syntax::TsAnyType;
-use rome_rowan::AstNode;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    AnyTsType, JsRestParameter, JsSyntaxKind, JsSyntaxNode, TsAnyType, TsArrayType,
+    TsReferenceType, TsTypeAnnotation, TsTypeArgumentList, TsTypeOperatorType, T,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 declare_rule! {
     /// Disallow the `any` type usage.
@@ -50,7 +62,23 @@ declare_rule! {
     /// function fn(param: Array<Array<unknown>>): Array<unknown> {}
     /// ```
     ///
+    /// ## Options
+    ///
+    /// The option `ignoreRestArgs` allows `any` in the type of a rest parameter,
+    /// such as `...args: any[]`, `...args: Array<any>`, or `...args: any`.
+    ///
+    /// The option `fixToUnknown` enables a code fix that replaces `any` with `unknown`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "ignoreRestArgs": true,
+    ///         "fixToUnknown": true
+    ///     }
+    /// }
     /// ```
+    ///
     pub(crate) NoExplicitAny {
         version: "1.0.0",
         name: "noExplicitAny",
@@ -58,13 +86,74 @@ declare_rule! {
     }
 }
 
+/// Options for the rule `noExplicitAny`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ExplicitAnyOptions {
+    /// Whether `any` is allowed in the type of a rest parameter.
+    #[bpaf(hide)]
+    pub ignore_rest_args: bool,
+    /// Whether the rule suggests replacing `any` with `unknown`.
+    #[bpaf(hide)]
+    pub fix_to_unknown: bool,
+}
+
+impl ExplicitAnyOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["ignoreRestArgs", "fixToUnknown"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ExplicitAnyOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ExplicitAnyOptions {}
+impl VisitNode<JsonLanguage> for ExplicitAnyOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        match name_text {
+            "ignoreRestArgs" => {
+                self.ignore_rest_args = self.map_to_boolean(&value, name_text, diagnostics)?;
+            }
+            "fixToUnknown" => {
+                self.fix_to_unknown = self.map_to_boolean(&value, name_text, diagnostics)?;
+            }
+            _ => {}
+        }
+        Some(())
+    }
+}
+
 impl Rule for NoExplicitAny {
     type Query = Ast<TsAnyType>;
     type State = ();
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = ExplicitAnyOptions;
 
-    fn run(_: &RuleContext<Self>) -> Self::Signals {
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if ctx.options().ignore_rest_args && is_rest_parameter_type(ctx.query()) {
+            return None;
+        }
         Some(())
     }
 
@@ -80,4 +169,59 @@ impl Rule for NoExplicitAny {
 
         Some(diagnostic)
     }
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        if !ctx.options().fix_to_unknown {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyTsType::from(ctx.query().clone()),
+            AnyTsType::from(make::ts_unknown_type(make::token(T![unknown]))),
+        );
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>"unknown"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Checks if `any` is the type of a rest parameter:
+/// `...args: any`, `...args: any[]`, `...args: Array<any>`, or their `readonly` forms.
+fn is_rest_parameter_type(node: &TsAnyType) -> bool {
+    let mut node = node.syntax().clone();
+    if let Some(parent) = array_of(&node) {
+        node = parent;
+    }
+    if let Some(parent) = node.parent().filter(|parent| {
+        TsTypeOperatorType::cast_ref(parent)
+            .and_then(|operator| operator.operator_token().ok())
+            .map_or(false, |token| token.kind() == T![readonly])
+    }) {
+        node = parent;
+    }
+    node.parent()
+        .filter(|parent| TsTypeAnnotation::can_cast(parent.kind()))
+        .and_then(|annotation| annotation.parent())
+        .map_or(false, |parameter| {
+            JsRestParameter::can_cast(parameter.kind())
+        })
+}
+
+/// Returns the array type of `node` if `node` is its element type: `T[]`, `Array<T>`, or `ReadonlyArray<T>`.
+fn array_of(node: &JsSyntaxNode) -> Option<JsSyntaxNode> {
+    let parent = node.parent()?;
+    if TsArrayType::can_cast(parent.kind()) {
+        return Some(parent);
+    }
+    if parent.kind() != JsSyntaxKind::TS_TYPE_ARGUMENT_LIST
+        || TsTypeArgumentList::cast_ref(&parent)?.len() != 1
+    {
+        return None;
+    }
+    let reference = TsReferenceType::cast(parent.parent()?.parent()?)?;
+    let name = reference.name().ok()?;
+    let name = name.syntax().text_trimmed();
+    (name == "Array" || name == "ReadonlyArray").then(|| reference.into_syntax())
 }
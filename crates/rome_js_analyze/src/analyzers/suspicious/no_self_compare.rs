@@ -1,8 +1,15 @@
 use crate::utils::is_node_equal;
+use crate::utils::is_side_effect_free;
+use crate::JsRuleAction;
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic};
-use rome_js_syntax::JsBinaryExpression;
-use rome_rowan::AstNode;
+use rome_analyze::{declare_rule, ActionCategory, Ast, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, JsBinaryExpression, JsBinaryOperator, JsCallExpression, T,
+};
+use rome_rowan::{AstNode, BatchMutationExt};
 
 declare_rule! {
     /// Disallow comparisons where both sides are exactly the same.
@@ -12,6 +19,11 @@ declare_rule! {
     /// > The only time you would compare a variable against itself is when you are testing for `NaN`.
     /// However, it is far more appropriate to use `typeof x === 'number' && Number.isNaN(x)` for that use case rather than leaving the reader of the code to determine the intent of self comparison.
     ///
+    /// Comparisons of literals, of `NaN`, and of expressions that contain a call are ignored,
+    /// because a call can return a different value each time.
+    ///
+    /// For `x !== x` and `x != x`, the rule suggests to use `Number.isNaN(x)` instead.
+    ///
     /// Source: [no-self-compare](https://eslint.org/docs/latest/rules/no-self-compare).
     ///
     /// ## Examples
@@ -23,7 +35,14 @@ declare_rule! {
     /// ```
     ///
     /// ```js,expect_diagnostic
-    /// if (a.b.c() !== a.b .c()) {}
+    /// if (a.b.c !== a.b .c) {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// if (x === y) {}
+    /// if (random() === random()) {}
     /// ```
     ///
     pub(crate) NoSelfCompare {
@@ -49,18 +68,90 @@ impl Rule for NoSelfCompare {
         let left = node.left().ok()?;
         let right = node.right().ok()?;
 
-        if is_node_equal(left.syntax(), right.syntax()) {
-            return Some(());
+        if !is_node_equal(left.syntax(), right.syntax()) {
+            return None;
+        }
+
+        match left.clone().omit_parentheses() {
+            AnyJsExpression::AnyJsLiteralExpression(_) => return None,
+            AnyJsExpression::JsIdentifierExpression(ident) => {
+                let name = ident.name().ok()?;
+                if name.value_token().ok()?.text_trimmed() == "NaN" {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+
+        // A call can return a different value each time
+        if left
+            .syntax()
+            .descendants()
+            .any(|node| JsCallExpression::can_cast(node.kind()))
+        {
+            return None;
         }
 
-        None
+        Some(())
     }
 
     fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
-        Some(RuleDiagnostic::new(
+        let node = ctx.query();
+        let diagnostic = RuleDiagnostic::new(
             rule_category!(),
-            ctx.query().range(),
+            node.range(),
             "Comparing to itself is potentially pointless.",
-        ))
+        );
+        if is_nan_check(node) {
+            return Some(diagnostic.note(markup! {
+                "If this checks whether the value is "<Emphasis>"NaN"</Emphasis>", use "<Emphasis>"Number.isNaN()"</Emphasis>" instead."
+            }));
+        }
+        Some(diagnostic)
     }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        if !is_nan_check(node) {
+            return None;
+        }
+        let operand = node.left().ok()?.omit_parentheses();
+        if !is_side_effect_free(&operand) {
+            return None;
+        }
+        let is_nan = make::js_static_member_expression(
+            make::js_identifier_expression(make::js_reference_identifier(make::ident("Number")))
+                .into(),
+            make::token(T![.]),
+            make::js_name(make::ident("isNaN")).into(),
+        );
+        let call = make::js_call_expression(
+            is_nan.into(),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list(
+                    [AnyJsCallArgument::AnyJsExpression(operand.trim()?)],
+                    [],
+                ),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(node.clone()), call.into());
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>"Number.isNaN()"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `node` is the `x !== x` idiom that checks whether `x` is `NaN`.
+fn is_nan_check(node: &JsBinaryExpression) -> bool {
+    matches!(
+        node.operator(),
+        Ok(JsBinaryOperator::StrictInequality | JsBinaryOperator::Inequality)
+    )
 }
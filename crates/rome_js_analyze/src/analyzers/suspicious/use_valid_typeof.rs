@@ -8,6 +8,7 @@ use rome_js_syntax::{
 };
 use rome_rowan::{AstNode, BatchMutationExt};
 
+use crate::utils::edit_distance;
 use crate::JsRuleAction;
 
 declare_rule! {
@@ -15,6 +16,9 @@ declare_rule! {
     /// compared to valid values, either string literals containing valid type
     /// names or other `typeof` expressions
     ///
+    /// When a string literal is a single typo away from exactly one valid type name,
+    /// the rule suggests to replace it with this type name.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -125,17 +129,20 @@ impl Rule for UseValidTypeof {
                         .text_trimmed()
                         .trim_start_matches(['"', '\''])
                         .trim_end_matches(['"', '\''])
-                        .to_lowercase();
+                        .to_string();
 
                     if JsTypeName::from_str(&literal).is_some() {
                         return None;
                     }
 
-                    // Try to fix the casing of the literal eg. "String" -> "string"
+                    // Try to fix the casing of the literal eg. "String" -> "string",
+                    // or a typo eg. "strnig" -> "string"
                     let suggestion = literal.to_lowercase();
+                    let type_name = JsTypeName::from_str(&suggestion)
+                        .or_else(|| JsTypeName::from_typo(&suggestion));
                     return Some((
                         TypeofError::InvalidLiteral(range, literal),
-                        JsTypeName::from_str(&suggestion).map(|type_name| (lit.clone(), type_name)),
+                        type_name.map(|type_name| (lit.clone(), type_name)),
                     ));
                 }
 
@@ -271,6 +278,28 @@ impl JsTypeName {
         })
     }
 
+    /// construct a [JsTypeName] from a misspelled type name,
+    /// if exactly one type name is a single edit away from it
+    fn from_typo(s: &str) -> Option<Self> {
+        let mut candidates = [
+            "undefined",
+            "object",
+            "boolean",
+            "number",
+            "string",
+            "function",
+            "symbol",
+            "bigint",
+        ]
+        .into_iter()
+        .filter(|type_name| edit_distance(s, type_name) == 1);
+        let candidate = candidates.next()?;
+        if candidates.next().is_some() {
+            return None;
+        }
+        Self::from_str(candidate)
+    }
+
     /// Convert a [JsTypeName] to a JS string literal
     const fn as_str(&self) -> &'static str {
         match self {
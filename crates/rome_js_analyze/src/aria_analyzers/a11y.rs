@@ -4,6 +4,7 @@ use rome_analyze::declare_group;
 
 pub(crate) mod no_noninteractive_element_to_interactive_role;
 pub(crate) mod use_aria_props_for_role;
+pub(crate) mod use_key_with_click_events;
 pub(crate) mod use_valid_aria_props;
 pub(crate) mod use_valid_lang;
 
@@ -13,6 +14,7 @@ declare_group! {
         rules : [
             self :: no_noninteractive_element_to_interactive_role :: NoNoninteractiveElementToInteractiveRole ,
             self :: use_aria_props_for_role :: UseAriaPropsForRole ,
+            self :: use_key_with_click_events :: UseKeyWithClickEvents ,
             self :: use_valid_aria_props :: UseValidAriaProps ,
             self :: use_valid_lang :: UseValidLang ,
         ]
@@ -9,6 +9,8 @@ use rome_rowan::AstNode;
 declare_rule! {
     /// Enforce that elements with ARIA roles must have all required ARIA attributes for that role.
     ///
+    /// The elements with a spread attribute are ignored, because the spread object can provide the required attributes.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -31,6 +33,10 @@ declare_rule! {
     /// <span role="heading" aria-level="1"></span>
     /// ```
     ///
+    /// ```jsx
+    /// <span role="checkbox" {...props}></span>
+    /// ```
+    ///
     ///
     /// ## Accessibility guidelines
     /// - [WCAG 4.1.2](https://www.w3.org/WAI/WCAG21/Understanding/name-role-value)
@@ -91,7 +97,8 @@ impl Rule for UseAriaPropsForRole {
             })
             .unwrap_or(false);
 
-        if is_inside_element {
+        // A spread attribute can provide the missing properties
+        if is_inside_element && !node.has_spread_prop() {
             let role_attribute = node.find_attribute_by_name("role")?;
 
             let name = role_attribute
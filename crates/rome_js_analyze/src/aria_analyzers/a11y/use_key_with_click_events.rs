@@ -1,6 +1,7 @@
-use rome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use crate::aria_services::Aria;
+use rome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
 use rome_console::markup;
-use rome_js_syntax::{jsx_ext::AnyJsxElement, AnyJsxAttribute, AnyJsxElementName};
+use rome_js_syntax::{jsx_ext::AnyJsxElement, AnyJsxAttribute, AnyJsxElementName, JsxAttribute};
 use rome_rowan::AstNode;
 
 declare_rule! {
@@ -8,6 +9,7 @@ declare_rule! {
     ///
     /// Coding for the keyboard is important for users with physical disabilities who cannot use a mouse, AT compatibility, and screenreader users.
     /// This does not apply for interactive or hidden elements.
+    /// An element with an interactive `role`, such as `button`, is considered interactive.
     ///
     /// ## Examples
     ///
@@ -45,6 +47,10 @@ declare_rule! {
     /// ```
     ///
     /// ```jsx
+    /// <div role="button" onClick={() => {}} />
+    /// ```
+    ///
+    /// ```jsx
     /// <div {...spread} onClick={() => {}} ></div>
     /// ```
     ///
@@ -64,8 +70,9 @@ declare_rule! {
 }
 
 impl Rule for UseKeyWithClickEvents {
-    type Query = Ast<AnyJsxElement>;
-    type State = ();
+    type Query = Aria<AnyJsxElement>;
+    /// The `onClick` attribute.
+    type State = JsxAttribute;
     type Signals = Option<Self::State>;
     type Options = ();
 
@@ -76,11 +83,10 @@ impl Rule for UseKeyWithClickEvents {
             Ok(AnyJsxElementName::JsxName(name)) => {
                 let element_name = name.value_token().ok()?.text_trimmed().to_lowercase();
 
-                // Don't handle interactive roles
-                // TODO Support aria roles https://github.com/rome/tools/issues/3640
+                // Don't handle interactive elements
                 if matches!(
                     element_name.as_str(),
-                    "button" | "checkbox" | "combobox" | "a" | "input"
+                    "button" | "a" | "input" | "select" | "textarea" | "option" | "summary"
                 ) {
                     return None;
                 }
@@ -90,14 +96,18 @@ impl Rule for UseKeyWithClickEvents {
             }
         }
 
-        let attributes = element.attributes();
-        let on_click_attribute = attributes.find_by_name("onClick").ok()?;
-
-        #[allow(clippy::question_mark)]
-        if on_click_attribute.is_none() {
+        // Don't handle interactive roles
+        if element
+            .find_attribute_by_name("role")
+            .and_then(|attribute| attribute.as_static_value())
+            .is_some_and(|role| ctx.aria_roles().is_role_interactive(role.text()))
+        {
             return None;
         }
 
+        let attributes = element.attributes();
+        let on_click_attribute = attributes.find_by_name("onClick").ok()??;
+
         for attribute in attributes {
             match attribute {
                 AnyJsxAttribute::JsxAttribute(attribute) => {
@@ -118,15 +128,16 @@ impl Rule for UseKeyWithClickEvents {
             }
         }
 
-        Some(())
+        Some(on_click_attribute)
     }
 
-    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
-        let node = ctx.query();
-
+    fn diagnostic(
+        _: &RuleContext<Self>,
+        on_click_attribute: &Self::State,
+    ) -> Option<RuleDiagnostic> {
         Some(RuleDiagnostic::new(
             rule_category!(),
-            node.range(),
+            on_click_attribute.range(),
             markup! {
                 "Enforce to have the "<Emphasis>"onClick"</Emphasis>" mouse event with the "<Emphasis>"onKeyUp"</Emphasis>", the "<Emphasis>"onKeyDown"</Emphasis>", or the "<Emphasis>"onKeyPress"</Emphasis>" keyboard event."
             },
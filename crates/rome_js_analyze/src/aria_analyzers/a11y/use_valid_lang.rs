@@ -1,4 +1,5 @@
 use crate::aria_services::Aria;
+use crate::utils::edit_distance;
 use rome_analyze::context::RuleContext;
 use rome_analyze::{declare_rule, Rule, RuleDiagnostic};
 use rome_console::markup;
@@ -7,6 +8,9 @@ use rome_rowan::{AstNode, TextRange};
 declare_rule! {
     /// Ensure that the attribute passed to the `lang` attribute is a correct ISO language and/or country.
     ///
+    /// Only the string values are checked, the values computed by an expression are ignored.
+    /// When the invalid language or country is close to a valid one, the diagnostic suggests it.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -23,11 +27,19 @@ declare_rule! {
     /// <html lang="en-GB-typo" />
     /// ```
     ///
+    /// ```jsx,expect_diagnostic
+    /// <html lang="en-us" />
+    /// ```
+    ///
     /// ### Valid
     ///
     /// ```jsx
     /// <Html lang="en-babab" />
     /// ```
+    ///
+    /// ```jsx
+    /// <html lang={language} />
+    /// ```
     pub(crate) UseValidLang {
         version: "1.0.0",
         name: "useValidLang",
@@ -44,6 +56,8 @@ enum InvalidKind {
 pub(crate) struct UseValidLangState {
     invalid_kind: InvalidKind,
     attribute_range: TextRange,
+    /// A valid value close to the invalid one.
+    suggestion: Option<String>,
 }
 
 impl Rule for UseValidLang {
@@ -58,25 +72,30 @@ impl Rule for UseValidLang {
         if element_text.text_trimmed() == "html" {
             let attribute = node.find_attribute_by_name("lang")?;
             let attribute_value = attribute.initializer()?.value().ok()?;
-            let attribute_static_value = attribute_value.as_static_value()?;
-            let attribute_text = attribute_static_value.text();
-            let mut split_value = attribute_text.split('-');
+            // Don't check the values computed by an expression
+            let attribute_text = attribute_value.as_jsx_string()?.inner_string_text().ok()?;
+            let mut split_value = attribute_text.text().split('-');
             match (split_value.next(), split_value.next()) {
                 (Some(language), Some(country)) => {
                     if !ctx.is_valid_iso_language(language) {
                         return Some(UseValidLangState {
                             attribute_range: attribute_value.range(),
                             invalid_kind: InvalidKind::Language,
+                            suggestion: closest_value(language, ctx.iso_language_list())
+                                .map(|language| format!("{language}-{country}")),
                         });
                     } else if !ctx.is_valid_iso_country(country) {
                         return Some(UseValidLangState {
                             attribute_range: attribute_value.range(),
                             invalid_kind: InvalidKind::Country,
+                            suggestion: closest_value(country, ctx.iso_country_list())
+                                .map(|country| format!("{language}-{country}")),
                         });
                     } else if split_value.next().is_some() {
                         return Some(UseValidLangState {
                             attribute_range: attribute_value.range(),
                             invalid_kind: InvalidKind::Value,
+                            suggestion: None,
                         });
                     }
                 }
@@ -86,6 +105,8 @@ impl Rule for UseValidLang {
                         return Some(UseValidLangState {
                             attribute_range: attribute_value.range(),
                             invalid_kind: InvalidKind::Language,
+                            suggestion: closest_value(language, ctx.iso_language_list())
+                                .map(ToString::to_string),
                         });
                     }
                 }
@@ -104,6 +125,11 @@ impl Rule for UseValidLang {
                 "Provide a valid value for the "<Emphasis>"lang"</Emphasis>" attribute."
             },
         );
+        if let Some(suggestion) = &state.suggestion {
+            return Some(diagnostic.note(markup! {
+                "Did you mean "<Emphasis>{suggestion}</Emphasis>"?"
+            }));
+        }
         diagnostic = match state.invalid_kind {
             InvalidKind::Language => {
                 let languages = ctx.iso_language_list();
@@ -130,3 +156,28 @@ impl Rule for UseValidLang {
         Some(diagnostic)
     }
 }
+
+/// Returns the value of `values` closest to `value`, ignoring the case.
+///
+/// A value is returned only if it is the single one at an edit distance of at most 1.
+fn closest_value(value: &str, values: &'static [&'static str]) -> Option<&'static str> {
+    let value = value.to_ascii_lowercase();
+    let mut closest = None;
+    let mut closest_distance = usize::MAX;
+    let mut is_ambiguous = false;
+    for candidate in values {
+        let distance = edit_distance(&value, &candidate.to_ascii_lowercase());
+        if distance < closest_distance {
+            closest = Some(*candidate);
+            closest_distance = distance;
+            is_ambiguous = false;
+        } else if distance == closest_distance {
+            is_ambiguous = true;
+        }
+    }
+    if closest_distance <= 1 && !is_ambiguous {
+        closest
+    } else {
+        None
+    }
+}
@@ -3,18 +3,26 @@
 use rome_analyze::declare_group;
 
 pub(crate) mod no_aria_unsupported_elements;
+pub(crate) mod no_interactive_element_to_noninteractive_role;
+pub(crate) mod no_label_without_control;
 pub(crate) mod no_noninteractive_tabindex;
 pub(crate) mod no_redundant_roles;
+pub(crate) mod no_static_element_interactions;
 pub(crate) mod use_aria_prop_types;
+pub(crate) mod use_focusable_interactive;
 
 declare_group! {
     pub (crate) Nursery {
         name : "nursery" ,
         rules : [
             self :: no_aria_unsupported_elements :: NoAriaUnsupportedElements ,
+            self :: no_interactive_element_to_noninteractive_role :: NoInteractiveElementToNoninteractiveRole ,
+            self :: no_label_without_control :: NoLabelWithoutControl ,
             self :: no_noninteractive_tabindex :: NoNoninteractiveTabindex ,
             self :: no_redundant_roles :: NoRedundantRoles ,
+            self :: no_static_element_interactions :: NoStaticElementInteractions ,
             self :: use_aria_prop_types :: UseAriaPropTypes ,
+            self :: use_focusable_interactive :: UseFocusableInteractive ,
         ]
      }
 }
@@ -0,0 +1,110 @@
+use crate::aria_services::Aria;
+use crate::utils::aria::is_interactive_element;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::jsx_ext::AnyJsxElement;
+use rome_rowan::{AstNode, TextRange};
+
+declare_rule! {
+    /// Enforce that non-interactive ARIA roles are not assigned to interactive HTML elements.
+    ///
+    /// Interactive HTML elements indicate _controls_ in the user interface.
+    /// Interactive elements include `<a href>`, `<button>`, `<input>`, `<select>`, `<textarea>`.
+    ///
+    /// Non-interactive ARIA roles, such as `article`, `listitem` or `presentation`,
+    /// remove the interactivity of these elements for the users of assistive technologies,
+    /// while they can still be focused and activated.
+    ///
+    /// This is the opposite of [noNoninteractiveElementToInteractiveRole](https://biomejs.dev/linter/rules/no-noninteractive-element-to-interactive-role).
+    ///
+    /// Source: https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/docs/rules/no-interactive-element-to-noninteractive-role.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <button role="presentation">Submit</button>
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <input role="img" />
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <button role="switch">Dark mode</button>
+    /// ```
+    ///
+    /// ```jsx
+    /// <div role="presentation"></div>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 4.1.2](https://www.w3.org/WAI/WCAG21/Understanding/name-role-value)
+    ///
+    pub(crate) NoInteractiveElementToNoninteractiveRole {
+        version: "next",
+        name: "noInteractiveElementToNoninteractiveRole",
+        recommended: false,
+    }
+}
+
+pub(crate) struct RuleState {
+    attribute_range: TextRange,
+    element_name: String,
+    role: String,
+}
+
+impl Rule for NoInteractiveElementToNoninteractiveRole {
+    type Query = Aria<AnyJsxElement>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let element_name = node.name().ok()?.as_jsx_name()?.value_token().ok()?;
+        let element_name = element_name.text_trimmed();
+        if !is_interactive_element(node) {
+            return None;
+        }
+        let role_attribute = node.find_attribute_by_name("role")?;
+        let role_attribute_static_value = role_attribute.as_static_value()?;
+        // The first role is the one used by the assistive technologies that support it
+        let role = role_attribute_static_value
+            .text()
+            .split_whitespace()
+            .next()?;
+        let is_non_interactive_role = role == "none"
+            || ctx
+                .aria_roles()
+                .get_role(role)
+                .is_some_and(|role| !role.is_interactive());
+        if !is_non_interactive_role {
+            return None;
+        }
+        Some(RuleState {
+            attribute_range: role_attribute.range(),
+            element_name: element_name.to_string(),
+            role: role.to_string(),
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            state.attribute_range,
+            markup! {
+                "The HTML element "<Emphasis>{{&state.element_name}}</Emphasis>" is interactive and should not have the non-interactive role "<Emphasis>{{&state.role}}</Emphasis>"."
+            },
+        ).note(
+            markup!{
+                "Remove the role, or replace "<Emphasis>{{&state.element_name}}</Emphasis>" with a non-interactive element, such as a div or a span."
+            }
+        ))
+    }
+}
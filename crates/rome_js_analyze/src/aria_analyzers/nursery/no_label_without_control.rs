@@ -0,0 +1,197 @@
+use crate::aria_services::Aria;
+use bpaf::Bpaf;
+use rome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_js_syntax::{jsx_ext::AnyJsxElement, JsxElement};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, AstNodeList, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Enforce that a `label` element is associated with a form control.
+    ///
+    /// A label is associated with a form control when it has a `htmlFor` (or `for`) attribute,
+    /// or when it wraps the control.
+    /// The controls are the `input`, `select` and `textarea` elements,
+    /// and the elements with an interactive `role`, at any depth inside the label.
+    ///
+    /// Source: https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/docs/rules/label-has-associated-control.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <label>Name</label>
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <label><span>Name</span></label>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <label htmlFor="name">Name</label>
+    /// ```
+    ///
+    /// ```jsx
+    /// <label>
+    ///     <span>Name</span>
+    ///     <input />
+    /// </label>
+    /// ```
+    ///
+    /// ```jsx
+    /// <label>
+    ///     Subscribe
+    ///     <div role="checkbox" aria-checked="false" tabIndex={0} />
+    /// </label>
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `ignoreSpread` to ignore the labels that have a spread attribute,
+    /// which can provide the `htmlFor` attribute.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "ignoreSpread": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 1.3.1](https://www.w3.org/WAI/WCAG21/Understanding/info-and-relationships)
+    /// - [WCAG 4.1.2](https://www.w3.org/WAI/WCAG21/Understanding/name-role-value)
+    ///
+    pub(crate) NoLabelWithoutControl {
+        version: "next",
+        name: "noLabelWithoutControl",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noLabelWithoutControl`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LabelWithoutControlOptions {
+    /// Whether the labels with a spread attribute are ignored.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub ignore_spread: bool,
+}
+
+impl LabelWithoutControlOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["ignoreSpread"];
+}
+
+// Required by [Bpaf].
+impl FromStr for LabelWithoutControlOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for LabelWithoutControlOptions {}
+impl VisitNode<JsonLanguage> for LabelWithoutControlOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "ignoreSpread" {
+            self.ignore_spread = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
+impl Rule for NoLabelWithoutControl {
+    type Query = Aria<AnyJsxElement>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = LabelWithoutControlOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let name = node.name().ok()?.as_jsx_name()?.value_token().ok()?;
+        if name.text_trimmed() != "label" {
+            return None;
+        }
+        if ctx.options().ignore_spread && node.has_spread_prop() {
+            return None;
+        }
+        let has_for_attribute = ["htmlFor", "for"].into_iter().any(|name| {
+            node.find_attribute_by_name(name)
+                .is_some_and(|attribute| !attribute.is_value_null_or_undefined())
+        });
+        if has_for_attribute {
+            return None;
+        }
+        let has_control = match node {
+            AnyJsxElement::JsxOpeningElement(opening) => opening
+                .parent::<JsxElement>()
+                .is_some_and(|element| has_control_descendant(ctx, &element)),
+            AnyJsxElement::JsxSelfClosingElement(_) => false,
+        };
+        (!has_control).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "A "<Emphasis>"label"</Emphasis>" element must be associated with a form control."
+                },
+            )
+            .note(markup! {
+                "Add a "<Emphasis>"htmlFor"</Emphasis>" attribute with the "<Emphasis>"id"</Emphasis>" of the control, or put the control inside the label."
+            }),
+        )
+    }
+}
+
+/// Checks if a form control or an element with an interactive role is nested in `label`.
+fn has_control_descendant(ctx: &RuleContext<NoLabelWithoutControl>, label: &JsxElement) -> bool {
+    label
+        .children()
+        .iter()
+        .flat_map(|child| child.syntax().descendants())
+        .filter_map(AnyJsxElement::cast)
+        .any(|element| {
+            let is_control = element
+                .name()
+                .ok()
+                .and_then(|name| name.as_jsx_name()?.value_token().ok())
+                .is_some_and(|name| matches!(name.text_trimmed(), "input" | "select" | "textarea"));
+            is_control
+                || element
+                    .find_attribute_by_name("role")
+                    .and_then(|attribute| attribute.as_static_value())
+                    .is_some_and(|role| ctx.aria_roles().is_role_interactive(role.text()))
+        })
+}
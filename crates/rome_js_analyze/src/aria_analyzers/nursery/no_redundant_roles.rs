@@ -11,6 +11,10 @@ use rome_rowan::{AstNode, BatchMutationExt};
 declare_rule! {
     /// Enforce explicit `role` property is not the same as implicit/default role property on an element.
     ///
+    /// The implicit role can depend on the attributes of the element,
+    /// for example `<input type="checkbox">` has the implicit role `checkbox`.
+    /// Removing a redundant role doesn't change the semantics of the element, so the code fix is safe.
+    ///
     /// ESLint (eslint-plugin-jsx-a11y) Equivalent: [no-redundant-roles](https://github.com/evcohen/eslint-plugin-jsx-a11y/blob/main/docs/rules/no-redundant-roles.md)
     ///
     /// ## Examples
@@ -29,6 +33,10 @@ declare_rule! {
     /// <h1 role='heading' aria-level='1'>title</h1>
     /// ```
     ///
+    /// ```jsx,expect_diagnostic
+    /// <input type="checkbox" role="checkbox" />
+    /// ```
+    ///
     /// ## Valid
     ///
     /// ```jsx
@@ -92,7 +100,7 @@ impl Rule for NoRedundantRoles {
         let element = state.element_name.to_string();
         Some(RuleDiagnostic::new(
             rule_category!(),
-            state.redundant_attribute_value.range(),
+            state.redundant_attribute.range(),
             markup! {
                 "Using the role attribute '"{role_attribute}"' on the '"{element}"' element is redundant."
             },
@@ -104,7 +112,7 @@ impl Rule for NoRedundantRoles {
         mutation.remove_node(state.redundant_attribute.clone());
         Some(JsRuleAction {
             category: ActionCategory::QuickFix,
-            applicability: Applicability::MaybeIncorrect,
+            applicability: Applicability::Always,
             message: markup! { "Remove the "<Emphasis>"role"</Emphasis>" attribute." }.to_owned(),
             mutation,
         })
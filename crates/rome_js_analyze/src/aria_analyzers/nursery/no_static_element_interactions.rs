@@ -0,0 +1,196 @@
+use crate::aria_services::Aria;
+use crate::utils::aria::is_interactive_element;
+use bpaf::Bpaf;
+use rome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_js_syntax::{jsx_ext::AnyJsxElement, AnyJsxAttribute};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, AstNodeList, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Enforce that static elements with event handlers have an interactive role.
+    ///
+    /// Static HTML elements, such as `div`, `span` or `li`, have no semantic meaning.
+    /// When they handle the user interactions, the assistive technologies cannot tell the users
+    /// that the element is interactive.
+    ///
+    /// The rule reports the HTML elements that have one of the handlers `onClick`, `onMouseDown`, `onMouseUp`,
+    /// `onKeyPress`, `onKeyDown` or `onKeyUp`, unless the element is inherently interactive,
+    /// such as `button` or `a` with a `href`, or it has an interactive `role`.
+    /// Custom components are ignored.
+    ///
+    /// Source: https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/docs/rules/no-static-element-interactions.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <div onClick={() => {}}></div>
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <span onKeyDown={handleKeyDown}>Open</span>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <button onClick={() => {}}>Submit</button>
+    /// ```
+    ///
+    /// ```jsx
+    /// <div role="button" onClick={() => {}} onKeyDown={handleKeyDown} tabIndex={0}></div>
+    /// ```
+    ///
+    /// ```jsx
+    /// <MyComponent onClick={() => {}} />
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `handlers` to check additional event handlers.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "handlers": ["onDoubleClick", "onTouchStart"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 4.1.2](https://www.w3.org/WAI/WCAG21/Understanding/name-role-value)
+    ///
+    pub(crate) NoStaticElementInteractions {
+        version: "next",
+        name: "noStaticElementInteractions",
+        recommended: false,
+    }
+}
+
+/// The event handlers that are checked by default.
+const DEFAULT_HANDLERS: [&str; 6] = [
+    "onClick",
+    "onMouseDown",
+    "onMouseUp",
+    "onKeyPress",
+    "onKeyDown",
+    "onKeyUp",
+];
+
+/// Options for the rule `noStaticElementInteractions`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct StaticElementInteractionsOptions {
+    /// Additional event handlers to check.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide, argument::<String>("NAME"), many, optional)]
+    pub handlers: Option<Vec<String>>,
+}
+
+impl StaticElementInteractionsOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["handlers"];
+
+    fn is_handler(&self, name: &str) -> bool {
+        DEFAULT_HANDLERS.contains(&name)
+            || self
+                .handlers
+                .as_ref()
+                .is_some_and(|handlers| handlers.iter().any(|handler| handler == name))
+    }
+}
+
+// Required by [Bpaf].
+impl FromStr for StaticElementInteractionsOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for StaticElementInteractionsOptions {}
+impl VisitNode<JsonLanguage> for StaticElementInteractionsOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "handlers" {
+            self.handlers = self.map_to_array_of_strings(&value, name_text, diagnostics);
+        }
+        Some(())
+    }
+}
+
+impl Rule for NoStaticElementInteractions {
+    type Query = Aria<AnyJsxElement>;
+    /// The name of the element.
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = StaticElementInteractionsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+        if !element.is_element() {
+            return None;
+        }
+        let name = element.name_value_token()?.text_trimmed().to_string();
+        if is_interactive_element(element) {
+            return None;
+        }
+        if element
+            .find_attribute_by_name("role")
+            .and_then(|attribute| attribute.as_static_value())
+            .is_some_and(|role| ctx.aria_roles().is_role_interactive(role.text().trim()))
+        {
+            return None;
+        }
+        let options = ctx.options();
+        let has_handler = element
+            .attributes()
+            .iter()
+            .any(|attribute| match attribute {
+                AnyJsxAttribute::JsxAttribute(attribute) => attribute
+                    .name_value_token()
+                    .is_some_and(|name| options.is_handler(name.text_trimmed())),
+                AnyJsxAttribute::JsxSpreadAttribute(_) => false,
+            });
+        has_handler.then_some(name)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "The static element "<Emphasis>{name}</Emphasis>" has an event handler, but no interactive role."
+                },
+            )
+            .note(markup! {
+                "Add an interactive role, such as "<Emphasis>"role=\"button\""</Emphasis>", or use an interactive element, such as "<Emphasis>"button"</Emphasis>", instead."
+            }),
+        )
+    }
+}
@@ -0,0 +1,134 @@
+use crate::aria_services::Aria;
+use crate::utils::append_jsx_attribute;
+use crate::utils::aria::is_natively_focusable;
+use crate::JsRuleAction;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    jsx_ext::AnyJsxElement, AnyJsExpression, AnyJsxAttributeName, AnyJsxAttributeValue,
+    TriviaPieceKind, T,
+};
+use rome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Enforce that the elements with an interactive role are focusable.
+    ///
+    /// An element with an interactive `role`, such as `button`, `link` or `checkbox`,
+    /// is announced as a control by the assistive technologies.
+    /// The users of the keyboard must be able to reach it,
+    /// so the element must be natively focusable, such as `button` or `a` with a `href`,
+    /// or it must have a `tabIndex`.
+    ///
+    /// The disabled elements and the elements with a spread attribute are ignored.
+    /// The code fix adds `tabIndex={0}` to the element.
+    ///
+    /// Source: https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/docs/rules/interactive-supports-focus.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <div role="button" onClick={handleClick}>Submit</div>
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <span role="checkbox" aria-checked="false" />
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <div role="button" tabIndex={0} onClick={handleClick}>Submit</div>
+    /// ```
+    ///
+    /// ```jsx
+    /// <a href="/" role="button">Submit</a>
+    /// ```
+    ///
+    /// ```jsx
+    /// <div role="article" />
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 2.1.1](https://www.w3.org/WAI/WCAG21/Understanding/keyboard)
+    ///
+    pub(crate) UseFocusableInteractive {
+        version: "next",
+        name: "useFocusableInteractive",
+        recommended: false,
+    }
+}
+
+impl Rule for UseFocusableInteractive {
+    type Query = Aria<AnyJsxElement>;
+    /// The interactive role.
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+        if !element.is_element()
+            || element.has_spread_prop()
+            || element.find_attribute_by_name("tabIndex").is_some()
+            || element.has_truthy_attribute("disabled")
+            || element.has_truthy_attribute("aria-disabled")
+            || is_natively_focusable(element)
+        {
+            return None;
+        }
+        let role = element.find_attribute_by_name("role")?.as_static_value()?;
+        // The first role is the one used by the assistive technologies that support it
+        let role = role.text().split_whitespace().next()?;
+        ctx.aria_roles()
+            .is_role_interactive(role)
+            .then(|| role.to_string())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, role: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "The element has the interactive role "<Emphasis>{role}</Emphasis>", but it isn't focusable."
+                },
+            )
+            .note(markup! {
+                "The users of the keyboard cannot reach the element. Add a "<Emphasis>"tabIndex"</Emphasis>", or use a natively focusable element instead."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let tabindex_attribute = make::jsx_attribute(AnyJsxAttributeName::JsxName(make::jsx_name(
+            make::jsx_ident("tabIndex").with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        )))
+        .with_initializer(make::jsx_attribute_initializer_clause(
+            make::token(T![=]),
+            AnyJsxAttributeValue::JsxExpressionAttributeValue(
+                make::jsx_expression_attribute_value(
+                    make::token(T!['{']),
+                    AnyJsExpression::AnyJsLiteralExpression(
+                        make::js_number_literal_expression(make::js_number_literal(0)).into(),
+                    ),
+                    make::token(T!['}']),
+                ),
+            ),
+        ))
+        .build();
+        let mut mutation = ctx.root().begin();
+        append_jsx_attribute(&mut mutation, ctx.query(), tabindex_attribute)?;
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Add the attribute "<Emphasis>"tabIndex={0}"</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
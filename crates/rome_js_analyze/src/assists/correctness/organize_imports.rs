@@ -17,11 +17,11 @@ use rome_js_syntax::{
     JsLanguage, JsModule, JsSyntaxToken, TextRange, TriviaPieceKind, T,
 };
 use rome_rowan::{
-    chain_trivia_pieces, syntax::SyntaxTrivia, AstNode, AstNodeExt, AstNodeList, AstSeparatedList,
-    BatchMutationExt, SyntaxTriviaPiece, TokenText, TriviaPiece,
+    chain_trivia_pieces, AstNode, AstNodeExt, AstNodeList, AstSeparatedList, BatchMutationExt,
+    SyntaxTriviaPiece, TokenText, TriviaPiece,
 };
 
-use crate::JsRuleAction;
+use crate::{utils::has_empty_line, JsRuleAction};
 
 declare_rule! {
     /// Provides a whole-source code action to sort the imports in the file
@@ -744,16 +744,3 @@ impl From<&str> for ImportCategory {
 fn is_ascii_whitespace(piece: &SyntaxTriviaPiece<JsLanguage>) -> bool {
     piece.is_newline() || piece.is_whitespace()
 }
-
-/// Returns true if the provided trivia contains an empty line (two consecutive newline pieces, ignoring whitespace)
-fn has_empty_line(trivia: SyntaxTrivia<JsLanguage>) -> bool {
-    let mut was_newline = false;
-    trivia
-        .pieces()
-        .filter(|piece| !piece.is_whitespace())
-        .any(|piece| {
-            let prev_newline = was_newline;
-            was_newline = piece.is_newline();
-            prev_newline && was_newline
-        })
-}
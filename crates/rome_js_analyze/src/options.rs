@@ -1,15 +1,86 @@
 //! This module contains the rules that have options
 
+use crate::analyzers::nursery::no_await_in_loop::{await_in_loop_options, AwaitInLoopOptions};
+use crate::analyzers::nursery::no_barrel_file::{barrel_file_options, BarrelFileOptions};
+use crate::analyzers::nursery::no_confusing_void_type::{
+    confusing_void_type_options, ConfusingVoidTypeOptions,
+};
+use crate::analyzers::nursery::no_empty_block_statements::{
+    empty_block_statements_options, EmptyBlockStatementsOptions,
+};
 use crate::analyzers::nursery::no_excessive_complexity::{complexity_options, ComplexityOptions};
+use crate::analyzers::nursery::no_re_export_all::{re_export_all_options, ReExportAllOptions};
+use crate::analyzers::nursery::use_consistent_array_type::{
+    consistent_array_type_options, ConsistentArrayTypeOptions,
+};
+use crate::analyzers::nursery::use_consistent_curly_braces::{
+    consistent_curly_braces_options, ConsistentCurlyBracesOptions,
+};
+use crate::analyzers::nursery::use_consistent_member_accessibility::{
+    consistent_member_accessibility_options, ConsistentMemberAccessibilityOptions,
+};
+use crate::analyzers::nursery::use_consistent_type_definitions::{
+    consistent_type_definitions_options, ConsistentTypeDefinitionsOptions,
+};
+use crate::analyzers::nursery::use_import_extensions::{
+    import_extensions_options, ImportExtensionsOptions,
+};
+use crate::analyzers::nursery::use_import_grouping::{
+    import_grouping_options, ImportGroupingOptions,
+};
+use crate::analyzers::nursery::use_numeric_separators::{
+    numeric_separators_options, NumericSeparatorsOptions,
+};
+use crate::analyzers::style::no_implicit_boolean::{
+    implicit_boolean_options, ImplicitBooleanOptions,
+};
+use crate::analyzers::style::use_self_closing_elements::{
+    self_closing_elements_options, SelfClosingElementsOptions,
+};
+use crate::analyzers::suspicious::no_explicit_any::{explicit_any_options, ExplicitAnyOptions};
+use crate::aria_analyzers::nursery::no_label_without_control::{
+    label_without_control_options, LabelWithoutControlOptions,
+};
+use crate::aria_analyzers::nursery::no_static_element_interactions::{
+    static_element_interactions_options, StaticElementInteractionsOptions,
+};
+use crate::semantic_analyzers::a11y::use_button_type::{button_type_options, ButtonTypeOptions};
+use crate::semantic_analyzers::complexity::no_useless_fragments::{
+    useless_fragments_options, UselessFragmentsOptions,
+};
+use crate::semantic_analyzers::nursery::no_console::{console_options, ConsoleOptions};
+use crate::semantic_analyzers::nursery::no_constant_condition::{
+    constant_condition_options, ConstantConditionOptions,
+};
+use crate::semantic_analyzers::nursery::no_process_env::{process_env_options, ProcessEnvOptions};
 use crate::semantic_analyzers::nursery::use_exhaustive_dependencies::{
     hooks_options, HooksOptions,
 };
+use crate::semantic_analyzers::nursery::use_exports_last::{
+    exports_last_options, ExportsLastOptions,
+};
+use crate::semantic_analyzers::nursery::use_import_type::{import_type_options, ImportTypeOptions};
 use crate::semantic_analyzers::nursery::use_naming_convention::{
     naming_convention_options, NamingConventionOptions,
 };
+use crate::semantic_analyzers::nursery::use_node_assert_strict::{
+    node_assert_strict_options, NodeAssertStrictOptions,
+};
+use crate::semantic_analyzers::nursery::use_node_prefix_for_builtins::{
+    node_prefix_for_builtins_options, NodePrefixForBuiltinsOptions,
+};
+use crate::semantic_analyzers::security::no_dangerously_set_inner_html::{
+    dangerously_set_inner_html_options, DangerouslySetInnerHtmlOptions,
+};
+use crate::semantic_analyzers::style::no_parameter_assign::{
+    parameter_assign_options, ParameterAssignOptions,
+};
 use crate::semantic_analyzers::style::no_restricted_globals::{
     restricted_globals_options, RestrictedGlobalsOptions,
 };
+use crate::semantic_analyzers::suspicious::no_double_equals::{
+    double_equals_options, DoubleEqualsOptions,
+};
 use bpaf::Bpaf;
 use rome_analyze::options::RuleOptions;
 use rome_analyze::RuleKey;
@@ -34,6 +105,89 @@ pub enum PossibleOptions {
     NamingConvention(#[bpaf(external(naming_convention_options), hide)] NamingConventionOptions),
     /// Options for `noRestrictedGlobals` rule
     RestrictedGlobals(#[bpaf(external(restricted_globals_options), hide)] RestrictedGlobalsOptions),
+    /// Options for `useNumericSeparators` rule
+    NumericSeparators(#[bpaf(external(numeric_separators_options), hide)] NumericSeparatorsOptions),
+    /// Options for `useConsistentArrayType` rule
+    ConsistentArrayType(
+        #[bpaf(external(consistent_array_type_options), hide)] ConsistentArrayTypeOptions,
+    ),
+    /// Options for `noConstantCondition` rule
+    ConstantCondition(#[bpaf(external(constant_condition_options), hide)] ConstantConditionOptions),
+    /// Options for `noAwaitInLoop` rule
+    AwaitInLoop(#[bpaf(external(await_in_loop_options), hide)] AwaitInLoopOptions),
+    /// Options for `noReExportAll` rule
+    ReExportAll(#[bpaf(external(re_export_all_options), hide)] ReExportAllOptions),
+    /// Options for `useImportType` rule
+    ImportType(#[bpaf(external(import_type_options), hide)] ImportTypeOptions),
+    /// Options for `useConsistentMemberAccessibility` rule
+    ConsistentMemberAccessibility(
+        #[bpaf(external(consistent_member_accessibility_options), hide)]
+        ConsistentMemberAccessibilityOptions,
+    ),
+    /// Options for `noParameterAssign` rule
+    ParameterAssign(#[bpaf(external(parameter_assign_options), hide)] ParameterAssignOptions),
+    /// Options for `useNodeAssertStrict` rule
+    NodeAssertStrict(#[bpaf(external(node_assert_strict_options), hide)] NodeAssertStrictOptions),
+    /// Options for `useNodePrefixForBuiltins` rule
+    NodePrefixForBuiltins(
+        #[bpaf(external(node_prefix_for_builtins_options), hide)] NodePrefixForBuiltinsOptions,
+    ),
+    /// Options for `noBarrelFile` rule
+    BarrelFile(#[bpaf(external(barrel_file_options), hide)] BarrelFileOptions),
+    /// Options for `useExportsLast` rule
+    ExportsLast(#[bpaf(external(exports_last_options), hide)] ExportsLastOptions),
+    /// Options for `noProcessEnv` rule
+    ProcessEnv(#[bpaf(external(process_env_options), hide)] ProcessEnvOptions),
+    /// Options for `noConsole` rule
+    Console(#[bpaf(external(console_options), hide)] ConsoleOptions),
+    /// Options for `useConsistentCurlyBraces` rule
+    ConsistentCurlyBraces(
+        #[bpaf(external(consistent_curly_braces_options), hide)] ConsistentCurlyBracesOptions,
+    ),
+    /// Options for `noImplicitBoolean` rule
+    ImplicitBoolean(#[bpaf(external(implicit_boolean_options), hide)] ImplicitBooleanOptions),
+    /// Options for `noUselessFragments` rule
+    UselessFragments(#[bpaf(external(useless_fragments_options), hide)] UselessFragmentsOptions),
+    /// Options for `useSelfClosingElements` rule
+    SelfClosingElements(
+        #[bpaf(external(self_closing_elements_options), hide)] SelfClosingElementsOptions,
+    ),
+    /// Options for `useButtonType` rule
+    ButtonType(#[bpaf(external(button_type_options), hide)] ButtonTypeOptions),
+    /// Options for `noStaticElementInteractions` rule
+    StaticElementInteractions(
+        #[bpaf(external(static_element_interactions_options), hide)]
+        StaticElementInteractionsOptions,
+    ),
+    /// Options for `noLabelWithoutControl` rule
+    LabelWithoutControl(
+        #[bpaf(external(label_without_control_options), hide)] LabelWithoutControlOptions,
+    ),
+    /// Options for `noDangerouslySetInnerHtml` rule
+    DangerouslySetInnerHtml(
+        #[bpaf(external(dangerously_set_inner_html_options), hide)] DangerouslySetInnerHtmlOptions,
+    ),
+    /// Options for `useImportExtensions` rule
+    ImportExtensions(#[bpaf(external(import_extensions_options), hide)] ImportExtensionsOptions),
+    /// Options for `useImportGrouping` rule
+    ImportGrouping(#[bpaf(external(import_grouping_options), hide)] ImportGroupingOptions),
+    /// Options for `noEmptyBlockStatements` rule
+    EmptyBlockStatements(
+        #[bpaf(external(empty_block_statements_options), hide)] EmptyBlockStatementsOptions,
+    ),
+    /// Options for `noConfusingVoidType` rule
+    ConfusingVoidType(
+        #[bpaf(external(confusing_void_type_options), hide)] ConfusingVoidTypeOptions,
+    ),
+    /// Options for `noExplicitAny` rule
+    ExplicitAny(#[bpaf(external(explicit_any_options), hide)] ExplicitAnyOptions),
+    /// Options for `useConsistentTypeDefinitions` rule
+    ConsistentTypeDefinitions(
+        #[bpaf(external(consistent_type_definitions_options), hide)]
+        ConsistentTypeDefinitionsOptions,
+    ),
+    /// Options for `noDoubleEquals` rule
+    NoDoubleEquals(#[bpaf(external(double_equals_options), hide)] DoubleEqualsOptions),
     /// No options available
     #[default]
     NoOptions,
@@ -78,6 +232,209 @@ impl PossibleOptions {
                 };
                 RuleOptions::new(options)
             }
+            "useNumericSeparators" => {
+                let options = match self {
+                    PossibleOptions::NumericSeparators(options) => options.clone(),
+                    _ => NumericSeparatorsOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useConsistentArrayType" => {
+                let options = match self {
+                    PossibleOptions::ConsistentArrayType(options) => options.clone(),
+                    _ => ConsistentArrayTypeOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noConstantCondition" => {
+                let options = match self {
+                    PossibleOptions::ConstantCondition(options) => options.clone(),
+                    _ => ConstantConditionOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noAwaitInLoop" => {
+                let options = match self {
+                    PossibleOptions::AwaitInLoop(options) => options.clone(),
+                    _ => AwaitInLoopOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noReExportAll" => {
+                let options = match self {
+                    PossibleOptions::ReExportAll(options) => options.clone(),
+                    _ => ReExportAllOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useImportType" => {
+                let options = match self {
+                    PossibleOptions::ImportType(options) => options.clone(),
+                    _ => ImportTypeOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useConsistentMemberAccessibility" => {
+                let options = match self {
+                    PossibleOptions::ConsistentMemberAccessibility(options) => options.clone(),
+                    _ => ConsistentMemberAccessibilityOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noParameterAssign" => {
+                let options = match self {
+                    PossibleOptions::ParameterAssign(options) => options.clone(),
+                    _ => ParameterAssignOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useNodeAssertStrict" => {
+                let options = match self {
+                    PossibleOptions::NodeAssertStrict(options) => options.clone(),
+                    _ => NodeAssertStrictOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useNodePrefixForBuiltins" => {
+                let options = match self {
+                    PossibleOptions::NodePrefixForBuiltins(options) => options.clone(),
+                    _ => NodePrefixForBuiltinsOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noBarrelFile" => {
+                let options = match self {
+                    PossibleOptions::BarrelFile(options) => options.clone(),
+                    _ => BarrelFileOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useExportsLast" => {
+                let options = match self {
+                    PossibleOptions::ExportsLast(options) => options.clone(),
+                    _ => ExportsLastOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noProcessEnv" => {
+                let options = match self {
+                    PossibleOptions::ProcessEnv(options) => options.clone(),
+                    _ => ProcessEnvOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noConsole" => {
+                let options = match self {
+                    PossibleOptions::Console(options) => options.clone(),
+                    _ => ConsoleOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useConsistentCurlyBraces" => {
+                let options = match self {
+                    PossibleOptions::ConsistentCurlyBraces(options) => options.clone(),
+                    _ => ConsistentCurlyBracesOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noImplicitBoolean" => {
+                let options = match self {
+                    PossibleOptions::ImplicitBoolean(options) => options.clone(),
+                    _ => ImplicitBooleanOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noUselessFragments" => {
+                let options = match self {
+                    PossibleOptions::UselessFragments(options) => options.clone(),
+                    _ => UselessFragmentsOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useSelfClosingElements" => {
+                let options = match self {
+                    PossibleOptions::SelfClosingElements(options) => options.clone(),
+                    _ => SelfClosingElementsOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useButtonType" => {
+                let options = match self {
+                    PossibleOptions::ButtonType(options) => options.clone(),
+                    _ => ButtonTypeOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noStaticElementInteractions" => {
+                let options = match self {
+                    PossibleOptions::StaticElementInteractions(options) => options.clone(),
+                    _ => StaticElementInteractionsOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noLabelWithoutControl" => {
+                let options = match self {
+                    PossibleOptions::LabelWithoutControl(options) => options.clone(),
+                    _ => LabelWithoutControlOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noDangerouslySetInnerHtml" => {
+                let options = match self {
+                    PossibleOptions::DangerouslySetInnerHtml(options) => options.clone(),
+                    _ => DangerouslySetInnerHtmlOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useImportExtensions" => {
+                let options = match self {
+                    PossibleOptions::ImportExtensions(options) => options.clone(),
+                    _ => ImportExtensionsOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useImportGrouping" => {
+                let options = match self {
+                    PossibleOptions::ImportGrouping(options) => options.clone(),
+                    _ => ImportGroupingOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noEmptyBlockStatements" => {
+                let options = match self {
+                    PossibleOptions::EmptyBlockStatements(options) => options.clone(),
+                    _ => EmptyBlockStatementsOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noConfusingVoidType" => {
+                let options = match self {
+                    PossibleOptions::ConfusingVoidType(options) => options.clone(),
+                    _ => ConfusingVoidTypeOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noExplicitAny" => {
+                let options = match self {
+                    PossibleOptions::ExplicitAny(options) => options.clone(),
+                    _ => ExplicitAnyOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "useConsistentTypeDefinitions" => {
+                let options = match self {
+                    PossibleOptions::ConsistentTypeDefinitions(options) => options.clone(),
+                    _ => ConsistentTypeDefinitionsOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
+            "noDoubleEquals" => {
+                let options = match self {
+                    PossibleOptions::NoDoubleEquals(options) => options.clone(),
+                    _ => DoubleEqualsOptions::default(),
+                };
+                RuleOptions::new(options)
+            }
             // TODO: review error
             _ => panic!("This rule {:?} doesn't have options", rule_key),
         }
@@ -135,6 +492,267 @@ impl PossibleOptions {
                     *self = PossibleOptions::RestrictedGlobals(options);
                 }
 
+                "minimumDigits" => {
+                    let mut options = match self {
+                        PossibleOptions::NumericSeparators(options) => options.clone(),
+                        _ => NumericSeparatorsOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::NumericSeparators(options);
+                }
+
+                "syntax" => {
+                    let mut options = match self {
+                        PossibleOptions::ConsistentArrayType(options) => options.clone(),
+                        _ => ConsistentArrayTypeOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ConsistentArrayType(options);
+                }
+
+                "allowConstantLoopConditions" => {
+                    let mut options = match self {
+                        PossibleOptions::ConstantCondition(options) => options.clone(),
+                        _ => ConstantConditionOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ConstantCondition(options);
+                }
+
+                "ignoreForOf" => {
+                    let mut options = match self {
+                        PossibleOptions::AwaitInLoop(options) => options.clone(),
+                        _ => AwaitInLoopOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::AwaitInLoop(options);
+                }
+
+                "allowNamedExportAll" => {
+                    let mut options = match self {
+                        PossibleOptions::ReExportAll(options) => options.clone(),
+                        _ => ReExportAllOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ReExportAll(options);
+                }
+
+                "style" if rule_name == "useConsistentTypeDefinitions" => {
+                    let mut options = match self {
+                        PossibleOptions::ConsistentTypeDefinitions(options) => options.clone(),
+                        _ => ConsistentTypeDefinitionsOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ConsistentTypeDefinitions(options);
+                }
+
+                "style" => {
+                    let mut options = match self {
+                        PossibleOptions::ImportType(options) => options.clone(),
+                        _ => ImportTypeOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ImportType(options);
+                }
+
+                "accessibility" => {
+                    let mut options = match self {
+                        PossibleOptions::ConsistentMemberAccessibility(options) => options.clone(),
+                        _ => ConsistentMemberAccessibilityOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ConsistentMemberAccessibility(options);
+                }
+
+                "props" => {
+                    let mut options = match self {
+                        PossibleOptions::ParameterAssign(options) => options.clone(),
+                        _ => ParameterAssignOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ParameterAssign(options);
+                }
+
+                "ignoreNodeProtocol" => {
+                    let mut options = match self {
+                        PossibleOptions::NodeAssertStrict(options) => options.clone(),
+                        _ => NodeAssertStrictOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::NodeAssertStrict(options);
+                }
+
+                "checkAmbiguousModules" => {
+                    let mut options = match self {
+                        PossibleOptions::NodePrefixForBuiltins(options) => options.clone(),
+                        _ => NodePrefixForBuiltinsOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::NodePrefixForBuiltins(options);
+                }
+
+                "threshold" => {
+                    let mut options = match self {
+                        PossibleOptions::BarrelFile(options) => options.clone(),
+                        _ => BarrelFileOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::BarrelFile(options);
+                }
+
+                "ignoreTypeExports" => {
+                    let mut options = match self {
+                        PossibleOptions::ExportsLast(options) => options.clone(),
+                        _ => ExportsLastOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ExportsLast(options);
+                }
+
+                "allowEnvVars" => {
+                    let mut options = match self {
+                        PossibleOptions::ProcessEnv(options) => options.clone(),
+                        _ => ProcessEnvOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ProcessEnv(options);
+                }
+
+                "allow" => {
+                    let mut options = match self {
+                        PossibleOptions::Console(options) => options.clone(),
+                        _ => ConsoleOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::Console(options);
+                }
+
+                "braces" => {
+                    let mut options = match self {
+                        PossibleOptions::ConsistentCurlyBraces(options) => options.clone(),
+                        _ => ConsistentCurlyBracesOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ConsistentCurlyBraces(options);
+                }
+
+                "explicitValues" | "ignoredAttributes" => {
+                    let mut options = match self {
+                        PossibleOptions::ImplicitBoolean(options) => options.clone(),
+                        _ => ImplicitBooleanOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ImplicitBoolean(options);
+                }
+
+                "allowExpressions" => {
+                    let mut options = match self {
+                        PossibleOptions::UselessFragments(options) => options.clone(),
+                        _ => UselessFragmentsOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::UselessFragments(options);
+                }
+
+                "ignoreHtmlElements" => {
+                    let mut options = match self {
+                        PossibleOptions::SelfClosingElements(options) => options.clone(),
+                        _ => SelfClosingElementsOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::SelfClosingElements(options);
+                }
+
+                "strict" => {
+                    let mut options = match self {
+                        PossibleOptions::ButtonType(options) => options.clone(),
+                        _ => ButtonTypeOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ButtonType(options);
+                }
+
+                "handlers" => {
+                    let mut options = match self {
+                        PossibleOptions::StaticElementInteractions(options) => options.clone(),
+                        _ => StaticElementInteractionsOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::StaticElementInteractions(options);
+                }
+
+                "ignoreSpread" => {
+                    let mut options = match self {
+                        PossibleOptions::LabelWithoutControl(options) => options.clone(),
+                        _ => LabelWithoutControlOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::LabelWithoutControl(options);
+                }
+
+                "allowWithSanitizer" => {
+                    let mut options = match self {
+                        PossibleOptions::DangerouslySetInnerHtml(options) => options.clone(),
+                        _ => DangerouslySetInnerHtmlOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::DangerouslySetInnerHtml(options);
+                }
+
+                "suggestedExtensions" => {
+                    let mut options = match self {
+                        PossibleOptions::ImportExtensions(options) => options.clone(),
+                        _ => ImportExtensionsOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ImportExtensions(options);
+                }
+
+                "groups" => {
+                    let mut options = match self {
+                        PossibleOptions::ImportGrouping(options) => options.clone(),
+                        _ => ImportGroupingOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ImportGrouping(options);
+                }
+
+                "allowEmptyCatch" => {
+                    let mut options = match self {
+                        PossibleOptions::EmptyBlockStatements(options) => options.clone(),
+                        _ => EmptyBlockStatementsOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::EmptyBlockStatements(options);
+                }
+
+                "fixToUndefined" => {
+                    let mut options = match self {
+                        PossibleOptions::ConfusingVoidType(options) => options.clone(),
+                        _ => ConfusingVoidTypeOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ConfusingVoidType(options);
+                }
+
+                "ignoreRestArgs" | "fixToUnknown" => {
+                    let mut options = match self {
+                        PossibleOptions::ExplicitAny(options) => options.clone(),
+                        _ => ExplicitAnyOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::ExplicitAny(options);
+                }
+
+                "ignoreNull" => {
+                    let mut options = match self {
+                        PossibleOptions::NoDoubleEquals(options) => options.clone(),
+                        _ => DoubleEqualsOptions::default(),
+                    };
+                    options.visit_map(key.syntax(), value.syntax(), diagnostics)?;
+                    *self = PossibleOptions::NoDoubleEquals(options);
+                }
+
                 _ => (),
             }
         }
@@ -187,6 +805,267 @@ impl PossibleOptions {
                     ));
                 }
             }
+            "useNumericSeparators" => {
+                if !matches!(key_name, "minimumDigits") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        NumericSeparatorsOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useConsistentArrayType" => {
+                if !matches!(key_name, "syntax") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ConsistentArrayTypeOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noConstantCondition" => {
+                if !matches!(key_name, "allowConstantLoopConditions") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ConstantConditionOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noAwaitInLoop" => {
+                if !matches!(key_name, "ignoreForOf") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        AwaitInLoopOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noReExportAll" => {
+                if !matches!(key_name, "allowNamedExportAll") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ReExportAllOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useImportType" => {
+                if !matches!(key_name, "style") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ImportTypeOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useConsistentMemberAccessibility" => {
+                if !matches!(key_name, "accessibility") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ConsistentMemberAccessibilityOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noParameterAssign" => {
+                if !matches!(key_name, "props") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ParameterAssignOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useNodeAssertStrict" => {
+                if !matches!(key_name, "ignoreNodeProtocol") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        NodeAssertStrictOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useNodePrefixForBuiltins" => {
+                if !matches!(key_name, "checkAmbiguousModules") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        NodePrefixForBuiltinsOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noBarrelFile" => {
+                if !matches!(key_name, "threshold") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        BarrelFileOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useExportsLast" => {
+                if !matches!(key_name, "ignoreTypeExports") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ExportsLastOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noProcessEnv" => {
+                if !matches!(key_name, "allowEnvVars") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ProcessEnvOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noConsole" => {
+                if !matches!(key_name, "allow") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ConsoleOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useConsistentCurlyBraces" => {
+                if !matches!(key_name, "braces") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ConsistentCurlyBracesOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noImplicitBoolean" => {
+                if !matches!(key_name, "explicitValues" | "ignoredAttributes") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ImplicitBooleanOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noUselessFragments" => {
+                if !matches!(key_name, "allowExpressions") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        UselessFragmentsOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useSelfClosingElements" => {
+                if !matches!(key_name, "ignoreHtmlElements") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        SelfClosingElementsOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useButtonType" => {
+                if !matches!(key_name, "strict") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ButtonTypeOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noStaticElementInteractions" => {
+                if !matches!(key_name, "handlers") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        StaticElementInteractionsOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noLabelWithoutControl" => {
+                if !matches!(key_name, "ignoreSpread") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        LabelWithoutControlOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noDangerouslySetInnerHtml" => {
+                if !matches!(key_name, "allowWithSanitizer") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        DangerouslySetInnerHtmlOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useImportExtensions" => {
+                if !matches!(key_name, "suggestedExtensions") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ImportExtensionsOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useImportGrouping" => {
+                if !matches!(key_name, "groups") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ImportGroupingOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noEmptyBlockStatements" => {
+                if !matches!(key_name, "allowEmptyCatch") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        EmptyBlockStatementsOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noConfusingVoidType" => {
+                if !matches!(key_name, "fixToUndefined") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ConfusingVoidTypeOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noExplicitAny" => {
+                if !matches!(key_name, "ignoreRestArgs" | "fixToUnknown") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ExplicitAnyOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "useConsistentTypeDefinitions" => {
+                if !matches!(key_name, "style") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        ConsistentTypeDefinitionsOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
+            "noDoubleEquals" => {
+                if !matches!(key_name, "ignoreNull") {
+                    diagnostics.push(DeserializationDiagnostic::new_unknown_key(
+                        key_name,
+                        node.range(),
+                        DoubleEqualsOptions::KNOWN_KEYS,
+                    ));
+                }
+            }
             _ => {}
         }
 
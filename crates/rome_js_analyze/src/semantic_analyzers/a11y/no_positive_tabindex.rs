@@ -1,20 +1,28 @@
 use crate::react::{ReactApiCall, ReactCreateElementCall};
 use crate::semantic_services::Semantic;
+use crate::JsRuleAction;
 use rome_analyze::context::RuleContext;
-use rome_analyze::{declare_rule, Rule, RuleDiagnostic};
+use rome_analyze::{declare_rule, ActionCategory, Rule, RuleDiagnostic};
 use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
 use rome_js_semantic::SemanticModel;
 use rome_js_syntax::jsx_ext::AnyJsxElement;
 use rome_js_syntax::{
-    AnyJsxAttributeValue, JsCallExpression, JsNumberLiteralExpression, JsPropertyObjectMember,
-    JsStringLiteralExpression, JsUnaryExpression, JsxAttribute, TextRange,
+    AnyJsExpression, AnyJsLiteralExpression, AnyJsxAttributeValue, JsCallExpression,
+    JsNumberLiteralExpression, JsPropertyObjectMember, JsStringLiteralExpression,
+    JsUnaryExpression, JsxAttribute,
 };
-use rome_rowan::{declare_node_union, AstNode};
+use rome_rowan::{declare_node_union, AstNode, BatchMutationExt};
 
 declare_rule! {
     /// Prevent the usage of positive integers on `tabIndex` property
     ///
     /// Avoid positive `tabIndex` property values to synchronize the flow of the page with keyboard tab order.
+    ///
+    /// The rule also checks the lowercase `tabindex` attribute.
+    /// The code fix sets the value to `0`, which keeps the element focusable in the natural tab order.
+    ///
     /// ## Accessibility guidelines
     ///
     /// [WCAG 2.4.3](https://www.w3.org/WAI/WCAG21/Understanding/focus-order)
@@ -59,6 +67,11 @@ declare_node_union! {
     pub(crate) NoPositiveTabindexQuery = AnyJsxElement | JsCallExpression
 }
 
+declare_node_union! {
+    /// The value of a `tabIndex` prop.
+    pub(crate) AnyTabindexValue = AnyJsxAttributeValue | AnyJsExpression
+}
+
 declare_node_union! {
     /// Subset of expressions supported by this rule.
     ///
@@ -76,6 +89,7 @@ impl NoPositiveTabindexQuery {
         match self {
             NoPositiveTabindexQuery::AnyJsxElement(jsx) => jsx
                 .find_attribute_by_name("tabIndex")
+                .or_else(|| jsx.find_attribute_by_name("tabindex"))
                 .map(TabindexProp::from),
             NoPositiveTabindexQuery::JsCallExpression(expression) => {
                 let react_create_element =
@@ -113,7 +127,7 @@ impl AnyNumberLikeExpression {
 
 impl Rule for NoPositiveTabindex {
     type Query = Semantic<NoPositiveTabindexQuery>;
-    type State = TextRange;
+    type State = AnyTabindexValue;
     type Signals = Option<Self::State>;
     type Options = ();
 
@@ -127,7 +141,7 @@ impl Rule for NoPositiveTabindex {
                 let jsx_any_attribute_value = jsx_attribute.initializer()?.value().ok()?;
 
                 if !attribute_has_valid_tabindex(&jsx_any_attribute_value)? {
-                    return Some(jsx_any_attribute_value.syntax().text_trimmed_range());
+                    return Some(jsx_any_attribute_value.into());
                 }
             }
             TabindexProp::JsPropertyObjectMember(js_object_member) => {
@@ -137,7 +151,7 @@ impl Rule for NoPositiveTabindex {
                     AnyNumberLikeExpression::cast_ref(expression_syntax_node)?.value()?;
 
                 if !is_tabindex_valid(&expression_value) {
-                    return Some(expression_syntax_node.text_trimmed_range());
+                    return Some(expression.into());
                 }
             }
         }
@@ -148,7 +162,7 @@ impl Rule for NoPositiveTabindex {
     fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
         let diagnostic = RuleDiagnostic::new(
             rule_category!(),
-            state,
+            state.range(),
             markup!{"Avoid positive values for the "<Emphasis>"tabIndex"</Emphasis>" prop."}.to_owned(),
         )
         .note(
@@ -159,6 +173,41 @@ impl Rule for NoPositiveTabindex {
 
         Some(diagnostic)
     }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let zero = AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsNumberLiteralExpression(make::js_number_literal_expression(
+                make::js_number_literal(0),
+            )),
+        );
+        match state {
+            AnyTabindexValue::AnyJsxAttributeValue(AnyJsxAttributeValue::JsxString(string)) => {
+                mutation.replace_node(
+                    string.clone(),
+                    make::jsx_string(make::jsx_string_literal("0")),
+                );
+            }
+            AnyTabindexValue::AnyJsxAttributeValue(
+                AnyJsxAttributeValue::JsxExpressionAttributeValue(value),
+            ) => {
+                mutation.replace_node(value.expression().ok()?, zero);
+            }
+            AnyTabindexValue::AnyJsxAttributeValue(AnyJsxAttributeValue::AnyJsxTag(_)) => {
+                return None
+            }
+            AnyTabindexValue::AnyJsExpression(expression) => {
+                mutation.replace_node(expression.clone(), zero);
+            }
+        }
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Replace the "<Emphasis>"tabIndex"</Emphasis>" value with "<Emphasis>"0"</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        })
+    }
 }
 
 /// Verify that a JSX attribute value has a valid tab index, meaning it is not positive.
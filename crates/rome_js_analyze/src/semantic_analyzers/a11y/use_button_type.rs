@@ -1,12 +1,25 @@
 use crate::react::{ReactApiCall, ReactCreateElementCall};
 use crate::semantic_services::Semantic;
-use rome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use crate::utils::append_jsx_attribute;
+use crate::JsRuleAction;
+use bpaf::Bpaf;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
 use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
 use rome_js_syntax::{
-    AnyJsxElementName, JsCallExpression, JsxAttribute, JsxOpeningElement, JsxSelfClosingElement,
-    TextRange,
+    jsx_ext::AnyJsxElement, AnyJsObjectMember, AnyJsxAttribute, AnyJsxAttributeName,
+    AnyJsxAttributeValue, AnyJsxElementName, JsCallExpression, JsxAttribute, JsxAttributeList,
+    JsxOpeningElement, JsxSelfClosingElement, TextRange, TriviaPieceKind, T,
 };
-use rome_rowan::{declare_node_union, AstNode};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{
+    declare_node_union, AstNode, AstNodeList, AstSeparatedList, BatchMutationExt, SyntaxNode,
+};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 declare_rule! {
     /// Enforces the usage of the attribute `type` for the element `button`
@@ -35,6 +48,27 @@ declare_rule! {
     ///     <button type={buttonType}>Do something</button>
     /// </>
     /// ```
+    ///
+    /// The elements with a spread attribute are ignored, because the spread object can provide the `type`.
+    ///
+    /// ```jsx
+    /// <button {...props}>Do something</button>
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `strict` to also report the elements with a spread attribute and no `type`.
+    /// Its default value is `false`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "strict": true
+    ///     }
+    /// }
+    /// ```
+    ///
     pub(crate) UseButtonType {
         version: "1.0.0",
         name: "useButtonType",
@@ -42,6 +76,56 @@ declare_rule! {
     }
 }
 
+/// Options for the rule `useButtonType`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ButtonTypeOptions {
+    /// Whether the elements with a spread attribute and no `type` are reported.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub strict: bool,
+}
+
+impl ButtonTypeOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["strict"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ButtonTypeOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ButtonTypeOptions {}
+impl VisitNode<JsonLanguage> for ButtonTypeOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "strict" {
+            self.strict = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
 const ALLOWED_BUTTON_TYPES: [&str; 3] = ["submit", "button", "reset"];
 
 declare_node_union! {
@@ -57,10 +141,11 @@ impl Rule for UseButtonType {
     type Query = Semantic<UseButtonTypeQuery>;
     type State = UseButtonTypeState;
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = ButtonTypeOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
+        let strict = ctx.options().strict;
         match node {
             UseButtonTypeQuery::JsxSelfClosingElement(element) => {
                 let name = element.name().ok()?;
@@ -69,6 +154,9 @@ impl Rule for UseButtonType {
                 }
                 let type_attribute = element.find_attribute_by_name("type").ok()?;
                 let Some(attribute) = type_attribute else {
+                    if !strict && has_spread_attribute(&element.attributes()) {
+                        return None;
+                    }
                     return Some(UseButtonTypeState {
                         range: element.range(),
                         missing_prop: true,
@@ -83,6 +171,9 @@ impl Rule for UseButtonType {
                 }
                 let type_attribute = element.find_attribute_by_name("type").ok()?;
                 let Some(attribute) = type_attribute else {
+                    if !strict && has_spread_attribute(&element.attributes()) {
+                        return None;
+                    }
                     return Some(UseButtonTypeState {
                         range: element.range(),
                         missing_prop: true,
@@ -125,6 +216,16 @@ impl Rule for UseButtonType {
                             }
                         }
 
+                        // the spread members can provide the property "type"
+                        if !strict
+                            && props
+                                .members()
+                                .iter()
+                                .any(|member| matches!(member, Ok(AnyJsObjectMember::JsSpread(_))))
+                        {
+                            return None;
+                        }
+
                         // if we are here, it means that we haven't found the property "type" and
                         // we have to return a diagnostic
                         Some(UseButtonTypeState {
@@ -169,6 +270,43 @@ impl Rule for UseButtonType {
             }
         ))
     }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        if !state.missing_prop {
+            return None;
+        }
+        let element = match ctx.query() {
+            UseButtonTypeQuery::JsxSelfClosingElement(element) => {
+                AnyJsxElement::from(element.clone())
+            }
+            UseButtonTypeQuery::JsxOpeningElement(element) => AnyJsxElement::from(element.clone()),
+            UseButtonTypeQuery::JsCallExpression(_) => return None,
+        };
+        let type_attribute = make::jsx_attribute(AnyJsxAttributeName::JsxName(make::jsx_name(
+            make::jsx_ident("type").with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        )))
+        .with_initializer(make::jsx_attribute_initializer_clause(
+            make::token(T![=]),
+            AnyJsxAttributeValue::JsxString(make::jsx_string(make::jsx_string_literal("button"))),
+        ))
+        .build();
+        let mut mutation = ctx.root().begin();
+        append_jsx_attribute(&mut mutation, &element, type_attribute)?;
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Add the attribute "<Emphasis>"type=\"button\""</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `attributes` contains a spread attribute, which can provide the `type`.
+fn has_spread_attribute(attributes: &JsxAttributeList) -> bool {
+    attributes
+        .iter()
+        .any(|attribute| matches!(attribute, AnyJsxAttribute::JsxSpreadAttribute(_)))
 }
 
 fn inspect_jsx_type_attribute(attribute: JsxAttribute) -> Option<UseButtonTypeState> {
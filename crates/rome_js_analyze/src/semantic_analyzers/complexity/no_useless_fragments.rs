@@ -1,16 +1,26 @@
 use crate::react::{jsx_member_name_is_react_fragment, jsx_reference_identifier_is_fragment};
 use crate::semantic_services::Semantic;
 use crate::JsRuleAction;
+use bpaf::Bpaf;
 use rome_analyze::context::RuleContext;
 use rome_analyze::{declare_rule, ActionCategory, Rule, RuleDiagnostic};
 use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
 use rome_diagnostics::Applicability;
-use rome_js_factory::make::{ident, js_expression_statement, jsx_string, jsx_tag_expression};
+use rome_js_factory::make::{
+    ident, js_expression_statement, jsx_child_list, jsx_string, jsx_tag_expression,
+};
 use rome_js_syntax::{
     AnyJsxChild, AnyJsxElementName, AnyJsxTag, JsLanguage, JsParenthesizedExpression, JsSyntaxKind,
-    JsxChildList, JsxElement, JsxFragment, JsxTagExpression,
+    JsxChildList, JsxElement, JsxFragment, JsxTagExpression, TextRange,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{
+    declare_node_union, AstNode, AstNodeList, BatchMutation, BatchMutationExt, SyntaxNode,
 };
-use rome_rowan::{declare_node_union, AstNode, AstNodeList, BatchMutation, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 declare_rule! {
     /// Disallow unnecessary fragments
@@ -41,6 +51,40 @@ declare_rule! {
     /// ```jsx,expect_diagnostic
     /// <></>
     /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <div>
+    ///     <>
+    ///         <Header />
+    ///         <Content />
+    ///     </>
+    /// </div>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <>
+    ///     <Header />
+    ///     <Content />
+    /// </>
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `allowExpressions` to allow the fragments that only contain an expression,
+    /// such as `<>{children}</>`.
+    /// Its default value is `false`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowExpressions": true
+    ///     }
+    /// }
+    /// ```
+    ///
     pub(crate) NoUselessFragments {
         version: "1.0.0",
         name: "noUselessFragments",
@@ -48,10 +92,62 @@ declare_rule! {
     }
 }
 
+/// Options for the rule `noUselessFragments`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UselessFragmentsOptions {
+    /// Whether the fragments that only contain an expression are allowed.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub allow_expressions: bool,
+}
+
+impl UselessFragmentsOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["allowExpressions"];
+}
+
+// Required by [Bpaf].
+impl FromStr for UselessFragmentsOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for UselessFragmentsOptions {}
+impl VisitNode<JsonLanguage> for UselessFragmentsOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "allowExpressions" {
+            self.allow_expressions = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
 #[derive(Debug)]
 pub(crate) enum NoUselessFragmentsState {
     Empty,
     Child(AnyJsxChild),
+    /// The fragment has several children, and is itself the child of another element.
+    Children,
 }
 
 declare_node_union! {
@@ -91,19 +187,52 @@ impl NoUselessFragmentsQuery {
             NoUselessFragmentsQuery::JsxElement(element) => element.children(),
         }
     }
+
+    /// Returns the range of the opening fragment.
+    fn opening_range(&self) -> Option<TextRange> {
+        match self {
+            NoUselessFragmentsQuery::JsxFragment(fragment) => {
+                Some(fragment.opening_fragment().ok()?.range())
+            }
+            NoUselessFragmentsQuery::JsxElement(element) => {
+                Some(element.opening_element().ok()?.range())
+            }
+        }
+    }
+
+    fn state(&self, options: &UselessFragmentsOptions) -> Option<NoUselessFragmentsState> {
+        let child_list = self.children();
+        match child_list.first() {
+            Some(AnyJsxChild::JsxExpressionChild(_))
+                if child_list.len() == 1 && options.allow_expressions =>
+            {
+                None
+            }
+            Some(first) if child_list.len() == 1 => Some(NoUselessFragmentsState::Child(first)),
+            None => Some(NoUselessFragmentsState::Empty),
+            Some(_) => {
+                let is_in_list = self
+                    .syntax()
+                    .parent()
+                    .is_some_and(|parent| JsxChildList::can_cast(parent.kind()));
+                is_in_list.then_some(NoUselessFragmentsState::Children)
+            }
+        }
+    }
 }
 
 impl Rule for NoUselessFragments {
     type Query = Semantic<NoUselessFragmentsQuery>;
     type State = NoUselessFragmentsState;
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = UselessFragmentsOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
         let model = ctx.model();
+        let options = ctx.options();
         match node {
-            NoUselessFragmentsQuery::JsxFragment(fragment) => {
+            NoUselessFragmentsQuery::JsxFragment(_) => {
                 let parents_where_fragments_must_be_preserved = node
                     .syntax()
                     .parent()
@@ -137,16 +266,8 @@ impl Rule for NoUselessFragments {
                     })
                     .unwrap_or(false);
 
-                let child_list = fragment.children();
-
                 if !parents_where_fragments_must_be_preserved {
-                    match child_list.first() {
-                        Some(first) if child_list.len() == 1 => {
-                            Some(NoUselessFragmentsState::Child(first))
-                        }
-                        None => Some(NoUselessFragmentsState::Empty),
-                        _ => None,
-                    }
+                    node.state(options)
                 } else {
                     None
                 }
@@ -166,7 +287,6 @@ impl Rule for NoUselessFragments {
                 };
 
                 if is_valid_react_fragment {
-                    let child_list = element.children();
                     // The `Fragment` component supports only the "key" prop and react emits a warning for not supported props.
                     // We assume that the user knows - and fixed - that and only care about the prop that is actually supported.
                     let attribute_key =
@@ -185,13 +305,7 @@ impl Rule for NoUselessFragments {
                                 }
                             });
                     if attribute_key.is_none() {
-                        return match child_list.first() {
-                            Some(first) if child_list.len() == 1 => {
-                                Some(NoUselessFragmentsState::Child(first))
-                            }
-                            None => Some(NoUselessFragmentsState::Empty),
-                            _ => None,
-                        };
+                        return node.state(options);
                     }
                 }
 
@@ -209,15 +323,26 @@ impl Rule for NoUselessFragments {
             .parent()
             .map_or(false, |parent| JsxChildList::can_cast(parent.kind()));
         if is_in_list {
-            let new_child = match state {
-                NoUselessFragmentsState::Empty => None,
-                NoUselessFragmentsState::Child(child) => Some(child.clone()),
-            };
-
-            if let Some(new_child) = new_child {
-                node.replace_node(&mut mutation, new_child);
-            } else {
-                node.remove_node_from_list(&mut mutation);
+            match state {
+                NoUselessFragmentsState::Empty => node.remove_node_from_list(&mut mutation),
+                NoUselessFragmentsState::Child(child) => {
+                    node.replace_node(&mut mutation, child.clone())
+                }
+                NoUselessFragmentsState::Children => {
+                    // Move the children of the fragment into the list of its parent
+                    let list = node.parent::<JsxChildList>()?;
+                    let children: Vec<_> = list
+                        .iter()
+                        .flat_map(|child| {
+                            if child.syntax() == node.syntax() {
+                                node.children().iter().collect()
+                            } else {
+                                vec![child]
+                            }
+                        })
+                        .collect();
+                    mutation.replace_node(list, jsx_child_list(children));
+                }
             }
         } else if let Some(parent) = node.parent::<JsxTagExpression>() {
             let parent = parent.syntax().parent()?;
@@ -276,7 +401,7 @@ impl Rule for NoUselessFragments {
         let node = ctx.query();
         Some(RuleDiagnostic::new(
             rule_category!(),
-            node.syntax().text_trimmed_range(),
+            node.opening_range()?,
             markup! {
                 "Avoid using unnecessary "<Emphasis>"Fragment"</Emphasis>"."
             },
@@ -3,32 +3,78 @@
 use rome_analyze::declare_group;
 
 pub(crate) mod no_accumulating_spread;
+pub(crate) mod no_alert;
+pub(crate) mod no_array_constructor;
 pub(crate) mod no_banned_types;
+pub(crate) mod no_console;
 pub(crate) mod no_constant_condition;
+pub(crate) mod no_document_cookie;
+pub(crate) mod no_document_write;
+pub(crate) mod no_global_eval;
 pub(crate) mod no_global_is_finite;
 pub(crate) mod no_global_is_nan;
+pub(crate) mod no_head_import_in_document;
+pub(crate) mod no_import_type_as_value;
+pub(crate) mod no_new_native_non_constructor;
+pub(crate) mod no_process_env;
+pub(crate) mod no_throw_literal;
 pub(crate) mod no_unsafe_declaration_merging;
+pub(crate) mod no_useless_string_raw;
 pub(crate) mod no_useless_this_alias;
+pub(crate) mod use_consistent_builtin_instantiation;
+pub(crate) mod use_date_now;
+pub(crate) mod use_error_cause;
 pub(crate) mod use_exhaustive_dependencies;
+pub(crate) mod use_exports_last;
 pub(crate) mod use_hook_at_top_level;
+pub(crate) mod use_import_type;
 pub(crate) mod use_is_array;
 pub(crate) mod use_naming_convention;
+pub(crate) mod use_node_assert_strict;
+pub(crate) mod use_node_prefix_for_builtins;
+pub(crate) mod use_number_namespace;
+pub(crate) mod use_object_spread;
+pub(crate) mod use_regex_literals;
+pub(crate) mod use_throw_new_error;
 
 declare_group! {
     pub (crate) Nursery {
         name : "nursery" ,
         rules : [
             self :: no_accumulating_spread :: NoAccumulatingSpread ,
+            self :: no_alert :: NoAlert ,
+            self :: no_array_constructor :: NoArrayConstructor ,
             self :: no_banned_types :: NoBannedTypes ,
+            self :: no_console :: NoConsole ,
             self :: no_constant_condition :: NoConstantCondition ,
+            self :: no_document_cookie :: NoDocumentCookie ,
+            self :: no_document_write :: NoDocumentWrite ,
+            self :: no_global_eval :: NoGlobalEval ,
             self :: no_global_is_finite :: NoGlobalIsFinite ,
             self :: no_global_is_nan :: NoGlobalIsNan ,
+            self :: no_head_import_in_document :: NoHeadImportInDocument ,
+            self :: no_import_type_as_value :: NoImportTypeAsValue ,
+            self :: no_new_native_non_constructor :: NoNewNativeNonConstructor ,
+            self :: no_process_env :: NoProcessEnv ,
+            self :: no_throw_literal :: NoThrowLiteral ,
             self :: no_unsafe_declaration_merging :: NoUnsafeDeclarationMerging ,
+            self :: no_useless_string_raw :: NoUselessStringRaw ,
             self :: no_useless_this_alias :: NoUselessThisAlias ,
+            self :: use_consistent_builtin_instantiation :: UseConsistentBuiltinInstantiation ,
+            self :: use_date_now :: UseDateNow ,
+            self :: use_error_cause :: UseErrorCause ,
             self :: use_exhaustive_dependencies :: UseExhaustiveDependencies ,
+            self :: use_exports_last :: UseExportsLast ,
             self :: use_hook_at_top_level :: UseHookAtTopLevel ,
+            self :: use_import_type :: UseImportType ,
             self :: use_is_array :: UseIsArray ,
             self :: use_naming_convention :: UseNamingConvention ,
+            self :: use_node_assert_strict :: UseNodeAssertStrict ,
+            self :: use_node_prefix_for_builtins :: UseNodePrefixForBuiltins ,
+            self :: use_number_namespace :: UseNumberNamespace ,
+            self :: use_object_spread :: UseObjectSpread ,
+            self :: use_regex_literals :: UseRegexLiterals ,
+            self :: use_throw_new_error :: UseThrowNewError ,
         ]
      }
 }
@@ -2,10 +2,12 @@ use rome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
 use rome_console::markup;
 use rome_js_semantic::SemanticModel;
 use rome_js_syntax::{
-    AnyJsFunction, AnyJsMemberExpression, JsCallArgumentList, JsCallArguments, JsCallExpression,
-    JsFormalParameter, JsParameterList, JsParameters, JsSpread,
+    AnyFunctionLike, AnyJsFunction, AnyJsMemberExpression, JsArrayExpression, JsCallArgumentList,
+    JsCallArguments, JsCallExpression, JsConditionalExpression, JsFormalParameter,
+    JsLogicalExpression, JsObjectExpression, JsParameterList, JsParameters,
+    JsParenthesizedExpression, JsReturnStatement, JsSpread,
 };
-use rome_rowan::AstNode;
+use rome_rowan::{AstNode, AstSeparatedList};
 
 use crate::semantic_services::Semantic;
 
@@ -17,6 +19,9 @@ declare_rule! {
     /// Spread syntax should be avoided on accumulators (like those in `.reduce`)
     /// because it causes a time complexity of `O(n^2)` instead of `O(n)`.
     ///
+    /// The rule reports the spread of the accumulator in an array or object literal
+    /// returned by the callback of `.reduce` or `.reduceRight`.
+    ///
     /// Source: https://prateeksurana.me/blog/why-using-object-spread-with-reduce-bad-idea/
     ///
     /// ## Examples
@@ -45,6 +50,11 @@ declare_rule! {
     /// a.reduce((acc, val) => {acc.push(val); return acc}, []);
     /// ```
     ///
+    /// ```js
+    /// var a = ['a', 'b', 'c'];
+    /// a.reduce((acc, val) => Object.assign(acc, {[val]: val}), {});
+    /// ```
+    ///
     pub(crate) NoAccumulatingSpread {
         version: "1.0.0",
         name: "noAccumulatingSpread",
@@ -77,6 +87,9 @@ impl Rule for NoAccumulatingSpread {
             )
             .note(markup! {
                 "Spread syntax should be avoided on accumulators (like those in `.reduce`) because it causes a time complexity of `O(n^2)`."
+            })
+            .note(markup! {
+                "Consider mutating the accumulator instead, for example with "<Emphasis>"push"</Emphasis>" or "<Emphasis>"Object.assign"</Emphasis>"."
             }),
         )
     }
@@ -97,9 +110,16 @@ fn is_known_accumulator(node: &JsSpread, model: &SemanticModel) -> Option<bool>
         .parent::<JsParameterList>()
         .and_then(|list| list.parent::<JsParameters>())
         .and_then(|parameters| parameters.parent::<AnyJsFunction>())?;
-    let call_expression = function
-        .parent::<JsCallArgumentList>()
-        .and_then(|arguments| arguments.parent::<JsCallArguments>())
+    if !is_returned_by(node, &function) {
+        return None;
+    }
+    let argument_list = function.parent::<JsCallArgumentList>()?;
+    // The callback is the first argument of `reduce`
+    if argument_list.first()?.ok()?.syntax() != function.syntax() {
+        return None;
+    }
+    let call_expression = argument_list
+        .parent::<JsCallArguments>()
         .and_then(|arguments| arguments.parent::<JsCallExpression>())?;
     let callee = call_expression.callee().ok()?;
     let member_expression = AnyJsMemberExpression::cast_ref(callee.syntax())?;
@@ -109,3 +129,46 @@ fn is_known_accumulator(node: &JsSpread, model: &SemanticModel) -> Option<bool>
     }
     Some(parameter.syntax().index() == 0)
 }
+
+/// Returns `true` if `node` is an element of an array or object literal returned by `function`.
+///
+/// The literal can be wrapped in parentheses, in a branch of a conditional expression,
+/// or in an operand of a logical expression.
+fn is_returned_by(node: &JsSpread, function: &AnyJsFunction) -> bool {
+    let Some(mut literal) = node.syntax().grand_parent() else {
+        return false;
+    };
+    if !JsArrayExpression::can_cast(literal.kind()) && !JsObjectExpression::can_cast(literal.kind())
+    {
+        return false;
+    }
+    while let Some(parent) = literal.parent() {
+        if let Some(conditional) = JsConditionalExpression::cast_ref(&parent) {
+            // The test of a conditional expression is not returned
+            if conditional
+                .test()
+                .is_ok_and(|test| test.syntax() == &literal)
+            {
+                return false;
+            }
+        } else if !JsParenthesizedExpression::can_cast(parent.kind())
+            && !JsLogicalExpression::can_cast(parent.kind())
+        {
+            break;
+        }
+        literal = parent;
+    }
+    let Some(parent) = literal.parent() else {
+        return false;
+    };
+    if JsReturnStatement::can_cast(parent.kind()) {
+        // The closest function must be the callback
+        parent
+            .ancestors()
+            .find(|ancestor| AnyFunctionLike::can_cast(ancestor.kind()))
+            .is_some_and(|closest_function| &closest_function == function.syntax())
+    } else {
+        // The expression body of an arrow function
+        &parent == function.syntax()
+    }
+}
@@ -0,0 +1,78 @@
+use crate::semantic_services::Semantic;
+use rome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::{global_identifier, static_value::StaticValue, JsCallExpression};
+use rome_rowan::AstNode;
+
+declare_rule! {
+    /// Disallow the use of `alert`, `confirm`, and `prompt`.
+    ///
+    /// These functions open dialogs that block the page until the user closes them,
+    /// and their appearance cannot be customized.
+    /// A dialog built with HTML provides a better user experience.
+    ///
+    /// The rule reports the calls to the global functions, including `window.alert()` and `globalThis.alert()`.
+    /// The calls to a local function with the same name are ignored.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-alert
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// alert("Saved!");
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const remove = window.confirm("Do you want to remove this item?");
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { alert } from "./dialogs.js";
+    /// alert("Saved!");
+    /// ```
+    ///
+    pub(crate) NoAlert {
+        version: "next",
+        name: "noAlert",
+        recommended: false,
+    }
+}
+
+const DIALOG_FUNCTIONS: [&str; 3] = ["alert", "confirm", "prompt"];
+
+impl Rule for NoAlert {
+    type Query = Semantic<JsCallExpression>;
+    /// The name of the called function.
+    type State = StaticValue;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let callee = ctx.query().callee().ok()?.omit_parentheses();
+        let (reference, name) = global_identifier(&callee)?;
+        if !DIALOG_FUNCTIONS.contains(&name.text()) || ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        Some(name)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        let name = name.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Don't use "<Emphasis>{name}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The dialogs opened by "<Emphasis>{name}</Emphasis>" block the page and cannot be customized. Use a dialog built with HTML instead."
+            }),
+        )
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    global_identifier, AnyJsArrayElement, AnyJsCallArgument, AnyJsExpression, JsCallArguments,
+    JsCallExpression, JsNewExpression, T,
+};
+use rome_rowan::{declare_node_union, AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow `Array` constructors.
+    ///
+    /// Array literals are shorter and clearer than the `Array` constructor.
+    /// Moreover, the `Array` constructor behaves differently depending on its number of arguments:
+    /// with a single numeric argument, it creates a sparse array of that length
+    /// instead of an array that contains the argument.
+    ///
+    /// When the constructor is called with a single numeric argument,
+    /// the rule reports the call without suggesting a fix.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-array-constructor
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const xs = new Array();
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const xs = Array(0, 1, 2);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const xs = new Array(...ys, 0);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const xs = new Array(3);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const xs = [0, 1, 2];
+    /// const ys = new Array(length);
+    /// const zs = Array.from({ length: 3 });
+    /// ```
+    ///
+    pub(crate) NoArrayConstructor {
+        version: "next",
+        name: "noArrayConstructor",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) JsNewOrCallExpression = JsNewExpression | JsCallExpression
+}
+
+impl JsNewOrCallExpression {
+    fn callee(&self) -> Option<AnyJsExpression> {
+        match self {
+            JsNewOrCallExpression::JsNewExpression(expr) => expr.callee().ok(),
+            JsNewOrCallExpression::JsCallExpression(expr) => expr.callee().ok(),
+        }
+    }
+
+    /// Returns the arguments of the call.
+    /// `None` is returned for a `new` expression without parentheses.
+    fn arguments(&self) -> Option<JsCallArguments> {
+        match self {
+            JsNewOrCallExpression::JsNewExpression(expr) => expr.arguments(),
+            JsNewOrCallExpression::JsCallExpression(expr) => expr.arguments().ok(),
+        }
+    }
+
+    fn has_type_arguments(&self) -> bool {
+        match self {
+            JsNewOrCallExpression::JsNewExpression(expr) => expr.type_arguments().is_some(),
+            JsNewOrCallExpression::JsCallExpression(expr) => expr.type_arguments().is_some(),
+        }
+    }
+
+    fn is_optional(&self) -> bool {
+        match self {
+            JsNewOrCallExpression::JsNewExpression(_) => false,
+            JsNewOrCallExpression::JsCallExpression(expr) => expr.is_optional_chain(),
+        }
+    }
+}
+
+impl From<JsNewOrCallExpression> for AnyJsExpression {
+    fn from(expr: JsNewOrCallExpression) -> Self {
+        match expr {
+            JsNewOrCallExpression::JsNewExpression(expr) => expr.into(),
+            JsNewOrCallExpression::JsCallExpression(expr) => expr.into(),
+        }
+    }
+}
+
+pub(crate) enum ArrayConstructorIssue {
+    /// The call can be replaced by an array literal.
+    ReplaceableByLiteral,
+    /// The call has a single numeric argument and creates a sparse array.
+    SparseArray,
+}
+
+impl Rule for NoArrayConstructor {
+    type Query = Semantic<JsNewOrCallExpression>;
+    type State = ArrayConstructorIssue;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        // A type argument such as in `new Array<number>()` would be lost by the fix.
+        if node.has_type_arguments() || node.is_optional() {
+            return None;
+        }
+        let callee = node.callee()?.omit_parentheses();
+        let (reference, name) = global_identifier(&callee)?;
+        if name.text() != "Array" || ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        let Some(arguments) = node.arguments() else {
+            return Some(ArrayConstructorIssue::ReplaceableByLiteral);
+        };
+        let args = arguments.args();
+        if args.len() != 1 {
+            return Some(ArrayConstructorIssue::ReplaceableByLiteral);
+        }
+        match args.first()?.ok()? {
+            AnyJsCallArgument::AnyJsExpression(AnyJsExpression::AnyJsLiteralExpression(
+                literal,
+            )) if literal.as_js_number_literal_expression().is_some() => {
+                Some(ArrayConstructorIssue::SparseArray)
+            }
+            // The argument may be a length or an element: we cannot know.
+            _ => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            ArrayConstructorIssue::ReplaceableByLiteral => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Use an array literal instead of the "<Emphasis>"Array"</Emphasis>" constructor."
+                },
+            ),
+            ArrayConstructorIssue::SparseArray => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "The "<Emphasis>"Array"</Emphasis>" constructor creates a sparse array when it is called with a single number."
+                },
+            )
+            .note(markup! {
+                "Use "<Emphasis>"Array.from({ length })"</Emphasis>" or an array literal instead."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        if matches!(state, ArrayConstructorIssue::SparseArray) {
+            return None;
+        }
+        let node = ctx.query();
+        let (l_brack, elements, r_brack) = match node.arguments() {
+            Some(arguments) => {
+                let args = arguments.args();
+                let mut items = Vec::with_capacity(args.len());
+                let mut separators = Vec::with_capacity(args.len());
+                for element in args.elements() {
+                    items.push(match element.node().ok()?.clone() {
+                        AnyJsCallArgument::AnyJsExpression(expr) => {
+                            AnyJsArrayElement::AnyJsExpression(expr)
+                        }
+                        AnyJsCallArgument::JsSpread(spread) => AnyJsArrayElement::JsSpread(spread),
+                    });
+                    if let Some(separator) = element.trailing_separator().ok()? {
+                        separators.push(separator.clone());
+                    }
+                }
+                let l_paren = arguments.l_paren_token().ok()?;
+                let r_paren = arguments.r_paren_token().ok()?;
+                (
+                    make::token(T!['['])
+                        .with_leading_trivia_pieces(l_paren.leading_trivia().pieces())
+                        .with_trailing_trivia_pieces(l_paren.trailing_trivia().pieces()),
+                    make::js_array_element_list(items, separators),
+                    make::token(T![']'])
+                        .with_leading_trivia_pieces(r_paren.leading_trivia().pieces())
+                        .with_trailing_trivia_pieces(r_paren.trailing_trivia().pieces()),
+                )
+            }
+            None => (
+                make::token(T!['[']),
+                make::js_array_element_list([], []),
+                make::token(T![']']),
+            ),
+        };
+        let array = make::js_array_expression(l_brack, elements, r_brack);
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node::<AnyJsExpression>(node.clone().into(), array.into());
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use an array literal." }.to_owned(),
+            mutation,
+        })
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use bpaf::Bpaf;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_syntax::{
+    global_identifier, AnyJsMemberExpression, JsCallExpression, JsExpressionStatement,
+    JsModuleItemList, JsStatementList,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, BatchMutationExt, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow the use of `console`.
+    ///
+    /// In a browser environment, the messages written with `console` are usually debugging leftovers
+    /// that should not ship to production.
+    ///
+    /// The code fix removes the call when its result is unused.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-console
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// console.error('hello world')
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const console = { log() {} };
+    /// console.log();
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `allow` to allow some methods of `console`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allow": ["error", "warn"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoConsole {
+        version: "next",
+        name: "noConsole",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noConsole`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConsoleOptions {
+    /// The methods of `console` that can be called.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide, argument::<String>("NAME"), many, optional)]
+    pub allow: Option<Vec<String>>,
+}
+
+impl ConsoleOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["allow"];
+
+    fn is_allowed(&self, method: &str) -> bool {
+        self.allow
+            .as_ref()
+            .is_some_and(|allow| allow.iter().any(|allowed| allowed == method))
+    }
+}
+
+// Required by [Bpaf].
+impl FromStr for ConsoleOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ConsoleOptions {}
+impl VisitNode<JsonLanguage> for ConsoleOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "allow" {
+            self.allow = self.map_to_array_of_strings(&value, name_text, diagnostics);
+        }
+        Some(())
+    }
+}
+
+impl Rule for NoConsole {
+    type Query = Semantic<JsCallExpression>;
+    /// The callee `console.method`.
+    type State = AnyJsMemberExpression;
+    type Signals = Option<Self::State>;
+    type Options = ConsoleOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call_expression = ctx.query();
+        let callee = call_expression.callee().ok()?.omit_parentheses();
+        let member_expression = AnyJsMemberExpression::cast_ref(callee.syntax())?;
+        if ctx
+            .options()
+            .is_allowed(member_expression.member_name()?.text())
+        {
+            return None;
+        }
+        let object = member_expression.object().ok()?.omit_parentheses();
+        let (reference, name) = global_identifier(&object)?;
+        if name.text() != "console" || ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        Some(member_expression)
+    }
+
+    fn diagnostic(
+        _: &RuleContext<Self>,
+        member_expression: &Self::State,
+    ) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                member_expression.range(),
+                markup! {
+                    "Don't use "<Emphasis>"console"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The calls to "<Emphasis>"console"</Emphasis>" are usually debugging leftovers."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        // Only remove the calls whose result is unused,
+        // and that can be removed without leaving an empty body, such as in `if (a) console.log(a)`.
+        let statement = ctx.query().parent::<JsExpressionStatement>()?;
+        let parent = statement.syntax().parent()?;
+        if !JsStatementList::can_cast(parent.kind()) && !JsModuleItemList::can_cast(parent.kind()) {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        // Keep the comments attached to the call by moving them to the next token
+        let leading_trivia = statement.syntax().first_leading_trivia()?;
+        if leading_trivia.pieces().any(|piece| piece.is_comments()) {
+            let next_token = statement.syntax().last_token()?.next_token()?;
+            let trivia = leading_trivia
+                .pieces()
+                .chain(
+                    next_token
+                        .leading_trivia()
+                        .pieces()
+                        .skip_while(|piece| piece.is_newline() || piece.is_whitespace()),
+                )
+                .collect::<Vec<_>>();
+            mutation.replace_token_discard_trivia(
+                next_token.clone(),
+                next_token.with_leading_trivia_pieces(trivia),
+            );
+        }
+        mutation.remove_node(statement);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Remove "<Emphasis>"console"</Emphasis>"." }.to_owned(),
+            mutation,
+        })
+    }
+}
@@ -1,14 +1,21 @@
 use crate::{semantic_services::Semantic, utils::rename::RenamableNode};
+use bpaf::Bpaf;
 use rome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
 use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
 use rome_js_semantic::SemanticModel;
 use rome_js_syntax::{
-    AnyJsArrayElement, AnyJsExpression, AnyJsLiteralExpression, AnyJsStatement,
-    AnyJsTemplateElement, JsAssignmentOperator, JsConditionalExpression, JsDoWhileStatement,
-    JsForStatement, JsFunctionDeclaration, JsFunctionExpression, JsIfStatement, JsLogicalOperator,
-    JsStatementList, JsSyntaxKind, JsUnaryOperator, JsWhileStatement, JsYieldExpression, TextRange,
+    numbers::parse_js_number, AnyJsArrayElement, AnyJsExpression, AnyJsLiteralExpression,
+    AnyJsStatement, AnyJsTemplateElement, JsAssignmentOperator, JsConditionalExpression,
+    JsDoWhileStatement, JsForStatement, JsFunctionDeclaration, JsFunctionExpression, JsIfStatement,
+    JsLogicalOperator, JsStatementList, JsUnaryOperator, JsWhileStatement, JsYieldExpression,
+    TextRange,
 };
-use rome_rowan::{declare_node_union, AstNode, AstSeparatedList};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{declare_node_union, AstNode, AstSeparatedList, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 declare_rule! {
     /// Disallow constant expressions in conditions
@@ -36,18 +43,6 @@ declare_rule! {
     /// ```
     ///
     /// ```js,expect_diagnostic
-    /// for (;-2;) {
-    ///     doSomethingForever();
-    /// }
-    /// ```
-    ///
-    /// ```js,expect_diagnostic
-    /// while (typeof x) {
-    ///     doSomethingForever();
-    /// }
-    /// ```
-    ///
-    /// ```js,expect_diagnostic
     /// var result = 0 ? a : b;
     /// ```
     ///
@@ -72,13 +67,28 @@ declare_rule! {
     ///
     /// var result = x !== 0 ? a : b;
     ///
-    /// // Exception
     /// while (true) {
     ///     if (x) { break; }
     ///     x = f();
     /// }
     /// ```
     ///
+    /// ## Options
+    ///
+    /// By default, constant conditions of `while`, `do...while`, and `for` loops are allowed,
+    /// because loops such as `while (true)` are usually exited with `break` or `return`.
+    /// Set the option `allowConstantLoopConditions` to `false` to report them too.
+    /// Loops of generator functions that yield are always allowed.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowConstantLoopConditions": false
+    ///     }
+    /// }
+    /// ```
+    ///
     pub(crate) NoConstantCondition    {
         version: "1.0.0",
         name: "noConstantCondition",
@@ -90,16 +100,95 @@ declare_node_union! {
     pub(crate) ConditionalStatement = JsConditionalExpression | JsWhileStatement | JsDoWhileStatement | JsIfStatement | JsForStatement
 }
 
+/// Options for the rule `noConstantCondition`.
+#[derive(Deserialize, Serialize, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConstantConditionOptions {
+    /// Whether the conditions of `while`, `do...while`, and `for` loops may be constant.
+    #[bpaf(hide)]
+    pub allow_constant_loop_conditions: bool,
+}
+
+impl ConstantConditionOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["allowConstantLoopConditions"];
+}
+
+impl Default for ConstantConditionOptions {
+    fn default() -> Self {
+        Self {
+            allow_constant_loop_conditions: true,
+        }
+    }
+}
+
+// Required by [Bpaf].
+impl FromStr for ConstantConditionOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ConstantConditionOptions {}
+impl VisitNode<JsonLanguage> for ConstantConditionOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "allowConstantLoopConditions" {
+            self.allow_constant_loop_conditions =
+                self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
+/// The value that a constant condition always evaluates to.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum ConstantKind {
+    /// The condition is always truthy.
+    Truthy,
+    /// The condition is always falsy.
+    Falsy,
+    /// The condition is constant, but its value is not easily determined.
+    Unknown,
+}
+
+pub(crate) struct ConstantConditionState {
+    /// The range of the constant condition.
+    range: TextRange,
+    kind: ConstantKind,
+}
+
 impl Rule for NoConstantCondition {
     type Query = Semantic<ConditionalStatement>;
-    type State = TextRange;
+    type State = ConstantConditionState;
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = ConstantConditionOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let conditional_stmt = ctx.query();
         let model = ctx.model();
 
+        if ctx.options().allow_constant_loop_conditions && conditional_stmt.is_loop() {
+            return None;
+        }
+
         // We must verify that the conditional statement is within a generator function.
         // If the statement contains a valid yield expression returned from a `while`, `for`, or `do...while` statement,
         // we don't need to examine the statement's `test`.
@@ -112,27 +201,36 @@ impl Rule for NoConstantCondition {
         }
 
         let test = conditional_stmt.test()?;
-        // Ignore `while (true) { ... }`
-        if matches!(conditional_stmt, ConditionalStatement::JsWhileStatement(_))
-            && test
-                .as_any_js_literal_expression()
-                .and_then(|test| test.as_js_boolean_literal_expression())
-                .and_then(|test| Some(test.value_token().ok()?.kind() == JsSyntaxKind::TRUE_KW))
-                .unwrap_or_default()
-        {
-            return None;
-        }
-        is_constant_condition(&test, true, model).map(|_| Some(test.range()))?
+        is_constant_condition(&test, true, model)?;
+        let kind = match get_constant_value(&test) {
+            Some(true) => ConstantKind::Truthy,
+            Some(false) => ConstantKind::Falsy,
+            None => ConstantKind::Unknown,
+        };
+        Some(ConstantConditionState {
+            range: test.range(),
+            kind,
+        })
     }
 
     fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
-        Some(RuleDiagnostic::new(
+        let diagnostic = RuleDiagnostic::new(
             rule_category!(),
-            state,
+            state.range,
             markup! {
                 "Unexpected constant condition."
             },
-        ))
+        );
+        let diagnostic = match state.kind {
+            ConstantKind::Truthy => diagnostic.note(markup! {
+                "This condition is always "<Emphasis>"truthy"</Emphasis>"."
+            }),
+            ConstantKind::Falsy => diagnostic.note(markup! {
+                "This condition is always "<Emphasis>"falsy"</Emphasis>"."
+            }),
+            ConstantKind::Unknown => diagnostic,
+        };
+        Some(diagnostic)
     }
 }
 
@@ -146,6 +244,12 @@ impl ConditionalStatement {
             Self::JsForStatement(it) => it.test(),
         }
     }
+    fn is_loop(&self) -> bool {
+        matches!(
+            self,
+            Self::JsWhileStatement(_) | Self::JsDoWhileStatement(_) | Self::JsForStatement(_)
+        )
+    }
     fn body(&self) -> Option<AnyJsStatement> {
         match self {
             Self::JsWhileStatement(it) => it.body().ok(),
@@ -393,6 +497,55 @@ fn is_constant_condition(
     }
 }
 
+/// Returns the boolean value of a constant condition, if it can be easily determined.
+fn get_constant_value(test: &AnyJsExpression) -> Option<bool> {
+    use AnyJsExpression::*;
+
+    match test.clone().omit_parentheses() {
+        AnyJsLiteralExpression(node) => Some(get_boolean_value(node)),
+        JsObjectExpression(_)
+        | JsArrayExpression(_)
+        | JsFunctionExpression(_)
+        | JsArrowFunctionExpression(_)
+        | JsClassExpression(_)
+        | JsNewExpression(_) => Some(true),
+        JsUnaryExpression(node) => match node.operator().ok()? {
+            JsUnaryOperator::Void => Some(false),
+            JsUnaryOperator::Typeof => Some(true),
+            JsUnaryOperator::LogicalNot => {
+                get_constant_value(&node.argument().ok()?).map(|value| !value)
+            }
+            _ => None,
+        },
+        JsSequenceExpression(node) => get_constant_value(&node.right().ok()?),
+        JsAssignmentExpression(node) => {
+            if node.operator().ok()? != JsAssignmentOperator::Assign {
+                return None;
+            }
+            get_constant_value(&node.right().ok()?)
+        }
+        JsIdentifierExpression(node) => node.name().ok()?.is_undefined().then_some(false),
+        JsCallExpression(node) => {
+            // Only `Boolean(...)` calls are constant conditions
+            match node.arguments().ok()?.args().first() {
+                Some(argument) => get_constant_value(argument.ok()?.as_any_js_expression()?),
+                None => Some(false),
+            }
+        }
+        JsTemplateExpression(node) => node
+            .elements()
+            .into_iter()
+            .any(|element| {
+                element
+                    .as_js_template_chunk_element()
+                    .and_then(|chunk| chunk.template_chunk_token().ok())
+                    .is_some_and(|chunk| !chunk.text_trimmed().is_empty())
+            })
+            .then_some(true),
+        _ => None,
+    }
+}
+
 fn is_logical_identity(node: AnyJsExpression, operator: JsLogicalOperator) -> bool {
     use AnyJsExpression::*;
     use JsLogicalOperator::*;
@@ -469,6 +622,20 @@ fn get_boolean_value(node: AnyJsLiteralExpression) -> bool {
     use AnyJsLiteralExpression::*;
     match node {
         JsRegexLiteralExpression(_) => true,
+        JsNumberLiteralExpression(node) => node
+            .value_token()
+            .ok()
+            .and_then(|token| parse_js_number(token.text_trimmed()))
+            .map_or(false, |value| value != 0.0),
+        // `0b0n`, `0o0n`, and `0x0n` are also falsy
+        JsBigintLiteralExpression(node) => node.value_token().map_or(false, |token| {
+            let text = token.text_trimmed().trim_end_matches('n');
+            let digits = match text.get(..2) {
+                Some("0b" | "0B" | "0o" | "0O" | "0x" | "0X") => &text[2..],
+                _ => text,
+            };
+            digits.chars().any(|c| c != '0' && c != '_')
+        }),
         _ => node
             .as_static_value()
             .map_or(false, |value| !value.is_falsy()),
@@ -506,6 +673,10 @@ mod tests {
         assert_boolean_value("0", false);
         assert_boolean_value("-0", false);
         assert_boolean_value("0n", false);
+        assert_boolean_value("0.0", false);
+        assert_boolean_value("0x0", false);
+        assert_boolean_value("0b0n", false);
+        assert_boolean_value("0x0_0n", false);
         assert_boolean_value("let any_js_stmt =\"\"", false);
         assert_boolean_value("let any_js_stmt = ''", false);
         assert_boolean_value("null", false);
@@ -515,6 +686,7 @@ mod tests {
         assert_boolean_value("let any_js_stmt = \"false\"", true);
         assert_boolean_value("-42", true);
         assert_boolean_value("12n", true);
+        assert_boolean_value("0x1n", true);
         assert_boolean_value("3.14", true);
         assert_boolean_value("-3.14", true);
     }
@@ -0,0 +1,96 @@
+use crate::semantic_services::Semantic;
+use rome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::{global_identifier, AnyJsAssignment, AnyJsExpression, JsAssignmentExpression};
+use rome_rowan::AstNode;
+
+declare_rule! {
+    /// Disallow direct assignments to `document.cookie`.
+    ///
+    /// Writing cookies by building the `document.cookie` string by hand is error-prone:
+    /// the attributes of a cookie and the encoding of its value are easy to get wrong.
+    /// The [Cookie Store API](https://developer.mozilla.org/en-US/docs/Web/API/Cookie_Store_API)
+    /// or a dedicated library provide a safer interface.
+    ///
+    /// The rule only reports the writes to the global `document.cookie`.
+    /// Reading `document.cookie` is allowed.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// document.cookie = "theme=dark";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// document.cookie += "; max-age=3600";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const cookies = document.cookie;
+    /// ```
+    ///
+    /// ```js
+    /// await cookieStore.set({ name: "theme", value: "dark" });
+    /// ```
+    ///
+    pub(crate) NoDocumentCookie {
+        version: "next",
+        name: "noDocumentCookie",
+        recommended: false,
+    }
+}
+
+impl Rule for NoDocumentCookie {
+    type Query = Semantic<JsAssignmentExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let left = ctx.query().left().ok()?;
+        let object = match left.as_any_js_assignment()? {
+            AnyJsAssignment::JsStaticMemberAssignment(assignment) => {
+                let member = assignment.member().ok()?;
+                if member.as_js_name()?.value_token().ok()?.text_trimmed() != "cookie" {
+                    return None;
+                }
+                assignment.object().ok()?
+            }
+            AnyJsAssignment::JsComputedMemberAssignment(assignment) => {
+                let member = assignment.member().ok()?.omit_parentheses();
+                if member.as_static_value()?.as_string_constant()? != "cookie" {
+                    return None;
+                }
+                assignment.object().ok()?
+            }
+            _ => return None,
+        };
+        is_global_document(ctx, &object.omit_parentheses()).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Don't assign to "<Emphasis>"document.cookie"</Emphasis>" directly."
+                },
+            )
+            .note(markup! {
+                "Use the "<Emphasis>"Cookie Store API"</Emphasis>" or a cookie library instead."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `object` is the global `document`.
+fn is_global_document(ctx: &RuleContext<NoDocumentCookie>, object: &AnyJsExpression) -> bool {
+    global_identifier(object).is_some_and(|(reference, name)| {
+        name.text() == "document" && ctx.model().binding(&reference).is_none()
+    })
+}
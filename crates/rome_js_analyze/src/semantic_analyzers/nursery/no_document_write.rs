@@ -0,0 +1,86 @@
+use crate::semantic_services::Semantic;
+use rome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::{
+    global_identifier, static_value::StaticValue, AnyJsMemberExpression, JsCallExpression,
+};
+use rome_rowan::AstNode;
+
+declare_rule! {
+    /// Disallow the use of `document.write()` and `document.writeln()`.
+    ///
+    /// `document.write()` blocks the parsing of the page while the written content is loaded,
+    /// and it replaces the whole document when it is called after the page is loaded.
+    /// Its use is strongly discouraged by the HTML specification.
+    /// Use the DOM methods, such as `element.append()`, instead.
+    ///
+    /// The rule only reports the calls on the global `document`, including `window.document.write()`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// document.write("<p>Hello</p>");
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// window.document.writeln("<p>Hello</p>");
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// document.body.append(paragraph);
+    /// ```
+    ///
+    /// ```js
+    /// function print(document) {
+    ///     document.write("Hello");
+    /// }
+    /// ```
+    ///
+    pub(crate) NoDocumentWrite {
+        version: "next",
+        name: "noDocumentWrite",
+        recommended: false,
+    }
+}
+
+impl Rule for NoDocumentWrite {
+    type Query = Semantic<JsCallExpression>;
+    /// The name of the called method.
+    type State = StaticValue;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let callee = ctx.query().callee().ok()?.omit_parentheses();
+        let callee = AnyJsMemberExpression::cast_ref(callee.syntax())?;
+        let method = callee.member_name()?;
+        if !matches!(method.text(), "write" | "writeln") {
+            return None;
+        }
+        let (reference, name) = global_identifier(&callee.object().ok()?.omit_parentheses())?;
+        if name.text() != "document" || ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        Some(method)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, method: &Self::State) -> Option<RuleDiagnostic> {
+        let method = method.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Don't use "<Emphasis>"document."{method}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                <Emphasis>"document."{method}</Emphasis>" blocks the parsing of the page, and replaces the document when it is called after the page is loaded. Use the DOM methods instead."
+            }),
+        )
+    }
+}
@@ -0,0 +1,110 @@
+use crate::semantic_services::Semantic;
+use rome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::{global_identifier, AnyJsExpression, JsCallExpression, JsNewExpression};
+use rome_rowan::{declare_node_union, AstNode};
+
+declare_rule! {
+    /// Disallow the use of global `eval()` and of the `Function` constructor.
+    ///
+    /// `eval()` and `Function()` run a string as code.
+    /// When the string contains user input, an attacker can run arbitrary code in the page.
+    /// They also prevent many optimizations of the JavaScript engines.
+    ///
+    /// The rule reports the calls to the global `eval`, including `window.eval()` and `globalThis.eval()`,
+    /// and the calls to the global `Function`, with or without `new`.
+    /// The calls to a local function with the same name are ignored.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-eval
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// eval("var a = 0");
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// window.eval("var a = 0");
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const add = new Function("a", "b", "return a + b");
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { Function } from "./sandbox.js";
+    /// const add = new Function("a", "b", "return a + b");
+    /// ```
+    ///
+    pub(crate) NoGlobalEval {
+        version: "next",
+        name: "noGlobalEval",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyEvalCall = JsCallExpression | JsNewExpression
+}
+
+/// The function that runs code.
+pub(crate) enum EvalForm {
+    /// `eval(...)`
+    Eval,
+    /// `Function(...)` or `new Function(...)`
+    FunctionConstructor,
+}
+
+impl Rule for NoGlobalEval {
+    type Query = Semantic<AnyEvalCall>;
+    type State = EvalForm;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let (callee, is_new) = match ctx.query() {
+            AnyEvalCall::JsCallExpression(call) => (call.callee().ok()?, false),
+            AnyEvalCall::JsNewExpression(call) => (call.callee().ok()?, true),
+        };
+        let (reference, name) = global_identifier(&AnyJsExpression::omit_parentheses(callee))?;
+        let form = match name.text() {
+            "eval" if !is_new => EvalForm::Eval,
+            "Function" => EvalForm::FunctionConstructor,
+            _ => return None,
+        };
+        if ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        Some(form)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, form: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match form {
+            EvalForm::Eval => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Don't use "<Emphasis>"eval"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                <Emphasis>"eval"</Emphasis>" runs a string as code, which can be exploited to run arbitrary code."
+            }),
+            EvalForm::FunctionConstructor => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Don't use the "<Emphasis>"Function"</Emphasis>" constructor."
+                },
+            )
+            .note(markup! {
+                "The "<Emphasis>"Function"</Emphasis>" constructor runs a string as code, which can be exploited to run arbitrary code."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,120 @@
+use crate::semantic_services::Semantic;
+use rome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_js_syntax::{jsx_ext::AnyJsxElement, JsImport, JsImportDefaultClause};
+use rome_rowan::{AstNode, AstNodeList};
+
+declare_rule! {
+    /// Prevent the use of `next/head` in a module that defines a custom Next.js document.
+    ///
+    /// The `Head` component of `next/head` manages the `<head>` of a page.
+    /// In `pages/_document`, the `Head` component of `next/document` must be used instead,
+    /// otherwise the content of the head is lost.
+    ///
+    /// The rule doesn't know the path of the file,
+    /// so it reports the use of the default export of `next/head` in a module that also imports `next/document`.
+    ///
+    /// Source: https://nextjs.org/docs/messages/no-head-import-in-document
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// import Document, { Html, Main, NextScript } from "next/document";
+    /// import Head from "next/head";
+    ///
+    /// export default function MyDocument() {
+    ///     return (
+    ///         <Html>
+    ///             <Head />
+    ///             <body>
+    ///                 <Main />
+    ///                 <NextScript />
+    ///             </body>
+    ///         </Html>
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// import Document, { Html, Head, Main, NextScript } from "next/document";
+    ///
+    /// export default function MyDocument() {
+    ///     return (
+    ///         <Html>
+    ///             <Head />
+    ///             <body>
+    ///                 <Main />
+    ///                 <NextScript />
+    ///             </body>
+    ///         </Html>
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// ```jsx
+    /// import Head from "next/head";
+    ///
+    /// export default function Page() {
+    ///     return (
+    ///         <Head>
+    ///             <title>Home</title>
+    ///         </Head>
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub(crate) NoHeadImportInDocument {
+        version: "next",
+        name: "noHeadImportInDocument",
+        recommended: false,
+    }
+}
+
+impl Rule for NoHeadImportInDocument {
+    type Query = Semantic<AnyJsxElement>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+        let name = element.name().ok()?;
+        let reference = name.as_jsx_reference_identifier()?;
+        let binding = ctx.model().binding(reference)?;
+        let import = binding
+            .syntax()
+            .ancestors()
+            .find_map(JsImportDefaultClause::cast)?
+            .parent::<JsImport>()?;
+        if !import.source_is("next/head").ok()? {
+            return None;
+        }
+        let imports_document = ctx
+            .root()
+            .as_js_module()?
+            .items()
+            .iter()
+            .filter_map(|item| item.as_js_import().cloned())
+            .any(|import| import.source_is("next/document").unwrap_or(false));
+        imports_document.then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Don't use the "<Emphasis>"Head"</Emphasis>" component of "<Emphasis>"next/head"</Emphasis>" in a custom document."
+                },
+            )
+            .note(markup! {
+                "The content of this component is lost in "<Emphasis>"pages/_document"</Emphasis>". Import "<Emphasis>"Head"</Emphasis>" from "<Emphasis>"next/document"</Emphasis>" instead."
+            }),
+        )
+    }
+}
@@ -0,0 +1,327 @@
+use super::use_import_type::{is_in_type_position, without_inline_type};
+use crate::{semantic_services::Semantic, JsRuleAction};
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_semantic::{ReferencesExtensions, SemanticModel};
+use rome_js_syntax::{
+    AnyJsBinding, AnyJsImportClause, AnyJsModuleItem, AnyJsNamedImport, AnyJsNamedImportSpecifier,
+    JsImport, JsNamedImportSpecifierList, JsNamedImportSpecifiers, JsSyntaxKind, JsSyntaxNode,
+    JsSyntaxToken, TextRange, TriviaPieceKind, T,
+};
+use rome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow using as values the bindings imported with `import type`.
+    ///
+    /// The bindings imported with `import type` or with an inline `type` are erased at compile time.
+    /// Using them as values is a type error that often appears in the middle of a refactoring,
+    /// when a type-only import starts to be used at runtime.
+    ///
+    /// The rule reports the `type` keywords of the imports used as values.
+    /// Exporting a type-only import with `export { A }` is not a use as a value.
+    ///
+    /// The code fix removes the `type` keyword.
+    /// If only some named imports of an `import type` are used as values,
+    /// they are moved to a new import.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// import type { A } from "./mod.js";
+    /// const a = new A();
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// import { type A, B } from "./mod.js";
+    /// const a = A.create(B);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// import type { A } from "./mod.js";
+    /// import { type B, C } from "./mod.js";
+    /// let a: A = new C<B>();
+    /// export { B };
+    /// ```
+    ///
+    pub(crate) NoImportTypeAsValue {
+        version: "next",
+        name: "noImportTypeAsValue",
+        recommended: false,
+    }
+}
+
+pub(crate) struct TypeImportUsedAsValue {
+    /// The `type` keyword to remove.
+    type_token: JsSyntaxToken,
+    /// The named imports used as values,
+    /// if only some of the named imports of an `import type` are used as values.
+    split_specifiers: Vec<AnyJsNamedImportSpecifier>,
+    /// The first reference used as a value of each binding.
+    value_references: Vec<TextRange>,
+}
+
+impl Rule for NoImportTypeAsValue {
+    type Query = Semantic<JsImport>;
+    type State = TypeImportUsedAsValue;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.model();
+        let mut signals = Vec::new();
+        let Ok(clause) = ctx.query().import_clause() else {
+            return signals;
+        };
+        let (type_token, bindings) = match clause {
+            AnyJsImportClause::JsImportBareClause(_) => return signals,
+            AnyJsImportClause::JsImportDefaultClause(clause) => (
+                clause.type_token(),
+                clause.local_name().into_iter().collect(),
+            ),
+            AnyJsImportClause::JsImportNamespaceClause(clause) => (
+                clause.type_token(),
+                clause.local_name().into_iter().collect(),
+            ),
+            AnyJsImportClause::JsImportNamedClause(clause) => {
+                let mut bindings: Vec<_> = clause
+                    .default_specifier()
+                    .and_then(|specifier| specifier.local_name().ok())
+                    .into_iter()
+                    .collect();
+                match clause.named_import() {
+                    Ok(AnyJsNamedImport::JsNamespaceImportSpecifier(specifier)) => {
+                        bindings.extend(specifier.local_name().ok());
+                    }
+                    Ok(AnyJsNamedImport::JsNamedImportSpecifiers(specifiers)) => {
+                        let statement_type_token = clause.type_token();
+                        let mut split_specifiers = Vec::new();
+                        let mut value_references = Vec::new();
+                        let mut specifier_count = 0;
+                        for specifier in specifiers.specifiers().iter().flatten() {
+                            specifier_count += 1;
+                            let (type_token, local_name) = match &specifier {
+                                AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                                    (specifier.type_token(), specifier.local_name())
+                                }
+                                AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(
+                                    specifier,
+                                ) => (specifier.type_token(), specifier.local_name()),
+                                AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => {
+                                    continue
+                                }
+                            };
+                            let Some(value_reference) = local_name
+                                .ok()
+                                .and_then(|local_name| first_value_reference(&local_name, model))
+                            else {
+                                continue;
+                            };
+                            if let Some(type_token) = type_token {
+                                signals.push(TypeImportUsedAsValue {
+                                    type_token,
+                                    split_specifiers: Vec::new(),
+                                    value_references: vec![value_reference],
+                                });
+                            } else if statement_type_token.is_some() {
+                                split_specifiers.push(specifier);
+                                value_references.push(value_reference);
+                            }
+                        }
+                        if let Some(type_token) = statement_type_token {
+                            if !value_references.is_empty() {
+                                if split_specifiers.len() == specifier_count
+                                    && clause.default_specifier().is_none()
+                                {
+                                    split_specifiers.clear();
+                                }
+                                signals.push(TypeImportUsedAsValue {
+                                    type_token,
+                                    split_specifiers,
+                                    value_references,
+                                });
+                            }
+                        }
+                        return signals;
+                    }
+                    Err(_) => {}
+                }
+                (clause.type_token(), bindings)
+            }
+        };
+        if let Some(type_token) = type_token {
+            let value_references: Vec<_> = bindings
+                .iter()
+                .filter_map(|binding| first_value_reference(binding, model))
+                .collect();
+            if !value_references.is_empty() {
+                signals.push(TypeImportUsedAsValue {
+                    type_token,
+                    split_specifiers: Vec::new(),
+                    value_references,
+                });
+            }
+        }
+        signals
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.type_token.text_trimmed_range(),
+            markup! {
+                "This import is only available as a type, but it is used as a value."
+            },
+        );
+        let diagnostic = state
+            .value_references
+            .iter()
+            .fold(diagnostic, |diagnostic, range| {
+                diagnostic.detail(range, markup! { "It is used as a value here." })
+            });
+        Some(diagnostic.note(markup! {
+            "The imports marked with "<Emphasis>"type"</Emphasis>" are removed at compile time."
+        }))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let node = state.type_token.parent()?;
+        if let Some(specifier) = AnyJsNamedImportSpecifier::cast_ref(&node) {
+            mutation.replace_node(specifier.clone(), without_inline_type(specifier)?);
+        } else if state.split_specifiers.is_empty() {
+            let clause = AnyJsImportClause::cast(node)?;
+            let new_clause = match clause.clone() {
+                AnyJsImportClause::JsImportBareClause(_) => return None,
+                AnyJsImportClause::JsImportDefaultClause(clause) => {
+                    clause.with_type_token(None).into()
+                }
+                AnyJsImportClause::JsImportNamespaceClause(clause) => {
+                    clause.with_type_token(None).into()
+                }
+                AnyJsImportClause::JsImportNamedClause(clause) => {
+                    clause.with_type_token(None).into()
+                }
+            };
+            mutation.replace_node(clause, new_clause);
+        } else {
+            // Move the named imports used as values to a new import
+            let import = ctx.query();
+            let clause = import.import_clause().ok()?;
+            let AnyJsImportClause::JsImportNamedClause(named_clause) = &clause else {
+                return None;
+            };
+            let specifiers = named_clause.named_import().ok()?;
+            let specifiers = specifiers.as_js_named_import_specifiers()?;
+            let is_split =
+                |specifier: &AnyJsNamedImportSpecifier| state.split_specifiers.contains(specifier);
+            let type_import = import.clone().with_import_clause(
+                named_clause
+                    .clone()
+                    .with_named_import(
+                        filter_specifiers(specifiers, |specifier| !is_split(specifier))?.into(),
+                    )
+                    .into(),
+            );
+            let value_import = import
+                .clone()
+                .with_import_token(
+                    make::token(T![import])
+                        .with_leading_trivia([(TriviaPieceKind::Newline, "\n")])
+                        .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                )
+                .with_import_clause(
+                    named_clause
+                        .clone()
+                        .with_type_token(None)
+                        .with_named_import(filter_specifiers(specifiers, is_split)?.into())
+                        .into(),
+                );
+            mutation.replace_node_discard_trivia(import.clone(), type_import);
+            mutation.add_node_after(
+                AnyJsModuleItem::from(import.clone()),
+                AnyJsModuleItem::from(value_import),
+            );
+        }
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Import as a value instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the range of the first reference of `binding` that is used as a value.
+fn first_value_reference(binding: &AnyJsBinding, model: &SemanticModel) -> Option<TextRange> {
+    binding
+        .as_js_identifier_binding()?
+        .all_references(model)
+        .find(|reference| {
+            let reference = reference.syntax();
+            !is_in_type_position(reference) && !is_exported(reference)
+        })
+        .map(|reference| reference.syntax().text_trimmed_range())
+}
+
+/// Returns `true` if `reference` is exported with `export { reference }`.
+fn is_exported(reference: &JsSyntaxNode) -> bool {
+    reference.parent().is_some_and(|parent| {
+        matches!(
+            parent.kind(),
+            JsSyntaxKind::JS_EXPORT_NAMED_SHORTHAND_SPECIFIER
+                | JsSyntaxKind::JS_EXPORT_NAMED_SPECIFIER
+        )
+    })
+}
+
+/// Returns a copy of `specifiers` that only contains the specifiers matching `predicate`.
+///
+/// The kept specifiers take the leading trivia of the first specifier
+/// and the trailing trivia of the last specifier.
+fn filter_specifiers(
+    specifiers: &JsNamedImportSpecifiers,
+    predicate: impl Fn(&AnyJsNamedImportSpecifier) -> bool,
+) -> Option<JsNamedImportSpecifiers> {
+    let list = specifiers.specifiers();
+    let first_specifier = list.first()?.ok()?;
+    let last_specifier = list.last()?.ok()?;
+    let has_trailing_separator = list.trailing_separator().is_some();
+    let mut kept: Vec<(AnyJsNamedImportSpecifier, Option<JsSyntaxToken>)> = Vec::new();
+    for element in list.elements() {
+        let specifier = element.node().ok()?.clone();
+        if predicate(&specifier) {
+            kept.push((specifier, element.trailing_separator().ok()?.cloned()));
+        }
+    }
+    let kept_count = kept.len();
+    let mut items = Vec::with_capacity(kept_count);
+    let mut separators = Vec::with_capacity(kept_count);
+    for (index, (mut specifier, separator)) in kept.into_iter().enumerate() {
+        if index == 0 {
+            specifier = specifier.with_leading_trivia_pieces(
+                first_specifier.syntax().first_leading_trivia()?.pieces(),
+            )?;
+        }
+        let is_last = index + 1 == kept_count;
+        if is_last {
+            specifier = specifier.with_trailing_trivia_pieces(
+                last_specifier.syntax().last_trailing_trivia()?.pieces(),
+            )?;
+        }
+        if !is_last || has_trailing_separator {
+            separators.push(separator.unwrap_or_else(|| {
+                make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+            }));
+        }
+        items.push(specifier);
+    }
+    let new_list: JsNamedImportSpecifierList =
+        make::js_named_import_specifier_list(items, separators);
+    Some(specifiers.clone().with_specifiers(new_list))
+}
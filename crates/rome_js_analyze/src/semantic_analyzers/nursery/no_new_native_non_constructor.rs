@@ -0,0 +1,122 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    global_identifier, static_value::StaticValue, AnyJsExpression, JsCallExpression,
+    JsNewExpression, T,
+};
+use rome_rowan::{chain_trivia_pieces, trim_leading_trivia_pieces, AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow `new` operators with global non-constructor functions.
+    ///
+    /// Some global functions cannot be called using the `new` operator and
+    /// will throw a `TypeError` if you attempt to do so. These functions are:
+    ///
+    /// - [`Symbol`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/Symbol)
+    /// - [`BigInt`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/BigInt)
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-new-native-nonconstructor
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// var foo = new Symbol('foo');
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// var bar = new BigInt(9007199254740991);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// var foo = Symbol('foo');
+    /// var bar = BigInt(9007199254740991);
+    ///
+    /// function baz(Symbol) {
+    ///     const qux = new Symbol("baz");
+    /// }
+    /// ```
+    pub(crate) NoNewNativeNonConstructor {
+        version: "next",
+        name: "noNewNativeNonConstructor",
+        recommended: false,
+    }
+}
+
+impl Rule for NoNewNativeNonConstructor {
+    type Query = Semantic<JsNewExpression>;
+    type State = StaticValue;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let callee = ctx.query().callee().ok()?.omit_parentheses();
+        let (reference, name) = global_identifier(&callee)?;
+        if !matches!(name.text(), "Symbol" | "BigInt") {
+            return None;
+        }
+        ctx.model().binding(&reference).is_none().then_some(name)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        let name = name.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().new_token().ok()?.text_trimmed_range(),
+                markup! {
+                    <Emphasis>{name}</Emphasis>" cannot be called as a constructor."
+                },
+            )
+            .note(markup! {
+                "Calling "<Emphasis>{name}</Emphasis>" with the "<Emphasis>"new"</Emphasis>" operator throws a "<Emphasis>"TypeError"</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let call_expression = convert_new_expression_to_call_expression(node)?;
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia::<AnyJsExpression>(
+            node.clone().into(),
+            call_expression.into(),
+        );
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Remove "<Emphasis>"new"</Emphasis>"." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+pub(crate) fn convert_new_expression_to_call_expression(
+    expr: &JsNewExpression,
+) -> Option<JsCallExpression> {
+    let new_token = expr.new_token().ok()?;
+    // Keep the trivia of `new`, without the whitespace that separates it from the callee.
+    let callee = expr
+        .callee()
+        .ok()?
+        .prepend_trivia_pieces(chain_trivia_pieces(
+            new_token.leading_trivia().pieces(),
+            trim_leading_trivia_pieces(new_token.trailing_trivia().pieces()),
+        ))?;
+    let arguments = match expr.arguments() {
+        Some(arguments) => arguments,
+        // `new Symbol` is equivalent to `new Symbol()`
+        None => make::js_call_arguments(
+            make::token(T!['(']),
+            make::js_call_argument_list([], []),
+            make::token(T![')']),
+        ),
+    };
+    Some(make::js_call_expression(callee, arguments).build())
+}
@@ -0,0 +1,257 @@
+use crate::semantic_services::Semantic;
+use bpaf::Bpaf;
+use rome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_js_syntax::{
+    global_identifier, AnyJsBindingPattern, AnyJsMemberExpression, AnyJsObjectBindingPatternMember,
+    JsComputedMemberAssignment, JsInitializerClause, JsStaticMemberAssignment,
+    JsVariableDeclarator, TextRange,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, AstSeparatedList, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Disallow the use of `process.env`.
+    ///
+    /// Reading the environment variables in many places makes the configuration of an application
+    /// hard to find and to validate.
+    /// A common practice is to read `process.env` in a single configuration module
+    /// and to import the configuration from there.
+    ///
+    /// The rule reports the accesses to the global `process.env`,
+    /// including the destructuring of `process.env`.
+    ///
+    /// Source: https://github.com/eslint-community/eslint-plugin-n/blob/master/docs/rules/no-process-env.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// if (process.env.NODE_ENV === "development") {}
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const { API_KEY } = process.env;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { config } from "./config.js";
+    /// if (config.isDevelopment) {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `allowEnvVars` to allow the access to some environment variables.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowEnvVars": ["NODE_ENV"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoProcessEnv {
+        version: "next",
+        name: "noProcessEnv",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noProcessEnv`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ProcessEnvOptions {
+    /// The environment variables that can be accessed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(hide, argument::<String>("NAME"), many, optional)]
+    pub allow_env_vars: Option<Vec<String>>,
+}
+
+impl ProcessEnvOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["allowEnvVars"];
+
+    fn is_allowed(&self, name: &str) -> bool {
+        self.allow_env_vars
+            .as_ref()
+            .is_some_and(|allow_env_vars| allow_env_vars.iter().any(|allowed| allowed == name))
+    }
+}
+
+// Required by [Bpaf].
+impl FromStr for ProcessEnvOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ProcessEnvOptions {}
+impl VisitNode<JsonLanguage> for ProcessEnvOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "allowEnvVars" {
+            self.allow_env_vars = self.map_to_array_of_strings(&value, name_text, diagnostics);
+        }
+        Some(())
+    }
+}
+
+impl Rule for NoProcessEnv {
+    type Query = Semantic<AnyJsMemberExpression>;
+    /// The range of the access.
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = ProcessEnvOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let process_env = ctx.query();
+        if process_env.member_name()?.text() != "env" {
+            return None;
+        }
+        let (reference, name) = global_identifier(&process_env.object().ok()?.omit_parentheses())?;
+        if name.text() != "process" || ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        let access = EnvAccess::of(process_env);
+        let options = ctx.options();
+        let is_allowed = access
+            .names
+            .is_some_and(|names| names.iter().all(|name| options.is_allowed(name)));
+        (!is_allowed).then_some(access.range)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Don't use "<Emphasis>"process.env"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Read the environment variables in a single configuration module, and import the configuration instead."
+            }),
+        )
+    }
+}
+
+/// An access to `process.env`.
+struct EnvAccess {
+    /// The range of the access, including the accessed environment variable if any.
+    range: TextRange,
+    /// The accessed environment variables, if they are known.
+    names: Option<Vec<String>>,
+}
+
+impl EnvAccess {
+    fn of(process_env: &AnyJsMemberExpression) -> Self {
+        let unknown = EnvAccess {
+            range: process_env.range(),
+            names: None,
+        };
+        let Some(parent) = process_env.syntax().parent() else {
+            return unknown;
+        };
+        // `process.env.NAME`
+        if let Some(member) = AnyJsMemberExpression::cast_ref(&parent).filter(|member| {
+            member
+                .object()
+                .is_ok_and(|object| object.syntax() == process_env.syntax())
+        }) {
+            return EnvAccess {
+                range: member.range(),
+                names: member
+                    .member_name()
+                    .map(|name| vec![name.text().to_string()]),
+            };
+        }
+        // `process.env.NAME = value`
+        if let Some(assignment) = JsStaticMemberAssignment::cast_ref(&parent) {
+            return EnvAccess {
+                range: assignment.range(),
+                names: assignment
+                    .member()
+                    .ok()
+                    .and_then(|member| member.as_js_name()?.value_token().ok())
+                    .map(|name| vec![name.text_trimmed().to_string()]),
+            };
+        }
+        // `process.env["NAME"] = value`
+        if let Some(assignment) =
+            JsComputedMemberAssignment::cast_ref(&parent).filter(|assignment| {
+                assignment
+                    .object()
+                    .is_ok_and(|object| object.syntax() == process_env.syntax())
+            })
+        {
+            return EnvAccess {
+                range: assignment.range(),
+                names: assignment
+                    .member()
+                    .ok()
+                    .and_then(|member| member.omit_parentheses().as_static_value())
+                    .map(|name| vec![name.text().to_string()]),
+            };
+        }
+        // `const { NAME } = process.env`
+        let names = JsInitializerClause::cast(parent)
+            .and_then(|initializer| initializer.parent::<JsVariableDeclarator>())
+            .and_then(|declarator| match declarator.id().ok()? {
+                AnyJsBindingPattern::JsObjectBindingPattern(pattern) => pattern
+                    .properties()
+                    .iter()
+                    .map(|property| {
+                        match property.ok()? {
+                        AnyJsObjectBindingPatternMember::JsObjectBindingPatternProperty(
+                            property,
+                        ) => Some(property.member().ok()?.name()?.text().to_string()),
+                        AnyJsObjectBindingPatternMember::JsObjectBindingPatternShorthandProperty(
+                            property,
+                        ) => Some(
+                            property
+                                .identifier()
+                                .ok()?
+                                .as_js_identifier_binding()?
+                                .name_token()
+                                .ok()?
+                                .text_trimmed()
+                                .to_string(),
+                        ),
+                        // A rest element accesses all the environment variables
+                        AnyJsObjectBindingPatternMember::JsObjectBindingPatternRest(_)
+                        | AnyJsObjectBindingPatternMember::JsBogusBinding(_) => None,
+                    }
+                    })
+                    .collect(),
+                _ => None,
+            });
+        EnvAccess { names, ..unknown }
+    }
+}
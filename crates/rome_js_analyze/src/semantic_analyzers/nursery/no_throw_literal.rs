@@ -0,0 +1,138 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    global_identifier, AnyJsCallArgument, AnyJsExpression, AnyJsLiteralExpression,
+    JsThrowStatement, TriviaPieceKind, T,
+};
+use rome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow throwing literals and other values that cannot be errors.
+    ///
+    /// Only `Error` objects capture a stack trace and carry a standard `message` property.
+    /// Throwing a literal makes the thrown value harder to handle and to debug.
+    ///
+    /// The rule reports thrown literals, template literals, object literals, array literals, and `undefined`.
+    /// Thrown identifiers and other expressions are not reported, because they may hold an error.
+    ///
+    /// The code fix wraps a thrown string literal in `new Error()`.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-throw-literal
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// throw "Something failed";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// throw { message: "Something failed" };
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// throw `Failed: ${reason}`;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// throw new Error("Something failed");
+    /// ```
+    ///
+    /// ```js
+    /// throw error;
+    /// ```
+    ///
+    pub(crate) NoThrowLiteral {
+        version: "next",
+        name: "noThrowLiteral",
+        recommended: false,
+    }
+}
+
+impl Rule for NoThrowLiteral {
+    type Query = Semantic<JsThrowStatement>;
+    /// The thrown value.
+    type State = AnyJsExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let argument = ctx.query().argument().ok()?.omit_parentheses();
+        let is_literal = match &argument {
+            AnyJsExpression::AnyJsLiteralExpression(_)
+            | AnyJsExpression::JsObjectExpression(_)
+            | AnyJsExpression::JsArrayExpression(_) => true,
+            AnyJsExpression::JsTemplateExpression(template) => template.tag().is_none(),
+            AnyJsExpression::JsIdentifierExpression(_) => {
+                global_identifier(&argument).is_some_and(|(reference, name)| {
+                    name.text() == "undefined" && ctx.model().binding(&reference).is_none()
+                })
+            }
+            _ => false,
+        };
+        is_literal.then_some(argument)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, argument: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                argument.range(),
+                markup! {
+                    "Do not throw a value that is not an "<Emphasis>"Error"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Unlike an "<Emphasis>"Error"</Emphasis>" object, this value doesn't capture a stack trace."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, argument: &Self::State) -> Option<JsRuleAction> {
+        if !matches!(
+            argument,
+            AnyJsExpression::AnyJsLiteralExpression(
+                AnyJsLiteralExpression::JsStringLiteralExpression(_)
+            )
+        ) {
+            return None;
+        }
+        // `new Error()` must refer to the global `Error`.
+        let is_error_shadowed = ctx
+            .model()
+            .scope(ctx.query().syntax())
+            .ancestors()
+            .any(|scope| scope.get_binding("Error").is_some());
+        if is_error_shadowed {
+            return None;
+        }
+        let error = make::js_new_expression(
+            make::token(T![new]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            make::js_identifier_expression(make::js_reference_identifier(make::ident("Error")))
+                .into(),
+        )
+        .with_arguments(make::js_call_arguments(
+            make::token(T!['(']),
+            make::js_call_argument_list(
+                [AnyJsCallArgument::AnyJsExpression(argument.clone().trim()?)],
+                [],
+            ),
+            make::token(T![')']),
+        ))
+        .build();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(argument.clone(), error.into());
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Throw an "<Emphasis>"Error"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
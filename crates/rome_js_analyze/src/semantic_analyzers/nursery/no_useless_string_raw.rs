@@ -0,0 +1,125 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    global_identifier, AnyJsExpression, AnyJsMemberExpression, AnyJsTemplateElement,
+    JsTemplateExpression,
+};
+use rome_rowan::{AstNode, BatchMutationExt, Direction};
+
+declare_rule! {
+    /// Disallow `String.raw` on template literals without escape sequences.
+    ///
+    /// `String.raw` returns the raw text of a template literal:
+    /// backslashes are kept as is instead of introducing escape sequences.
+    /// When the template literal contains no backslash, `String.raw` is useless.
+    ///
+    /// The code fix removes the `String.raw` tag.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const foo = String.raw`bar`;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const foo = String.raw`bar ${baz} qux`;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const path = String.raw`C:\Users\foo`;
+    /// const foo = `bar`;
+    /// ```
+    ///
+    pub(crate) NoUselessStringRaw {
+        version: "next",
+        name: "noUselessStringRaw",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUselessStringRaw {
+    type Query = Semantic<JsTemplateExpression>;
+    /// The `String.raw` tag.
+    type State = AnyJsExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.type_arguments().is_some() {
+            return None;
+        }
+        let tag = node.tag()?;
+        let member = AnyJsMemberExpression::cast_ref(tag.clone().omit_parentheses().syntax())?;
+        if member.member_name()?.text() != "raw" {
+            return None;
+        }
+        let (reference, name) = global_identifier(&member.object().ok()?.omit_parentheses())?;
+        if name.text() != "String" || ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        for element in node.elements() {
+            if let AnyJsTemplateElement::JsTemplateChunkElement(chunk) = element {
+                if chunk
+                    .template_chunk_token()
+                    .ok()?
+                    .text_trimmed()
+                    .contains('\\')
+                {
+                    return None;
+                }
+            }
+        }
+        Some(tag)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, tag: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                tag.range(),
+                markup! {
+                    "This "<Emphasis>"String.raw"</Emphasis>" is useless."
+                },
+            )
+            .note(markup! {
+                "The template literal contains no backslash: its raw text is the same as its cooked text."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, tag: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let l_tick = node.l_tick_token().ok()?;
+        // Don't lose the comments between the tag and the template literal
+        let has_comments = l_tick.has_leading_comments()
+            || tag
+                .syntax()
+                .descendants_tokens(Direction::Next)
+                .enumerate()
+                .any(|(index, token)| {
+                    (index > 0 && token.has_leading_comments()) || token.has_trailing_comments()
+                });
+        if has_comments {
+            return None;
+        }
+        let template =
+            make::js_template_expression(l_tick, node.elements(), node.r_tick_token().ok()?)
+                .build();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(node.clone(), template);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Remove "<Emphasis>"String.raw"</Emphasis>"." }.to_owned(),
+            mutation,
+        })
+    }
+}
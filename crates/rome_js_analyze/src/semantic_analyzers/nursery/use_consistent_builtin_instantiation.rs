@@ -0,0 +1,204 @@
+use super::no_new_native_non_constructor::convert_new_expression_to_call_expression;
+use crate::{semantic_services::Semantic, JsRuleAction};
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    global_identifier, static_value::StaticValue, AnyJsExpression, JsCallExpression,
+    JsNewExpression, TriviaPieceKind, T,
+};
+use rome_rowan::{declare_node_union, AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Enforce the use of `new` for built-in constructors, and its absence for built-in conversion functions.
+    ///
+    /// The following built-ins must be instantiated with `new`:
+    /// `Map`, `Set`, `WeakMap`, `WeakSet`, `WeakRef`, `FinalizationRegistry`, `Date`, `Promise`, `Proxy`,
+    /// `ArrayBuffer`, `SharedArrayBuffer`, `DataView`, and the typed arrays such as `Uint8Array`.
+    /// Calling most of them without `new` throws a `TypeError`,
+    /// and `Date()` returns a string instead of a `Date` object.
+    ///
+    /// The built-ins `Symbol` and `BigInt` must be called without `new`:
+    /// `new Symbol()` and `new BigInt()` throw a `TypeError`.
+    ///
+    /// Built-ins that behave the same with and without `new`, such as `Array`, `Object`, and `Error`, are ignored.
+    ///
+    /// Source: https://github.com/sindresorhus/eslint-plugin-unicorn/blob/main/docs/rules/new-for-builtins.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const map = Map();
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const date = Date();
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const id = new Symbol("id");
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const map = new Map();
+    /// const id = Symbol("id");
+    /// const list = Array(3);
+    /// ```
+    ///
+    pub(crate) UseConsistentBuiltinInstantiation {
+        version: "next",
+        name: "useConsistentBuiltinInstantiation",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) JsNewOrCallExpression = JsNewExpression | JsCallExpression
+}
+
+/// The way a built-in must be used.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum ExpectedInstantiation {
+    /// The built-in must be instantiated with `new`.
+    New,
+    /// The built-in must be called without `new`.
+    Call,
+}
+
+pub(crate) struct BuiltinInstantiationState {
+    /// The name of the built-in.
+    name: StaticValue,
+    expected: ExpectedInstantiation,
+}
+
+const BUILTINS_REQUIRING_NEW: [&str; 23] = [
+    "ArrayBuffer",
+    "BigInt64Array",
+    "BigUint64Array",
+    "DataView",
+    "Date",
+    "FinalizationRegistry",
+    "Float32Array",
+    "Float64Array",
+    "Int16Array",
+    "Int32Array",
+    "Int8Array",
+    "Map",
+    "Promise",
+    "Proxy",
+    "Set",
+    "SharedArrayBuffer",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "WeakMap",
+    "WeakRef",
+    "WeakSet",
+];
+
+const BUILTINS_FORBIDDING_NEW: [&str; 2] = ["BigInt", "Symbol"];
+
+impl Rule for UseConsistentBuiltinInstantiation {
+    type Query = Semantic<JsNewOrCallExpression>;
+    type State = BuiltinInstantiationState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let (callee, expected) = match ctx.query() {
+            JsNewOrCallExpression::JsNewExpression(node) => {
+                (node.callee().ok()?, ExpectedInstantiation::Call)
+            }
+            JsNewOrCallExpression::JsCallExpression(node) => {
+                (node.callee().ok()?, ExpectedInstantiation::New)
+            }
+        };
+        let (reference, name) = global_identifier(&callee.omit_parentheses())?;
+        let builtins: &[&str] = match expected {
+            ExpectedInstantiation::New => &BUILTINS_REQUIRING_NEW,
+            ExpectedInstantiation::Call => &BUILTINS_FORBIDDING_NEW,
+        };
+        if !builtins.contains(&name.text()) || ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        Some(BuiltinInstantiationState { name, expected })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.name.text();
+        let diagnostic = match state.expected {
+            ExpectedInstantiation::New => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Use "<Emphasis>"new "{name}"()"</Emphasis>" instead of "<Emphasis>{name}"()"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                <Emphasis>{name}</Emphasis>" is a constructor: calling it without "<Emphasis>"new"</Emphasis>" throws a "<Emphasis>"TypeError"</Emphasis>" or returns a different value."
+            }),
+            ExpectedInstantiation::Call => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Use "<Emphasis>{name}"()"</Emphasis>" instead of "<Emphasis>"new "{name}"()"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Calling "<Emphasis>{name}</Emphasis>" with "<Emphasis>"new"</Emphasis>" throws a "<Emphasis>"TypeError"</Emphasis>"."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        match ctx.query() {
+            JsNewOrCallExpression::JsNewExpression(node) => {
+                // The type arguments would be lost
+                if node.type_arguments().is_some() {
+                    return None;
+                }
+                let call_expression = convert_new_expression_to_call_expression(node)?;
+                mutation.replace_node_discard_trivia::<AnyJsExpression>(
+                    node.clone().into(),
+                    call_expression.into(),
+                );
+            }
+            JsNewOrCallExpression::JsCallExpression(node) => {
+                // `new` cannot be applied to an optional chain.
+                if node.is_optional_chain() {
+                    return None;
+                }
+                let mut new_expression = make::js_new_expression(
+                    make::token(T![new]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                    node.callee().ok()?.trim_start()?,
+                )
+                .with_arguments(node.arguments().ok()?);
+                if let Some(type_arguments) = node.type_arguments() {
+                    new_expression = new_expression.with_type_arguments(type_arguments);
+                }
+                mutation.replace_node::<AnyJsExpression>(
+                    node.clone().into(),
+                    new_expression.build().into(),
+                );
+            }
+        }
+        let message = match state.expected {
+            ExpectedInstantiation::New => markup! { "Add "<Emphasis>"new"</Emphasis>"." },
+            ExpectedInstantiation::Call => markup! { "Remove "<Emphasis>"new"</Emphasis>"." },
+        };
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: message.to_owned(),
+            mutation,
+        })
+    }
+}
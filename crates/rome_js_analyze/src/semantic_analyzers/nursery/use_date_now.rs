@@ -0,0 +1,153 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_semantic::SemanticModel;
+use rome_js_syntax::{
+    global_identifier, AnyJsExpression, JsCallExpression, JsNewExpression, JsUnaryExpression,
+    JsUnaryOperator, T,
+};
+use rome_rowan::{declare_node_union, AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Use `Date.now()` to get the number of milliseconds since the Unix Epoch.
+    ///
+    /// `Date.now()` is shorter than `new Date().getTime()` and avoids
+    /// the creation of an intermediate `Date` instance.
+    ///
+    /// Source: https://github.com/sindresorhus/eslint-plugin-unicorn/blob/main/docs/rules/prefer-date-now.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const ts = new Date().getTime();
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const ts = new Date().valueOf();
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const ts = +new Date();
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const ts = Date.now();
+    /// const time = new Date(date).getTime();
+    /// ```
+    ///
+    pub(crate) UseDateNow {
+        version: "next",
+        name: "useDateNow",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyDateTimestampExpression = JsCallExpression | JsUnaryExpression
+}
+
+impl Rule for UseDateNow {
+    type Query = Semantic<AnyDateTimestampExpression>;
+    /// The `new Date()` expression.
+    type State = JsNewExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let object = match ctx.query() {
+            AnyDateTimestampExpression::JsCallExpression(call) => {
+                if call.is_optional_chain() || !call.arguments().ok()?.args().is_empty() {
+                    return None;
+                }
+                let callee = call.callee().ok()?.omit_parentheses();
+                let member = callee.as_js_static_member_expression()?;
+                let member_name = member.member().ok()?.as_js_name()?.value_token().ok()?;
+                if !matches!(member_name.text_trimmed(), "getTime" | "valueOf") {
+                    return None;
+                }
+                member.object().ok()?
+            }
+            AnyDateTimestampExpression::JsUnaryExpression(unary) => {
+                if unary.operator().ok()? != JsUnaryOperator::Plus {
+                    return None;
+                }
+                unary.argument().ok()?
+            }
+        };
+        new_date_expression(&object, ctx.model())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Use "<Emphasis>"Date.now()"</Emphasis>" to get the current timestamp."
+                },
+            )
+            .note(markup! {
+                <Emphasis>"Date.now()"</Emphasis>" avoids the creation of an unnecessary "<Emphasis>"Date"</Emphasis>" instance."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, new_date: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        // Reuse the callee to preserve forms such as `globalThis.Date`.
+        let date = new_date.callee().ok()?.trim()?;
+        let date_now = make::js_static_member_expression(
+            date,
+            make::token(T![.]),
+            make::js_name(make::ident("now")).into(),
+        );
+        let call = make::js_call_expression(
+            date_now.into(),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list([], []),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node::<AnyJsExpression>(node.clone().into(), call.into());
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>"Date.now()"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+impl From<AnyDateTimestampExpression> for AnyJsExpression {
+    fn from(expr: AnyDateTimestampExpression) -> Self {
+        match expr {
+            AnyDateTimestampExpression::JsCallExpression(expr) => expr.into(),
+            AnyDateTimestampExpression::JsUnaryExpression(expr) => expr.into(),
+        }
+    }
+}
+
+/// Returns `expr` if it is `new Date()` where `Date` is the global `Date` class.
+fn new_date_expression(expr: &AnyJsExpression, model: &SemanticModel) -> Option<JsNewExpression> {
+    let new_expression = JsNewExpression::cast_ref(expr.clone().omit_parentheses().syntax())?;
+    if new_expression.type_arguments().is_some() {
+        return None;
+    }
+    if let Some(arguments) = new_expression.arguments() {
+        if !arguments.args().is_empty() {
+            return None;
+        }
+    }
+    let callee = new_expression.callee().ok()?.omit_parentheses();
+    let (reference, name) = global_identifier(&callee)?;
+    (name.text() == "Date" && model.binding(&reference).is_none()).then_some(new_expression)
+}
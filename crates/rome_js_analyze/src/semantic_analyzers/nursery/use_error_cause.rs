@@ -0,0 +1,295 @@
+use crate::control_flow::AnyJsControlFlowRoot;
+use crate::{semantic_services::Semantic, JsRuleAction};
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_semantic::SemanticModel;
+use rome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsCallArgument, AnyJsExpression, AnyJsObjectMember,
+    AnyJsObjectMemberName, JsCatchClause, JsIdentifierBinding, JsLanguage, JsNewExpression,
+    JsObjectExpression, JsSyntaxToken, JsThrowStatement, TriviaPieceKind, T,
+};
+use rome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxResult};
+
+declare_rule! {
+    /// Require passing the caught error as the `cause` of a new error thrown in a `catch` clause.
+    ///
+    /// When an error is caught and a new error is thrown instead,
+    /// the original error is lost unless it is passed as the `cause` option of the new error.
+    /// The cause keeps the original message and stack trace available for debugging.
+    ///
+    /// The rule reports `new` expressions thrown in a `catch` clause
+    /// that don't receive the caught error as argument or as `cause` option.
+    ///
+    /// The code fix adds the caught error as `cause` option:
+    /// it appends a `{ cause: error }` object to the arguments,
+    /// or adds a `cause` property to the last argument if it is an object literal.
+    ///
+    /// Source: https://github.com/sindresorhus/eslint-plugin-unicorn/blob/main/docs/rules/prefer-error-cause.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// try {
+    ///     doSomething();
+    /// } catch (error) {
+    ///     throw new Error("Something failed");
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// try {
+    ///     doSomething();
+    /// } catch (error) {
+    ///     throw new Error("Something failed", { cause: error });
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// try {
+    ///     doSomething();
+    /// } catch (error) {
+    ///     throw error;
+    /// }
+    /// ```
+    ///
+    pub(crate) UseErrorCause {
+        version: "next",
+        name: "useErrorCause",
+        recommended: false,
+    }
+}
+
+pub(crate) struct UseErrorCauseState {
+    /// The thrown `new` expression.
+    new_expression: JsNewExpression,
+    /// The binding of the caught error.
+    caught_error: JsIdentifierBinding,
+}
+
+impl Rule for UseErrorCause {
+    type Query = Semantic<JsThrowStatement>;
+    type State = UseErrorCauseState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let model = ctx.model();
+        let AnyJsExpression::JsNewExpression(new_expression) =
+            node.argument().ok()?.omit_parentheses()
+        else {
+            return None;
+        };
+        // A throw statement in a nested function is not executed by the `catch` clause.
+        let catch_clause = node
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .take_while(|ancestor| !AnyJsControlFlowRoot::can_cast(ancestor.kind()))
+            .find_map(JsCatchClause::cast)?;
+        let AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(caught_error)) =
+            catch_clause.declaration()?.binding().ok()?
+        else {
+            return None;
+        };
+        // The caught error must not be shadowed at the throw statement.
+        let name = caught_error.name_token().ok()?;
+        let caught_binding = model.as_binding(&caught_error);
+        let resolved_binding = model
+            .scope(node.syntax())
+            .ancestors()
+            .find_map(|scope| scope.get_binding(name.text_trimmed()))?;
+        if resolved_binding.syntax() != caught_binding.syntax() {
+            return None;
+        }
+        if let Some(arguments) = new_expression.arguments() {
+            for argument in arguments.args() {
+                let AnyJsCallArgument::AnyJsExpression(argument) = argument.ok()? else {
+                    // A spread argument could contain the cause.
+                    return None;
+                };
+                if passes_caught_error(&argument, &caught_error, model) {
+                    return None;
+                }
+            }
+        }
+        Some(UseErrorCauseState {
+            new_expression,
+            caught_error,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.caught_error.name_token().ok()?;
+        let name = name.text_trimmed();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.new_expression.range(),
+                markup! {
+                    "The caught error is not passed as the cause of the thrown error."
+                },
+            )
+            .detail(
+                state.caught_error.range(),
+                markup! {
+                    "This is the caught error."
+                },
+            )
+            .note(markup! {
+                "Pass the caught error with "<Emphasis>"{ cause: "{name}" }"</Emphasis>" to preserve its message and stack trace."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let name = state.caught_error.name_token().ok()?;
+        let cause = cause_member(name.text_trimmed());
+        let argument_list = state.new_expression.arguments()?.args();
+        let mut mutation = ctx.root().begin();
+        // `new Error(message)` requires a message before the options.
+        match argument_list.last()?.ok()? {
+            AnyJsCallArgument::AnyJsExpression(AnyJsExpression::JsObjectExpression(options))
+                if argument_list.len() > 1 =>
+            {
+                let members = options.members();
+                let new_options = if members.is_empty() {
+                    options_object(cause)
+                } else {
+                    let (items, separators) = append_item(&members, cause)?;
+                    options
+                        .clone()
+                        .with_members(make::js_object_member_list(items, separators))
+                };
+                mutation.replace_node(options, new_options);
+            }
+            _ => {
+                let options = options_object(cause);
+                let (items, separators) = append_item(
+                    &argument_list,
+                    AnyJsCallArgument::AnyJsExpression(options.into()),
+                )?;
+                mutation.replace_node(
+                    argument_list,
+                    make::js_call_argument_list(items, separators),
+                );
+            }
+        }
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! {
+                "Pass the caught error as "<Emphasis>"cause"</Emphasis>"."
+            }
+            .to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `argument` is the caught error or an object literal with a `cause` property.
+fn passes_caught_error(
+    argument: &AnyJsExpression,
+    caught_error: &JsIdentifierBinding,
+    model: &SemanticModel,
+) -> bool {
+    match argument.clone().omit_parentheses() {
+        AnyJsExpression::JsIdentifierExpression(identifier) => identifier
+            .name()
+            .ok()
+            .and_then(|reference| model.binding(&reference))
+            .is_some_and(|binding| binding.syntax() == caught_error.syntax()),
+        AnyJsExpression::JsObjectExpression(object) => {
+            object.members().iter().any(|member| match member {
+                Ok(AnyJsObjectMember::JsPropertyObjectMember(property)) => property
+                    .name()
+                    .ok()
+                    .and_then(|name| match name {
+                        AnyJsObjectMemberName::JsLiteralMemberName(name) => name.name().ok(),
+                        AnyJsObjectMemberName::JsComputedMemberName(_) => None,
+                    })
+                    .is_some_and(|name| name.text() == "cause"),
+                Ok(AnyJsObjectMember::JsShorthandPropertyObjectMember(property)) => property
+                    .name()
+                    .ok()
+                    .and_then(|name| name.name().ok())
+                    .is_some_and(|name| name.text() == "cause"),
+                // A spread member could contain the cause.
+                Ok(AnyJsObjectMember::JsSpread(_)) => true,
+                _ => false,
+            })
+        }
+        _ => false,
+    }
+}
+
+/// Returns the member `cause: name`, or `cause` if `name` is `cause`.
+fn cause_member(name: &str) -> AnyJsObjectMember {
+    if name == "cause" {
+        make::js_shorthand_property_object_member(make::js_reference_identifier(make::ident(
+            "cause",
+        )))
+        .into()
+    } else {
+        make::js_property_object_member(
+            make::js_literal_member_name(make::ident("cause")).into(),
+            make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            make::js_identifier_expression(make::js_reference_identifier(make::ident(name))).into(),
+        )
+        .into()
+    }
+}
+
+/// Returns the object literal `{ member }`.
+fn options_object(member: AnyJsObjectMember) -> JsObjectExpression {
+    make::js_object_expression(
+        make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        make::js_object_member_list([member], []),
+        make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+    )
+}
+
+/// Returns the items and the separators of the non-empty `list` with `item` appended.
+///
+/// The new item is indented like the first item, and a trailing separator is preserved.
+fn append_item<L, N>(list: &L, item: N) -> Option<(Vec<N>, Vec<JsSyntaxToken>)>
+where
+    L: AstSeparatedList<Language = JsLanguage, Node = N>,
+    N: AstNode<Language = JsLanguage>,
+{
+    let mut items = list.iter().collect::<SyntaxResult<Vec<_>>>().ok()?;
+    let mut separators = list.separators().collect::<SyntaxResult<Vec<_>>>().ok()?;
+    let indentation = items
+        .first()?
+        .syntax()
+        .first_token()?
+        .leading_trivia()
+        .pieces()
+        .filter(|piece| piece.is_whitespace() || piece.is_newline())
+        .collect::<Vec<_>>();
+    let is_multiline = indentation.iter().any(|piece| piece.is_newline());
+    let item = item.with_leading_trivia_pieces(indentation)?;
+    if separators.len() == items.len() {
+        // The trailing separator now separates the new item from the previous one.
+        items.push(item);
+        separators.push(make::token(T![,]));
+    } else {
+        // The trailing trivia of the last item now follows the new item.
+        let last_item = items.pop()?;
+        let trailing_trivia = last_item.syntax().last_token()?.trailing_trivia().pieces();
+        items.push(last_item.trim_end()?);
+        items.push(item.with_trailing_trivia_pieces(trailing_trivia)?);
+        separators.push(if is_multiline {
+            make::token(T![,])
+        } else {
+            make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+        });
+    }
+    Some((items, separators))
+}
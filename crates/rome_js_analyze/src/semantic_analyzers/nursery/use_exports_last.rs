@@ -0,0 +1,325 @@
+use crate::semantic_services::Semantic;
+use crate::JsRuleAction;
+use bpaf::Bpaf;
+use rome_analyze::context::RuleContext;
+use rome_analyze::{declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_semantic::{ReferencesExtensions, SemanticModel};
+use rome_js_syntax::{
+    AnyJsDeclarationClause, AnyJsExportClause, AnyJsExportDefaultDeclaration, AnyJsModuleItem,
+    JsExport, JsIdentifierBinding, JsModule,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, AstNodeList, BatchMutationExt, SyntaxNode, TextRange};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Require that all exports are declared after all non-export statements.
+    ///
+    /// Grouping the exports at the end of a module makes the public interface of the module
+    /// easy to find.
+    ///
+    /// The rule reports every statement that is not an export and that follows an export.
+    /// The code fix moves the exports after the other statements.
+    /// The order of the exports and the order of the other statements are preserved.
+    /// The code fix isn't suggested when a statement uses a variable or a class declared by a previous export,
+    /// because the statement would then use it before its declaration.
+    /// Moving a declaration can still change the behavior of the code,
+    /// for example when its initializer has side effects.
+    ///
+    /// Source: https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/exports-last.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// export const a = 1;
+    /// const b = 2;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// export { a };
+    /// const a = 1;
+    /// export const b = 2;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const a = 1;
+    /// export { a };
+    /// export const b = 2;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `ignoreTypeExports` to allow the exports of types anywhere in the module,
+    /// such as `export type A = string` or `export interface B {}`.
+    /// Its default value is `false`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "ignoreTypeExports": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseExportsLast {
+        version: "next",
+        name: "useExportsLast",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useExportsLast`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ExportsLastOptions {
+    /// Whether the exports of types can appear anywhere in the module.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub ignore_type_exports: bool,
+}
+
+impl ExportsLastOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["ignoreTypeExports"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ExportsLastOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ExportsLastOptions {}
+impl VisitNode<JsonLanguage> for ExportsLastOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "ignoreTypeExports" {
+            self.ignore_type_exports = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
+/// How a module item is ordered.
+#[derive(Debug, Eq, PartialEq)]
+enum ItemKind {
+    Export,
+    /// An export that can appear anywhere in the module.
+    IgnoredExport,
+    Other,
+}
+
+impl ItemKind {
+    fn of(item: &AnyJsModuleItem, options: &ExportsLastOptions) -> Self {
+        match item {
+            AnyJsModuleItem::JsExport(export) => {
+                if options.ignore_type_exports && is_type_export(export) {
+                    ItemKind::IgnoredExport
+                } else {
+                    ItemKind::Export
+                }
+            }
+            _ => ItemKind::Other,
+        }
+    }
+}
+
+pub(crate) struct MisplacedStatement {
+    /// The statement that follows an export.
+    statement: AnyJsModuleItem,
+    /// The range of the first export of the module.
+    first_export_range: TextRange,
+}
+
+impl Rule for UseExportsLast {
+    type Query = Semantic<JsModule>;
+    type State = MisplacedStatement;
+    type Signals = Vec<Self::State>;
+    type Options = ExportsLastOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let mut first_export_range = None;
+        let mut signals = Vec::new();
+        for item in ctx.query().items() {
+            match ItemKind::of(&item, options) {
+                ItemKind::Export => {
+                    first_export_range.get_or_insert_with(|| item.range());
+                }
+                ItemKind::IgnoredExport => {}
+                ItemKind::Other => {
+                    if let Some(first_export_range) = first_export_range {
+                        signals.push(MisplacedStatement {
+                            statement: item,
+                            first_export_range,
+                        });
+                    }
+                }
+            }
+        }
+        signals
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.statement.range(),
+                markup! {
+                    "This statement follows an export."
+                },
+            )
+            .detail(
+                state.first_export_range,
+                markup! {
+                    "The first export of the module is here."
+                },
+            )
+            .note(markup! {
+                "All exports should be declared after the other statements of the module."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let options = ctx.options();
+        let model = ctx.model();
+        let items = ctx.query().items();
+        // Don't move an export after a statement that uses its declaration
+        let mut previous_exports = Vec::new();
+        for item in items.iter() {
+            match item {
+                AnyJsModuleItem::JsExport(export)
+                    if ItemKind::of(&item, options) == ItemKind::Export =>
+                {
+                    previous_exports.push(export);
+                }
+                _ => {
+                    if previous_exports
+                        .iter()
+                        .any(|export| is_used_by(export, &item, model))
+                    {
+                        return None;
+                    }
+                }
+            }
+        }
+        let (mut new_items, exports): (Vec<_>, Vec<_>) = items
+            .iter()
+            .partition(|item| ItemKind::of(item, options) != ItemKind::Export);
+        new_items.extend(exports);
+        // Keep the leading trivia of the module, such as a license header, at the start of the module.
+        let old_first_item = items.first()?;
+        let new_first_item = new_items.first()?.clone();
+        if old_first_item != new_first_item {
+            let old_first_trivia = old_first_item.syntax().first_leading_trivia()?;
+            let new_first_trivia = new_first_item.syntax().first_leading_trivia()?;
+            // The blank lines that precede the comments of the new first item
+            // now separate the old first item from the previous statement.
+            let separator_len = new_first_trivia
+                .pieces()
+                .take_while(|piece| piece.is_newline() || piece.is_whitespace())
+                .count();
+            let separator: Vec<_> = new_first_trivia.pieces().take(separator_len).collect();
+            let comments: Vec<_> = new_first_trivia.pieces().skip(separator_len).collect();
+            for item in new_items.iter_mut() {
+                if item == &old_first_item {
+                    *item = item.clone().with_leading_trivia_pieces(separator.clone())?;
+                } else if item == &new_first_item {
+                    let trivia: Vec<_> =
+                        old_first_trivia.pieces().chain(comments.clone()).collect();
+                    *item = item.clone().with_leading_trivia_pieces(trivia)?;
+                }
+            }
+        }
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(items, make::js_module_item_list(new_items));
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Move the exports to the end of the module." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `item` references a binding declared by `export`.
+fn is_used_by(export: &JsExport, item: &AnyJsModuleItem, model: &SemanticModel) -> bool {
+    // Function declarations are hoisted
+    if matches!(
+        export.export_clause(),
+        Ok(AnyJsExportClause::AnyJsDeclarationClause(
+            AnyJsDeclarationClause::JsFunctionDeclaration(_)
+        ))
+    ) {
+        return false;
+    }
+    let item_range = item.range();
+    export
+        .syntax()
+        .descendants()
+        .filter_map(JsIdentifierBinding::cast)
+        .any(|binding| {
+            binding
+                .all_references(model)
+                .any(|reference| item_range.contains_range(*reference.range()))
+        })
+}
+
+/// Returns `true` if `export` only exports types.
+fn is_type_export(export: &JsExport) -> bool {
+    let Ok(clause) = export.export_clause() else {
+        return false;
+    };
+    match clause {
+        AnyJsExportClause::AnyJsDeclarationClause(declaration) => is_type_declaration(&declaration),
+        AnyJsExportClause::TsExportDeclareClause(clause) => clause
+            .declaration()
+            .is_ok_and(|declaration| is_type_declaration(&declaration)),
+        AnyJsExportClause::JsExportDefaultDeclarationClause(clause) => matches!(
+            clause.declaration(),
+            Ok(AnyJsExportDefaultDeclaration::TsInterfaceDeclaration(_))
+        ),
+        AnyJsExportClause::JsExportNamedClause(clause) => clause.type_token().is_some(),
+        AnyJsExportClause::JsExportNamedFromClause(clause) => clause.type_token().is_some(),
+        AnyJsExportClause::JsExportFromClause(clause) => clause.type_token().is_some(),
+        _ => false,
+    }
+}
+
+fn is_type_declaration(declaration: &AnyJsDeclarationClause) -> bool {
+    matches!(
+        declaration,
+        AnyJsDeclarationClause::TsInterfaceDeclaration(_)
+            | AnyJsDeclarationClause::TsTypeAliasDeclaration(_)
+    )
+}
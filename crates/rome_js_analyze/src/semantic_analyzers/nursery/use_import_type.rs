@@ -0,0 +1,475 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use bpaf::Bpaf;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_semantic::{ReferencesExtensions, SemanticModel};
+use rome_js_syntax::{
+    AnyJsBinding, AnyJsImportClause, AnyJsNamedImport, AnyJsNamedImportSpecifier, AnyTsType,
+    JsImport, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, TriviaPieceKind, T,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Promotes the use of `import type` for imports only used as types.
+    ///
+    /// TypeScript erases the imports that are only used as types.
+    /// However, some transpilers compile each file in isolation and cannot know
+    /// whether an import refers to a type or to a value.
+    /// Marking the type-only imports with `import type` makes sure that they are removed,
+    /// and that the imported module is not loaded at runtime.
+    ///
+    /// The rule reports imports whose references are all in type positions.
+    /// If every imported binding of an import is only used as a type,
+    /// the code fix turns the import into an `import type`.
+    /// If some bindings are also used as values,
+    /// the code fix only adds an inline `type` to the named imports used as types.
+    ///
+    /// Unused imports and imports that are re-exported are ignored.
+    ///
+    /// Source: https://typescript-eslint.io/rules/consistent-type-imports
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// import { A } from "./mod.js";
+    /// let a: A;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// import { A, B } from "./mod.js";
+    /// let a: A = new B();
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// import type { A } from "./mod.js";
+    /// import { type B, C } from "./mod.js";
+    /// let a: A;
+    /// let b: B = new C();
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `style` to choose how an import only used as types is fixed:
+    ///
+    /// - `"separated"` (default) turns it into an `import type`;
+    /// - `"inline"` adds an inline `type` to each of its named imports.
+    ///
+    /// Default imports and namespace imports cannot have an inline `type`,
+    /// so they are always turned into an `import type`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "style": "inline"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseImportType {
+        version: "next",
+        name: "useImportType",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useImportType`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ImportTypeOptions {
+    /// How an import only used as types is fixed.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub style: ImportTypeStyle,
+}
+
+impl ImportTypeOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["style"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ImportTypeOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ImportTypeOptions {}
+impl VisitNode<JsonLanguage> for ImportTypeOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "style" {
+            let mut style = ImportTypeStyle::default();
+            self.map_to_known_string(&value, name_text, &mut style, diagnostics)?;
+            self.style = style;
+        }
+        Some(())
+    }
+}
+
+/// Supported styles for imports only used as types.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum ImportTypeStyle {
+    /// `import type { A } from "mod"`
+    #[default]
+    Separated,
+    /// `import { type A } from "mod"`
+    Inline,
+}
+
+impl ImportTypeStyle {
+    pub const KNOWN_VALUES: &'static [&'static str] = &["separated", "inline"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ImportTypeStyle {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(ImportTypeStyle::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for ImportTypeStyle {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, Self::KNOWN_VALUES, diagnostics)?;
+        match node.inner_string_text().ok()?.text() {
+            "separated" => *self = Self::Separated,
+            "inline" => *self = Self::Inline,
+            _ => (),
+        }
+        Some(())
+    }
+}
+
+pub(crate) enum ImportTypeFix {
+    /// All the imported bindings are only used as types:
+    /// the import is turned into an `import type`.
+    ///
+    /// An import that has both a default import and named imports cannot be turned into an `import type`.
+    /// In this case, no code fix is suggested.
+    UseImportType,
+    /// The listed named imports are only used as types:
+    /// an inline `type` is added to each of them.
+    AddInlineType {
+        specifiers: Vec<AnyJsNamedImportSpecifier>,
+        /// Whether none of the imports is used as a value.
+        all_types: bool,
+    },
+}
+
+/// How an imported binding is used.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum BindingUsage {
+    Unused,
+    Type,
+    Value,
+}
+
+impl Rule for UseImportType {
+    type Query = Semantic<JsImport>;
+    type State = ImportTypeFix;
+    type Signals = Option<Self::State>;
+    type Options = ImportTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.model();
+        let mut has_value_usage = false;
+        let mut has_type_usage = false;
+        let mut type_only_specifiers = Vec::new();
+        let mut named_specifiers_only = false;
+        let mut visit = |binding: AnyJsBinding, specifier: Option<AnyJsNamedImportSpecifier>| {
+            match binding_usage(&binding, model) {
+                BindingUsage::Unused => {}
+                BindingUsage::Type => {
+                    has_type_usage = true;
+                    type_only_specifiers.extend(specifier);
+                }
+                BindingUsage::Value => has_value_usage = true,
+            }
+        };
+        match ctx.query().import_clause().ok()? {
+            AnyJsImportClause::JsImportBareClause(_) => return None,
+            AnyJsImportClause::JsImportDefaultClause(clause) => {
+                if clause.type_token().is_some() {
+                    return None;
+                }
+                visit(clause.local_name().ok()?, None);
+            }
+            AnyJsImportClause::JsImportNamespaceClause(clause) => {
+                if clause.type_token().is_some() {
+                    return None;
+                }
+                visit(clause.local_name().ok()?, None);
+            }
+            AnyJsImportClause::JsImportNamedClause(clause) => {
+                if clause.type_token().is_some() {
+                    return None;
+                }
+                if let Some(default_specifier) = clause.default_specifier() {
+                    visit(default_specifier.local_name().ok()?, None);
+                }
+                match clause.named_import().ok()? {
+                    AnyJsNamedImport::JsNamespaceImportSpecifier(specifier) => {
+                        visit(specifier.local_name().ok()?, None);
+                    }
+                    AnyJsNamedImport::JsNamedImportSpecifiers(specifiers) => {
+                        named_specifiers_only = clause.default_specifier().is_none();
+                        for specifier in specifiers.specifiers().iter() {
+                            let specifier = specifier.ok()?;
+                            let (type_token, local_name) = match &specifier {
+                                AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+                                    (specifier.type_token(), specifier.local_name().ok()?)
+                                }
+                                AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(
+                                    specifier,
+                                ) => (specifier.type_token(), specifier.local_name().ok()?),
+                                AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => {
+                                    return None
+                                }
+                            };
+                            // Named imports with an inline `type` are already only used as types
+                            if type_token.is_none() {
+                                visit(local_name, Some(specifier));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        if !has_type_usage {
+            return None;
+        }
+        if has_value_usage
+            || (named_specifiers_only && ctx.options().style == ImportTypeStyle::Inline)
+        {
+            // Default imports and namespace imports cannot have an inline `type`
+            (!type_only_specifiers.is_empty()).then_some(ImportTypeFix::AddInlineType {
+                specifiers: type_only_specifiers,
+                all_types: !has_value_usage,
+            })
+        } else {
+            Some(ImportTypeFix::UseImportType)
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            ImportTypeFix::UseImportType => {
+                let diagnostic = RuleDiagnostic::new(
+                    rule_category!(),
+                    ctx.query().range(),
+                    markup! {
+                        "All these imports are only used as types."
+                    },
+                );
+                if has_default_and_named_imports(ctx.query()) {
+                    diagnostic.note(markup! {
+                        "A type-only import can specify a default import or named imports, but not both. Split the import in two "<Emphasis>"import type"</Emphasis>"."
+                    })
+                } else {
+                    diagnostic
+                }
+            }
+            ImportTypeFix::AddInlineType {
+                specifiers,
+                all_types,
+            } => specifiers.iter().fold(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    ctx.query().range(),
+                    if *all_types {
+                        markup! {
+                            "All these imports are only used as types."
+                        }
+                    } else {
+                        markup! {
+                            "Some of these imports are only used as types."
+                        }
+                    },
+                ),
+                |diagnostic, specifier| {
+                    diagnostic.detail(
+                        specifier.range(),
+                        markup! { "This import is only used as a type." },
+                    )
+                },
+            ),
+        };
+        Some(diagnostic.note(markup! {
+            "Importing the types with "<Emphasis>"import type"</Emphasis>" ensures that they are removed by the transpilers and avoids loading unnecessary modules."
+        }))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let message = match state {
+            ImportTypeFix::UseImportType => {
+                let clause = ctx.query().import_clause().ok()?;
+                let new_clause = match clause.clone() {
+                    AnyJsImportClause::JsImportBareClause(_) => return None,
+                    AnyJsImportClause::JsImportDefaultClause(clause) => {
+                        clause.with_type_token(Some(type_token())).into()
+                    }
+                    AnyJsImportClause::JsImportNamespaceClause(clause) => {
+                        clause.with_type_token(Some(type_token())).into()
+                    }
+                    AnyJsImportClause::JsImportNamedClause(clause) => {
+                        if clause.default_specifier().is_some() {
+                            return None;
+                        }
+                        let named_import = match clause.named_import().ok()? {
+                            AnyJsNamedImport::JsNamedImportSpecifiers(specifiers) => {
+                                // `import type { type A }` is invalid
+                                let list = specifiers.specifiers();
+                                let new_list = make::js_named_import_specifier_list(
+                                    list.iter()
+                                        .map(|specifier| without_inline_type(specifier.ok()?))
+                                        .collect::<Option<Vec<_>>>()?,
+                                    list.separators().collect::<Result<Vec<_>, _>>().ok()?,
+                                );
+                                specifiers.with_specifiers(new_list).into()
+                            }
+                            named_import => named_import,
+                        };
+                        clause
+                            .with_type_token(Some(type_token()))
+                            .with_named_import(named_import)
+                            .into()
+                    }
+                };
+                mutation.replace_node(clause, new_clause);
+                markup! { "Use "<Emphasis>"import type"</Emphasis>"." }
+            }
+            ImportTypeFix::AddInlineType { specifiers, .. } => {
+                for specifier in specifiers {
+                    mutation.replace_node(specifier.clone(), with_inline_type(specifier.clone())?);
+                }
+                markup! { "Add inline "<Emphasis>"type"</Emphasis>" keywords." }
+            }
+        };
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: message.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `true` if `import` has a default import and named or namespace imports,
+/// such as `import A, { B } from "mod"`.
+fn has_default_and_named_imports(import: &JsImport) -> bool {
+    matches!(
+        import.import_clause(),
+        Ok(AnyJsImportClause::JsImportNamedClause(clause)) if clause.default_specifier().is_some()
+    )
+}
+
+/// Returns how `binding` is used.
+///
+/// Exported bindings and bindings used in both type and value positions are considered used as values.
+fn binding_usage(binding: &AnyJsBinding, model: &SemanticModel) -> BindingUsage {
+    let Some(binding) = binding.as_js_identifier_binding() else {
+        return BindingUsage::Value;
+    };
+    let mut usage = BindingUsage::Unused;
+    for reference in binding.all_references(model) {
+        if !is_in_type_position(reference.syntax()) {
+            return BindingUsage::Value;
+        }
+        usage = BindingUsage::Type;
+    }
+    usage
+}
+
+/// Returns `true` if `reference` is a type reference such as `A` in `let a: A`,
+/// `A.B` in `let b: A.B`, or `typeof A` in `let a: typeof A`.
+pub(crate) fn is_in_type_position(reference: &JsSyntaxNode) -> bool {
+    reference
+        .ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.kind() != JsSyntaxKind::TS_QUALIFIED_NAME)
+        .is_some_and(|ancestor| AnyTsType::can_cast(ancestor.kind()))
+}
+
+pub(crate) fn type_token() -> JsSyntaxToken {
+    make::token(T![type]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+}
+
+/// Adds an inline `type` to `specifier`, keeping its leading trivia before `type`.
+fn with_inline_type(specifier: AnyJsNamedImportSpecifier) -> Option<AnyJsNamedImportSpecifier> {
+    let leading_trivia = specifier.syntax().first_leading_trivia()?;
+    let type_token = type_token().with_leading_trivia_pieces(leading_trivia.pieces());
+    Some(match specifier.with_leading_trivia_pieces([])? {
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+            specifier.with_type_token(Some(type_token)).into()
+        }
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+            specifier.with_type_token(Some(type_token)).into()
+        }
+        specifier => specifier,
+    })
+}
+
+/// Removes the inline `type` of `specifier`, keeping its leading trivia.
+pub(crate) fn without_inline_type(
+    specifier: AnyJsNamedImportSpecifier,
+) -> Option<AnyJsNamedImportSpecifier> {
+    let type_token = match &specifier {
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => specifier.type_token(),
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+            specifier.type_token()
+        }
+        AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => None,
+    };
+    let Some(type_token) = type_token else {
+        return Some(specifier);
+    };
+    let specifier: AnyJsNamedImportSpecifier = match specifier {
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+            specifier.with_type_token(None).into()
+        }
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+            specifier.with_type_token(None).into()
+        }
+        specifier => specifier,
+    };
+    specifier.with_leading_trivia_pieces(type_token.leading_trivia().pieces())
+}
@@ -0,0 +1,169 @@
+use crate::{semantic_services::Semantic, utils::AnyJsModuleRequest, JsRuleAction};
+use bpaf::Bpaf;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_syntax::{inner_string_text, JsSyntaxKind, JsSyntaxToken};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{BatchMutationExt, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Promote the use of `node:assert/strict` over `node:assert`.
+    ///
+    /// The assertion functions of the legacy mode of `node:assert`, such as `assert.equal` and `assert.deepEqual`,
+    /// use the loose equality `==`.
+    /// The strict mode exported by `node:assert/strict` uses strict equality
+    /// and reports more useful error messages.
+    ///
+    /// The rule reports the imports and the `require` calls of `assert` and `node:assert`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import assert from "assert";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import * as assert from "node:assert";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const assert = require("assert");
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import assert from "node:assert/strict";
+    /// const { strictEqual } = require("node:assert/strict");
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `ignoreNodeProtocol` to only report the bare module `"assert"`
+    /// and to allow `"node:assert"`.
+    /// Its default value is `false`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "ignoreNodeProtocol": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseNodeAssertStrict {
+        version: "next",
+        name: "useNodeAssertStrict",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useNodeAssertStrict`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NodeAssertStrictOptions {
+    /// Whether the module `"node:assert"` is allowed.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub ignore_node_protocol: bool,
+}
+
+impl NodeAssertStrictOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["ignoreNodeProtocol"];
+}
+
+// Required by [Bpaf].
+impl FromStr for NodeAssertStrictOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for NodeAssertStrictOptions {}
+impl VisitNode<JsonLanguage> for NodeAssertStrictOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "ignoreNodeProtocol" {
+            self.ignore_node_protocol = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
+impl Rule for UseNodeAssertStrict {
+    type Query = Semantic<AnyJsModuleRequest>;
+    /// The string literal of the module specifier.
+    type State = JsSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = NodeAssertStrictOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let specifier = ctx.query().specifier(ctx.model())?;
+        match inner_string_text(&specifier).text() {
+            "assert" => Some(specifier),
+            "node:assert" if !ctx.options().ignore_node_protocol => Some(specifier),
+            _ => None,
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, specifier: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                specifier.text_trimmed_range(),
+                markup! {
+                    "Use "<Emphasis>"node:assert/strict"</Emphasis>" instead of "<Emphasis>{inner_string_text(specifier).text()}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The legacy assertion functions use the loose equality "<Emphasis>"=="</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, specifier: &Self::State) -> Option<JsRuleAction> {
+        // Preserve the quotes of the original string
+        let quote = specifier.text_trimmed().chars().next()?;
+        let new_specifier = JsSyntaxToken::new_detached(
+            JsSyntaxKind::JS_STRING_LITERAL,
+            &format!("{quote}node:assert/strict{quote}"),
+            [],
+            [],
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(specifier.clone(), new_specifier);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use "<Emphasis>"node:assert/strict"</Emphasis>" instead." }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
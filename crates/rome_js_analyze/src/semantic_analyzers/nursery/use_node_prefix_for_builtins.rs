@@ -0,0 +1,199 @@
+use crate::{
+    semantic_services::Semantic,
+    utils::{AnyJsModuleRequest, NODE_BUILTIN_MODULES},
+    JsRuleAction,
+};
+use bpaf::Bpaf;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_syntax::{inner_string_text, JsSyntaxKind, JsSyntaxToken};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{BatchMutationExt, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+declare_rule! {
+    /// Enforce the use of the `node:` protocol for Node.js built-in modules.
+    ///
+    /// The `node:` protocol makes it clear that the imported module is a Node.js built-in module
+    /// and not a package installed in `node_modules`.
+    ///
+    /// Some built-in modules, such as `buffer` or `events`, share their name with a popular npm package
+    /// that polyfills them for the browsers.
+    /// They are ignored by default, because importing them without the `node:` protocol may be intended.
+    ///
+    /// Source: https://github.com/sindresorhus/eslint-plugin-unicorn/blob/main/docs/rules/prefer-node-protocol.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import fs from "fs";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// import { readFile } from "fs/promises";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const path = require("path");
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import fs from "node:fs";
+    /// import { EventEmitter } from "events";
+    /// const path = require("node:path");
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `checkAmbiguousModules` to also report the built-in modules
+    /// that share their name with a popular npm package.
+    /// Its default value is `false`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "checkAmbiguousModules": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) UseNodePrefixForBuiltins {
+        version: "next",
+        name: "useNodePrefixForBuiltins",
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useNodePrefixForBuiltins`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NodePrefixForBuiltinsOptions {
+    /// Whether the built-in modules that share their name with a popular npm package are reported.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub check_ambiguous_modules: bool,
+}
+
+impl NodePrefixForBuiltinsOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["checkAmbiguousModules"];
+}
+
+// Required by [Bpaf].
+impl FromStr for NodePrefixForBuiltinsOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for NodePrefixForBuiltinsOptions {}
+impl VisitNode<JsonLanguage> for NodePrefixForBuiltinsOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "checkAmbiguousModules" {
+            self.check_ambiguous_modules = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
+/// Built-in modules that share their name with a popular npm package.
+const AMBIGUOUS_MODULES: &[&str] = &[
+    "assert",
+    "buffer",
+    "events",
+    "process",
+    "punycode",
+    "querystring",
+    "string_decoder",
+    "url",
+    "util",
+];
+
+impl Rule for UseNodePrefixForBuiltins {
+    type Query = Semantic<AnyJsModuleRequest>;
+    /// The string literal of the module specifier.
+    type State = JsSyntaxToken;
+    type Signals = Option<Self::State>;
+    type Options = NodePrefixForBuiltinsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let specifier = ctx.query().specifier(ctx.model())?;
+        let module_name = inner_string_text(&specifier);
+        let module_name = module_name.text();
+        let is_reported = NODE_BUILTIN_MODULES.binary_search(&module_name).is_ok()
+            && (ctx.options().check_ambiguous_modules
+                || AMBIGUOUS_MODULES.binary_search(&module_name).is_err());
+        is_reported.then_some(specifier)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, specifier: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                specifier.text_trimmed_range(),
+                markup! {
+                    "A Node.js built-in module should be imported with the "<Emphasis>"node:"</Emphasis>" protocol."
+                },
+            )
+            .note(markup! {
+                "The protocol distinguishes the built-in modules from the packages installed in "<Emphasis>"node_modules"</Emphasis>"."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, specifier: &Self::State) -> Option<JsRuleAction> {
+        // Preserve the quotes of the original string
+        let quote = specifier.text_trimmed().chars().next()?;
+        let new_specifier = JsSyntaxToken::new_detached(
+            JsSyntaxKind::JS_STRING_LITERAL,
+            &format!("{quote}node:{}{quote}", inner_string_text(specifier).text()),
+            [],
+            [],
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(specifier.clone(), new_specifier);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: markup! { "Add the "<Emphasis>"node:"</Emphasis>" protocol." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+#[test]
+fn test_order() {
+    for items in NODE_BUILTIN_MODULES.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
+    for items in AMBIGUOUS_MODULES.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
+}
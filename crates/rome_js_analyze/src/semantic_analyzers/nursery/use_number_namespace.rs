@@ -0,0 +1,155 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    global_identifier, static_value::StaticValue, AnyJsExpression, JsCallExpression, T,
+};
+use rome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Use the `Number` properties instead of the global ones.
+    ///
+    /// ES2015 moved some globals into the `Number` namespace for consistency:
+    /// `parseInt`, `parseFloat`, `isNaN`, and `isFinite`.
+    ///
+    /// `Number.parseInt` and `Number.parseFloat` are the same functions as their global counterparts,
+    /// so the rule provides a code fix for them.
+    /// However, `Number.isNaN` and `Number.isFinite` don't coerce their argument to a number,
+    /// unlike the global `isNaN` and `isFinite`.
+    /// Thus, the rule doesn't provide a code fix for them.
+    ///
+    /// Source: https://github.com/sindresorhus/eslint-plugin-unicorn/blob/main/docs/rules/prefer-number-properties.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// parseInt("1");
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// parseFloat("1.1");
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// isNaN(value);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// isFinite(value);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// Number.parseInt("1");
+    /// Number.parseFloat("1.1");
+    /// Number.isNaN(value);
+    /// Number.isFinite(value);
+    /// ```
+    ///
+    pub(crate) UseNumberNamespace {
+        version: "next",
+        name: "useNumberNamespace",
+        recommended: false,
+    }
+}
+
+impl Rule for UseNumberNamespace {
+    type Query = Semantic<JsCallExpression>;
+    type State = (AnyJsExpression, StaticValue);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let callee = ctx.query().callee().ok()?.omit_parentheses();
+        let (reference, name) = global_identifier(&callee)?;
+        if !matches!(
+            name.text(),
+            "parseInt" | "parseFloat" | "isNaN" | "isFinite"
+        ) {
+            return None;
+        }
+        ctx.model()
+            .binding(&reference)
+            .is_none()
+            .then_some((callee, name))
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, (callee, name): &Self::State) -> Option<RuleDiagnostic> {
+        let name = name.text();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            callee.range(),
+            markup! {
+                "Use "<Emphasis>"Number."{name}</Emphasis>" instead of the global "<Emphasis>{name}</Emphasis>"."
+            },
+        );
+        Some(if matches!(name, "isNaN" | "isFinite") {
+            diagnostic.note(markup! {
+                "Unlike the global "<Emphasis>{name}</Emphasis>", "<Emphasis>"Number."{name}</Emphasis>" doesn't coerce its argument to a number."
+            })
+        } else {
+            diagnostic
+        })
+    }
+
+    fn action(ctx: &RuleContext<Self>, (callee, name): &Self::State) -> Option<JsRuleAction> {
+        let name = name.text();
+        if !matches!(name, "parseInt" | "parseFloat") {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        let (old, new) = match callee {
+            AnyJsExpression::JsIdentifierExpression(_) => (
+                callee.clone(),
+                make::js_static_member_expression(
+                    make::js_identifier_expression(make::js_reference_identifier(make::ident(
+                        "Number",
+                    )))
+                    .into(),
+                    make::token(T![.]),
+                    make::js_name(make::ident(name)).into(),
+                ),
+            ),
+            AnyJsExpression::JsStaticMemberExpression(expression) => (
+                callee.clone(),
+                make::js_static_member_expression(
+                    make::js_static_member_expression(
+                        expression.object().ok()?,
+                        make::token(T![.]),
+                        make::js_name(make::ident("Number")).into(),
+                    )
+                    .into(),
+                    expression.operator_token().ok()?,
+                    expression.member().ok()?,
+                ),
+            ),
+            AnyJsExpression::JsComputedMemberExpression(expression) => {
+                let object = expression.object().ok()?;
+                (
+                    object.clone(),
+                    make::js_static_member_expression(
+                        object,
+                        make::token(T![.]),
+                        make::js_name(make::ident("Number")).into(),
+                    ),
+                )
+            }
+            _ => return None,
+        };
+        mutation.replace_node(old, new.into());
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::Always,
+            message: markup! {
+                "Use "<Emphasis>"Number."{name}</Emphasis>" instead."
+            }
+            .to_owned(),
+            mutation,
+        })
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{semantic_services::Semantic, utils::has_inner_comments, JsRuleAction};
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    global_identifier, AnyJsCallArgument, AnyJsExpression, AnyJsMemberExpression,
+    AnyJsObjectMember, AnyJsObjectMemberName, JsArrowFunctionExpression, JsCallExpression,
+    JsExpressionStatement, JsObjectExpression, JsSyntaxNode, TriviaPieceKind, T,
+};
+use rome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Prefer object spread over `Object.assign()` when constructing a new object.
+    ///
+    /// Calling `Object.assign()` with an empty object literal as first argument
+    /// creates a new object from the other arguments.
+    /// The object spread syntax is more concise and easier to read.
+    ///
+    /// The code fix spreads the arguments in a new object literal.
+    /// The properties of object literal arguments are directly inlined.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/prefer-object-spread
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const merged = Object.assign({}, a, b);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const withDefaults = Object.assign({}, { enabled: true }, options);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const merged = { ...a, ...b };
+    /// Object.assign(target, source);
+    /// Object.assign({}, ...sources);
+    /// ```
+    ///
+    pub(crate) UseObjectSpread {
+        version: "next",
+        name: "useObjectSpread",
+        recommended: false,
+    }
+}
+
+impl Rule for UseObjectSpread {
+    type Query = Semantic<JsCallExpression>;
+    /// The arguments to merge, without the first empty object literal.
+    type State = Vec<AnyJsExpression>;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.is_optional_chain() || node.type_arguments().is_some() {
+            return None;
+        }
+        let callee = node.callee().ok()?.omit_parentheses();
+        let member = AnyJsMemberExpression::cast_ref(callee.syntax())?;
+        if member.member_name()?.text() != "assign" {
+            return None;
+        }
+        let (reference, name) = global_identifier(&member.object().ok()?.omit_parentheses())?;
+        if name.text() != "Object" || ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        let mut arguments = node.arguments().ok()?.args().iter();
+        let AnyJsCallArgument::AnyJsExpression(AnyJsExpression::JsObjectExpression(target)) =
+            arguments.next()?.ok()?
+        else {
+            return None;
+        };
+        if !target.members().is_empty() {
+            return None;
+        }
+        arguments
+            .map(|argument| match argument.ok()? {
+                AnyJsCallArgument::AnyJsExpression(expression) => Some(expression),
+                AnyJsCallArgument::JsSpread(_) => None,
+            })
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Use an object spread instead of "<Emphasis>"Object.assign()"</Emphasis>" to create a new object."
+                },
+            )
+            .note(markup! {
+                "The object spread syntax is more concise and easier to read."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, arguments: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        // Don't lose the comments inside the call
+        if has_inner_comments(node.syntax()) {
+            return None;
+        }
+        let mut members = Vec::new();
+        for argument in arguments {
+            match inlinable_members(argument) {
+                Some(object) => {
+                    for member in object.members() {
+                        members.push(member.ok()?.trim()?);
+                    }
+                }
+                None => members
+                    .push(make::js_spread(make::token(T![...]), argument.clone().trim()?).into()),
+            }
+        }
+        let separator_count = members.len().saturating_sub(1);
+        let (l_curly, r_curly) = if members.is_empty() {
+            (make::token(T!['{']), make::token(T!['}']))
+        } else {
+            (
+                make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            )
+        };
+        let object = make::js_object_expression(
+            l_curly,
+            make::js_object_member_list(
+                members,
+                (0..separator_count).map(|_| {
+                    make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+                }),
+            ),
+            r_curly,
+        );
+        let object: AnyJsExpression = if is_at_statement_or_arrow_body_start(node.syntax()) {
+            make::js_parenthesized_expression(
+                make::token(T!['(']),
+                object.into(),
+                make::token(T![')']),
+            )
+            .into()
+        } else {
+            object.into()
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(node.clone()), object);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use an object spread instead." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns `expr` if it is an object literal whose members can be inlined in another object literal.
+///
+/// Getters, setters, and `__proto__` properties don't behave the same once inlined.
+fn inlinable_members(expr: &AnyJsExpression) -> Option<JsObjectExpression> {
+    let AnyJsExpression::JsObjectExpression(object) = expr.clone().omit_parentheses() else {
+        return None;
+    };
+    object
+        .members()
+        .iter()
+        .all(|member| match member {
+            Ok(AnyJsObjectMember::JsPropertyObjectMember(property)) => match property.name() {
+                Ok(AnyJsObjectMemberName::JsComputedMemberName(_)) => true,
+                Ok(AnyJsObjectMemberName::JsLiteralMemberName(name)) => {
+                    name.name().is_ok_and(|name| name.text() != "__proto__")
+                }
+                Err(_) => false,
+            },
+            Ok(
+                AnyJsObjectMember::JsShorthandPropertyObjectMember(_)
+                | AnyJsObjectMember::JsMethodObjectMember(_)
+                | AnyJsObjectMember::JsSpread(_),
+            ) => true,
+            _ => false,
+        })
+        .then_some(object)
+}
+
+/// Returns `true` if an object literal that replaces `node` would be parsed as a block.
+fn is_at_statement_or_arrow_body_start(node: &JsSyntaxNode) -> bool {
+    let mut current = node.clone();
+    while let Some(parent) = current.parent() {
+        if JsExpressionStatement::can_cast(parent.kind()) {
+            return true;
+        }
+        if let Some(arrow) = JsArrowFunctionExpression::cast_ref(&parent) {
+            return arrow.body().is_ok_and(|body| body.syntax() == &current);
+        }
+        if !AnyJsExpression::can_cast(parent.kind())
+            || parent.first_token() != current.first_token()
+        {
+            return false;
+        }
+        current = parent;
+    }
+    false
+}
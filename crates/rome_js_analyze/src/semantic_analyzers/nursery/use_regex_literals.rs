@@ -0,0 +1,186 @@
+use crate::semantic_services::Semantic;
+use crate::utils::escape_string;
+use crate::JsRuleAction;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    global_identifier, AnyJsCallArgument, AnyJsExpression, AnyJsLiteralExpression, JsCallArguments,
+    JsCallExpression, JsNewExpression, JsSyntaxKind, JsSyntaxToken,
+};
+use rome_rowan::{declare_node_union, AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Enforce the use of regular expression literals instead of the `RegExp` constructor when possible.
+    ///
+    /// A regular expression literal is shorter than a call to `RegExp` with a constant pattern,
+    /// it doesn't need the escapes of a string literal, and its syntax is checked when the code is parsed.
+    ///
+    /// The rule reports the calls to the global `RegExp`, with or without `new`,
+    /// whose pattern and flags are string literals.
+    ///
+    /// The code fix creates the equivalent regular expression literal:
+    /// the escape sequences of the strings are interpreted, and the slashes of the pattern are escaped.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/prefer-regex-literals
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// new RegExp("abc");
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// RegExp("\\d+", "g");
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /abc/;
+    /// new RegExp(pattern);
+    /// new RegExp("abc", flags);
+    /// new RegExp(`^${prefix}`);
+    /// ```
+    ///
+    pub(crate) UseRegexLiterals {
+        version: "next",
+        name: "useRegexLiterals",
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub(crate) AnyRegExpCreation = JsCallExpression | JsNewExpression
+}
+
+impl Rule for UseRegexLiterals {
+    type Query = Semantic<AnyRegExpCreation>;
+    /// The text of the regular expression literal.
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let (callee, arguments) = match ctx.query() {
+            AnyRegExpCreation::JsCallExpression(call) => {
+                (call.callee().ok()?, call.arguments().ok()?)
+            }
+            AnyRegExpCreation::JsNewExpression(call) => (call.callee().ok()?, call.arguments()?),
+        };
+        let (reference, name) = global_identifier(&callee.omit_parentheses())?;
+        if name.text() != "RegExp" || ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        create_regex_literal(&arguments)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Use a regular expression literal instead of the "<Emphasis>"RegExp"</Emphasis>" constructor."
+                },
+            )
+            .note(markup! {
+                "A regular expression literal doesn't need the escapes of a string, and its syntax is checked when the code is parsed."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, regex: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let old_expression = match node {
+            AnyRegExpCreation::JsCallExpression(call) => AnyJsExpression::from(call.clone()),
+            AnyRegExpCreation::JsNewExpression(call) => AnyJsExpression::from(call.clone()),
+        };
+        let new_expression = AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsRegexLiteralExpression(make::js_regex_literal_expression(
+                JsSyntaxToken::new_detached(JsSyntaxKind::JS_REGEX_LITERAL, regex, [], []),
+            )),
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(old_expression, new_expression);
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Use a regular expression literal." }.to_owned(),
+            mutation,
+        })
+    }
+}
+
+/// Returns the regular expression literal equivalent to the arguments,
+/// if they are a string literal pattern and optional string literal flags.
+fn create_regex_literal(arguments: &JsCallArguments) -> Option<String> {
+    let arguments = arguments.args();
+    if arguments.is_empty() || arguments.len() > 2 {
+        return None;
+    }
+    let mut strings = arguments.iter().map(|argument| {
+        let AnyJsCallArgument::AnyJsExpression(AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsStringLiteralExpression(string),
+        )) = argument.ok()?
+        else {
+            return None;
+        };
+        escape_string(string.inner_string_text().ok()?.text()).ok()
+    });
+    let pattern = strings.next()??;
+    let flags = match strings.next() {
+        Some(flags) => flags?,
+        None => String::new(),
+    };
+    let is_valid_flags = flags.char_indices().all(|(index, flag)| {
+        matches!(flag, 'd' | 'g' | 'i' | 'm' | 's' | 'u' | 'v' | 'y')
+            && !flags[..index].contains(flag)
+    });
+    if !is_valid_flags {
+        return None;
+    }
+    if pattern.is_empty() {
+        // `//` is a comment
+        return Some(format!("/(?:)/{flags}"));
+    }
+    let mut regex = String::with_capacity(pattern.len() + flags.len() + 2);
+    regex.push('/');
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                // A pattern can't end with a backslash,
+                // and a regex literal can't contain an escaped line terminator
+                let escaped = chars
+                    .next()
+                    .filter(|escaped| !is_line_terminator(*escaped))?;
+                regex.push(c);
+                regex.push(escaped);
+            }
+            '/' => regex.push_str("\\/"),
+            _ => push_regex_char(&mut regex, c),
+        }
+    }
+    regex.push('/');
+    regex.push_str(&flags);
+    Some(regex)
+}
+
+/// Pushes `c` to `regex`, escaping the line terminators that a regex literal can't contain.
+fn push_regex_char(regex: &mut String, c: char) {
+    match c {
+        '\n' => regex.push_str("\\n"),
+        '\r' => regex.push_str("\\r"),
+        '\u{2028}' => regex.push_str("\\u2028"),
+        '\u{2029}' => regex.push_str("\\u2029"),
+        _ => regex.push(c),
+    }
+}
+
+fn is_line_terminator(c: char) -> bool {
+    matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}')
+}
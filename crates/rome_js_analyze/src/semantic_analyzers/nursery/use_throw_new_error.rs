@@ -0,0 +1,124 @@
+use crate::{semantic_services::Semantic, JsRuleAction};
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{
+    global_identifier, AnyJsExpression, JsCallExpression, JsThrowStatement, TriviaPieceKind, T,
+};
+use rome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Require `new` when throwing a built-in error.
+    ///
+    /// The built-in error constructors can be called without `new`:
+    /// `Error("message")` creates an error like `new Error("message")` does.
+    /// However, `new` makes it clear that an error object is created,
+    /// and is consistent with the way other errors are constructed.
+    ///
+    /// The rule reports thrown calls to the global `Error`, `AggregateError`, `EvalError`,
+    /// `RangeError`, `ReferenceError`, `SyntaxError`, `TypeError`, and `URIError`.
+    ///
+    /// Source: https://github.com/sindresorhus/eslint-plugin-unicorn/blob/main/docs/rules/throw-new-error.md
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// throw Error("Something failed");
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// throw TypeError("Expected a string");
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// throw new Error("Something failed");
+    /// ```
+    ///
+    /// ```js
+    /// throw createError("Something failed");
+    /// ```
+    ///
+    pub(crate) UseThrowNewError {
+        version: "next",
+        name: "useThrowNewError",
+        recommended: false,
+    }
+}
+
+const ERROR_CONSTRUCTORS: [&str; 8] = [
+    "AggregateError",
+    "Error",
+    "EvalError",
+    "RangeError",
+    "ReferenceError",
+    "SyntaxError",
+    "TypeError",
+    "URIError",
+];
+
+impl Rule for UseThrowNewError {
+    type Query = Semantic<JsThrowStatement>;
+    /// The thrown call.
+    type State = JsCallExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let AnyJsExpression::JsCallExpression(call) = node.argument().ok()?.omit_parentheses()
+        else {
+            return None;
+        };
+        // `new` cannot be applied to an optional chain.
+        if call.is_optional_chain() {
+            return None;
+        }
+        let (reference, name) = global_identifier(&call.callee().ok()?.omit_parentheses())?;
+        if !ERROR_CONSTRUCTORS.contains(&name.text()) || ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        Some(call)
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, call: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                call.range(),
+                markup! {
+                    "Use "<Emphasis>"new"</Emphasis>" when throwing an error."
+                },
+            )
+            .note(markup! {
+                "Calling an error constructor with "<Emphasis>"new"</Emphasis>" makes it clear that an error object is created."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, call: &Self::State) -> Option<JsRuleAction> {
+        let mut new_expression = make::js_new_expression(
+            make::token(T![new]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            call.callee().ok()?.trim_start()?,
+        )
+        .with_arguments(call.arguments().ok()?);
+        if let Some(type_arguments) = call.type_arguments() {
+            new_expression = new_expression.with_type_arguments(type_arguments);
+        }
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsExpression::from(call.clone()),
+            new_expression.build().into(),
+        );
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            message: markup! { "Add "<Emphasis>"new"</Emphasis>"." }.to_owned(),
+            mutation,
+        })
+    }
+}
@@ -1,14 +1,26 @@
 use crate::react::ReactCreateElementCall;
 use crate::semantic_services::Semantic;
+use bpaf::Bpaf;
 use rome_analyze::context::RuleContext;
 use rome_analyze::{declare_rule, Rule, RuleDiagnostic};
 use rome_console::markup;
-use rome_js_syntax::{AnyJsxAttributeName, JsCallExpression, JsLiteralMemberName, JsxAttribute};
-use rome_rowan::{declare_node_union, AstNode};
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_js_syntax::{
+    AnyJsExpression, AnyJsObjectMember, AnyJsxAttributeName, AnyJsxAttributeValue,
+    JsCallExpression, JsLiteralMemberName, JsxAttribute,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{declare_node_union, AstNode, AstSeparatedList, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 declare_rule! {
     /// Prevent the usage of dangerous JSX props
     ///
+    /// The rule reports the `dangerouslySetInnerHTML` prop of the JSX elements,
+    /// and the `dangerouslySetInnerHTML` property of the props passed to `React.createElement`.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -25,6 +37,21 @@ declare_rule! {
     ///     dangerouslySetInnerHTML: { __html: 'child' }
     /// });
     /// ```
+    ///
+    /// ## Options
+    ///
+    /// Use the option `allowWithSanitizer` to allow the content that is passed to a known sanitizer,
+    /// such as `DOMPurify.sanitize(html)`.
+    /// The known sanitizers are `DOMPurify.sanitize`, `sanitize`, `sanitizeHtml` and `xss`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowWithSanitizer": true
+    ///     }
+    /// }
+    /// ```
     pub(crate) NoDangerouslySetInnerHtml {
         version: "1.0.0",
         name: "noDangerouslySetInnerHtml",
@@ -32,6 +59,59 @@ declare_rule! {
     }
 }
 
+/// Options for the rule `noDangerouslySetInnerHtml`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DangerouslySetInnerHtmlOptions {
+    /// Whether the content passed to a known sanitizer is allowed.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub allow_with_sanitizer: bool,
+}
+
+impl DangerouslySetInnerHtmlOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["allowWithSanitizer"];
+}
+
+// Required by [Bpaf].
+impl FromStr for DangerouslySetInnerHtmlOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for DangerouslySetInnerHtmlOptions {}
+impl VisitNode<JsonLanguage> for DangerouslySetInnerHtmlOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "allowWithSanitizer" {
+            self.allow_with_sanitizer = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
+/// The functions that sanitize HTML.
+const KNOWN_SANITIZERS: [&str; 4] = ["DOMPurify.sanitize", "sanitize", "sanitizeHtml", "xss"];
+
 declare_node_union! {
     pub(crate) AnyJsCreateElement = JsxAttribute | JsCallExpression
 }
@@ -45,17 +125,33 @@ impl Rule for NoDangerouslySetInnerHtml {
     type Query = Semantic<AnyJsCreateElement>;
     type State = NoDangerState;
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = DangerouslySetInnerHtmlOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let node = ctx.query();
         let model = ctx.model();
+        let allow_with_sanitizer = ctx.options().allow_with_sanitizer;
         match node {
             AnyJsCreateElement::JsxAttribute(jsx_attribute) => {
                 let name = jsx_attribute.name().ok()?;
                 match name {
                     AnyJsxAttributeName::JsxName(jsx_name) => {
                         if jsx_name.syntax().text_trimmed() == "dangerouslySetInnerHTML" {
+                            let value = jsx_attribute
+                                .initializer()
+                                .and_then(|initializer| initializer.value().ok());
+                            if allow_with_sanitizer
+                                && value.is_some_and(|value| match value {
+                                    AnyJsxAttributeValue::JsxExpressionAttributeValue(value) => {
+                                        value.expression().is_ok_and(|expression| {
+                                            is_sanitized_content(&expression)
+                                        })
+                                    }
+                                    _ => false,
+                                })
+                            {
+                                return None;
+                            }
                             return Some(NoDangerState::Attribute(jsx_attribute.clone()));
                         }
                     }
@@ -71,15 +167,30 @@ impl Rule for NoDangerouslySetInnerHtml {
                     // should be an object expression. We look for a member that has as name
                     // "dangerouslySetInnerHTML"
                     if let Some(props) = props {
-                        let members = props.members();
-                        for member in members {
-                            let member = member.ok()?;
-                            let property_member =
-                                member.as_js_property_object_member()?.name().ok()?;
-                            let name = property_member.as_js_literal_member_name()?;
-
-                            if name.syntax().text_trimmed() == "dangerouslySetInnerHTML" {
-                                return Some(NoDangerState::Property(name.clone()));
+                        for member in props.members().iter().flatten() {
+                            let AnyJsObjectMember::JsPropertyObjectMember(member) = member else {
+                                continue;
+                            };
+                            let Some(name) = member
+                                .name()
+                                .ok()
+                                .and_then(|name| name.as_js_literal_member_name().cloned())
+                            else {
+                                continue;
+                            };
+
+                            if name
+                                .name()
+                                .is_ok_and(|name| name == "dangerouslySetInnerHTML")
+                            {
+                                if allow_with_sanitizer
+                                    && member
+                                        .value()
+                                        .is_ok_and(|value| is_sanitized_content(&value))
+                                {
+                                    return None;
+                                }
+                                return Some(NoDangerState::Property(name));
                             }
                         }
                     }
@@ -112,3 +223,32 @@ impl Rule for NoDangerouslySetInnerHtml {
         Some(diagnostic)
     }
 }
+
+/// Checks if `expression` is an object `{ __html: sanitizer(...) }`,
+/// where `sanitizer` is a known sanitizer.
+fn is_sanitized_content(expression: &AnyJsExpression) -> bool {
+    let AnyJsExpression::JsObjectExpression(object) = expression.clone().omit_parentheses() else {
+        return false;
+    };
+    object.members().iter().flatten().any(|member| {
+        let AnyJsObjectMember::JsPropertyObjectMember(member) = member else {
+            return false;
+        };
+        let is_html = member
+            .name()
+            .ok()
+            .and_then(|name| name.as_js_literal_member_name()?.name().ok())
+            .is_some_and(|name| name == "__html");
+        is_html
+            && member.value().is_ok_and(|value| {
+                let AnyJsExpression::JsCallExpression(call) = value.omit_parentheses() else {
+                    return false;
+                };
+                call.callee().is_ok_and(|callee| {
+                    let callee = callee.omit_parentheses();
+                    let callee = callee.syntax().text_trimmed().to_string();
+                    KNOWN_SANITIZERS.contains(&callee.as_str())
+                })
+            })
+    })
+}
@@ -1,5 +1,6 @@
 use crate::react::{ReactApiCall, ReactCreateElementCall};
 use crate::semantic_services::Semantic;
+use crate::utils::is_rendered;
 use rome_analyze::context::RuleContext;
 use rome_analyze::{declare_rule, Rule, RuleDiagnostic};
 use rome_console::markup;
@@ -34,6 +35,15 @@ declare_rule! {
     /// ```js,expect_diagnostic
     /// React.createElement('div', { dangerouslySetInnerHTML: { __html: 'HTML' } }, 'children')
     /// ```
+    ///
+    /// ### Valid
+    ///
+    /// The whitespace that contains a line break is not rendered, so it doesn't count as children.
+    ///
+    /// ```jsx
+    /// <div dangerouslySetInnerHTML={{ __html: 'HTML' }}>
+    /// </div>
+    /// ```
     pub(crate) NoDangerouslySetInnerHtmlWithChildren {
         version: "1.0.0",
         name: "noDangerouslySetInnerHtmlWithChildren",
@@ -84,8 +94,9 @@ impl AnyJsCreateElement {
     fn has_children(&self, model: &SemanticModel) -> Option<JsSyntaxNode> {
         match self {
             AnyJsCreateElement::JsxElement(element) => {
-                if !element.children().is_empty() {
-                    Some(element.children().syntax().clone())
+                let children = element.children();
+                if children.iter().any(|child| is_rendered(&child)) {
+                    Some(children.syntax().clone())
                 } else {
                     None
                 }
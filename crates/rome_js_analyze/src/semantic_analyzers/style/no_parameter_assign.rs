@@ -1,9 +1,20 @@
 use crate::semantic_services::Semantic;
+use bpaf::Bpaf;
 use rome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
 use rome_console::markup;
-use rome_js_semantic::{AllBindingWriteReferencesIter, Reference, ReferencesExtensions};
-use rome_js_syntax::{AnyJsBinding, AnyJsBindingPattern, AnyJsFormalParameter, AnyJsParameter};
-use rome_rowan::AstNode;
+use rome_deserialize::json::{has_only_known_keys, with_only_known_variants, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_js_semantic::ReferencesExtensions;
+use rome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsExpression, AnyJsFormalParameter, AnyJsParameter,
+    JsComputedMemberAssignment, JsComputedMemberExpression, JsStaticMemberAssignment,
+    JsStaticMemberExpression, JsSyntaxKind, JsSyntaxNode, JsUnaryExpression, JsUnaryOperator,
+    TextRange,
+};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{AstNode, SyntaxNode};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 declare_rule! {
     /// Disallow reassigning `function` parameters.
@@ -14,9 +25,6 @@ declare_rule! {
     ///
     /// Source: https://eslint.org/docs/latest/rules/no-param-reassign
     ///
-    /// In contrast to the _ESLint_ rule, this rule cannot be configured to report
-    /// assignments to a property of a parameter.
-    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -55,6 +63,21 @@ declare_rule! {
     /// }
     /// ```
     ///
+    /// ## Options
+    ///
+    /// Use the option `props` to report the assignments to a property of a parameter,
+    /// such as `param.x = 1`, `param.x++`, or `delete param.x`.
+    /// Its default value is `"allow"`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "props": "deny"
+    ///     }
+    /// }
+    /// ```
+    ///
     pub(crate) NoParameterAssign {
         version: "1.0.0",
         name: "noParameterAssign",
@@ -62,43 +85,171 @@ declare_rule! {
     }
 }
 
+/// Options for the rule `noParameterAssign`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Eq, PartialEq, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ParameterAssignOptions {
+    /// Whether the assignments to a property of a parameter are allowed.
+    #[bpaf(hide)]
+    #[serde(default)]
+    pub props: PropertyAssignmentMode,
+}
+
+impl ParameterAssignOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["props"];
+}
+
+// Required by [Bpaf].
+impl FromStr for ParameterAssignOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for ParameterAssignOptions {}
+impl VisitNode<JsonLanguage> for ParameterAssignOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "props" {
+            let mut props = PropertyAssignmentMode::default();
+            self.map_to_known_string(&value, name_text, &mut props, diagnostics)?;
+            self.props = props;
+        }
+        Some(())
+    }
+}
+
+/// Whether the assignments to a property of a parameter are allowed.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum PropertyAssignmentMode {
+    /// `param.x = 1` is allowed.
+    #[default]
+    Allow,
+    /// `param.x = 1` is reported.
+    Deny,
+}
+
+impl PropertyAssignmentMode {
+    pub const KNOWN_VALUES: &'static [&'static str] = &["allow", "deny"];
+}
+
+// Required by [Bpaf].
+impl FromStr for PropertyAssignmentMode {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(PropertyAssignmentMode::default())
+    }
+}
+
+impl VisitNode<JsonLanguage> for PropertyAssignmentMode {
+    fn visit_member_value(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let node = with_only_known_variants(node, Self::KNOWN_VALUES, diagnostics)?;
+        match node.inner_string_text().ok()?.text() {
+            "allow" => *self = Self::Allow,
+            "deny" => *self = Self::Deny,
+            _ => (),
+        }
+        Some(())
+    }
+}
+
+pub(crate) enum ParameterAssignment {
+    /// The parameter is reassigned.
+    /// The range is the range of the assigned reference.
+    Reassignment(TextRange),
+    /// A property of the parameter is assigned or deleted.
+    /// The range is the range of the assigned property.
+    PropertyAssignment(TextRange),
+}
+
 impl Rule for NoParameterAssign {
     type Query = Semantic<AnyJsParameter>;
-    type State = Reference;
-    type Signals = AllBindingWriteReferencesIter;
-    type Options = ();
+    type State = ParameterAssignment;
+    type Signals = Vec<Self::State>;
+    type Options = ParameterAssignOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let param = ctx.query();
         let model = ctx.model();
-        if let Some(AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding))) =
+        let Some(AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding))) =
             binding_of(param)
-        {
-            return binding.all_writes(model);
-        }
-        // Empty iterator that conforms to `AllBindingWriteReferencesIter` type.
-        std::iter::successors(None, |_| None)
+        else {
+            return Vec::new();
+        };
+        let deny_props = ctx.options().props == PropertyAssignmentMode::Deny;
+        binding
+            .all_references(model)
+            .filter_map(|reference| {
+                if reference.is_write() {
+                    Some(ParameterAssignment::Reassignment(
+                        reference.syntax().text_trimmed_range(),
+                    ))
+                } else if deny_props {
+                    assigned_property(reference.syntax()).map(|property| {
+                        ParameterAssignment::PropertyAssignment(property.text_trimmed_range())
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect()
     }
 
-    fn diagnostic(ctx: &RuleContext<Self>, reference: &Self::State) -> Option<RuleDiagnostic> {
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
         let param = ctx.query();
-        Some(
-            RuleDiagnostic::new(
+        let diagnostic = match state {
+            ParameterAssignment::Reassignment(range) => RuleDiagnostic::new(
                 rule_category!(),
-                reference.syntax().text_trimmed_range(),
+                range,
                 markup! {
                     "Reassigning a "<Emphasis>"function parameter"</Emphasis>" is confusing."
                 },
-            )
-            .detail(
-                param.syntax().text_trimmed_range(),
+            ),
+            ParameterAssignment::PropertyAssignment(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
                 markup! {
-                    "The "<Emphasis>"parameter"</Emphasis>" is declared here:"
+                    "Assigning a property of a "<Emphasis>"function parameter"</Emphasis>" is confusing."
                 },
-            )
-            .note(markup! {
-                "Use a local variable instead."
-            }),
+            ),
+        };
+        Some(
+            diagnostic
+                .detail(
+                    param.syntax().text_trimmed_range(),
+                    markup! {
+                        "The "<Emphasis>"parameter"</Emphasis>" is declared here:"
+                    },
+                )
+                .note(markup! {
+                    "Use a local variable instead."
+                }),
         )
     }
 }
@@ -113,3 +264,36 @@ fn binding_of(param: &AnyJsParameter) -> Option<AnyJsBindingPattern> {
         AnyJsParameter::TsThisParameter(_) => None,
     }
 }
+
+/// Returns the assigned or deleted property if `reference` is the object of this property,
+/// such as `a` in `a.b.c = 0`, `a[b]++`, or `delete a.b`.
+fn assigned_property(reference: &JsSyntaxNode) -> Option<JsSyntaxNode> {
+    let mut object = reference.parent()?;
+    loop {
+        let parent = object.parent()?;
+        if let Some(member) = JsStaticMemberAssignment::cast_ref(&parent) {
+            return (member.object().ok()?.syntax() == &object).then_some(parent);
+        } else if let Some(member) = JsComputedMemberAssignment::cast_ref(&parent) {
+            return (member.object().ok()?.syntax() == &object).then_some(parent);
+        } else if let Some(member) = JsStaticMemberExpression::cast_ref(&parent) {
+            if member.object().ok()?.syntax() != &object {
+                return None;
+            }
+        } else if let Some(member) = JsComputedMemberExpression::cast_ref(&parent) {
+            if member.object().ok()?.syntax() != &object {
+                return None;
+            }
+        } else if let Some(unary) = JsUnaryExpression::cast_ref(&parent) {
+            // `delete a` is invalid in strict mode
+            let is_deleted_property = unary.operator().ok()? == JsUnaryOperator::Delete
+                && !matches!(
+                    AnyJsExpression::cast_ref(&object)?.omit_parentheses(),
+                    AnyJsExpression::JsIdentifierExpression(_)
+                );
+            return is_deleted_property.then_some(object);
+        } else if parent.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION {
+            return None;
+        }
+        object = parent;
+    }
+}
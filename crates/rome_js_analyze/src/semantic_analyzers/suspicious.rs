@@ -6,6 +6,7 @@ pub(crate) mod no_array_index_key;
 pub(crate) mod no_catch_assign;
 pub(crate) mod no_class_assign;
 pub(crate) mod no_console_log;
+pub(crate) mod no_double_equals;
 pub(crate) mod no_duplicate_parameters;
 pub(crate) mod no_function_assign;
 pub(crate) mod no_import_assign;
@@ -20,6 +21,7 @@ declare_group! {
             self :: no_catch_assign :: NoCatchAssign ,
             self :: no_class_assign :: NoClassAssign ,
             self :: no_console_log :: NoConsoleLog ,
+            self :: no_double_equals :: NoDoubleEquals ,
             self :: no_duplicate_parameters :: NoDuplicateParameters ,
             self :: no_function_assign :: NoFunctionAssign ,
             self :: no_import_assign :: NoImportAssign ,
@@ -0,0 +1,229 @@
+use bpaf::Bpaf;
+use rome_analyze::{context::RuleContext, declare_rule, ActionCategory, Rule, RuleDiagnostic};
+use rome_console::markup;
+use rome_deserialize::json::{has_only_known_keys, VisitJsonNode};
+use rome_deserialize::{DeserializationDiagnostic, VisitNode};
+use rome_diagnostics::Applicability;
+use rome_js_factory::make;
+use rome_js_syntax::{AnyJsExpression, AnyJsLiteralExpression, JsBinaryExpression, T};
+use rome_js_syntax::{JsSyntaxKind::*, JsSyntaxToken};
+use rome_json_syntax::JsonLanguage;
+use rome_rowan::{BatchMutationExt, SyntaxNode, SyntaxResult};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use crate::semantic_services::Semantic;
+use crate::JsRuleAction;
+
+declare_rule! {
+    /// Require the use of `===` and `!==`
+    ///
+    /// It is generally bad practice to use `==` for comparison instead of
+    /// `===`. Double operators will triger implicit [type coercion](https://developer.mozilla.org/en-US/docs/Glossary/Type_coercion)
+    /// and are thus not prefered. Using strict equality operators is almost
+    /// always best practice.
+    ///
+    /// Comparing against `null` with `==` or `!=` is a common idiom
+    /// to check for both `null` and `undefined`.
+    /// For these comparisons, the rule emits a softer diagnostic and doesn't suggest a code fix.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// foo == bar
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// foo == null
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// foo === bar
+    ///```
+    ///
+    /// ```js
+    /// foo === null || foo === undefined
+    ///```
+    ///
+    /// ## Options
+    ///
+    /// Set the option `ignoreNull` to `true` to allow the comparisons against `null`.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "ignoreNull": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub(crate) NoDoubleEquals {
+        version: "1.0.0",
+        name: "noDoubleEquals",
+        recommended: true,
+    }
+}
+
+/// Options for the rule `noDoubleEquals`.
+#[derive(Default, Deserialize, Serialize, Debug, Clone, Bpaf)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DoubleEqualsOptions {
+    /// Whether `==` and `!=` are allowed when comparing against `null`.
+    #[bpaf(hide)]
+    pub ignore_null: bool,
+}
+
+impl DoubleEqualsOptions {
+    pub const KNOWN_KEYS: &'static [&'static str] = &["ignoreNull"];
+}
+
+// Required by [Bpaf].
+impl FromStr for DoubleEqualsOptions {
+    type Err = &'static str;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        // WARNING: should not be used.
+        Ok(Self::default())
+    }
+}
+
+impl VisitJsonNode for DoubleEqualsOptions {}
+impl VisitNode<JsonLanguage> for DoubleEqualsOptions {
+    fn visit_member_name(
+        &mut self,
+        node: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        has_only_known_keys(node, Self::KNOWN_KEYS, diagnostics)
+    }
+
+    fn visit_map(
+        &mut self,
+        key: &SyntaxNode<JsonLanguage>,
+        value: &SyntaxNode<JsonLanguage>,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<()> {
+        let (name, value) = self.get_key_and_value(key, value, diagnostics)?;
+        let name_text = name.text();
+        if name_text == "ignoreNull" {
+            self.ignore_null = self.map_to_boolean(&value, name_text, diagnostics)?;
+        }
+        Some(())
+    }
+}
+
+pub(crate) enum DoubleEqualsState {
+    /// A comparison with the `==` or `!=` operator.
+    DoubleEquals(JsSyntaxToken),
+    /// A comparison with the `==` or `!=` operator where one of the operands is the `null` literal.
+    NullComparison(JsSyntaxToken),
+}
+
+impl DoubleEqualsState {
+    fn operator(&self) -> &JsSyntaxToken {
+        match self {
+            DoubleEqualsState::DoubleEquals(operator)
+            | DoubleEqualsState::NullComparison(operator) => operator,
+        }
+    }
+}
+
+impl Rule for NoDoubleEquals {
+    type Query = Semantic<JsBinaryExpression>;
+    type State = DoubleEqualsState;
+    type Signals = Option<Self::State>;
+    type Options = DoubleEqualsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+        let n = ctx.query();
+
+        let op = n.operator_token().ok()?;
+
+        if !matches!(op.kind(), EQ2 | NEQ) {
+            return None;
+        }
+
+        // TODO: Implement SyntaxResult helpers to make this cleaner
+        if is_null_literal(n.left()) || is_null_literal(n.right()) {
+            if ctx.options().ignore_null {
+                return None;
+            }
+            return Some(DoubleEqualsState::NullComparison(op));
+        }
+
+        Some(DoubleEqualsState::DoubleEquals(op))
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let op = state.operator();
+        let text_trimmed = op.text_trimmed();
+        let suggestion = if op.kind() == EQ2 { "===" } else { "!==" };
+        if let DoubleEqualsState::NullComparison(_) = state {
+            return Some(
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    op.text_trimmed_range(),
+                    markup! {
+                        "This comparison with "<Emphasis>{text_trimmed}</Emphasis>" matches both "<Emphasis>"null"</Emphasis>" and "<Emphasis>"undefined"</Emphasis>"."
+                    },
+                )
+                .note(markup! {
+                    "If this is intentional, you can ignore this diagnostic. Otherwise, use "<Emphasis>{suggestion}</Emphasis>" to only match "<Emphasis>"null"</Emphasis>"."
+                }),
+            );
+        }
+        let description = format!(
+            "Use {} instead of {}.\n{} is only allowed when comparing against `null`",
+            suggestion, text_trimmed, text_trimmed
+        );
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            op.text_trimmed_range(),
+            markup! {
+                "Use "<Emphasis>{suggestion}</Emphasis>" instead of "<Emphasis>{text_trimmed}</Emphasis>
+            },
+        )
+        .detail(op.text_trimmed_range(), markup! {
+            <Emphasis>{text_trimmed}</Emphasis>" is only allowed when comparing against "<Emphasis>"null"</Emphasis>
+        }).note(markup! {
+            "Using "<Emphasis>{suggestion}</Emphasis>" may be unsafe if you are relying on type coercion"
+        })
+        .description(description))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        // Comparing with `null` is likely intentional
+        let DoubleEqualsState::DoubleEquals(op) = state else {
+            return None;
+        };
+        let mut mutation = ctx.root().begin();
+
+        let suggestion = if op.kind() == EQ2 { T![===] } else { T![!==] };
+        mutation.replace_token(op.clone(), make::token(suggestion));
+
+        Some(JsRuleAction {
+            category: ActionCategory::QuickFix,
+            applicability: Applicability::MaybeIncorrect,
+            // SAFETY: `suggestion` can only be JsSyntaxKind::EQ3 or JsSyntaxKind::NEQ2,
+            // the implementation of `to_string` for these two variants always returns Some
+            message: markup! { "Use "<Emphasis>{suggestion.to_string().unwrap()}</Emphasis> }
+                .to_owned(),
+            mutation,
+        })
+    }
+}
+
+fn is_null_literal(res: SyntaxResult<AnyJsExpression>) -> bool {
+    matches!(
+        res,
+        Ok(AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsNullLiteralExpression(_)
+        ))
+    )
+}
@@ -1,12 +1,12 @@
 use rome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
 use rome_console::markup;
-use rome_js_syntax::parameter_ext::{AnyJsParameterList, AnyJsParameters, AnyParameter};
+use rome_js_syntax::parameter_ext::{AnyJsParameterList, AnyJsParameters};
 use rome_js_syntax::{
     AnyJsArrayBindingPatternElement, AnyJsBinding, AnyJsBindingPattern,
-    AnyJsObjectBindingPatternMember, JsIdentifierBinding,
+    AnyJsObjectBindingPatternMember, JsIdentifierBinding, TextRange,
 };
 use rome_rowan::AstNode;
-use rustc_hash::FxHashSet;
+use rustc_hash::FxHashMap;
 
 declare_rule! {
     ///  Disallow duplicate function parameter name.
@@ -15,6 +15,8 @@ declare_rule! {
     /// the last occurrence overrides the preceding occurrences.
     /// A duplicated name might be a typing error.
     ///
+    /// Parameters that are destructured, have a default value, or are rest parameters are also checked.
+    ///
     /// Source: https://eslint.org/docs/latest/rules/no-dupe-args
     ///
     /// ## Examples
@@ -29,6 +31,10 @@ declare_rule! {
     /// function b(a, b, b) {}
     /// ```
     ///
+    /// ```js,expect_diagnostic
+    /// const c = ({ a }, a) => {}
+    /// ```
+    ///
     /// ### Valid
     ///
     /// ```js
@@ -47,11 +53,12 @@ declare_rule! {
 
 impl Rule for NoDuplicateParameters {
     type Query = Ast<AnyJsParameters>;
-    type State = JsIdentifierBinding;
-    type Signals = Option<Self::State>;
+    /// A duplicated binding and the range of the first binding with the same name.
+    type State = (JsIdentifierBinding, TextRange);
+    type Signals = Vec<Self::State>;
     type Options = ();
 
-    fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
         let parameters = ctx.query();
 
         let list = match parameters {
@@ -63,126 +70,97 @@ impl Rule for NoDuplicateParameters {
             }
         };
 
-        let mut set = FxHashSet::default();
-        // Traversing the parameters of the function in preorder and checking for duplicates,
-        list.iter().find_map(|parameter| {
-            let parameter = parameter.ok()?;
-            traverse_parameter(parameter, &mut set)
-        })
+        // Collecting the bindings of the parameters of the function in preorder
+        let mut bindings = Vec::new();
+        for parameter in list.iter().flatten() {
+            if let Some(binding) = parameter.binding() {
+                collect_bindings(binding, &mut bindings);
+            }
+        }
+
+        let mut first_bindings = FxHashMap::default();
+        bindings
+            .into_iter()
+            .filter_map(|binding| {
+                let range = binding.range();
+                match first_bindings.get(&binding.text()) {
+                    Some(first_range) => Some((binding, *first_range)),
+                    None => {
+                        first_bindings.insert(binding.text(), range);
+                        None
+                    }
+                }
+            })
+            .collect()
     }
 
-    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
-        let binding_syntax_node = state;
+    fn diagnostic(
+        _: &RuleContext<Self>,
+        (binding, first_range): &Self::State,
+    ) -> Option<RuleDiagnostic> {
         Some(
             RuleDiagnostic::new(
                 rule_category!(),
-                binding_syntax_node.syntax().text_trimmed_range(),
+                binding.syntax().text_trimmed_range(),
                 markup! {
                     "Duplicate parameter name."
                 },
             )
+            .detail(first_range, "The parameter is first declared here.")
             .note("The parameter overrides a preceding parameter by using the same name."),
         )
     }
 }
 
-/// Traverse the parameter recursively and check if it is duplicated.
-/// Return `Some(JsIdentifierBinding)` if it is duplicated.
-fn traverse_parameter(
-    parameter: AnyParameter,
-    tracked_bindings: &mut FxHashSet<String>,
-) -> Option<JsIdentifierBinding> {
-    parameter
-        .binding()
-        .and_then(|binding| traverse_binding(binding, tracked_bindings))
-}
-
-/// Traverse a [JsAnyBindingPattern] in preorder and check if the name of [JsIdentifierBinding] has seem before.
-/// If true then add the [JsIdentifierBinding] to the [duplicated_arguments].
-/// If false then add the [JsIdentifierBinding] to the [tracked_bindings], mark it name as seen.
-/// If it is not a [JsIdentifierBinding] then recursively call [traverse_binding] on its children.
-fn traverse_binding(
-    binding: AnyJsBindingPattern,
-    tracked_bindings: &mut FxHashSet<String>,
-) -> Option<JsIdentifierBinding> {
+/// Collect the [JsIdentifierBinding]s of a [AnyJsBindingPattern] in preorder.
+fn collect_bindings(binding: AnyJsBindingPattern, bindings: &mut Vec<JsIdentifierBinding>) {
     match binding {
         AnyJsBindingPattern::AnyJsBinding(inner_binding) => match inner_binding {
-            AnyJsBinding::JsIdentifierBinding(id_binding) => {
-                if track_binding(&id_binding, tracked_bindings) {
-                    return Some(id_binding);
-                }
-            }
+            AnyJsBinding::JsIdentifierBinding(id_binding) => bindings.push(id_binding),
             AnyJsBinding::JsBogusBinding(_) => {}
         },
         AnyJsBindingPattern::JsArrayBindingPattern(inner_binding) => {
-            return inner_binding.elements().into_iter().find_map(|element| {
-                let element = element.ok()?;
-                match element {
-                    AnyJsArrayBindingPatternElement::AnyJsBindingPattern(pattern) => {
-                        traverse_binding(pattern, tracked_bindings)
-                    }
+            for element in inner_binding.elements().into_iter().flatten() {
+                let pattern = match element {
+                    AnyJsArrayBindingPatternElement::AnyJsBindingPattern(pattern) => Some(pattern),
                     AnyJsArrayBindingPatternElement::JsArrayBindingPatternRestElement(
                         binding_rest,
-                    ) => {
-                        let binding_pattern = binding_rest.pattern().ok()?;
-                        traverse_binding(binding_pattern, tracked_bindings)
-                    }
+                    ) => binding_rest.pattern().ok(),
                     AnyJsArrayBindingPatternElement::JsArrayHole(_) => None,
                     AnyJsArrayBindingPatternElement::JsBindingPatternWithDefault(
                         binding_with_default,
-                    ) => {
-                        let pattern = binding_with_default.pattern().ok()?;
-                        traverse_binding(pattern, tracked_bindings)
-                    }
+                    ) => binding_with_default.pattern().ok(),
+                };
+                if let Some(pattern) = pattern {
+                    collect_bindings(pattern, bindings);
                 }
-            })
+            }
         }
-
         AnyJsBindingPattern::JsObjectBindingPattern(pattern) => {
-            return pattern.properties().into_iter().find_map(|prop| {
-                let prop = prop.ok()?;
+            for prop in pattern.properties().into_iter().flatten() {
                 match prop {
                     AnyJsObjectBindingPatternMember::JsObjectBindingPatternProperty(pattern) => {
-                        let pattern = pattern.pattern().ok()?;
-                        traverse_binding(pattern, tracked_bindings)
+                        if let Ok(pattern) = pattern.pattern() {
+                            collect_bindings(pattern, bindings);
+                        }
                     }
                     AnyJsObjectBindingPatternMember::JsObjectBindingPatternRest(rest) => {
-                        let pattern = rest.binding().ok()?;
-                        match pattern {
-                            AnyJsBinding::JsIdentifierBinding(binding) => {
-                                track_binding(&binding, tracked_bindings).then_some(binding)
-                            }
-                            AnyJsBinding::JsBogusBinding(_) => None,
+                        if let Ok(AnyJsBinding::JsIdentifierBinding(binding)) = rest.binding() {
+                            bindings.push(binding);
                         }
                     }
                     AnyJsObjectBindingPatternMember::JsObjectBindingPatternShorthandProperty(
                         shorthand_binding,
-                    ) => match shorthand_binding.identifier().ok()? {
-                        AnyJsBinding::JsIdentifierBinding(id_binding) => {
-                            track_binding(&id_binding, tracked_bindings).then_some(id_binding)
+                    ) => {
+                        if let Ok(AnyJsBinding::JsIdentifierBinding(binding)) =
+                            shorthand_binding.identifier()
+                        {
+                            bindings.push(binding);
                         }
-                        AnyJsBinding::JsBogusBinding(_) => None,
-                    },
-                    AnyJsObjectBindingPatternMember::JsBogusBinding(_) => None,
+                    }
+                    AnyJsObjectBindingPatternMember::JsBogusBinding(_) => {}
                 }
-            })
+            }
         }
     }
-    None
-}
-
-#[inline]
-/// If the name of binding has been seen in set, then we push the `JsIdentifierBinding` into `identifier_vec`.
-/// Else we mark the name of binding as seen.
-fn track_binding(
-    id_binding: &JsIdentifierBinding,
-    tracked_bindings: &mut FxHashSet<String>,
-) -> bool {
-    let binding_text = id_binding.text();
-    if tracked_bindings.contains(&binding_text) {
-        true
-    } else {
-        tracked_bindings.insert(binding_text);
-        false
-    }
 }
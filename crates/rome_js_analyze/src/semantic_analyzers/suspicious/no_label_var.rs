@@ -1,12 +1,21 @@
-use crate::{semantic_services::Semantic, JsRuleAction};
+use crate::semantic_services::Semantic;
 use rome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic};
 use rome_console::markup;
-use rome_js_syntax::{JsLabeledStatement, JsSyntaxNode, JsSyntaxToken};
+use rome_js_syntax::{
+    binding_ext::{AnyJsBindingDeclaration, AnyJsIdentifierBinding},
+    JsLabeledStatement, JsSyntaxNode, JsSyntaxToken,
+};
 use rome_rowan::AstNode;
 
 declare_rule! {
     ///  Disallow labels that share a name with a variable
     ///
+    /// The rule reports labels that have the same name as a variable, a function, a class,
+    /// or a parameter declared in the scope of the label or in one of its enclosing scopes.
+    /// Types such as interfaces, type aliases, and type parameters are ignored.
+    ///
+    /// Source: https://eslint.org/docs/latest/rules/no-label-var
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -16,6 +25,12 @@ declare_rule! {
     /// x1: expr;
     /// ```
     ///
+    /// ```js,expect_diagnostic
+    /// function f(item) {
+    ///     item: for (const x of list) {}
+    /// }
+    /// ```
+    ///
     /// ### Valid
     ///
     /// ```js
@@ -46,6 +61,9 @@ impl Rule for NoLabelVar {
         // if we find a binding that has its name equal to label name, then we found a  `LabelVar` issue.
         for scope in model.scope(label_statement.syntax()).ancestors() {
             if let Some(binding) = scope.get_binding(name) {
+                if is_type_binding(&binding.tree()) {
+                    continue;
+                }
                 return Some((binding.syntax().clone(), label_token));
             }
         }
@@ -67,8 +85,16 @@ impl Rule for NoLabelVar {
         },)
         .note(markup! {"Creating a label with the same name as an in-scope variable leads to confusion."}))
     }
+}
 
-    fn action(_: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
-        None
-    }
+/// Returns `true` if `binding` only declares a type.
+fn is_type_binding(binding: &AnyJsIdentifierBinding) -> bool {
+    matches!(binding, AnyJsIdentifierBinding::TsTypeParameterName(_))
+        || matches!(
+            binding.declaration(),
+            Some(
+                AnyJsBindingDeclaration::TsInterfaceDeclaration(_)
+                    | AnyJsBindingDeclaration::TsTypeAliasDeclaration(_)
+            )
+        )
 }
@@ -1,11 +1,19 @@
 use rome_js_factory::make;
+use rome_js_semantic::SemanticModel;
 use rome_js_syntax::{
-    inner_string_text, AnyJsStatement, JsLanguage, JsModuleItemList, JsStatementList, JsSyntaxNode,
-    JsVariableDeclaration, JsVariableDeclarator, JsVariableDeclaratorList, JsVariableStatement, T,
+    global_identifier, inner_string_text, jsx_ext::AnyJsxElement, AnyJsCallArgument,
+    AnyJsExpression, AnyJsLiteralExpression, AnyJsStatement, AnyJsxAttribute, AnyJsxChild,
+    JsCallExpression, JsLanguage, JsModuleItemList, JsModuleSource, JsStatementList, JsSyntaxKind,
+    JsSyntaxNode, JsSyntaxToken, JsVariableDeclaration, JsVariableDeclarator,
+    JsVariableDeclaratorList, JsVariableStatement, JsxAttribute, T,
+};
+use rome_rowan::{
+    declare_node_union, syntax::SyntaxTrivia, AstNode, AstNodeList, AstSeparatedList,
+    BatchMutation, Direction, WalkEvent,
 };
-use rome_rowan::{AstNode, AstSeparatedList, BatchMutation, Direction, WalkEvent};
 use std::iter;
 
+pub mod aria;
 pub mod batch;
 pub mod case;
 pub mod rename;
@@ -19,29 +27,69 @@ pub(crate) enum EscapeError {
 }
 
 struct InterpretEscapedString<'a> {
-    s: std::str::Chars<'a>,
+    s: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl<'a> InterpretEscapedString<'a> {
+    /// Reads the hexadecimal digits of `\xHH`, `\uHHHH`, or `\u{H...}`.
+    fn read_code_point(&mut self, escape: char) -> Result<char, EscapeError> {
+        let mut digits = String::new();
+        if escape == 'u' && self.s.next_if_eq(&'{').is_some() {
+            while let Some(c) = self.s.next_if(|c| *c != '}') {
+                digits.push(c);
+            }
+            self.s.next().ok_or(EscapeError::EscapeAtEndOfString)?;
+        } else {
+            let length = if escape == 'x' { 2 } else { 4 };
+            for _ in 0..length {
+                digits.push(self.s.next().ok_or(EscapeError::EscapeAtEndOfString)?);
+            }
+        }
+        u32::from_str_radix(&digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or(EscapeError::InvalidEscapedChar(escape))
+    }
 }
 
 impl<'a> Iterator for InterpretEscapedString<'a> {
     type Item = Result<char, EscapeError>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.s.next().map(|c| match c {
-            '\\' => match self.s.next() {
-                None => Err(EscapeError::EscapeAtEndOfString),
-                Some('n') => Ok('\n'),
-                Some('\\') => Ok('\\'),
-                Some(c) => Err(EscapeError::InvalidEscapedChar(c)),
-            },
-            c => Ok(c),
-        })
+        let c = self.s.next()?;
+        if c != '\\' {
+            return Some(Ok(c));
+        }
+        let result = match self.s.next() {
+            None => Err(EscapeError::EscapeAtEndOfString),
+            Some('n') => Ok('\n'),
+            Some('r') => Ok('\r'),
+            Some('t') => Ok('\t'),
+            Some('b') => Ok('\u{8}'),
+            Some('f') => Ok('\u{c}'),
+            Some('v') => Ok('\u{b}'),
+            Some('0') if !self.s.peek().is_some_and(char::is_ascii_digit) => Ok('\0'),
+            Some(escape @ ('x' | 'u')) => self.read_code_point(escape),
+            // Legacy octal escapes
+            Some(c @ '0'..='9') => Err(EscapeError::InvalidEscapedChar(c)),
+            // Line continuations
+            Some('\r') => {
+                self.s.next_if_eq(&'\n');
+                return self.next();
+            }
+            Some('\n' | '\u{2028}' | '\u{2029}') => return self.next(),
+            Some(c) => Ok(c),
+        };
+        Some(result)
     }
 }
 
-/// unescape
-///
+/// Interprets the escape sequences of the text of a string literal.
 pub(crate) fn escape_string(s: &str) -> Result<String, EscapeError> {
-    (InterpretEscapedString { s: s.chars() }).collect()
+    (InterpretEscapedString {
+        s: s.chars().peekable(),
+    })
+    .collect()
 }
 
 /// Utility function to remove a statement node from a syntax tree, by either
@@ -65,6 +113,37 @@ where
     Some(())
 }
 
+/// Utility function to append an attribute to the attributes of a JSX element.
+///
+/// `attribute` is expected to start with a space. When the attributes are followed
+/// by a whitespace, as in `<div />`, the space is moved after `attribute` to keep the whitespace
+/// before the end of the tag.
+pub(crate) fn append_jsx_attribute(
+    mutation: &mut BatchMutation<JsLanguage>,
+    element: &AnyJsxElement,
+    attribute: JsxAttribute,
+) -> Option<()> {
+    let end_token = match element {
+        AnyJsxElement::JsxOpeningElement(element) => element.r_angle_token().ok()?,
+        AnyJsxElement::JsxSelfClosingElement(element) => element.slash_token().ok()?,
+    };
+    let previous_trailing_trivia = end_token.prev_token()?.trailing_trivia();
+    let attribute = if previous_trailing_trivia.pieces().len() > 0 {
+        attribute
+            .with_leading_trivia_pieces(iter::empty())?
+            .with_trailing_trivia_pieces(previous_trailing_trivia.pieces())?
+    } else {
+        attribute
+    };
+    let attributes = element.attributes();
+    let new_attributes = attributes
+        .iter()
+        .chain([AnyJsxAttribute::JsxAttribute(attribute)])
+        .collect::<Vec<_>>();
+    mutation.replace_node_discard_trivia(attributes, make::jsx_attribute_list(new_attributes));
+    Some(())
+}
+
 /// Removes the declarator, and:
 /// 1 - removes the statement if the declaration only has one declarator;
 /// 2 - removes commas around the declarator to keep the declaration list valid.
@@ -153,3 +232,174 @@ pub(crate) fn is_node_equal(a_node: &JsSyntaxNode, b_node: &JsSyntaxNode) -> boo
 
     true
 }
+
+/// Returns `true` if a comment is found between the first and the last token of `node`.
+pub(crate) fn has_inner_comments(node: &JsSyntaxNode) -> bool {
+    let (Some(first), Some(last)) = (node.first_token(), node.last_token()) else {
+        return false;
+    };
+    node.descendants_tokens(Direction::Next).any(|token| {
+        (token != first && token.has_leading_comments())
+            || (token != last && token.has_trailing_comments())
+    })
+}
+
+/// Returns `false` if JSX doesn't render `child`.
+pub(crate) fn is_rendered(child: &AnyJsxChild) -> bool {
+    match child {
+        // JSX removes the whitespace that contains a line break
+        AnyJsxChild::JsxText(text) => text.value_token().is_ok_and(|token| {
+            let text = token.text();
+            !text.trim().is_empty() || !text.contains('\n')
+        }),
+        AnyJsxChild::JsxExpressionChild(child) => child.expression().is_some(),
+        _ => true,
+    }
+}
+
+/// Returns `true` if `expr` can be evaluated twice without side effects.
+pub(crate) fn is_side_effect_free(expr: &AnyJsExpression) -> bool {
+    match expr {
+        AnyJsExpression::JsIdentifierExpression(_) | AnyJsExpression::JsThisExpression(_) => true,
+        AnyJsExpression::JsStaticMemberExpression(member) => member
+            .object()
+            .is_ok_and(|object| is_side_effect_free(&object)),
+        _ => false,
+    }
+}
+
+/// Returns the number of insertions, deletions, substitutions,
+/// and transpositions of two adjacent characters needed to turn `a` into `b`.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let mut before_previous_row: Vec<usize> = Vec::new();
+    let mut previous_row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.iter().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous_row[j] + usize::from(a_char != b_char);
+            let mut distance = substitution.min(previous_row[j + 1] + 1).min(row[j] + 1);
+            if i > 0 && j > 0 && *a_char == b[j - 1] && a[i - 1] == *b_char {
+                distance = distance.min(before_previous_row[j - 1] + 1);
+            }
+            row.push(distance);
+        }
+        before_previous_row = std::mem::replace(&mut previous_row, row);
+    }
+    previous_row[b.len()]
+}
+
+declare_node_union! {
+    pub(crate) AnyJsModuleRequest = JsModuleSource | JsCallExpression
+}
+
+impl AnyJsModuleRequest {
+    /// Returns the string literal of the requested module.
+    ///
+    /// A call expression only requests a module if it is a call to the global `require` function
+    /// with a single string literal.
+    pub(crate) fn specifier(&self, model: &SemanticModel) -> Option<JsSyntaxToken> {
+        match self {
+            AnyJsModuleRequest::JsModuleSource(source) => {
+                // Ignore `declare module "name" {}`
+                if source.syntax().parent()?.kind() == JsSyntaxKind::TS_EXTERNAL_MODULE_DECLARATION
+                {
+                    return None;
+                }
+                source.value_token().ok()
+            }
+            AnyJsModuleRequest::JsCallExpression(call) => {
+                let callee = call.callee().ok()?.omit_parentheses();
+                let (reference, name) = global_identifier(&callee)?;
+                if name.text() != "require" || model.binding(&reference).is_some() {
+                    return None;
+                }
+                let arguments = call.arguments().ok()?.args();
+                if arguments.len() != 1 {
+                    return None;
+                }
+                let AnyJsCallArgument::AnyJsExpression(AnyJsExpression::AnyJsLiteralExpression(
+                    AnyJsLiteralExpression::JsStringLiteralExpression(specifier),
+                )) = arguments.first()?.ok()?
+                else {
+                    return None;
+                };
+                specifier.value_token().ok()
+            }
+        }
+    }
+}
+
+/// Returns true if the provided trivia contains an empty line (two consecutive newline pieces, ignoring whitespace)
+pub(crate) fn has_empty_line(trivia: SyntaxTrivia<JsLanguage>) -> bool {
+    let mut was_newline = false;
+    trivia
+        .pieces()
+        .filter(|piece| !piece.is_whitespace())
+        .any(|piece| {
+            let prev_newline = was_newline;
+            was_newline = piece.is_newline();
+            prev_newline && was_newline
+        })
+}
+
+/// Built-in modules that can be imported without the `node:` protocol.
+///
+/// The modules only available with the `node:` protocol, such as `node:test`, are not listed.
+pub(crate) const NODE_BUILTIN_MODULES: &[&str] = &[
+    "assert",
+    "assert/strict",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "dns/promises",
+    "domain",
+    "events",
+    "fs",
+    "fs/promises",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "inspector/promises",
+    "module",
+    "net",
+    "os",
+    "path",
+    "path/posix",
+    "path/win32",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "readline/promises",
+    "repl",
+    "stream",
+    "stream/consumers",
+    "stream/promises",
+    "stream/web",
+    "string_decoder",
+    "sys",
+    "timers",
+    "timers/promises",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "util/types",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+];
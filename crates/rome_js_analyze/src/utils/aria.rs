@@ -0,0 +1,35 @@
+use rome_js_syntax::jsx_ext::AnyJsxElement;
+
+/// The HTML elements that are interactive regardless of their attributes.
+const INTERACTIVE_ELEMENTS: [&str; 5] = ["button", "option", "select", "summary", "textarea"];
+
+/// Checks if the HTML element is interactive without a `role`.
+pub(crate) fn is_interactive_element(element: &AnyJsxElement) -> bool {
+    let Some(name) = element.name_value_token() else {
+        return false;
+    };
+    match name.text_trimmed() {
+        "a" | "area" => element.find_attribute_by_name("href").is_some(),
+        "input" => element
+            .find_attribute_by_name("type")
+            .and_then(|attribute| attribute.as_static_value())
+            .map_or(true, |value| value.text() != "hidden"),
+        name => INTERACTIVE_ELEMENTS.contains(&name),
+    }
+}
+
+/// Checks if the HTML element is focusable without a `tabIndex`.
+pub(crate) fn is_natively_focusable(element: &AnyJsxElement) -> bool {
+    let Some(name) = element.name_value_token() else {
+        return false;
+    };
+    match name.text_trimmed() {
+        "a" | "area" => is_interactive_element(element),
+        // The disabled form controls aren't focusable
+        _ if element.has_truthy_attribute("disabled") => false,
+        "iframe" => true,
+        // An option is selected through its `select`
+        "option" => false,
+        _ => is_interactive_element(element),
+    }
+}
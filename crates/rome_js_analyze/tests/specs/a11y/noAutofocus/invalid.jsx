@@ -10,5 +10,6 @@
     <input /*comment*/ autoFocus={"false"} /*comment*/ />
     <div autoFocus />
     <div autoFocus={true} />
-    <div autoFocus={false} />
+    <div autofocus />
+    <input autofocus="true" />
 </>
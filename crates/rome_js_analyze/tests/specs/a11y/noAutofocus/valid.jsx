@@ -2,7 +2,7 @@
     <div />
     <button />
     <input />
-    <div autofocus />
-    <input autofocus="true" />
+    <div autoFocus={false} />
+    <input autofocus={false} />
     <MyComponent autoFocus={true} />
 </>
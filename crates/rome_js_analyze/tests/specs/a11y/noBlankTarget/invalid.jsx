@@ -10,4 +10,7 @@
     <a target="_blank" href="//example.com/17" rel></a>
     <a target="_blank" href={dynamicLink}></a>
     <a target={'_blank'} href="//example.com/18"></a>
+    <a target="_blank" rel="nofollow" href="https://example.com/19"></a>
+    <a target="_blank" rel=" nofollow external " href="https://example.com/20"></a>
+    <a target="_blank" href="https://example.com/21" />
 </>
@@ -30,4 +30,8 @@
     <a target={"targetValue"} href="/absolute/path"></a>
     <a target={null} href="//example.com"></a>
     <Link href="foobar" target="_blank" />
+    <a href="foobar" target="_blank" rel="noopener"></a>
+    <a href="foobar" target="_blank" rel="nofollow noopener"></a>
+    <a href="foobar" target="_blank" rel="NoOpener"></a>
+    <a href="foobar" target="_blank" rel={"noopener"}></a>
 </>
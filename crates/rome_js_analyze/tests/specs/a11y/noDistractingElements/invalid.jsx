@@ -2,4 +2,8 @@
     <div />
     <marquee />
     <blink />
+    <marquee behavior="scroll">News</marquee>
+    <blink>Sale</blink>
+    <mARQUEE />
+    <bLink></bLink>
 </div>
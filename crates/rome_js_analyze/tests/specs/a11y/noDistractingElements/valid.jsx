@@ -0,0 +1,7 @@
+<div>
+    <div />
+    <Marquee />
+    <Blink>Sale</Blink>
+    <Foo.marquee />
+    <span className="marquee" />
+</div>
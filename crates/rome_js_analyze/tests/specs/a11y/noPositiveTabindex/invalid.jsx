@@ -7,6 +7,8 @@
 	<div tabIndex={"1"}>foo</div>
 	<div tabIndex={'5'}>foo</div>
 	<div tabIndex={+5}>foo</div>
-	<div tabIndex={+05}>foo</div>
+	<div tabIndex={+15}>foo</div>
 	<div tabIndex="1">foo</div>
+	<div tabindex="2" />
+	<div tabindex={3}>foo</div>
 </>
@@ -1,4 +1,4 @@
 React.createElement("div", { tabIndex: '1' })
 React.createElement("div", { tabIndex: 1 })
 React.createElement("div", { tabIndex: +1 })
-React.createElement("div", { tabIndex: +01 })
+React.createElement("div", { tabIndex: +10 })
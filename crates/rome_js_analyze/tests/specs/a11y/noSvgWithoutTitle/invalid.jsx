@@ -4,13 +4,27 @@
 		<title></title>
 		<circle />
 	</svg>
-	<svg role="img" aria-label="title">
+	<svg role="img" aria-label="">
 		<span id="">foo</span>
 	</svg>
 	<svg role="img" title="title">
 		<span id="">foo</span>
 	</svg>
-	<svg role="img" aria-labelledby="title">
+	<svg role="img" aria-labelledby="">
 		<span id="">foo</span>
 	</svg>
+	<svg>
+		<title> </title>
+		<circle />
+	</svg>
+	<svg>
+		<g>
+			<circle />
+		</g>
+		<g>
+			<title></title>
+		</g>
+	</svg>
+	<svg aria-hidden="false" />
+	<svg role="img" />
 </>;
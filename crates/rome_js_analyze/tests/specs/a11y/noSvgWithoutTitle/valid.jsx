@@ -30,4 +30,22 @@
 	<svg role="img" aria-labelledby="title">
 		<span id="title">Pass</span>
 	</svg>
+	<svg aria-label="title" />
+	<svg aria-labelledby="title" />
+	<svg aria-hidden="true" />
+	<svg aria-hidden>
+		<circle />
+	</svg>
+	<svg role="presentation" />
+	<svg role="none">
+		<circle />
+	</svg>
+	<svg>
+		<g>
+			<circle />
+		</g>
+		<g>
+			<title>{title}</title>
+		</g>
+	</svg>
 </>;
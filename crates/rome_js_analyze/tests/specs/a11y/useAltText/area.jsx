@@ -5,7 +5,6 @@
   <area alt />
   <area alt={undefined} />
   <area src="xyz" />
-  <area {...this.props} />
   <area aria-label="" />
   <area aria-label={undefined} />
   <area aria-labelledby="" />
@@ -20,5 +19,6 @@
   <area alt="" />
   <area alt="This is descriptive!" />
   <area alt={altText} />
+  <area {...this.props} />
   <Area />
 </>;
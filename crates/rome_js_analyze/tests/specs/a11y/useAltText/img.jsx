@@ -5,11 +5,8 @@
   <img alt={undefined} />
   <img src="xyz" />
   <img role />
-  <img {...this.props} />
   <img alt={undefined} role="presentation" />
   <img alt role="presentation" />
-  <img role="presentation" />
-  <img role="none" />
   <img aria-label={undefined} />
   <img aria-labelledby={undefined} />
   <img aria-label="" />
@@ -50,4 +47,7 @@
   <img alt={plugin.name + " Logo"} />
   <img aria-label="foo" />
   <img aria-labelledby="id1" />
+  <img {...this.props} />
+  <img role="presentation" />
+  <img role="none" />
 </>;
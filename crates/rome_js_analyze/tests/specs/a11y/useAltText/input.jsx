@@ -4,7 +4,6 @@
   <input type="image" alt />
   <input type="image" alt={undefined} />
   <input type="image">Foo</input>
-  <input type="image" {...this.props} />
   <input type="image" aria-label="" />
   <input type="image" aria-label={undefined} />
   <input type="image" aria-labelledby="" />
@@ -21,5 +20,6 @@
   <input type="image" alt="" />
   <input type="image" alt="This is descriptive!" />
   <input type="image" alt={altText} />
+  <input type="image" {...this.props} />
   <Input type="image" />
 </>;
@@ -10,4 +10,6 @@ var a = <span role="heading" aria-level="1"></span>;
 var a = <span role="combobox" aria-controls="true" aria-expanded="true"></span>;
 var a = <span role="menuitemcheckbox" aria-checked="true"></span>;
 var a = <span role="menuitemradio" aria-checked="true"></span>;
-var a = <Span role="menuitemradio" ></Span>;
\ No newline at end of file
+var a = <Span role="menuitemradio" ></Span>;
+var a = <span role="checkbox" {...props}></span>;
+var a = <span {...props} role="heading"></span>;
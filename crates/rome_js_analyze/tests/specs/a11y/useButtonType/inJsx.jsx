@@ -8,7 +8,7 @@
     <button type/>
     <button onClick={null}>test</button>
     <button onClick={null}/>
-</>
+</>;
 
 
 // valid
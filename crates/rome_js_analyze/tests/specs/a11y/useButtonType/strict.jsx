@@ -0,0 +1,5 @@
+<>
+    <button {...props}>do something</button>
+    <button {...props} />
+    <button {...props} type="bar" />
+</>
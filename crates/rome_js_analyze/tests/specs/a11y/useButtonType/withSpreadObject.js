@@ -0,0 +1,2 @@
+React.createElement('button', { ...props });
+React.createElement('button', { ...props, type: "bar" });
@@ -15,4 +15,13 @@
 	<h1 children={null} />
 	<h1 children={undefined} />
 	<h1 {...props} aria-hidden="true" />
+	<h1>
+		<span></span>
+	</h1>
+	<h2>
+		<div>
+			<span>{undefined}</span>
+		</div>
+	</h2>
+	<h3 aria-label="" />
 </>;
@@ -46,4 +46,13 @@
 	<h1>
 		<>heading</>
 	</h1>
+	<h2>
+		<div>
+			<span>heading</span>
+		</div>
+	</h2>
+	<h3 aria-label="heading" />
+	<h3 aria-label="heading">
+		<Icon />
+	</h3>
 </>;
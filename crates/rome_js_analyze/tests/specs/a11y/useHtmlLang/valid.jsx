@@ -5,4 +5,6 @@
     <html lang={lang}></html>
     <html {...props}></html>
     <html lang="" {...props}></html>
+    <Foo.html></Foo.html>
+    <Html />
 </>
\ No newline at end of file
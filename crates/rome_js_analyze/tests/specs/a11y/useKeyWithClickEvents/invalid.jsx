@@ -1,5 +1,8 @@
 <>
     <div onClick={() => {}} />
+    <span onClick={() => {}}>foo</span>
+    <div role="article" onClick={() => {}} />
+    <div role={"presentation"} onClick={() => {}} />
     <div  {...spread} onClick={() => {}} />
     <div  {...spread} onClick={() => {}}>foo</div>
 </>
\ No newline at end of file
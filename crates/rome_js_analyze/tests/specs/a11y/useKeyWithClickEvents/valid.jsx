@@ -1,5 +1,11 @@
 <>
     <div onClick={() => {}} onKeyUp={handleKeyUp} />
+    <div role="button" onClick={() => {}} />
+    <span role="link" onClick={() => {}}>foo</span>
+    <div role={"checkbox"} onClick={() => {}} />
+    <button onClick={() => {}} />
+    <select onClick={() => {}} />
+    <textarea onClick={() => {}} />
     <div onClick={() => {}} onKeyDown={handleKeyDown} />
     <div onClick={() => {}} onKeyPress={handleKeyPress} />
     <div onClick={() => {}} {...spread} />
@@ -10,6 +10,8 @@
 	<a href="javascript:void(0)" />
 	<a href={"javascript:void(0)"} />
 	<a href={`javascript:void(0)`} />
+	<a href="JavaScript:void(0)" />
+	<a href=" javascript:void(0)" />
 	<a onClick={() => void 0} />
 	<a href="#" onClick={() => void 0} />
 	<a href="javascript:void(0)" onClick={() => void 0} />
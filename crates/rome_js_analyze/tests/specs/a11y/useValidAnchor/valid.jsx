@@ -7,6 +7,7 @@
 	<a href="/foo" />
 	<a href="#foo" />
 	<a href="javascript" />
+	<a href="/search?q=javascript:" />
 	<a href={`#foo`} />
 	<a href={"foo"} />
 	<a href={foo} />
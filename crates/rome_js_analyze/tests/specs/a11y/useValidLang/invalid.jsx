@@ -1,3 +1,7 @@
 let a = <html lang="lorem" />;
 let a = <html lang="en-babab" />;
 let a = <html lang="en-GB-something" />;
+let a = <html lang="en-us" />;
+let a = <html lang="EN" />;
+let a = <html lang="eng" />;
+let a = <html lang="fr-FRA" />;
@@ -2,3 +2,5 @@ let a = <Html lang="en-babab" />;
 let a = <html lang="en-US"></html>;
 let a = <html lang="en"></html>;
 let a = <html lang={lang}></html>;
+let a = <html lang={"lorem"}></html>;
+let a = <html lang={`en-${country}`}></html>;
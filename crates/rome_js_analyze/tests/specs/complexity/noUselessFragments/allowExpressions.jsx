@@ -0,0 +1,7 @@
+<>{children}</>;
+<div>
+	<>{children}</>
+</div>;
+<div>
+	<>foo</>
+</div>;
@@ -0,0 +1,19 @@
+<div>
+	<>
+		<Header />
+		<Content />
+	</>
+</div>;
+<>
+	<Header />
+	<>
+		<Content />
+		<Footer />
+	</>
+</>;
+<div>
+	<React.Fragment>
+		<Header />
+		<Content />
+	</React.Fragment>
+</div>;
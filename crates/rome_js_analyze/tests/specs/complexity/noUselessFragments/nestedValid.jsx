@@ -0,0 +1,14 @@
+<div>
+	<React.Fragment key="a">
+		<Header />
+		<Content />
+	</React.Fragment>
+</div>;
+function Component() {
+	return (
+		<>
+			<Header />
+			<Content />
+		</>
+	);
+}
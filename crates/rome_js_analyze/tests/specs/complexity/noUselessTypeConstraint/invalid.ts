@@ -13,3 +13,7 @@ class BazAny<T extends any> {
 const QuuxAny = <T extends any>() => {};
 
 function QuuzAny<T extends any>() {}
+
+type Defaulted<T extends unknown = string> = T;
+
+function withDefault<T extends any = number, U extends unknown>() {}
@@ -0,0 +1,7 @@
+const QuuxAny = <T extends any>() => {};
+
+const QuuxUnknown = <T extends unknown,>() => {};
+
+const QuuxMultiple = <T extends unknown, U>() => {};
+
+function QuuzAny<T extends any>() {}
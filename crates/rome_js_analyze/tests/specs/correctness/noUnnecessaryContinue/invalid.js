@@ -29,4 +29,6 @@ test: for (let i = 0; i < 9; i++) continue test;
 
 test2: do {
 	continue test2;
-} while (true);
\ No newline at end of file
+} while (true);
+
+for (const x of xs) continue;
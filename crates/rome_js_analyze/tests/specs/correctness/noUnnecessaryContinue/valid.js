@@ -55,4 +55,9 @@ for (const x of []) {
 	} else {
 		doSomeStuff();
 	}
-}
\ No newline at end of file
+}
+
+outer: for (const x of xs) {
+	for (const y of ys) continue outer;
+	doSomeStuff();
+}
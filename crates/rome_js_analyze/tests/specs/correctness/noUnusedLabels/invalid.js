@@ -71,3 +71,11 @@ M: {
  * "A: class Foo { foo() { break A; } }",
  * "A: { A: { break A; } }"
  */
+
+N: {
+	const f = () => {
+		N: for (;;) {
+			continue N;
+		}
+	};
+}
@@ -47,3 +47,12 @@ A: {
 		bar();
 	}
 }
+
+A: {
+	const f = () => {
+		A: for (;;) {
+			continue A;
+		}
+	};
+	if (f()) break A;
+}
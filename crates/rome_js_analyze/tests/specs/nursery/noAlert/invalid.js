@@ -0,0 +1,11 @@
+alert("a");
+confirm("b");
+prompt("c");
+window.alert("d");
+globalThis.confirm("e");
+window["prompt"]("f");
+(alert)("g");
+
+function f() {
+	return confirm("h");
+}
@@ -0,0 +1,13 @@
+import { alert } from "./dialogs.js";
+alert("a");
+
+function confirm() {}
+confirm("b");
+
+function f(prompt) {
+	prompt("c");
+}
+
+dialog.alert("d");
+window.open("e");
+notify("f");
@@ -0,0 +1,17 @@
+<>
+	<div aria-hidden="true" tabIndex="0" />
+	<div aria-hidden tabIndex={1} />
+	<span aria-hidden={true} tabIndex={+0} />
+	<button aria-hidden>Submit</button>
+	<button aria-hidden="true" tabIndex="0">Submit</button>
+	<a href="/" aria-hidden="true">Home</a>
+	<area href="/" aria-hidden="true" />
+	<input aria-hidden="true" />
+	<input type="text" aria-hidden="true" />
+	<select aria-hidden="true" />
+	<textarea aria-hidden="true" />
+	<summary aria-hidden="true">Details</summary>
+	<iframe aria-hidden="true" />
+	<button aria-hidden="true" tabIndex={tabIndex}>Submit</button>
+	<button /* comment */ aria-hidden="true" /* comment */>Submit</button>
+</>;
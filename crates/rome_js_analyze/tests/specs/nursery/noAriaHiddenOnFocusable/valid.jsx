@@ -0,0 +1,15 @@
+<>
+	<div aria-hidden="true" />
+	<div aria-hidden="true" tabIndex="-1" />
+	<div aria-hidden="false" tabIndex="0" />
+	<div aria-hidden={false} tabIndex="0" />
+	<div tabIndex="0" />
+	<div aria-hidden="true" tabIndex={tabIndex} />
+	<button aria-hidden="true" tabIndex="-1">Submit</button>
+	<button aria-hidden="true" tabIndex={-1}>Submit</button>
+	<button aria-hidden="true" disabled>Submit</button>
+	<a aria-hidden="true">Home</a>
+	<input type="hidden" aria-hidden="true" />
+	<Button aria-hidden="true" />
+	<button>Submit</button>
+</>;
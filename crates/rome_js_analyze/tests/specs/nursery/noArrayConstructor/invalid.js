@@ -0,0 +1,16 @@
+new Array();
+new Array;
+Array();
+new Array(0, 1, 2);
+Array(0, 1, 2);
+new Array(x, ...ys, z,);
+new globalThis.Array(1, 2);
+window.Array(1, 2);
+new (Array)(1, 2);
+const xs = /* comment */ new Array(
+	// first
+	a,
+	b, // second
+);
+new Array(3);
+Array(0.5);
@@ -0,0 +1,15 @@
+/* should not generate diagnostics */
+new Array(...xs);
+new Array(length);
+Array(foo);
+new Array("a");
+Array?.(0, 1);
+Array.from({ length: 3 });
+[0, 1, 2];
+function f(Array) {
+	new Array(0, 1, 2);
+}
+{
+	const Array = class {};
+	Array(0, 1);
+}
@@ -0,0 +1,3 @@
+/* should not generate diagnostics */
+new Array<number>();
+Array<string>("a", "b");
@@ -0,0 +1,21 @@
+async function forOf(urls) {
+	for (const url of urls) {
+		await fetch(url);
+	}
+}
+
+async function nestedLoop(groups) {
+	for (const group of groups) {
+		while (group.pending) {
+			await sleep(100);
+		}
+	}
+}
+
+async function outerLoop(groups) {
+	while (groups.length) {
+		for (const group of groups.pop()) {
+			await save(group);
+		}
+	}
+}
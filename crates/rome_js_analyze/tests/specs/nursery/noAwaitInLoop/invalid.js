@@ -0,0 +1,62 @@
+async function forOf(urls) {
+	for (const url of urls) {
+		await fetch(url);
+	}
+}
+
+async function forIn(object) {
+	for (const key in object) {
+		await save(key);
+	}
+}
+
+async function forLoop(items) {
+	for (let i = 0; i < items.length; i++) {
+		await save(items[i]);
+	}
+}
+
+async function forTest() {
+	for (; await isPending(); ) {}
+}
+
+async function forUpdate() {
+	for (let i = 0; i < 10; await next()) {}
+}
+
+async function whileLoop() {
+	while (true) {
+		await sleep(100);
+	}
+}
+
+async function whileTest() {
+	while (await isPending()) {}
+}
+
+async function doWhile() {
+	do {
+		await sleep(100);
+	} while (await isPending());
+}
+
+async function nested(groups) {
+	for (const group of groups) {
+		if (group.enabled) {
+			const { data } = await load(group);
+		}
+	}
+}
+
+async function blockInLoop(items) {
+	for (const item of items) {
+		for await (const chunk of item) {
+			await write(chunk);
+		}
+		await flush();
+	}
+}
+
+for (const url of urls) {
+	await fetch(url);
+}
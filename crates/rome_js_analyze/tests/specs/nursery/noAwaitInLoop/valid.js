@@ -0,0 +1,45 @@
+/* should not generate diagnostics */
+async function all(urls) {
+	await Promise.all(urls.map(async (url) => await fetch(url)));
+}
+
+async function forAwait(stream) {
+	for await (const chunk of stream) {
+		await write(chunk);
+	}
+}
+
+async function initializer() {
+	for (let i = await start(); i < 10; i++) {}
+}
+
+async function iterable() {
+	for (const item of await load()) {}
+	for (const key in await load()) {}
+}
+
+async function functionInLoop(items) {
+	for (const item of items) {
+		const process = async () => {
+			await save(item);
+		};
+		items.push(async function () {
+			await save(item);
+		});
+	}
+}
+
+async function methodInLoop(items) {
+	while (items.length) {
+		const object = {
+			async save() {
+				await save(items.pop());
+			},
+		};
+		class Saver {
+			async save() {
+				await save(items.pop());
+			}
+		}
+	}
+}
@@ -0,0 +1,4 @@
+// A barrel file
+export * from "./utils";
+export * as parser from "./parser";
+export { format, default as print } from "./printer";
@@ -0,0 +1,4 @@
+export type { Options } from "./options";
+export { parse } from "./parser";
+export type * from "./types";
+export * from "./utils";
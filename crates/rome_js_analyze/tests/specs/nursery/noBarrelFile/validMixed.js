@@ -0,0 +1,4 @@
+import { parse } from "./parser";
+export * from "./utils";
+export { format } from "./printer";
+export { parse };
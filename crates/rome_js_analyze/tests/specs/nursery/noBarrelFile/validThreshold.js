@@ -0,0 +1,3 @@
+export * from "./utils";
+export * from "./parser";
+export * from "./printer";
@@ -0,0 +1,3 @@
+export type { Options } from "./options";
+export type * from "./types";
+export { parse } from "./parser";
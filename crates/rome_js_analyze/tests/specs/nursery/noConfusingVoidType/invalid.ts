@@ -0,0 +1,8 @@
+let a: number | void;
+let b: void | string;
+type C = (void) | null;
+type D = { a: 1 } & void;
+type E = Array<void>;
+function f(): Promise<void> {}
+function g(): number | void {}
+type H = Map<string, void | number>;
@@ -0,0 +1,9 @@
+function f(): void {}
+let g: () => void;
+type A = void;
+type B = | void;
+type C = (void);
+let value: number | undefined;
+class Foo {
+	method(): void {}
+}
@@ -0,0 +1,3 @@
+console.error("a");
+console.warn("b");
+console.log("c");
@@ -0,0 +1,22 @@
+console.log("a");
+console.error("b");
+console["warn"]("c");
+globalThis.console.info("d");
+
+function f() {
+	// Debugging
+	console.debug(f);
+	return 1;
+}
+
+if (a) console.log(a);
+const value = console.log(a);
+g(console.log(a));
+a && console.log(a);
+
+function h() {
+	/* Keep this comment */
+	console.log(h);
+	// Keep this comment too
+	return 1;
+}
@@ -0,0 +1,9 @@
+const console = { log() {} };
+console.log();
+
+function f(console) {
+	console.log();
+}
+
+logger.log();
+console;
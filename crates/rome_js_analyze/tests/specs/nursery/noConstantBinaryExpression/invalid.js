@@ -0,0 +1,48 @@
+// constant truthiness
+a = {} || b;
+a = [] && b;
+a = (() => {}) || b;
+a = function () {} || b;
+a = class {} || b;
+a = new Foo() || b;
+a = 0 || b;
+a = "" && b;
+a = /regex/ || b;
+a = void x || b;
+a = typeof x && b;
+a = !{} || b;
+a = `text${x}` || b;
+a = `` && b;
+a = (1) || b;
+
+// constant nullishness
+a = {} ?? b;
+a = +x ?? b;
+a = !x ?? b;
+a = x + y ?? b;
+a = `${x}` ?? b;
+a = x++ ?? b;
+a = null ?? b;
+a = x instanceof Y ?? b;
+
+// newly created objects
+a = x === [];
+a = [] == [];
+a = {} !== x;
+a = x !== (() => {});
+a = x === /regex/;
+
+// comparisons with null
+a = !x == null;
+a = null === typeof x;
+a = x + y != null;
+
+// literals
+a = "abc" === "ABC";
+a = 1 == 1;
+a = true !== false;
+a = null == null;
+
+// redundant boolean comparisons
+a = !!x === true;
+a = false !== !x;
@@ -0,0 +1,21 @@
+/* should not generate diagnostics */
+a = x || b;
+a = x && b;
+a = x ?? b;
+a = x() ?? b;
+a = x.y ?? b;
+a = `${x}` || b;
+a = tag`text` || b;
+a = tag`x` ?? b;
+a = b || {};
+a = x === y;
+a = x.length === 0;
+a = x == null;
+a = null == x;
+a = x < [];
+a = {} != x;
+a = !!x == true;
+a = !!x === y;
+a = x === true;
+a = "a" < "b";
+a = x === /regex/.source;
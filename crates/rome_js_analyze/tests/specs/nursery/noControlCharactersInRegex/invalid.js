@@ -23,3 +23,7 @@ var regex = /(?<\u{1d49c}>.)\x1f/;
 var regex = /\u{1111}*\x1F/u;
 var regex = /\u{1F}/u;
 var regex = /\u{1F}/gui;
+var regex = /\d\x1F/;
+var regex = new RegExp("\\x1f\t");
+var regex = new RegExp("\u001F");
+var regex = new RegExp("\\d\\x1F");
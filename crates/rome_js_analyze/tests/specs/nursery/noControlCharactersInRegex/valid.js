@@ -18,3 +18,5 @@ var regex = /\u{1F}/g;
 var regex = /\t/;
 var regex = /\n/;
 new (function foo() {})("\\x1f");
+var regex = new RegExp("\t\\d\r\n");
+var regex = new RegExp("\\u{1F}\\d", "g");
@@ -0,0 +1,3 @@
+export default interface I {
+	a: string;
+}
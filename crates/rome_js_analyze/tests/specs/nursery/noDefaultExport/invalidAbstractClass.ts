@@ -0,0 +1 @@
+export default abstract class C<T> implements I {}
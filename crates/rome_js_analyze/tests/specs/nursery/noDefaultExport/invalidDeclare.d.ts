@@ -0,0 +1,3 @@
+declare module "mod" {
+	export default function f(a: string): void;
+}
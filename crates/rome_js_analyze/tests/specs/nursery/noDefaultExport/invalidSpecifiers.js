@@ -0,0 +1,5 @@
+const a = 1;
+export { a as default };
+export { b as "default" };
+export { default } from "./c";
+export { d as default } from "./d";
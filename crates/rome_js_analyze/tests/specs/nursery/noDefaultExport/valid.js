@@ -0,0 +1,7 @@
+export function f() {}
+export class C {}
+export const a = 1;
+export { a as b };
+export { default as c } from "./c";
+export * from "./d";
+import d from "./d";
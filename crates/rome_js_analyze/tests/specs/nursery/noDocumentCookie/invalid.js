@@ -0,0 +1,7 @@
+document.cookie = "a=b";
+document.cookie += "; max-age=3600";
+document["cookie"] = "a=b";
+window.document.cookie = "a=b";
+globalThis.document.cookie = "a=b";
+(document).cookie = "a=b";
+const value = (document.cookie = "a=b");
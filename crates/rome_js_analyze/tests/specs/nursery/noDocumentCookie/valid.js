@@ -0,0 +1,13 @@
+const cookies = document.cookie;
+if (document.cookie.includes("a=b")) {}
+
+const document = { cookie: "" };
+document.cookie = "a=b";
+
+function f(document) {
+	document.cookie = "a=b";
+}
+
+doc.cookie = "a=b";
+document.title = "Title";
+document[cookie] = "a=b";
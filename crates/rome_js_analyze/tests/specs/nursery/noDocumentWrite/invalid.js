@@ -0,0 +1,7 @@
+document.write("<p>Hello</p>");
+document.writeln("<p>Hello</p>");
+(document).write("<p>Hello</p>");
+document["write"]("<p>Hello</p>");
+window.document.write("<p>Hello</p>");
+globalThis.document.writeln("<p>Hello</p>");
+(document.write)("<p>Hello</p>");
@@ -0,0 +1,16 @@
+document.body.append(paragraph);
+document.open();
+foo.document.write("<p>Hello</p>");
+writer.write("<p>Hello</p>");
+document.writer("<p>Hello</p>");
+function print(document) {
+	document.write("Hello");
+}
+{
+	const document = iframe.contentDocument;
+	document.write("<p>Hello</p>");
+}
+{
+	const window = iframe.contentWindow;
+	window.document.write("<p>Hello</p>");
+}
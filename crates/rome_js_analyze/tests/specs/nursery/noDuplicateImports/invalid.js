@@ -0,0 +1,11 @@
+import { merge } from "module";
+import { find } from "module";
+import defaultExport from "default";
+import { a, b as c } from "default";
+import * as namespace from "namespace";
+import namespaceDefault from "namespace";
+import "side-effect";
+import "side-effect";
+import { x } from "many";
+import { y } from "many";
+import { z } from "many";
@@ -0,0 +1,4 @@
+import type { A } from "types";
+import type { B } from "types";
+import { type C } from "types";
+import { d } from "types";
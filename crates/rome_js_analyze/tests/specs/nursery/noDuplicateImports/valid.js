@@ -0,0 +1,11 @@
+import defaultExport, { merge, find } from "module";
+import * as namespace from "namespace";
+import { a } from "namespace";
+import "side-effect";
+import { b } from "side-effect";
+import first from "default";
+import second from "default";
+import * as one from "namespaces";
+import * as two from "namespaces";
+import json from "./data.json" with { type: "json" };
+import { c } from "./data.json";
@@ -0,0 +1,4 @@
+import type { A } from "module";
+import { b } from "module";
+import type Default from "types";
+import type { C } from "types";
@@ -0,0 +1,8 @@
+try {
+	doSomething();
+} catch (e) {}
+try {
+	doSomething();
+} catch {
+	// Ignore the error
+}
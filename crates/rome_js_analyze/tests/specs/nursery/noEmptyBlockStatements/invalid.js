@@ -0,0 +1,14 @@
+if (foo) {}
+if (foo) {
+} else {}
+while (foo) {}
+for (const x of xs) {}
+switch (foo) {
+	case 1: {}
+}
+try {} catch {}
+try {
+	doSomething();
+} finally {}
+{}
+label: {}
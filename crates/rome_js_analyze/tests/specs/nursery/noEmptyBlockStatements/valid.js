@@ -0,0 +1,23 @@
+if (foo) {
+	// Nothing to do
+}
+if (foo) {
+	bar();
+} else {
+	/* nothing */
+}
+while (foo) {/* wait */}
+try {
+	doSomething();
+} catch {}
+try {
+	doSomething();
+} catch (e) {}
+function noop() {}
+const arrow = () => {};
+class A {
+	static {}
+	method() {}
+}
+const object = {};
+switch (foo) {}
@@ -0,0 +1,9 @@
+/^abc[]/;
+/foo[]bar/;
+/[]]/;
+/\[[]/;
+/[^]/;
+/a|[^]/u;
+/[a][]/;
+/[[]]/v;
+/[a--[]]/v;
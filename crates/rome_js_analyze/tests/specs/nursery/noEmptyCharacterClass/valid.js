@@ -0,0 +1,10 @@
+/^abc[a-z]/;
+/\[]/;
+/\[\]/;
+/[\]]/;
+/[\[]/;
+/[[]/;
+/[^\]]/;
+"[]";
+/\\[a]/;
+/[a[b]]/v;
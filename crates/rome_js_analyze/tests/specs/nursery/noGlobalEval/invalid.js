@@ -0,0 +1,11 @@
+eval("var a = 0");
+(eval)("var a = 0");
+window.eval("var a = 0");
+globalThis.eval("var a = 0");
+globalThis.window["eval"]("var a = 0");
+Function("a", "return a");
+new Function("a", "b", "return a + b");
+new window.Function("return this");
+function f() {
+	return eval(code);
+}
@@ -0,0 +1,8 @@
+function f(Function) {
+	return new Function("a", "return a");
+}
+
+foo.eval("var a = 0");
+new eval("var a = 0");
+const fn = function () {};
+evaluate("var a = 0");
@@ -0,0 +1,7 @@
+import { Function } from "./sandbox.js";
+Function("return this");
+new Function("return this");
+{
+	const window = { eval() {} };
+	window.eval("var a = 0");
+}
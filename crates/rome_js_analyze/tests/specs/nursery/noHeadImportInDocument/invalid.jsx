@@ -0,0 +1,20 @@
+import Document, { Html, Main, NextScript } from "next/document";
+import Head from "next/head";
+import NextHead from "next/head";
+
+export default class MyDocument extends Document {
+	render() {
+		return (
+			<Html>
+				<Head>
+					<meta name="description" content="Home" />
+				</Head>
+				<NextHead />
+				<body>
+					<Main />
+					<NextScript />
+				</body>
+			</Html>
+		);
+	}
+}
@@ -0,0 +1,9 @@
+import Head from "next/head";
+
+export default function Page() {
+	return (
+		<Head>
+			<title>Home</title>
+		</Head>
+	);
+}
@@ -0,0 +1,10 @@
+import { Html } from "next/document";
+import Head from "next/head";
+
+function Layout({ Head }) {
+	return (
+		<Html>
+			<Head />
+		</Html>
+	);
+}
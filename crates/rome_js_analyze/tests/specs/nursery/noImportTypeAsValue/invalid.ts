@@ -0,0 +1,19 @@
+import type A from "./a";
+import type * as B from "./b";
+import type { C } from "./c";
+import { type D, E } from "./d";
+import type { F, G, H } from "./f";
+import type {
+    I,
+    // Comment
+    J,
+    K,
+} from "./i";
+
+new A();
+B.create();
+console.log(C, C);
+D(E);
+let g: G = F.create<H>();
+export default I;
+let j = { J };
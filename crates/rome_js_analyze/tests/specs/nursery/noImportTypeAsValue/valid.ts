@@ -0,0 +1,12 @@
+import type A from "./a";
+import type * as B from "./b";
+import type { C, D } from "./c";
+import { type E, F } from "./e";
+import type { G } from "./g";
+import { type H } from "./h";
+
+let a: A;
+let b: B.Type;
+let c: typeof C;
+let e: E = new F<E>();
+export { D, G as H2 };
@@ -0,0 +1,13 @@
+<>
+	<button role="presentation">Submit</button>
+	<button role="none" />
+	<a href="/" role="listitem">Home</a>
+	<area href="/" role="img" />
+	<input role="img" />
+	<input type="text" role="article" />
+	<select role="list" />
+	<textarea role="heading" />
+	<option role="presentation" />
+	<summary role="banner">Details</summary>
+	<button role="presentation button">Submit</button>
+</>;
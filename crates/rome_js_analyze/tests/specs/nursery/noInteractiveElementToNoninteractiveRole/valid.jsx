@@ -0,0 +1,14 @@
+<>
+	<button>Submit</button>
+	<button role="switch">Dark mode</button>
+	<button role={role}>Submit</button>
+	<a role="presentation">Home</a>
+	<input type="hidden" role="presentation" />
+	<input role="combobox" />
+	<div role="presentation" />
+	<span role="article" />
+	<li role="presentation" />
+	<Button role="presentation" />
+	<Foo.button role="presentation" />
+	<button role="unknown">Submit</button>
+</>;
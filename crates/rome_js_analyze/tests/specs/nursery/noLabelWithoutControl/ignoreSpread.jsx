@@ -0,0 +1,5 @@
+<>
+	<label {...props}>Name</label>
+	<label {...props} />
+	<label>Name</label>
+</>;
@@ -0,0 +1,15 @@
+<>
+	<label>Name</label>
+	<label />
+	<label><span>Name</span></label>
+	<label htmlFor={undefined}>Name</label>
+	<label>
+		Name
+		<Input />
+	</label>
+	<label>
+		Name
+		<div role="presentation" />
+	</label>
+	<label {...props}>Name</label>
+</>;
@@ -0,0 +1,26 @@
+<>
+	<label htmlFor="name">Name</label>
+	<label htmlFor={id}>Name</label>
+	<label for="name">Name</label>
+	<label htmlFor="name" />
+	<label>
+		Name
+		<input />
+	</label>
+	<label>
+		<span>Name</span>
+		<div>
+			<select />
+		</div>
+	</label>
+	<label>
+		Comment
+		<textarea />
+	</label>
+	<label>
+		Subscribe
+		<div role="checkbox" aria-checked="false" tabIndex={0} />
+	</label>
+	<Label>Name</Label>
+	<Foo.label>Name</Foo.label>
+</>;
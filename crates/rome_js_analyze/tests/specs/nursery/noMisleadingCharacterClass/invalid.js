@@ -0,0 +1,11 @@
+/^[👍]$/;
+/^[👍]+$/g;
+/^[\uD83D\uDC4D]$/;
+/[👍]/i;
+/[^👍]/;
+/[👍]./;
+/[👍]\-/;
+/^[Á]$/;
+/^[A\u0301]$/;
+/^[❇️]$/;
+const regex = /[🇯🇵]/  ;
@@ -0,0 +1,9 @@
+/^[abc]$/;
+/^[👍]$/u;
+/^[👍]$/v;
+/^👍$/;
+/^[\uD83D]\uDC4D$/;
+/^[\u0301]$/;
+/^[́]$/;
+/^[Á]$/;
+/\[👍]/u;
@@ -0,0 +1,8 @@
+x =- y;
+x =+ y;
+x =~ y;
+x =! y;
+x =-y;
+x.y =- 1;
+x =- -y;
+x /* comment */ =- y;
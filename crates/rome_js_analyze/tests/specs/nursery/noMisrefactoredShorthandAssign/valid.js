@@ -0,0 +1,11 @@
+/* should not generate diagnostics */
+x -= y;
+x += y;
+x = -y;
+x = +y;
+x=-y;
+x=- y;
+x = - y;
+x =typeof y;
+x == -y;
+x === -y;
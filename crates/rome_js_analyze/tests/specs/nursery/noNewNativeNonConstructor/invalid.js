@@ -0,0 +1,7 @@
+var foo = new Symbol('foo');
+var bar = new BigInt(9007199254740991);
+new Symbol;
+new globalThis.Symbol();
+new window.BigInt(1);
+new (Symbol)("foo");
+var baz = /* comment */ new /* other */ BigInt(1);
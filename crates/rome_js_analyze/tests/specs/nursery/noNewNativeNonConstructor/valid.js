@@ -0,0 +1,11 @@
+/* should not generate diagnostics */
+var foo = Symbol('foo');
+var bar = BigInt(9007199254740991);
+new Number(1);
+function f(Symbol) {
+	new Symbol("foo");
+}
+{
+	class BigInt {}
+	new BigInt(1);
+}
@@ -0,0 +1,13 @@
+foo?.bar!;
+foo?.["bar"]!;
+foo?.bar()!;
+foo.bar?.()!;
+foo?.bar.baz!;
+foo?.bar.baz()!;
+(foo?.bar)!;
+(foo?.bar)!.baz;
+(foo?.bar)!();
+(foo?.bar!);
+(foo?.bar!)();
+const value = foo?.bar! /* comment */;
+foo?.bar!.baz!;
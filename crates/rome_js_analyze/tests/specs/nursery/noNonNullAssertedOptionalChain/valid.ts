@@ -0,0 +1,12 @@
+foo.bar!;
+foo.bar!.baz;
+foo.bar!();
+foo.bar()!;
+foo?.bar;
+foo?.bar();
+(foo?.bar).baz!;
+(foo?.bar()).baz!;
+foo?.bar!.baz;
+foo?.bar!();
+foo?.["bar"]!.baz;
+foo?.bar!["baz"];
@@ -0,0 +1,8 @@
+const mode = process.env.NODE_ENV;
+const ci = process.env["CI"];
+const { NODE_ENV, CI } = process.env;
+process.env.NODE_ENV = "test";
+// Invalid
+const key = process.env.API_KEY;
+const { NODE_ENV: env, API_KEY } = process.env;
+const all = process.env;
@@ -0,0 +1,13 @@
+const mode = process.env.NODE_ENV;
+const key = process.env["API_KEY"];
+const value = process.env[name];
+const env = process.env;
+const { API_KEY, "SECRET": secret } = process.env;
+const { NODE_ENV, ...rest } = process.env;
+process.env.DEBUG = "1";
+process.env["DEBUG"] = "1";
+const port = globalThis.process.env.PORT;
+const host = (process).env.HOST;
+f(process.env);
+a[process.env];
+a[process.env] = 1;
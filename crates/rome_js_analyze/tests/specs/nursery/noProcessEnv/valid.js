@@ -0,0 +1,7 @@
+const env = config.env;
+const mode = process.argv;
+const { env: environment } = process;
+
+function f(process) {
+	return process.env.NODE_ENV;
+}
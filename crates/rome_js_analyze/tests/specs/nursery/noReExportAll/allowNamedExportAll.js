@@ -0,0 +1,2 @@
+export * as utils from "./utils";
+export * from "./utils";
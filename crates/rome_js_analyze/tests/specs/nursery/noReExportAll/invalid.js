@@ -0,0 +1,3 @@
+export * from "./utils";
+export * as utils from "./utils";
+export * from "./constants" assert { type: "json" };
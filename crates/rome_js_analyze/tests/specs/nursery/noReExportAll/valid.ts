@@ -0,0 +1,7 @@
+/* should not generate diagnostics */
+export { parse, stringify } from "./utils";
+export { default } from "./component";
+export type * from "./types";
+export type * as types from "./types";
+import * as utils from "./utils";
+export { utils };
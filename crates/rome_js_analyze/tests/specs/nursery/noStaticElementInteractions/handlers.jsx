@@ -0,0 +1,6 @@
+<>
+	<div onDoubleClick={handleDoubleClick} />
+	<span onTouchStart={handleTouchStart} />
+	<div onClick={handleClick} />
+	<div role="button" onDoubleClick={handleDoubleClick} />
+</>;
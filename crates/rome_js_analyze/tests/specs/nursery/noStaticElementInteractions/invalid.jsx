@@ -0,0 +1,13 @@
+<>
+	<div onClick={() => {}} />
+	<div onClick={() => {}}></div>
+	<span onKeyDown={handleKeyDown}>Open</span>
+	<li onMouseDown={handleMouseDown}>Item</li>
+	<section onMouseUp={handleMouseUp} />
+	<div onKeyPress={handleKeyPress} />
+	<div onKeyUp={handleKeyUp} />
+	<a onClick={handleClick}>Link</a>
+	<input type="hidden" onClick={handleClick} />
+	<div role="article" onClick={handleClick} />
+	<div role="presentation" onClick={handleClick} />
+</>;
@@ -0,0 +1,16 @@
+<>
+	<div />
+	<div onChange={handleChange} />
+	<div onDoubleClick={handleDoubleClick} />
+	<button onClick={handleClick}>Submit</button>
+	<a href="/" onClick={handleClick}>Home</a>
+	<input onClick={handleClick} />
+	<select onKeyDown={handleKeyDown} />
+	<textarea onKeyUp={handleKeyUp} />
+	<summary onClick={handleClick}>Details</summary>
+	<div role="button" onClick={handleClick} onKeyDown={handleKeyDown} tabIndex={0} />
+	<span role="link" onClick={handleClick} />
+	<MyComponent onClick={handleClick} />
+	<Foo.Bar onClick={handleClick} />
+	<div {...props} />
+</>;
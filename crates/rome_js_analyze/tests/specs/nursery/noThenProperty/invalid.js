@@ -0,0 +1,18 @@
+const a = { then() {} };
+const b = { then: () => {} };
+const c = { "then": 1 };
+const d = { 'then'() {} };
+const e = { get then() { return 1; } };
+const f = { async then() {} };
+class G {
+    then() {}
+}
+class H {
+    then = 1;
+}
+class I {
+    static then() {}
+}
+class J {
+    get then() {}
+}
@@ -0,0 +1,10 @@
+/* should not generate diagnostics */
+const a = { thenable: true };
+const b = { ["then"]: 1 };
+const c = { [then]() {} };
+foo.then = () => {};
+promise.then(() => {});
+class E {
+    #then() {}
+    [then]() {}
+}
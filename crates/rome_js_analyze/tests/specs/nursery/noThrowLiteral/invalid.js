@@ -0,0 +1,17 @@
+throw "message";
+throw ("message");
+throw 'message'; // comment
+throw 0;
+throw 1n;
+throw true;
+throw null;
+throw /regex/;
+throw undefined;
+throw `message`;
+throw `message: ${foo}`;
+throw {};
+throw { message: "message" };
+throw [];
+function foo(Error) {
+	throw "message";
+}
@@ -0,0 +1,11 @@
+/* should not generate diagnostics */
+throw new Error("message");
+throw Error("message");
+throw error;
+throw foo.bar;
+throw foo();
+throw tag`message`;
+throw a ? b : c;
+function foo(undefined) {
+	throw undefined;
+}
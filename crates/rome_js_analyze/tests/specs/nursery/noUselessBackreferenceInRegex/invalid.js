@@ -0,0 +1,15 @@
+/(a\1)/;
+/(a(b\1))/;
+/(?<foo>a\k<foo>)/;
+/(a)|\1b/;
+/(?:(a)|b)|\1/;
+/a(?:(b)|c\1)/;
+/\1(a)/;
+/\k<foo>(?<foo>a)/;
+/(?:\1(a))+/;
+/(?<=(a)\1)b/;
+/(?!(a))\1/;
+/(?<!(a))b\1/;
+/(?=(?!(a))b)\1/;
+/(a)\2(b)/;
+/(a\1)|\1/;
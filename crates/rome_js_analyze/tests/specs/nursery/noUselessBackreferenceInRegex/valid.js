@@ -0,0 +1,15 @@
+/(a)\1/;
+/(?<foo>a)\k<foo>/;
+/(a)(b)\2\1/;
+/(?:(a)|b)\1/;
+/(a)(?:\1|b)/;
+/(?<=\1(a))b/;
+/(?=(a))\1/;
+/(?!(a)\1)b/;
+/\1/;
+/(a)\2/;
+/[(]\1/;
+/\(a\)\1/;
+/[\1](a)/;
+/\k<foo>/;
+/(a)\10/;
@@ -0,0 +1,12 @@
+"a" + "b";
+"a" + 'b' + "c";
+'a' + 'b' + "c";
+"a" + 'b';
+("a" + "b") + "c";
+"a" + ("b" + "c");
+"a" + "b" + x;
+x + ("a" + "b");
+"it's" + ' "quoted"';
+'say ' + "\"hi\"" + ' and \'bye\'';
+"\u{1F600}" + "\n";
+"a" + "b" /* trailing */;
@@ -0,0 +1,12 @@
+/* should not generate diagnostics */
+"a" + b;
+a + "b";
+"a" + `b`;
+`a` + `b`;
+"a" + 1;
+1 + 2;
+"a" - "b";
+x + "a" + "b";
+const a = "a" +
+	"b";
+const b = "a" + /* comment */ "b";
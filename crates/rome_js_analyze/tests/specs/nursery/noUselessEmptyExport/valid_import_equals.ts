@@ -0,0 +1,5 @@
+namespace A {
+	export const b = 0;
+}
+import b = A.b;
+export {};
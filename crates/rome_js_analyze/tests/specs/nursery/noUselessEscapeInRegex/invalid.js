@@ -0,0 +1,13 @@
+/\-/;
+/\;/;
+/\:/g;
+/\ /;
+/\"\'/;
+/\#\!\@/;
+/[\.]/;
+/[\*\+\?]/;
+/[a\/]/;
+/[\(\)]/;
+/[\[]/;
+/[^\^]/;
+/a\-b[\.]c\=/iu;
@@ -0,0 +1,18 @@
+/* should not generate diagnostics */
+/\./;
+/\/\//;
+/\^\$\*\+\?\(\)\[\]\{\}\|\\/;
+/\d\w\s\b\B\n\t\r\0\1/;
+/A\x41\cA/;
+/\p{L}/u;
+/\k<name>(?<name>a)/;
+/[\]]/;
+/[\\]/;
+/[a\-z]/;
+/[\^a]/;
+/[\d\W]/;
+/a-b;c/;
+/[.*+?()]/;
+/[\[\]\-\(\)]/v;
+/[[a-z]--\&]/v;
+/-/gimsuy;
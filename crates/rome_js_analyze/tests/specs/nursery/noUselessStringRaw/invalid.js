@@ -0,0 +1,11 @@
+String.raw`foo`;
+const a = String.raw`foo ${bar} baz`;
+const b = String.raw`${foo}${bar}`;
+const c = String.raw``;
+const d = (String.raw)`foo`;
+const e = String["raw"]`foo`;
+const f = String.raw`multi
+line`;
+const g = foo(String.raw`bar`);
+const h = /* comment */ String.raw`foo`;
+const i = String.raw /* comment */ `foo`;
@@ -0,0 +1,14 @@
+/* should not generate diagnostics */
+const a = String.raw`C:\Users\foo`;
+const b = String.raw`foo ${bar} \n`;
+const c = String.raw`\${foo}`;
+const d = String.raw`\``;
+const e = `foo`;
+const f = tag`foo`;
+const g = String.foo`bar`;
+const h = foo.raw`bar`;
+const i = String[raw]`foo`;
+
+function shadowed(String) {
+	return String.raw`foo`;
+}
@@ -0,0 +1,16 @@
+const a = <const>["a", "b"];
+const b = <string>value;
+const c = (<string>value).length;
+const d = <Foo>value.prop;
+const e = x * <number>y;
+const f = <number>x * y;
+const g = !<boolean>x;
+const h = x === <string>y;
+const i = x ?? <Foo>y;
+<Foo>{ a: 1 };
+const j = () => <Foo>{ a: 1 };
+call(<Foo>value, /* comment */ <Bar> other /* trailing */);
+const k = <Foo><Bar>value;
+const l = <string>x in y;
+const m = x in <Foo>y;
+class A extends (<Foo>B) {}
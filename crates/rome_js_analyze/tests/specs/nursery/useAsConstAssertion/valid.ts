@@ -0,0 +1,4 @@
+const a = ["a", "b"] as const;
+const b = value as string;
+const c = (value as string).length;
+const d = generic<string>(value);
@@ -0,0 +1,2 @@
+const a = <Foo>text</Foo>;
+const b = value as string;
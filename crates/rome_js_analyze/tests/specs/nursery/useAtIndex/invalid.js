@@ -0,0 +1,9 @@
+array[array.length - 1];
+const a = array[array.length - 2];
+const b = this.items[this.items.length - 1];
+const c = foo.bar.baz[foo.bar.baz.length - 10];
+const d = array[(array.length - 1)];
+const e = array[array.length - 0x1];
+const f = array[array.length - 1].foo;
+const g = foo(array[array.length - 1]);
+array[array.length - 1].bar();
@@ -0,0 +1,17 @@
+/* should not generate diagnostics */
+array.at(-1);
+array[0];
+array[array.length];
+array[array.length - 0];
+array[array.length - 1.5];
+array[array.length + 1];
+array[array.length - n];
+array[other.length - 1];
+foo()[foo().length - 1];
+array?.[array.length - 1];
+array[array?.length - 1];
+array[array.size - 1];
+array[array.length - 1] = value;
+delete array[array.length - 1];
+array[array.length - 1]();
+new array[array.length - 1]();
@@ -0,0 +1,15 @@
+let a: Array<string>;
+let b: ReadonlyArray<string>;
+let c: Array<string | number>;
+let d: Array<A & B>;
+let e: Array<() => void>;
+let f: Array<new () => Foo>;
+let g: Array<keyof Foo>;
+let h: Array<readonly string[]>;
+let i: ReadonlyArray<string | number>;
+let j: Array<Foo<Bar>>;
+let k: Array<[number, number]>;
+let l: Array<{ a: string }>;
+let m: Array< /* comment */ string >;
+let n: Array<Array<string>>;
+function foo(x: Array<string>): Array<number> {}
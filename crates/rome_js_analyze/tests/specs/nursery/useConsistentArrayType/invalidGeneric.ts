@@ -0,0 +1,9 @@
+let a: string[];
+let b: readonly string[];
+let c: (string | number)[];
+let d: ((A & B))[];
+let e: string[][];
+let f: readonly (readonly string[])[];
+function foo(x: string[]): number[] {}
+let g: /* leading */ string[] /* trailing */;
+let h: (/* comment */ A | B)[];
@@ -0,0 +1,9 @@
+/* should not generate diagnostics */
+let a: string[];
+let b: readonly string[];
+let c: (string | number)[];
+let d: Array;
+let e: Array<string, number>;
+let f: Foo.Array<string>;
+let g: Promise<string>;
+let h: readonly [string, number];
@@ -0,0 +1,5 @@
+/* should not generate diagnostics */
+let a: Array<string>;
+let b: ReadonlyArray<string>;
+let c: readonly [string, number];
+let d: Foo["bar"];
@@ -0,0 +1,12 @@
+const a = Map();
+const b = Set([1, 2]);
+const c = WeakMap();
+const d = Date();
+const e = Promise((resolve) => resolve());
+const f = Uint8Array(8);
+const g = globalThis.Map();
+const h = (Map)();
+const i = new Symbol("i");
+const j = new BigInt(1);
+const k = new Symbol;
+const l = /* comment */ new BigInt(1);
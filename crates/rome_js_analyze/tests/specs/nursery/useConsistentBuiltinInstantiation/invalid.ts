@@ -0,0 +1,2 @@
+const a = Map<string, number>();
+const b = new Symbol<string>("b");
@@ -0,0 +1,22 @@
+/* should not generate diagnostics */
+const a = new Map();
+const b = new Set([1, 2]);
+const c = new Date();
+const d = Symbol("d");
+const e = String(1);
+const f = Array(3);
+const g = new Array(3);
+const h = Object();
+const i = new Object();
+const j = Error("j");
+const k = new Error("k");
+const l = foo.Map();
+const m = new foo.Symbol();
+const n = new String("n");
+const o = new Number(1);
+const p = new Boolean(true);
+
+function shadowed(Map, Symbol) {
+	const a = Map();
+	const b = new Symbol("b");
+}
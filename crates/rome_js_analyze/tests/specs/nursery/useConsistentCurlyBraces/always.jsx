@@ -0,0 +1,14 @@
+<a href="/home">Home</a>;
+<p>Hello {name}</p>;
+<p>Say "hi"</p>;
+<p>
+	Several
+	lines
+</p>;
+<p>{"Already"}</p>;
+<a href={"/home"}>{"Home"}</a>;
+<div>
+	<span />
+</div>;
+<p>&amp;</p>;
+<Foo title="a\b" />;
@@ -0,0 +1,10 @@
+<a href={"/home"}>Home</a>;
+<a href={'/home'}>Home</a>;
+<Foo title={"It's here"} />;
+<p>{"Hello"}</p>;
+<p>Hello {"world"}</p>;
+<p>{"a < b"}</p>;
+<p>{"{braces}"}</p>;
+<p>
+	{"multi line"}
+</p>;
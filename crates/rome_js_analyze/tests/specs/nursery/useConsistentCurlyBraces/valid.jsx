@@ -0,0 +1,12 @@
+<a href="/home">Home</a>;
+<a href={url}>Home</a>;
+<a href={`/home`}>Home</a>;
+<p>Hello{" "}{name}</p>;
+<p>{" leading"}</p>;
+<p>{"trailing "}</p>;
+<p>{""}</p>;
+<p>{"a\nb"}</p>;
+<p>{"&amp;"}</p>;
+<Foo title={"a\"b"} />;
+<Foo title={"&nbsp;"} />;
+<p>{1}</p>;
@@ -0,0 +1,22 @@
+class Person {
+    name: string;
+    static count = 0;
+    #age: number;
+    readonly id: string;
+    @observable
+    score: number;
+    @observable static total: number;
+
+    constructor(readonly email: string, private phone: string, nickname: string) {}
+
+    greet() {}
+    async *items() {}
+    get fullName() {
+        return this.name;
+    }
+    set fullName(value: string) {}
+    #secret() {}
+    override toString() {
+        return this.name;
+    }
+}
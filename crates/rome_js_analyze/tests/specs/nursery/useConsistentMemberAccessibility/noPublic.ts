@@ -0,0 +1,18 @@
+class Person {
+    public name: string;
+    public static count = 0;
+    @observable
+    public score: number;
+    @observable public total: number;
+    protected id: string;
+    private age: number;
+    nickname: string;
+
+    constructor(public readonly email: string) {}
+
+    public greet() {}
+    public get fullName() {
+        return this.name;
+    }
+    public set fullName(value: string) {}
+}
@@ -0,0 +1,4 @@
+class Person {
+    name;
+    greet() {}
+}
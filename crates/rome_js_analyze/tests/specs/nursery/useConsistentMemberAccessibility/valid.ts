@@ -0,0 +1,16 @@
+class Person {
+    public name: string;
+    protected static count = 0;
+    #age: number;
+    private readonly id: string;
+    @observable
+    public score: number;
+
+    constructor(public readonly email: string, nickname: string) {}
+
+    public greet() {}
+    private get fullName() {
+        return this.name;
+    }
+    protected set fullName(value: string) {}
+}
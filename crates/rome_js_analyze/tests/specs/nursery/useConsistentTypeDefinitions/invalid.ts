@@ -0,0 +1,13 @@
+type Point = { x: number; y: number; };
+
+type Box<T> = { value: T };
+
+export type Empty = {};
+
+declare type Options = {
+	name: string;
+	// comment
+	verbose?: boolean;
+} // trailing
+
+type Tree<T = unknown> = { value: T; children: Tree<T>[] }
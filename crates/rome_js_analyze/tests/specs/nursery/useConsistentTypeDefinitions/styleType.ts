@@ -0,0 +1,18 @@
+interface Point { x: number; y: number; }
+
+export interface Box<T> extends Container<T> {
+	value: T;
+}
+
+interface Named extends Base, Identified<string>, ns.Tagged {}
+
+interface Tree<T = unknown>
+{ value: T; children: Tree<T>[] } // trailing
+
+export default interface Props { name: string }
+
+declare global {
+	interface Window { custom: string }
+}
+
+type Id = { id: string };
@@ -0,0 +1,11 @@
+interface Point { x: number; y: number; }
+
+type Id = string | number;
+
+type Flags<T> = { [K in keyof T]: boolean };
+
+type Named = { name: string } & { id: number };
+
+type Callback = () => void;
+
+type Wrapped = ({ value: string });
@@ -0,0 +1,8 @@
+const a = new Date().getTime();
+const b = new Date().valueOf();
+const c = +new Date();
+const d = +new Date;
+const f = (new Date()).getTime();
+const g = new globalThis.Date().getTime();
+const h = +(new Date());
+foo(/* comment */ new Date().getTime() /* trailing */);
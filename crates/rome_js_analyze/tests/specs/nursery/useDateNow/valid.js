@@ -0,0 +1,13 @@
+/* should not generate diagnostics */
+const a = Date.now();
+const b = new Date(date).getTime();
+const c = +new Date(2000, 1, 1);
+const d = -new Date();
+const e = new Date().getTime(0);
+const f = new Date()?.getTime();
+const g = new Date().getTime?.();
+const h = new Date()["getTime"]();
+const i = date.getTime();
+function f(Date) {
+	return new Date().getTime();
+}
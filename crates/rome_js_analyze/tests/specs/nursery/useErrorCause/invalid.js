@@ -0,0 +1,45 @@
+try {} catch (error) {
+    throw new Error("Failed");
+}
+
+try {} catch (error) {
+    throw new TypeError(`Failed: ${error.message}`);
+}
+
+try {} catch (e) {
+    throw new Error("Failed", { code: 1 });
+}
+
+try {} catch (e) {
+    throw new Error("Failed", {});
+}
+
+try {} catch (e) {
+    throw new Error(
+        "Failed",
+    );
+}
+
+try {} catch (e) {
+    throw new Error("Failed", {
+        code: 1,
+    });
+}
+
+try {} catch (cause) {
+    throw new Error("Failed");
+}
+
+try {} catch (error) {
+    if (error instanceof Foo) {
+        throw new CustomError("Failed");
+    }
+}
+
+try {} catch (error) {
+    throw new Error;
+}
+
+try {} catch (error) {
+    throw new Error();
+}
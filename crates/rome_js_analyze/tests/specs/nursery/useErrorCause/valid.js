@@ -0,0 +1,57 @@
+/* should not generate diagnostics */
+throw new Error("Failed");
+
+try {} catch {
+    throw new Error("Failed");
+}
+
+try {} catch ({ message }) {
+    throw new Error(message);
+}
+
+try {} catch (error) {
+    throw error;
+}
+
+try {} catch (error) {
+    throw new Error("Failed", { cause: error });
+}
+
+try {} catch (cause) {
+    throw new Error("Failed", { cause });
+}
+
+try {} catch (error) {
+    throw new WrappedError(error);
+}
+
+try {} catch (error) {
+    throw new Error("Failed", { ...options });
+}
+
+try {} catch (error) {
+    throw new Error(...args);
+}
+
+try {} catch (error) {
+    const fn = () => {
+        throw new Error("Failed");
+    };
+}
+
+try {} catch (error) {
+    {
+        const error = 1;
+        throw new Error("Failed");
+    }
+}
+
+try {} catch (error) {
+    throw Error("Failed");
+}
+
+try {} catch (error) {
+    try {} catch {
+        throw new Error("Failed");
+    }
+}
@@ -0,0 +1,7 @@
+export type A = string;
+export interface B {}
+export type { C } from "./c";
+const a: A = "a";
+export { a };
+export default interface D {}
+const b = 1;
@@ -0,0 +1,12 @@
+// License header
+
+export const a = 1;
+
+// Comment of b
+const b = 2;
+
+export { b };
+
+function f() {}
+
+export default f;
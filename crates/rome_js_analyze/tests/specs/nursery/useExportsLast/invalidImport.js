@@ -0,0 +1,4 @@
+import { a } from "./a";
+export { a };
+import { b } from "./b";
+export { b };
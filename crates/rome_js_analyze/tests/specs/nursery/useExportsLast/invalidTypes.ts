@@ -0,0 +1,4 @@
+export type A = string;
+const a: A = "a";
+export interface B {}
+export { a };
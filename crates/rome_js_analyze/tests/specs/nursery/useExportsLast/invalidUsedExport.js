@@ -0,0 +1,9 @@
+export const a = 1;
+
+console.log(a);
+
+export class A {}
+
+function f() {
+	return new A();
+}
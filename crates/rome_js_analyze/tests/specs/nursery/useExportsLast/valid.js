@@ -0,0 +1,7 @@
+import { a } from "./a";
+const b = 1;
+function f() {}
+
+export { a, b };
+export default f;
+export * from "./c";
@@ -0,0 +1,4 @@
+const a = 1;
+export type A = string;
+export interface B {}
+export { a };
@@ -0,0 +1,5 @@
+/abc/ig;
+/abc/ymg;
+/abc/sd;
+/abc/yusmigd;
+const regex = /a\/b[/]/mi  ;
@@ -0,0 +1,6 @@
+/abc/;
+/abc/g;
+/abc/gi;
+/abc/gmy;
+/abc/dgimsuy;
+new RegExp("abc", "ig");
@@ -0,0 +1,8 @@
+<>
+	<div role="button" onClick={handleClick}>Submit</div>
+	<span role="checkbox" aria-checked="false" />
+	<div role="link">Home</div>
+	<li role="menuitem">Open</li>
+	<a role="button">Submit</a>
+	<div role="switch button" aria-checked="true" />
+</>;
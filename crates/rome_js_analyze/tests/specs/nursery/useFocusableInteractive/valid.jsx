@@ -0,0 +1,14 @@
+<>
+	<div role="button" tabIndex={0}>Submit</div>
+	<div role="button" tabIndex="-1">Submit</div>
+	<a href="/" role="button">Submit</a>
+	<button role="switch" aria-checked="true" />
+	<input role="combobox" />
+	<div role="article" />
+	<div role="presentation" />
+	<div />
+	<div role="button" {...props} />
+	<div role="button" aria-disabled="true" />
+	<Button role="button" />
+	<div role={role} />
+</>;
@@ -0,0 +1,7 @@
+import { sum } from "./math";
+import data from '../data';
+import "./polyfills";
+import * as strings from "../../utils/strings";
+export * from "./components/button";
+export { default } from "./.config/index";
+import("./lazy"); // dynamic imports are not checked
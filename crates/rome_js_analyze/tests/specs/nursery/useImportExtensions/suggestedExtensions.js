@@ -0,0 +1 @@
+import { sum } from "./math";
@@ -0,0 +1,11 @@
+import { sum } from "./math.js";
+import data from "../data.json";
+import "./polyfills.mjs";
+import lodash from "lodash";
+import fs from "node:fs";
+import parent from "..";
+import current from ".";
+import index from "./components/";
+import scoped from "@scope/package/sub";
+export * from "./button.jsx";
+export { a } from "/absolute/path";
@@ -0,0 +1,4 @@
+import lodash from "lodash";
+
+import fs from "node:fs";
+import { sum } from "./math.js";
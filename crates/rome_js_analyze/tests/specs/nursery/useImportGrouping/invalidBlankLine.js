@@ -0,0 +1,5 @@
+import fs from "node:fs";
+import path from "path";
+import lodash from "lodash";
+
+import { sum } from "./math.js";
@@ -0,0 +1,9 @@
+// License header
+
+import { sum } from "./math.js";
+// The file system
+import fs from "node:fs";
+import lodash from "lodash"; // trailing comment
+
+// Section comment
+import path from "path";
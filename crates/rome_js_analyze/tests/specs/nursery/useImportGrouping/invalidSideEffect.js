@@ -0,0 +1,5 @@
+import "./polyfill.js";
+import { sum } from "./math.js";
+import fs from "node:fs";
+import "./setup.js";
+import lodash from "lodash";
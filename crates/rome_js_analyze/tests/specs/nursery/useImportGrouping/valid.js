@@ -0,0 +1,12 @@
+import fs from "node:fs";
+import path from "path";
+
+import lodash from "lodash";
+import React from "react";
+
+import { sum } from "./math.js";
+import { a } from "../a.js";
+import "./polyfill.js";
+import os from "os";
+console.log(fs);
+import b from "b";
@@ -0,0 +1,9 @@
+import { A, B as C } from "./a";
+import D from "./d";
+import E, { F } from "./e";
+import { G, H } from "./g";
+
+let a: A<C>;
+let d: D;
+let f: E<F>;
+let g: G = H;
@@ -0,0 +1,25 @@
+import A from "./a";
+import * as B from "./b";
+import { C } from "./c";
+import { D as E, F } from "./d";
+import { type G, H } from "./g";
+import {
+    I,
+    // Comment
+    J,
+} from "./i";
+import K, { L } from "./k";
+import M, * as N from "./m";
+import O, { P, Q } from "./o";
+import { R, S } from "./r";
+
+let a: A;
+let b: B;
+let c: C;
+let e: E<F>;
+let g: G | H;
+let i: I & J;
+let k: K<L>;
+let m: M<N.Type>;
+let o: O = new P<Q>();
+let r: typeof R = S;
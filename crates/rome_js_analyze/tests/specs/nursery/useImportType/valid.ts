@@ -0,0 +1,27 @@
+import type A from "./a";
+import type * as B from "./b";
+import type { C } from "./c";
+import { type D, type E } from "./d";
+import F, { type G } from "./f";
+import { H } from "./h";
+import { I } from "./i";
+import { J } from "./j";
+import K from "./k";
+import L from "./l";
+import * as M from "./m";
+import "./n";
+
+let a: A;
+let b: B;
+let c: C;
+let d: D | E;
+let f: G = F;
+let h: H = new H();
+let i: I;
+export { I };
+J();
+class Cls extends K {}
+let l: { [L]: string };
+function isM(x: unknown): x is M.Type {
+    return x instanceof M.Class<M.Type>;
+}
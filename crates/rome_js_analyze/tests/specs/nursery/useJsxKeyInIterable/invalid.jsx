@@ -0,0 +1,18 @@
+items.map((item) => <li>{item}</li>);
+items.map((item) => <Item value={item} />);
+items.map((item) => (
+	<li>{item}</li>
+));
+items.map((item) => <>{item}</>);
+items.map(function (item) {
+	return <li>{item}</li>;
+});
+items.map((item) => {
+	if (item.hidden) {
+		return <li hidden />;
+	}
+	return <li>{item}</li>;
+});
+items.map((item) => (item.done ? <Done /> : <Todo key={item.id} />));
+items.map((item) => item.visible && <li>{item}</li>);
+items?.map((item) => <li>{item}</li>);
@@ -0,0 +1,12 @@
+items.map((item) => <li key={item.id}>{item}</li>);
+items.map((item) => <Item key={item.id} value={item} />);
+items.map((item) => <Fragment key={item.id}>{item}</Fragment>);
+items.map((item) => <Item {...item} />);
+items.map((item) => {
+	const render = () => <li>{item}</li>;
+	return <li key={item.id}>{render()}</li>;
+});
+items.map((item) => item.name);
+items.forEach((item) => <li>{item}</li>);
+map((item) => <li>{item}</li>);
+<ul>{items.map((item) => <li key={item}>{item}</li>)}</ul>;
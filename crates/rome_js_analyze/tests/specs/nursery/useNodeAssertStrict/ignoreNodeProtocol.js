@@ -0,0 +1,3 @@
+import assert from "node:assert";
+import assert2 from "assert";
+const assert3 = require("node:assert");
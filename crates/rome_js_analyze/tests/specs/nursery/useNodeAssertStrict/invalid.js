@@ -0,0 +1,9 @@
+import assert from "assert";
+import * as assert2 from 'node:assert';
+import { strictEqual } from "assert";
+import "node:assert";
+export { deepEqual } from "assert";
+
+const assert3 = require("assert");
+const { equal } = require('node:assert');
+const assert4 = globalThis.require("assert");
@@ -0,0 +1,11 @@
+import assert from "node:assert/strict";
+import { strictEqual } from "assert/strict";
+import assertion from "assertion";
+
+const assert2 = require("node:assert/strict");
+const assert3 = require(`assert`);
+const assert4 = require("assert", "other");
+
+function f(require) {
+	return require("assert");
+}
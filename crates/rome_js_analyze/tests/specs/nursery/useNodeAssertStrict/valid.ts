@@ -0,0 +1,3 @@
+declare module "assert" {
+	export function ok(value: unknown): void;
+}
@@ -0,0 +1,3 @@
+import { EventEmitter } from "events";
+const { Buffer } = require("buffer");
+import test from "test";
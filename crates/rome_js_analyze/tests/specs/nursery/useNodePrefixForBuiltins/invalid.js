@@ -0,0 +1,9 @@
+import fs from "fs";
+import { readFile } from 'fs/promises';
+import * as path from "path";
+import "child_process";
+export { Worker } from "worker_threads";
+
+const os = require("os");
+const { join } = require('path/posix');
+const http = globalThis.require("http");
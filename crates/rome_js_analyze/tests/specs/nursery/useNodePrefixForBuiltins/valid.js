@@ -0,0 +1,15 @@
+import fs from "node:fs";
+import test from "test";
+import { EventEmitter } from "events";
+import { Buffer } from "buffer";
+import react from "react";
+import fsExtra from "fs-extra";
+import local from "./fs";
+
+const path = require("node:path");
+const util = require("util");
+const os = require(`os`);
+
+function f(require) {
+	return require("fs");
+}
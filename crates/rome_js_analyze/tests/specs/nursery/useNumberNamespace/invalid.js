@@ -0,0 +1,8 @@
+parseInt("1");
+parseFloat("1.1");
+isNaN(value);
+isFinite(value);
+globalThis.parseInt("1");
+window["parseFloat"]("1.1");
+(parseInt)("1", 10);
+/* leading */ parseInt /* inner */ ("1");
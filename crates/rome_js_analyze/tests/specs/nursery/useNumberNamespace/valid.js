@@ -0,0 +1,14 @@
+/* should not generate diagnostics */
+Number.parseInt("1");
+Number.parseFloat("1.1");
+Number.isNaN(value);
+Number.isFinite(value);
+const fn = parseInt;
+foo.parseInt("1");
+function f(parseInt) {
+	parseInt("1");
+}
+{
+	const isNaN = () => false;
+	isNaN(value);
+}
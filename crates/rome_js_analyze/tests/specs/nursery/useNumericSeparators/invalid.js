@@ -0,0 +1,13 @@
+1000000;
+12345;
+123456.7;
+3.14159265;
+1234.567891;
+1e100000;
+1234567e8;
+0xFFFFFFFF;
+0XABCDEF;
+0o7777777;
+0b1010101010101010;
+9007199254740993n;
+0xFFFFFFFFFn;
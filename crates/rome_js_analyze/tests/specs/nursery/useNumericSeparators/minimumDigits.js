@@ -0,0 +1,3 @@
+123456;
+1234567;
+0.1234567;
@@ -0,0 +1,12 @@
+/* should not generate diagnostics */
+0;
+1;
+1234;
+1.5;
+0.1234;
+1_000_000;
+10_00_000;
+1_0000.123456;
+0xFFFF;
+0b11111111;
+100n;
@@ -0,0 +1,3 @@
+/* should not generate diagnostics */
+0777777;
+0123456;
@@ -0,0 +1,17 @@
+const a = Object.assign({}, foo);
+const b = Object.assign({}, foo, bar);
+const c = Object.assign({}, { a: 1, b }, foo);
+const d = Object.assign({}, { [key]: value, method() {}, ...rest });
+const e = Object.assign({});
+const f = Object.assign({}, {});
+const g = globalThis.Object.assign({}, foo);
+const h = Object["assign"]({}, foo);
+const i = Object.assign({}, { get a() { return 1; } });
+const j = Object.assign({}, { __proto__: proto });
+const k = Object.assign({}, a ? b : c, await d);
+Object.assign({}, foo);
+Object.assign({}, foo).bar;
+const l = () => Object.assign({}, foo);
+const m = () => Object.assign({}, foo).bar;
+const n = Object.assign({}, /* comment */ foo);
+const o = (Object.assign)({}, foo);
@@ -0,0 +1,13 @@
+/* should not generate diagnostics */
+Object.assign(target, foo);
+Object.assign({ a: 1 }, foo);
+Object.assign({}, ...sources);
+Object.assign({}, foo, ...sources);
+Object.assign();
+Object.assign?.({}, foo);
+Object.keys({}, foo);
+foo.assign({}, bar);
+
+function shadowed(Object) {
+    Object.assign({}, foo);
+}
@@ -0,0 +1,13 @@
+new RegExp("abc");
+new RegExp("abc", "g");
+RegExp("abc", "gi");
+new RegExp("\\d+\\.\\d*");
+new RegExp("a/b");
+new RegExp("[/]\\/");
+new RegExp("");
+new RegExp("line\nbreak");
+new RegExp('\x41');
+window.RegExp("abc");
+globalThis.RegExp("abc", "u");
+new RegExp("abc").test(value);
+const regex = (new RegExp("^#"));
@@ -0,0 +1,14 @@
+/abc/;
+new RegExp(pattern);
+new RegExp("abc", flags);
+new RegExp(`^${prefix}`);
+new RegExp("abc", "gg");
+new RegExp("abc", "x");
+new RegExp("abc\\");
+new RegExp(/abc/, "g");
+new RegExp();
+new RegExp("a", "g", "extra");
+new Foo("abc");
+function f(RegExp) {
+	return new RegExp("abc");
+}
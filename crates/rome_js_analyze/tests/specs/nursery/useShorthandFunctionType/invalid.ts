@@ -0,0 +1,28 @@
+interface Callback {
+    (x: number): void;
+}
+
+export interface Comparator<T> {
+    (a: T, b: T): number
+}
+
+// Comment
+interface Factory { <T extends object>(value: T, ...rest: unknown[]): Promise<T>, }
+
+declare interface Guard {
+    (value: unknown): value is string;
+}
+
+export default interface Handler {
+    (event: Event): void;
+}
+
+interface Untyped {
+    (event: Event)
+}
+
+function f() {
+    interface Local {
+        (): void;
+    }
+}
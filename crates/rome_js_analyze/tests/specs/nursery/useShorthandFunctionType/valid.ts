@@ -0,0 +1,25 @@
+type Callback = (x: number) => void;
+
+interface Overloaded {
+    (x: number): number;
+    (x: string): string;
+}
+
+interface WithProperty {
+    (x: number): void;
+    name: string;
+}
+
+interface Extended extends Base {
+    (x: number): void;
+}
+
+interface Constructor {
+    new (x: number): Constructor;
+}
+
+interface Fluent {
+    (x: number): this;
+}
+
+interface Empty {}
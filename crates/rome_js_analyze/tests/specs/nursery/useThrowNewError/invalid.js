@@ -0,0 +1,13 @@
+throw Error();
+throw Error("message");
+throw TypeError("message", { cause: error });
+throw (RangeError("message"));
+throw globalThis.SyntaxError("message");
+throw (Error)("message");
+throw AggregateError([], "message");
+throw ReferenceError(
+	"message",
+);
+function foo() {
+	throw URIError("message");
+}
@@ -0,0 +1 @@
+throw Error<string>("message");
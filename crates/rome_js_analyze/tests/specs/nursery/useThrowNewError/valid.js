@@ -0,0 +1,13 @@
+/* should not generate diagnostics */
+throw new Error("message");
+throw new TypeError("message");
+throw error;
+throw createError("message");
+throw foo.Error("message");
+throw Error;
+throw globalThis?.Error("message");
+const error = Error("message");
+
+function shadowed(Error) {
+	throw Error("message");
+}
@@ -0,0 +1,8 @@
+foo.trimLeft();
+foo.trimRight();
+foo.bar.trimLeft();
+foo?.trimRight();
+(foo.trimLeft)();
+"  foo  ".trimRight();
+foo
+    .trimLeft /* comment */ ();
@@ -0,0 +1,9 @@
+/* should not generate diagnostics */
+foo.trimStart();
+foo.trimEnd();
+trimLeft();
+foo.trimLeft(1);
+foo["trimLeft"]();
+foo.trimLeft;
+foo.trimLeftAndRight();
+new foo.trimLeft();
@@ -0,0 +1,9 @@
+<>
+	<input autoComplete="emial" />
+	<input autocomplete="incorrect" />
+	<input autoComplete="billing incorrect" />
+	<input autoComplete="shipping postal-cod" />
+	<input autoComplete="section-login new-pasword" />
+	<form autoComplete="of" />
+	<input autoComplete="given name" />
+</>
@@ -0,0 +1,14 @@
+<>
+	<input autoComplete="email" />
+	<input autocomplete="off" />
+	<input autoComplete="on" />
+	<input autoComplete="EMAIL" />
+	<input autoComplete="section-login shipping street-address" />
+	<input autoComplete="billing cc-number" />
+	<input autoComplete="work tel webauthn" />
+	<input autoComplete={autoComplete} />
+	<input autoComplete />
+	<Input autoComplete="incorrect" />
+	<Foo.Input autoComplete="incorrect" />
+	<input title="incorrect" />
+</>
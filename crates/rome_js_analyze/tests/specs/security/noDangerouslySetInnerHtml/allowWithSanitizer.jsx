@@ -0,0 +1,15 @@
+// valid
+<div dangerouslySetInnerHTML={{ __html: DOMPurify.sanitize(html) }} />;
+<div dangerouslySetInnerHTML={{ __html: sanitizeHtml(html) }} />;
+<div dangerouslySetInnerHTML={{ __html: (xss(html)) }} />;
+React.createElement("div", {
+	dangerouslySetInnerHTML: { __html: sanitize(html) },
+});
+
+// invalid
+<div dangerouslySetInnerHTML={{ __html: html }} />;
+<div dangerouslySetInnerHTML={{ __html: escape(html) }} />;
+<div dangerouslySetInnerHTML={createMarkup()} />;
+React.createElement("div", {
+	dangerouslySetInnerHTML: { __html: html },
+});
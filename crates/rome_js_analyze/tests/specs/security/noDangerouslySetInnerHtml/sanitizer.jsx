@@ -0,0 +1,5 @@
+<div dangerouslySetInnerHTML={{ __html: DOMPurify.sanitize(html) }} />;
+React.createElement("div", {
+	...props,
+	dangerouslySetInnerHTML: { __html: sanitize(html) },
+});
@@ -0,0 +1,12 @@
+<>
+    <div dangerouslySetInnerHTML={{ __html: 'HTML' }}>
+    </div>
+    <div dangerouslySetInnerHTML={{ __html: 'HTML' }}>
+        {/* comment */}
+    </div>
+    <div dangerouslySetInnerHTML={{ __html: 'HTML' }}>{}</div>
+    <div dangerouslySetInnerHTML={{ __html: 'HTML' }}> </div>
+    <div dangerouslySetInnerHTML={{ __html: 'HTML' }}>
+        child
+    </div>
+</>
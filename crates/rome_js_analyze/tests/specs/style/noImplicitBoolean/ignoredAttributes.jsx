@@ -0,0 +1 @@
+<input key ref disabled />;
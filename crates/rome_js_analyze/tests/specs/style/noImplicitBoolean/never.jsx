@@ -0,0 +1,8 @@
+<input disabled={true} />;
+<input disabled={true}/>;
+<input accept={true} /** some comment */ />;
+<input disabled />;
+<input disabled={false} />;
+<input disabled="true" />;
+<input disabled={"true"} />;
+<input key={true} />;
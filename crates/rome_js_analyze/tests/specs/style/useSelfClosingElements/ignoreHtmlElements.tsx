@@ -0,0 +1,4 @@
+<div></div>;
+<input></input>;
+<Component></Component>;
+<Foo.bar></Foo.bar>;
@@ -0,0 +1,7 @@
+<div>
+</div>;
+<Component>
+	{}
+</Component>;
+<input></input>;
+<img src="a.png"></img>;
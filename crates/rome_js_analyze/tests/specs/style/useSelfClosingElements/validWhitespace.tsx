@@ -0,0 +1,5 @@
+<div> </div>;
+<div>{/* comment */}</div>;
+<div>
+	{value}
+</div>;
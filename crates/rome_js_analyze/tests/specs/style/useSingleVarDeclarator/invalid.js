@@ -8,3 +8,11 @@ function test() {
 var x = 1,
 	// comment
     y = 2
+
+
+const a = 1, // first
+	b = 2; // last
+
+let c, /* c */ d
+
+for (let i = 0, j = 0; i < j; i++) {}
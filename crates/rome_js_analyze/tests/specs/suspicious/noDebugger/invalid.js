@@ -7,4 +7,13 @@ debugger;
 function test() {
   let a = 3;
   debugger;
-}
\ No newline at end of file
+}
+
+function onlyStatement() {
+  debugger;
+}
+
+for (;;) debugger;
+
+if (foo) {
+} else debugger;
@@ -26,3 +26,6 @@ class A {
 class A {
 	constructor(readonly a, private a) {}
 }
+function g({ a }, a) {}
+function h([a = 1], ...a) {}
+function i(a, { b: [a] }, { ...a }) {}
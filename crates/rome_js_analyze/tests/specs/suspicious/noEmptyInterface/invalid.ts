@@ -11,3 +11,9 @@ interface Foo<T> extends Bar<T> {}
 declare module FooBar {
   export interface Bar extends Baz {}
 }
+
+interface Baz extends Foo, Bar {}
+
+export interface Qux<T> extends Foo<T>, Bar, Baz<T[]> {}
+
+export default interface Quux extends Foo {}
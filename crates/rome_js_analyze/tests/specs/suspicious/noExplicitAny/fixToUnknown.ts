@@ -0,0 +1,3 @@
+let variable: any = 1;
+function generic(param: Array<any>): any[] {}
+const value = input as any;
@@ -0,0 +1,9 @@
+function foo1(...args: any[]) {}
+function foo2(...args: readonly any[]) {}
+function foo3(...args: Array<any>) {}
+function foo4(...args: ReadonlyArray<any>) {}
+function foo5(...args: any) {}
+const foo6 = (...args: any[]) => {};
+type Foo7 = (...args: any[]) => void;
+function foo8(first: any, ...args: Array<any[]>) {}
+function foo9(...args: Map<any, string>) {}
@@ -8,3 +8,13 @@ function test() {
     }
   }
 }
+
+function f(param) {
+  param: while (true) {
+    break param;
+  }
+}
+
+class C {}
+C: {
+}
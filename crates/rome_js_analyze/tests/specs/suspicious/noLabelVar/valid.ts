@@ -0,0 +1,16 @@
+interface Foo {}
+Foo: for (;;) {}
+
+type Bar = string;
+Bar: {
+}
+
+function f<T>() {
+  T: while (true) {}
+}
+
+{
+  const inner = 0;
+}
+inner: {
+}
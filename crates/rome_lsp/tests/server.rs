@@ -626,19 +626,19 @@ async fn pull_diagnostics() -> Result<()> {
 fn fixable_diagnostic(line: u32) -> Result<lsp::Diagnostic> {
     Ok(lsp::Diagnostic {
         range: lsp::Range {
-            start: lsp::Position { line, character: 3 },
+            start: lsp::Position { line, character: 5 },
             end: lsp::Position {
                 line,
-                character: 11,
+                character: 13,
             },
         },
         severity: Some(lsp::DiagnosticSeverity::ERROR),
         code: Some(lsp::NumberOrString::String(String::from(
-            "lint/suspicious/noCompareNegZero",
+            "lint/suspicious/noDebugger",
         ))),
         code_description: None,
         source: Some(String::from("biome")),
-        message: String::from("Do not use the === operator to compare against -0."),
+        message: String::from("This is an unexpected use of the debugger statement."),
         related_information: None,
         tags: None,
         data: None,
@@ -658,7 +658,7 @@ async fn pull_quick_fixes() -> Result<()> {
     server.initialize().await?;
     server.initialized().await?;
 
-    server.open_document("if(a === -0) {}").await?;
+    server.open_document("a(); debugger;").await?;
 
     let res: lsp::CodeActionResponse = server
         .request(
@@ -701,11 +701,11 @@ async fn pull_quick_fixes() -> Result<()> {
             range: lsp::Range {
                 start: lsp::Position {
                     line: 0,
-                    character: 9,
+                    character: 5,
                 },
                 end: lsp::Position {
                     line: 0,
-                    character: 10,
+                    character: 14,
                 },
             },
             new_text: String::new(),
@@ -713,9 +713,9 @@ async fn pull_quick_fixes() -> Result<()> {
     );
 
     let expected_code_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
-        title: String::from("Replace -0 with 0"),
+        title: String::from("Remove debugger statement"),
         kind: Some(lsp::CodeActionKind::new(
-            "quickfix.biome.suspicious.noCompareNegZero",
+            "quickfix.biome.suspicious.noDebugger",
         )),
         diagnostics: Some(vec![fixable_diagnostic(0)?]),
         edit: Some(lsp::WorkspaceEdit {
@@ -743,16 +743,14 @@ async fn pull_quick_fixes() -> Result<()> {
                     character: 0,
                 },
             },
-            new_text: String::from(
-                "// rome-ignore lint/suspicious/noCompareNegZero: <explanation>\n",
-            ),
+            new_text: String::from("// rome-ignore lint/suspicious/noDebugger: <explanation>\n"),
         }],
     );
 
     let expected_suppression_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
-        title: String::from("Suppress rule lint/suspicious/noCompareNegZero"),
+        title: String::from("Suppress rule lint/suspicious/noDebugger"),
         kind: Some(lsp::CodeActionKind::new(
-            "quickfix.suppressRule.biome.suspicious.noCompareNegZero",
+            "quickfix.suppressRule.biome.suspicious.noDebugger",
         )),
         diagnostics: Some(vec![fixable_diagnostic(0)?]),
         edit: Some(lsp::WorkspaceEdit {
@@ -1325,9 +1323,7 @@ async fn pull_fix_all() -> Result<()> {
     server.initialize().await?;
     server.initialized().await?;
 
-    server
-        .open_document("if(a === -0) {}\nif(a === -0) {}\nif(a === -0) {}")
-        .await?;
+    server.open_document("a(); debugger;\nb();\nc();").await?;
 
     let res: lsp::CodeActionResponse = server
         .request(
@@ -1348,11 +1344,7 @@ async fn pull_fix_all() -> Result<()> {
                     },
                 },
                 context: lsp::CodeActionContext {
-                    diagnostics: vec![
-                        fixable_diagnostic(0)?,
-                        fixable_diagnostic(1)?,
-                        fixable_diagnostic(2)?,
-                    ],
+                    diagnostics: vec![fixable_diagnostic(0)?],
                     only: Some(vec![lsp::CodeActionKind::new("source.fixAll")]),
                     ..Default::default()
                 },
@@ -1382,18 +1374,14 @@ async fn pull_fix_all() -> Result<()> {
                     character: 0,
                 },
             },
-            new_text: String::from("if (a === 0) {\n}\nif (a === 0) {\n}\nif (a === 0) {\n}\n"),
+            new_text: String::from("a();\nb();\nc();\n"),
         }],
     );
 
     let expected_action = lsp::CodeActionOrCommand::CodeAction(lsp::CodeAction {
         title: String::from("Fix all auto-fixable issues"),
         kind: Some(lsp::CodeActionKind::new("source.fixAll.biome")),
-        diagnostics: Some(vec![
-            fixable_diagnostic(0)?,
-            fixable_diagnostic(1)?,
-            fixable_diagnostic(2)?,
-        ]),
+        diagnostics: Some(vec![fixable_diagnostic(0)?]),
         edit: Some(lsp::WorkspaceEdit {
             changes: Some(changes),
             document_changes: None,
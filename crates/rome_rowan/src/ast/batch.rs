@@ -42,6 +42,8 @@ struct CommitChange<L: Language> {
     parent_range: Option<(u32, u32)>,
     new_node_slot: usize,
     new_node: Option<SyntaxElement<L>>,
+    /// Whether "new_node" is inserted before "new_node_slot" instead of replacing it.
+    is_insertion: bool,
 }
 
 impl<L: Language> CommitChange<L> {
@@ -277,13 +279,61 @@ where
         self.push_change(prev_element, None)
     }
 
+    /// Push a change to insert "next_node" after "prev_node".
+    /// The parent of "prev_node" must be a list without separators.
+    ///
+    /// Changes to take effect must be committed.
+    pub fn add_node_after<T>(&mut self, prev_node: T, next_node: T)
+    where
+        T: AstNode<Language = L>,
+    {
+        self.add_element_after(
+            prev_node.into_syntax().into(),
+            next_node.into_syntax().into(),
+        )
+    }
+
+    /// Push a change to insert "next_element" after "prev_element".
+    /// The parent of "prev_element" must be a list without separators.
+    ///
+    /// Changes to take effect must be committed.
+    pub fn add_element_after(
+        &mut self,
+        prev_element: SyntaxElement<L>,
+        next_element: SyntaxElement<L>,
+    ) {
+        debug_assert!(prev_element
+            .parent()
+            .is_some_and(|parent| parent.kind().is_list()));
+        let new_node_slot = prev_element.index() + 1;
+        self.push_change_at(
+            prev_element.parent(),
+            new_node_slot,
+            Some(next_element),
+            true,
+        )
+    }
+
     fn push_change(
         &mut self,
         prev_element: SyntaxElement<L>,
         next_element: Option<SyntaxElement<L>>,
     ) {
-        let new_node_slot = prev_element.index();
-        let parent = prev_element.parent();
+        self.push_change_at(
+            prev_element.parent(),
+            prev_element.index(),
+            next_element,
+            false,
+        )
+    }
+
+    fn push_change_at(
+        &mut self,
+        parent: Option<SyntaxNode<L>>,
+        new_node_slot: usize,
+        next_element: Option<SyntaxElement<L>>,
+        is_insertion: bool,
+    ) {
         let parent_range: Option<(u32, u32)> = parent.as_ref().map(|p| {
             let range = p.text_range();
             (range.start().into(), range.end().into())
@@ -297,6 +347,7 @@ where
             parent_range,
             new_node_slot,
             new_node: next_element,
+            is_insertion,
         });
     }
 
@@ -310,10 +361,19 @@ where
 
         for change in &self.changes {
             let parent = change.parent.as_ref().unwrap_or(&self.root);
-            let delete = match parent.slots().nth(change.new_node_slot) {
-                Some(SyntaxSlot::Node(node)) => node.text_range(),
-                Some(SyntaxSlot::Token(token)) => token.text_range(),
-                _ => continue,
+            let delete = if change.is_insertion {
+                // The element is inserted at the end of the previous slot
+                match parent.slots().nth(change.new_node_slot - 1) {
+                    Some(SyntaxSlot::Node(node)) => TextRange::empty(node.text_range().end()),
+                    Some(SyntaxSlot::Token(token)) => TextRange::empty(token.text_range().end()),
+                    _ => continue,
+                }
+            } else {
+                match parent.slots().nth(change.new_node_slot) {
+                    Some(SyntaxSlot::Node(node)) => node.text_range(),
+                    Some(SyntaxSlot::Token(token)) => token.text_range(),
+                    _ => continue,
+                }
             };
 
             range = match range {
@@ -367,7 +427,8 @@ where
                 // Aggregate all modifications to the current parent
                 // This works because of the Ord we defined in the [CommitChange] struct
 
-                let mut modifications = vec![(item.new_node_slot, item.new_node)];
+                let mut modifications =
+                    vec![(item.new_node_slot, item.new_node, item.is_insertion)];
                 loop {
                     if let Some(next_change_parent) = changes.peek().and_then(|i| i.parent.as_ref())
                     {
@@ -378,11 +439,18 @@ where
                             // If we have two modification to the same slot,
                             // last write wins
                             if let Some(last) = modifications.last() {
-                                if last.0 == next_change.new_node_slot {
+                                if last.0 == next_change.new_node_slot
+                                    && !last.2
+                                    && !next_change.is_insertion
+                                {
                                     modifications.pop();
                                 }
                             }
-                            modifications.push((next_change.new_node_slot, next_change.new_node));
+                            modifications.push((
+                                next_change.new_node_slot,
+                                next_change.new_node,
+                                next_change.is_insertion,
+                            ));
                             continue;
                         }
                     }
@@ -395,13 +463,19 @@ where
                 let mut current_parent = current_parent.detach();
                 let is_list = current_parent.kind().is_list();
                 let mut removed_slots = 0;
+                let mut inserted_slots = 0;
 
-                for (index, replace_with) in modifications {
-                    debug_assert!(index >= removed_slots);
-                    let index = index.checked_sub(removed_slots)
+                for (index, replace_with, is_insertion) in modifications {
+                    debug_assert!(index + inserted_slots >= removed_slots);
+                    let index = (index + inserted_slots).checked_sub(removed_slots)
                         .unwrap_or_else(|| panic!("cannot replace element in slot {index} with {removed_slots} removed slots"));
 
-                    current_parent = if is_list && replace_with.is_none() {
+                    current_parent = if is_insertion {
+                        inserted_slots += 1;
+                        current_parent
+                            .clone()
+                            .splice_slots(index..index, once(replace_with))
+                    } else if is_list && replace_with.is_none() {
                         removed_slots += 1;
                         current_parent.clone().splice_slots(index..=index, empty())
                     } else {
@@ -417,6 +491,7 @@ where
                     parent_range: grandparent_range,
                     new_node_slot: current_parent_slot,
                     new_node: Some(SyntaxElement::Node(current_parent)),
+                    is_insertion: false,
                 });
             } else {
                 let root = item
@@ -485,6 +560,31 @@ pub mod test {
         (root, s)
     }
 
+    /// ```
+    /// 0: ROOT@0..2
+    ///     0: EXPRESSION_LIST@0..2
+    ///         0: LITERAL_EXPRESSION@0..1
+    ///             0: STRING_TOKEN@0..1 "a" [] []
+    ///         1: LITERAL_EXPRESSION@1..2
+    ///             0: STRING_TOKEN@1..2 "b" [] []
+    /// ```
+    fn tree_list(names: &[&str]) -> (RawLanguageRoot, String) {
+        let mut builder = RawSyntaxTreeBuilder::new();
+        builder
+            .start_node(RawLanguageKind::ROOT)
+            .start_node(RawLanguageKind::EXPRESSION_LIST);
+        for name in names {
+            builder
+                .start_node(RawLanguageKind::LITERAL_EXPRESSION)
+                .token(RawLanguageKind::STRING_TOKEN, name)
+                .finish_node();
+        }
+        builder.finish_node().finish_node();
+        let root = builder.finish().cast::<RawLanguageRoot>().unwrap();
+        let s = format!("{:#?}", root.syntax());
+        (root, s)
+    }
+
     fn find(root: &RawLanguageRoot, name: &str) -> LiteralExpression {
         root.syntax()
             .descendants()
@@ -546,4 +646,39 @@ pub mod test {
 
         assert_eq!(expected_debug, format!("{:#?}", after));
     }
+
+    #[test]
+    pub fn ok_batch_mutation_add_node_after() {
+        let (before, _) = tree_list(&["a", "b"]);
+        let (expected, expected_debug) = tree_list(&["a", "c", "b"]);
+
+        let a = find(&before, "a");
+        let c = clone_detach(&expected, "c");
+
+        let mut batch = before.begin();
+        batch.add_node_after(a, c);
+        let after = batch.commit();
+
+        assert_eq!(expected_debug, format!("{:#?}", after));
+    }
+
+    #[test]
+    pub fn ok_batch_mutation_replace_and_add_node_after() {
+        let (before, _) = tree_list(&["a", "b"]);
+        let (expected, expected_debug) = tree_list(&["c", "d", "e"]);
+
+        let a = find(&before, "a");
+        let b = find(&before, "b");
+        let c = clone_detach(&expected, "c");
+        let d = clone_detach(&expected, "d");
+        let e = clone_detach(&expected, "e");
+
+        let mut batch = before.begin();
+        batch.add_node_after(a.clone(), d);
+        batch.replace_node(b, e);
+        batch.replace_node(a, c);
+        let after = batch.commit();
+
+        assert_eq!(expected_debug, format!("{:#?}", after));
+    }
 }
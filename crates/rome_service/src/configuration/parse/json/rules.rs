@@ -1127,6 +1127,7 @@ impl VisitNode<JsonLanguage> for Correctness {
                 "noUnusedVariables",
                 "noVoidElementsWithChildren",
                 "noVoidTypeReturn",
+                "useGetterReturn",
                 "useIsNan",
                 "useValidForDirection",
                 "useYield",
@@ -1678,6 +1679,29 @@ impl VisitNode<JsonLanguage> for Correctness {
                     ));
                 }
             },
+            "useGetterReturn" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_getter_return = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useGetterReturn",
+                        diagnostics,
+                    )?;
+                    self.use_getter_return = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
             "useIsNan" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
@@ -1765,35 +1789,92 @@ impl VisitNode<JsonLanguage> for Nursery {
                 "recommended",
                 "all",
                 "noAccumulatingSpread",
+                "noAlert",
+                "noAriaHiddenOnFocusable",
                 "noAriaUnsupportedElements",
+                "noArrayConstructor",
+                "noAwaitInLoop",
                 "noBannedTypes",
+                "noBarrelFile",
                 "noConfusingArrow",
+                "noConfusingVoidType",
+                "noConsole",
+                "noConstantBinaryExpression",
                 "noConstantCondition",
                 "noControlCharactersInRegex",
+                "noDefaultExport",
+                "noDocumentCookie",
+                "noDocumentWrite",
+                "noDuplicateImports",
                 "noDuplicateJsonKeys",
+                "noEmptyBlockStatements",
+                "noEmptyCharacterClass",
                 "noExcessiveComplexity",
                 "noFallthroughSwitchClause",
+                "noGlobalEval",
                 "noGlobalIsFinite",
                 "noGlobalIsNan",
+                "noHeadImportInDocument",
+                "noImportTypeAsValue",
+                "noInteractiveElementToNoninteractiveRole",
+                "noLabelWithoutControl",
+                "noMisleadingCharacterClass",
+                "noMisrefactoredShorthandAssign",
+                "noNewNativeNonConstructor",
+                "noNonNullAssertedOptionalChain",
                 "noNoninteractiveTabindex",
                 "noNonoctalDecimalEscape",
+                "noProcessEnv",
+                "noReExportAll",
                 "noRedundantRoles",
                 "noSelfAssign",
+                "noStaticElementInteractions",
                 "noStaticOnlyClass",
+                "noThenProperty",
+                "noThrowLiteral",
                 "noUnsafeDeclarationMerging",
+                "noUselessBackreferenceInRegex",
+                "noUselessConcat",
                 "noUselessEmptyExport",
+                "noUselessEscapeInRegex",
+                "noUselessStringRaw",
                 "noUselessThisAlias",
                 "noVoid",
                 "useAriaPropTypes",
                 "useArrowFunction",
+                "useAsConstAssertion",
+                "useAtIndex",
+                "useConsistentArrayType",
+                "useConsistentBuiltinInstantiation",
+                "useConsistentCurlyBraces",
+                "useConsistentMemberAccessibility",
+                "useConsistentTypeDefinitions",
+                "useDateNow",
+                "useErrorCause",
                 "useExhaustiveDependencies",
-                "useGetterReturn",
+                "useExportsLast",
+                "useFlatRegexFlags",
+                "useFocusableInteractive",
                 "useGroupedTypeImport",
                 "useHookAtTopLevel",
+                "useImportExtensions",
+                "useImportGrouping",
                 "useImportRestrictions",
+                "useImportType",
                 "useIsArray",
+                "useJsxKeyInIterable",
                 "useLiteralEnumMembers",
                 "useNamingConvention",
+                "useNodeAssertStrict",
+                "useNodePrefixForBuiltins",
+                "useNumberNamespace",
+                "useNumericSeparators",
+                "useObjectSpread",
+                "useRegexLiterals",
+                "useShorthandFunctionType",
+                "useThrowNewError",
+                "useTrimStartEnd",
+                "useValidAutocomplete",
             ],
             diagnostics,
         )
@@ -1836,6 +1917,52 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
+            "noAlert" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_alert = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noAlert",
+                        diagnostics,
+                    )?;
+                    self.no_alert = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noAriaHiddenOnFocusable" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_aria_hidden_on_focusable = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noAriaHiddenOnFocusable",
+                        diagnostics,
+                    )?;
+                    self.no_aria_hidden_on_focusable = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
             "noAriaUnsupportedElements" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
@@ -1859,6 +1986,52 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
+            "noArrayConstructor" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_array_constructor = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noArrayConstructor",
+                        diagnostics,
+                    )?;
+                    self.no_array_constructor = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noAwaitInLoop" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_await_in_loop = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noAwaitInLoop",
+                        diagnostics,
+                    )?;
+                    self.no_await_in_loop = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
             "noBannedTypes" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
@@ -1882,6 +2055,29 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
+            "noBarrelFile" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_barrel_file = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noBarrelFile",
+                        diagnostics,
+                    )?;
+                    self.no_barrel_file = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
             "noConfusingArrow" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
@@ -1905,21 +2101,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noConstantCondition" => match value {
+            "noConfusingVoidType" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_constant_condition = Some(configuration);
+                    self.no_confusing_void_type = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noConstantCondition",
+                        "noConfusingVoidType",
                         diagnostics,
                     )?;
-                    self.no_constant_condition = Some(rule_configuration);
+                    self.no_confusing_void_type = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -1928,21 +2124,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noControlCharactersInRegex" => match value {
+            "noConsole" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_control_characters_in_regex = Some(configuration);
+                    self.no_console = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noControlCharactersInRegex",
+                        "noConsole",
                         diagnostics,
                     )?;
-                    self.no_control_characters_in_regex = Some(rule_configuration);
+                    self.no_console = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -1951,21 +2147,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noDuplicateJsonKeys" => match value {
+            "noConstantBinaryExpression" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_duplicate_json_keys = Some(configuration);
+                    self.no_constant_binary_expression = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noDuplicateJsonKeys",
+                        "noConstantBinaryExpression",
                         diagnostics,
                     )?;
-                    self.no_duplicate_json_keys = Some(rule_configuration);
+                    self.no_constant_binary_expression = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -1974,21 +2170,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noExcessiveComplexity" => match value {
+            "noConstantCondition" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_excessive_complexity = Some(configuration);
+                    self.no_constant_condition = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noExcessiveComplexity",
+                        "noConstantCondition",
                         diagnostics,
                     )?;
-                    self.no_excessive_complexity = Some(rule_configuration);
+                    self.no_constant_condition = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -1997,21 +2193,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noFallthroughSwitchClause" => match value {
+            "noControlCharactersInRegex" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_fallthrough_switch_clause = Some(configuration);
+                    self.no_control_characters_in_regex = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noFallthroughSwitchClause",
+                        "noControlCharactersInRegex",
                         diagnostics,
                     )?;
-                    self.no_fallthrough_switch_clause = Some(rule_configuration);
+                    self.no_control_characters_in_regex = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2020,21 +2216,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noGlobalIsFinite" => match value {
+            "noDefaultExport" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_global_is_finite = Some(configuration);
+                    self.no_default_export = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noGlobalIsFinite",
+                        "noDefaultExport",
                         diagnostics,
                     )?;
-                    self.no_global_is_finite = Some(rule_configuration);
+                    self.no_default_export = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2043,21 +2239,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noGlobalIsNan" => match value {
+            "noDocumentCookie" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_global_is_nan = Some(configuration);
+                    self.no_document_cookie = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noGlobalIsNan",
+                        "noDocumentCookie",
                         diagnostics,
                     )?;
-                    self.no_global_is_nan = Some(rule_configuration);
+                    self.no_document_cookie = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2066,21 +2262,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noNoninteractiveTabindex" => match value {
+            "noDocumentWrite" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_noninteractive_tabindex = Some(configuration);
+                    self.no_document_write = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noNoninteractiveTabindex",
+                        "noDocumentWrite",
                         diagnostics,
                     )?;
-                    self.no_noninteractive_tabindex = Some(rule_configuration);
+                    self.no_document_write = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2089,21 +2285,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noNonoctalDecimalEscape" => match value {
+            "noDuplicateImports" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_nonoctal_decimal_escape = Some(configuration);
+                    self.no_duplicate_imports = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noNonoctalDecimalEscape",
+                        "noDuplicateImports",
                         diagnostics,
                     )?;
-                    self.no_nonoctal_decimal_escape = Some(rule_configuration);
+                    self.no_duplicate_imports = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2112,21 +2308,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noRedundantRoles" => match value {
+            "noDuplicateJsonKeys" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_redundant_roles = Some(configuration);
+                    self.no_duplicate_json_keys = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noRedundantRoles",
+                        "noDuplicateJsonKeys",
                         diagnostics,
                     )?;
-                    self.no_redundant_roles = Some(rule_configuration);
+                    self.no_duplicate_json_keys = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2135,21 +2331,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noSelfAssign" => match value {
+            "noEmptyBlockStatements" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_self_assign = Some(configuration);
+                    self.no_empty_block_statements = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noSelfAssign",
+                        "noEmptyBlockStatements",
                         diagnostics,
                     )?;
-                    self.no_self_assign = Some(rule_configuration);
+                    self.no_empty_block_statements = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2158,21 +2354,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noStaticOnlyClass" => match value {
+            "noEmptyCharacterClass" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_static_only_class = Some(configuration);
+                    self.no_empty_character_class = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noStaticOnlyClass",
+                        "noEmptyCharacterClass",
                         diagnostics,
                     )?;
-                    self.no_static_only_class = Some(rule_configuration);
+                    self.no_empty_character_class = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2181,21 +2377,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noUnsafeDeclarationMerging" => match value {
+            "noExcessiveComplexity" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_unsafe_declaration_merging = Some(configuration);
+                    self.no_excessive_complexity = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noUnsafeDeclarationMerging",
+                        "noExcessiveComplexity",
                         diagnostics,
                     )?;
-                    self.no_unsafe_declaration_merging = Some(rule_configuration);
+                    self.no_excessive_complexity = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2204,21 +2400,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noUselessEmptyExport" => match value {
+            "noFallthroughSwitchClause" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_useless_empty_export = Some(configuration);
+                    self.no_fallthrough_switch_clause = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noUselessEmptyExport",
+                        "noFallthroughSwitchClause",
                         diagnostics,
                     )?;
-                    self.no_useless_empty_export = Some(rule_configuration);
+                    self.no_fallthrough_switch_clause = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2227,21 +2423,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noUselessThisAlias" => match value {
+            "noGlobalEval" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_useless_this_alias = Some(configuration);
+                    self.no_global_eval = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noUselessThisAlias",
+                        "noGlobalEval",
                         diagnostics,
                     )?;
-                    self.no_useless_this_alias = Some(rule_configuration);
+                    self.no_global_eval = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2250,21 +2446,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "noVoid" => match value {
+            "noGlobalIsFinite" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.no_void = Some(configuration);
+                    self.no_global_is_finite = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "noVoid",
+                        "noGlobalIsFinite",
                         diagnostics,
                     )?;
-                    self.no_void = Some(rule_configuration);
+                    self.no_global_is_finite = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2273,21 +2469,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useAriaPropTypes" => match value {
+            "noGlobalIsNan" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_aria_prop_types = Some(configuration);
+                    self.no_global_is_nan = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useAriaPropTypes",
+                        "noGlobalIsNan",
                         diagnostics,
                     )?;
-                    self.use_aria_prop_types = Some(rule_configuration);
+                    self.no_global_is_nan = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2296,21 +2492,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useArrowFunction" => match value {
+            "noHeadImportInDocument" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_arrow_function = Some(configuration);
+                    self.no_head_import_in_document = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useArrowFunction",
+                        "noHeadImportInDocument",
                         diagnostics,
                     )?;
-                    self.use_arrow_function = Some(rule_configuration);
+                    self.no_head_import_in_document = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2319,21 +2515,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useExhaustiveDependencies" => match value {
+            "noImportTypeAsValue" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_exhaustive_dependencies = Some(configuration);
+                    self.no_import_type_as_value = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useExhaustiveDependencies",
+                        "noImportTypeAsValue",
                         diagnostics,
                     )?;
-                    self.use_exhaustive_dependencies = Some(rule_configuration);
+                    self.no_import_type_as_value = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2342,21 +2538,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useGetterReturn" => match value {
+            "noInteractiveElementToNoninteractiveRole" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_getter_return = Some(configuration);
+                    self.no_interactive_element_to_noninteractive_role = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useGetterReturn",
+                        "noInteractiveElementToNoninteractiveRole",
                         diagnostics,
                     )?;
-                    self.use_getter_return = Some(rule_configuration);
+                    self.no_interactive_element_to_noninteractive_role = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2365,21 +2561,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useGroupedTypeImport" => match value {
+            "noLabelWithoutControl" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_grouped_type_import = Some(configuration);
+                    self.no_label_without_control = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useGroupedTypeImport",
+                        "noLabelWithoutControl",
                         diagnostics,
                     )?;
-                    self.use_grouped_type_import = Some(rule_configuration);
+                    self.no_label_without_control = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2388,21 +2584,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useHookAtTopLevel" => match value {
+            "noMisleadingCharacterClass" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_hook_at_top_level = Some(configuration);
+                    self.no_misleading_character_class = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useHookAtTopLevel",
+                        "noMisleadingCharacterClass",
                         diagnostics,
                     )?;
-                    self.use_hook_at_top_level = Some(rule_configuration);
+                    self.no_misleading_character_class = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2411,21 +2607,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useImportRestrictions" => match value {
+            "noMisrefactoredShorthandAssign" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_import_restrictions = Some(configuration);
+                    self.no_misrefactored_shorthand_assign = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useImportRestrictions",
+                        "noMisrefactoredShorthandAssign",
                         diagnostics,
                     )?;
-                    self.use_import_restrictions = Some(rule_configuration);
+                    self.no_misrefactored_shorthand_assign = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2434,21 +2630,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useIsArray" => match value {
+            "noNewNativeNonConstructor" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_is_array = Some(configuration);
+                    self.no_new_native_non_constructor = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useIsArray",
+                        "noNewNativeNonConstructor",
                         diagnostics,
                     )?;
-                    self.use_is_array = Some(rule_configuration);
+                    self.no_new_native_non_constructor = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2457,21 +2653,21 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useLiteralEnumMembers" => match value {
+            "noNonNullAssertedOptionalChain" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_literal_enum_members = Some(configuration);
+                    self.no_non_null_asserted_optional_chain = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useLiteralEnumMembers",
+                        "noNonNullAssertedOptionalChain",
                         diagnostics,
                     )?;
-                    self.use_literal_enum_members = Some(rule_configuration);
+                    self.no_non_null_asserted_optional_chain = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
@@ -2480,21 +2676,1217 @@ impl VisitNode<JsonLanguage> for Nursery {
                     ));
                 }
             },
-            "useNamingConvention" => match value {
+            "noNoninteractiveTabindex" => match value {
                 AnyJsonValue::JsonStringValue(_) => {
                     let mut configuration = RuleConfiguration::default();
                     self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
-                    self.use_naming_convention = Some(configuration);
+                    self.no_noninteractive_tabindex = Some(configuration);
                 }
                 AnyJsonValue::JsonObjectValue(_) => {
                     let mut rule_configuration = RuleConfiguration::default();
                     rule_configuration.map_rule_configuration(
                         &value,
                         name_text,
-                        "useNamingConvention",
+                        "noNoninteractiveTabindex",
                         diagnostics,
                     )?;
-                    self.use_naming_convention = Some(rule_configuration);
+                    self.no_noninteractive_tabindex = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noNonoctalDecimalEscape" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_nonoctal_decimal_escape = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noNonoctalDecimalEscape",
+                        diagnostics,
+                    )?;
+                    self.no_nonoctal_decimal_escape = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noProcessEnv" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_process_env = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noProcessEnv",
+                        diagnostics,
+                    )?;
+                    self.no_process_env = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noReExportAll" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_re_export_all = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noReExportAll",
+                        diagnostics,
+                    )?;
+                    self.no_re_export_all = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noRedundantRoles" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_redundant_roles = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noRedundantRoles",
+                        diagnostics,
+                    )?;
+                    self.no_redundant_roles = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noSelfAssign" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_self_assign = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noSelfAssign",
+                        diagnostics,
+                    )?;
+                    self.no_self_assign = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noStaticElementInteractions" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_static_element_interactions = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noStaticElementInteractions",
+                        diagnostics,
+                    )?;
+                    self.no_static_element_interactions = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noStaticOnlyClass" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_static_only_class = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noStaticOnlyClass",
+                        diagnostics,
+                    )?;
+                    self.no_static_only_class = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noThenProperty" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_then_property = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noThenProperty",
+                        diagnostics,
+                    )?;
+                    self.no_then_property = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noThrowLiteral" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_throw_literal = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noThrowLiteral",
+                        diagnostics,
+                    )?;
+                    self.no_throw_literal = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUnsafeDeclarationMerging" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_unsafe_declaration_merging = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUnsafeDeclarationMerging",
+                        diagnostics,
+                    )?;
+                    self.no_unsafe_declaration_merging = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUselessBackreferenceInRegex" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_useless_backreference_in_regex = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUselessBackreferenceInRegex",
+                        diagnostics,
+                    )?;
+                    self.no_useless_backreference_in_regex = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUselessConcat" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_useless_concat = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUselessConcat",
+                        diagnostics,
+                    )?;
+                    self.no_useless_concat = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUselessEmptyExport" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_useless_empty_export = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUselessEmptyExport",
+                        diagnostics,
+                    )?;
+                    self.no_useless_empty_export = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUselessEscapeInRegex" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_useless_escape_in_regex = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUselessEscapeInRegex",
+                        diagnostics,
+                    )?;
+                    self.no_useless_escape_in_regex = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUselessStringRaw" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_useless_string_raw = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUselessStringRaw",
+                        diagnostics,
+                    )?;
+                    self.no_useless_string_raw = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noUselessThisAlias" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_useless_this_alias = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noUselessThisAlias",
+                        diagnostics,
+                    )?;
+                    self.no_useless_this_alias = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "noVoid" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.no_void = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "noVoid",
+                        diagnostics,
+                    )?;
+                    self.no_void = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useAriaPropTypes" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_aria_prop_types = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useAriaPropTypes",
+                        diagnostics,
+                    )?;
+                    self.use_aria_prop_types = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useArrowFunction" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_arrow_function = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useArrowFunction",
+                        diagnostics,
+                    )?;
+                    self.use_arrow_function = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useAsConstAssertion" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_as_const_assertion = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useAsConstAssertion",
+                        diagnostics,
+                    )?;
+                    self.use_as_const_assertion = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useAtIndex" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_at_index = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useAtIndex",
+                        diagnostics,
+                    )?;
+                    self.use_at_index = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useConsistentArrayType" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_consistent_array_type = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useConsistentArrayType",
+                        diagnostics,
+                    )?;
+                    self.use_consistent_array_type = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useConsistentBuiltinInstantiation" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_consistent_builtin_instantiation = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useConsistentBuiltinInstantiation",
+                        diagnostics,
+                    )?;
+                    self.use_consistent_builtin_instantiation = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useConsistentCurlyBraces" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_consistent_curly_braces = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useConsistentCurlyBraces",
+                        diagnostics,
+                    )?;
+                    self.use_consistent_curly_braces = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useConsistentMemberAccessibility" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_consistent_member_accessibility = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useConsistentMemberAccessibility",
+                        diagnostics,
+                    )?;
+                    self.use_consistent_member_accessibility = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useConsistentTypeDefinitions" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_consistent_type_definitions = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useConsistentTypeDefinitions",
+                        diagnostics,
+                    )?;
+                    self.use_consistent_type_definitions = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useDateNow" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_date_now = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useDateNow",
+                        diagnostics,
+                    )?;
+                    self.use_date_now = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useErrorCause" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_error_cause = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useErrorCause",
+                        diagnostics,
+                    )?;
+                    self.use_error_cause = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useExhaustiveDependencies" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_exhaustive_dependencies = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useExhaustiveDependencies",
+                        diagnostics,
+                    )?;
+                    self.use_exhaustive_dependencies = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useExportsLast" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_exports_last = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useExportsLast",
+                        diagnostics,
+                    )?;
+                    self.use_exports_last = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useFlatRegexFlags" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_flat_regex_flags = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useFlatRegexFlags",
+                        diagnostics,
+                    )?;
+                    self.use_flat_regex_flags = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useFocusableInteractive" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_focusable_interactive = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useFocusableInteractive",
+                        diagnostics,
+                    )?;
+                    self.use_focusable_interactive = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useGroupedTypeImport" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_grouped_type_import = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useGroupedTypeImport",
+                        diagnostics,
+                    )?;
+                    self.use_grouped_type_import = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useHookAtTopLevel" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_hook_at_top_level = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useHookAtTopLevel",
+                        diagnostics,
+                    )?;
+                    self.use_hook_at_top_level = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useImportExtensions" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_import_extensions = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useImportExtensions",
+                        diagnostics,
+                    )?;
+                    self.use_import_extensions = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useImportGrouping" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_import_grouping = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useImportGrouping",
+                        diagnostics,
+                    )?;
+                    self.use_import_grouping = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useImportRestrictions" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_import_restrictions = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useImportRestrictions",
+                        diagnostics,
+                    )?;
+                    self.use_import_restrictions = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useImportType" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_import_type = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useImportType",
+                        diagnostics,
+                    )?;
+                    self.use_import_type = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useIsArray" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_is_array = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useIsArray",
+                        diagnostics,
+                    )?;
+                    self.use_is_array = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useJsxKeyInIterable" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_jsx_key_in_iterable = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useJsxKeyInIterable",
+                        diagnostics,
+                    )?;
+                    self.use_jsx_key_in_iterable = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useLiteralEnumMembers" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_literal_enum_members = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useLiteralEnumMembers",
+                        diagnostics,
+                    )?;
+                    self.use_literal_enum_members = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useNamingConvention" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_naming_convention = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useNamingConvention",
+                        diagnostics,
+                    )?;
+                    self.use_naming_convention = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useNodeAssertStrict" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_node_assert_strict = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useNodeAssertStrict",
+                        diagnostics,
+                    )?;
+                    self.use_node_assert_strict = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useNodePrefixForBuiltins" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_node_prefix_for_builtins = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useNodePrefixForBuiltins",
+                        diagnostics,
+                    )?;
+                    self.use_node_prefix_for_builtins = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useNumberNamespace" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_number_namespace = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useNumberNamespace",
+                        diagnostics,
+                    )?;
+                    self.use_number_namespace = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useNumericSeparators" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_numeric_separators = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useNumericSeparators",
+                        diagnostics,
+                    )?;
+                    self.use_numeric_separators = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useObjectSpread" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_object_spread = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useObjectSpread",
+                        diagnostics,
+                    )?;
+                    self.use_object_spread = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useRegexLiterals" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_regex_literals = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useRegexLiterals",
+                        diagnostics,
+                    )?;
+                    self.use_regex_literals = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useShorthandFunctionType" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_shorthand_function_type = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useShorthandFunctionType",
+                        diagnostics,
+                    )?;
+                    self.use_shorthand_function_type = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useThrowNewError" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_throw_new_error = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useThrowNewError",
+                        diagnostics,
+                    )?;
+                    self.use_throw_new_error = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useTrimStartEnd" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_trim_start_end = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useTrimStartEnd",
+                        diagnostics,
+                    )?;
+                    self.use_trim_start_end = Some(rule_configuration);
+                }
+                _ => {
+                    diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
+                        "object or string",
+                        value.range(),
+                    ));
+                }
+            },
+            "useValidAutocomplete" => match value {
+                AnyJsonValue::JsonStringValue(_) => {
+                    let mut configuration = RuleConfiguration::default();
+                    self.map_to_known_string(&value, name_text, &mut configuration, diagnostics)?;
+                    self.use_valid_autocomplete = Some(configuration);
+                }
+                AnyJsonValue::JsonObjectValue(_) => {
+                    let mut rule_configuration = RuleConfiguration::default();
+                    rule_configuration.map_rule_configuration(
+                        &value,
+                        name_text,
+                        "useValidAutocomplete",
+                        diagnostics,
+                    )?;
+                    self.use_valid_autocomplete = Some(rule_configuration);
                 }
                 _ => {
                     diagnostics.push(DeserializationDiagnostic::new_incorrect_type(
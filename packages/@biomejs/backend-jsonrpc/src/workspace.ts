@@ -281,7 +281,7 @@ export interface A11y {
 	 */
 	noAutofocus?: RuleConfiguration;
 	/**
-	 * Disallow target="_blank" attribute without rel="noreferrer"
+	 * Disallow target="_blank" attribute without rel="noopener" or rel="noreferrer"
 	 */
 	noBlankTarget?: RuleConfiguration;
 	/**
@@ -546,6 +546,10 @@ export interface Correctness {
 	 * It enables the recommended rules for this group
 	 */
 	recommended?: boolean;
+	/**
+	 * Enforce get methods to always return a value.
+	 */
+	useGetterReturn?: RuleConfiguration;
 	/**
 	 * Require calls to isNaN() when checking for NaN.
 	 */
@@ -571,18 +575,50 @@ export interface Nursery {
 	 * Disallow the use of spread (...) syntax on accumulators.
 	 */
 	noAccumulatingSpread?: RuleConfiguration;
+	/**
+	 * Disallow the use of alert, confirm, and prompt.
+	 */
+	noAlert?: RuleConfiguration;
+	/**
+	 * Disallow aria-hidden="true" on focusable elements.
+	 */
+	noAriaHiddenOnFocusable?: RuleConfiguration;
 	/**
 	 * Enforce that elements that do not support ARIA roles, states, and properties do not have those attributes.
 	 */
 	noAriaUnsupportedElements?: RuleConfiguration;
+	/**
+	 * Disallow Array constructors.
+	 */
+	noArrayConstructor?: RuleConfiguration;
+	/**
+	 * Disallow await inside loops.
+	 */
+	noAwaitInLoop?: RuleConfiguration;
 	/**
 	 * Disallow primitive type aliases and misleading types.
 	 */
 	noBannedTypes?: RuleConfiguration;
+	/**
+	 * Disallow modules that only re-export other modules.
+	 */
+	noBarrelFile?: RuleConfiguration;
 	/**
 	 * Disallow arrow functions where they could be confused with comparisons.
 	 */
 	noConfusingArrow?: RuleConfiguration;
+	/**
+	 * Disallow void in unions, intersections, and type arguments.
+	 */
+	noConfusingVoidType?: RuleConfiguration;
+	/**
+	 * Disallow the use of console.
+	 */
+	noConsole?: RuleConfiguration;
+	/**
+	 * Disallow expressions where the operation doesn't affect the value.
+	 */
+	noConstantBinaryExpression?: RuleConfiguration;
 	/**
 	 * Disallow constant expressions in conditions
 	 */
@@ -591,10 +627,34 @@ export interface Nursery {
 	 * Prevents from having control characters and some escape sequences that match control characters in regular expressions.
 	 */
 	noControlCharactersInRegex?: RuleConfiguration;
+	/**
+	 * Disallow default exports.
+	 */
+	noDefaultExport?: RuleConfiguration;
+	/**
+	 * Disallow direct assignments to document.cookie.
+	 */
+	noDocumentCookie?: RuleConfiguration;
+	/**
+	 * Disallow the use of document.write() and document.writeln().
+	 */
+	noDocumentWrite?: RuleConfiguration;
+	/**
+	 * Disallow duplicate imports of the same module.
+	 */
+	noDuplicateImports?: RuleConfiguration;
 	/**
 	 * Disallow two keys with the same name inside a JSON object.
 	 */
 	noDuplicateJsonKeys?: RuleConfiguration;
+	/**
+	 * Disallow empty block statements.
+	 */
+	noEmptyBlockStatements?: RuleConfiguration;
+	/**
+	 * Disallow empty character classes in regular expression literals.
+	 */
+	noEmptyCharacterClass?: RuleConfiguration;
 	/**
 	 * Disallow functions that exceed a given complexity score.
 	 */
@@ -603,6 +663,10 @@ export interface Nursery {
 	 * Disallow fallthrough of switch clauses.
 	 */
 	noFallthroughSwitchClause?: RuleConfiguration;
+	/**
+	 * Disallow the use of global eval() and of the Function constructor.
+	 */
+	noGlobalEval?: RuleConfiguration;
 	/**
 	 * Use Number.isFinite instead of global isFinite.
 	 */
@@ -611,6 +675,38 @@ export interface Nursery {
 	 * Use Number.isNaN instead of global isNaN.
 	 */
 	noGlobalIsNan?: RuleConfiguration;
+	/**
+	 * Prevent the use of next/head in a module that defines a custom Next.js document.
+	 */
+	noHeadImportInDocument?: RuleConfiguration;
+	/**
+	 * Disallow using as values the bindings imported with import type.
+	 */
+	noImportTypeAsValue?: RuleConfiguration;
+	/**
+	 * Enforce that non-interactive ARIA roles are not assigned to interactive HTML elements.
+	 */
+	noInteractiveElementToNoninteractiveRole?: RuleConfiguration;
+	/**
+	 * Enforce that a label element is associated with a form control.
+	 */
+	noLabelWithoutControl?: RuleConfiguration;
+	/**
+	 * Disallow characters made of multiple code units in the character classes of regular expressions.
+	 */
+	noMisleadingCharacterClass?: RuleConfiguration;
+	/**
+	 * Disallow a unary operator directly following the = of an assignment.
+	 */
+	noMisrefactoredShorthandAssign?: RuleConfiguration;
+	/**
+	 * Disallow new operators with global non-constructor functions.
+	 */
+	noNewNativeNonConstructor?: RuleConfiguration;
+	/**
+	 * Disallow non-null assertions after an optional chain.
+	 */
+	noNonNullAssertedOptionalChain?: RuleConfiguration;
 	/**
 	 * Enforce that tabIndex is not assigned to non-interactive HTML elements.
 	 */
@@ -619,6 +715,14 @@ export interface Nursery {
 	 * Disallow \8 and \9 escape sequences in string literals.
 	 */
 	noNonoctalDecimalEscape?: RuleConfiguration;
+	/**
+	 * Disallow the use of process.env.
+	 */
+	noProcessEnv?: RuleConfiguration;
+	/**
+	 * Disallow export * from re-exports.
+	 */
+	noReExportAll?: RuleConfiguration;
 	/**
 	 * Enforce explicit role property is not the same as implicit/default role property on an element.
 	 */
@@ -627,18 +731,46 @@ export interface Nursery {
 	 * Disallow assignments where both sides are exactly the same.
 	 */
 	noSelfAssign?: RuleConfiguration;
+	/**
+	 * Enforce that static elements with event handlers have an interactive role.
+	 */
+	noStaticElementInteractions?: RuleConfiguration;
 	/**
 	 * This rule reports when a class has no non-static members, such as for a class used exclusively as a static namespace.
 	 */
 	noStaticOnlyClass?: RuleConfiguration;
+	/**
+	 * Disallow then property.
+	 */
+	noThenProperty?: RuleConfiguration;
+	/**
+	 * Disallow throwing literals and other values that cannot be errors.
+	 */
+	noThrowLiteral?: RuleConfiguration;
 	/**
 	 * Disallow unsafe declaration merging between interfaces and classes.
 	 */
 	noUnsafeDeclarationMerging?: RuleConfiguration;
+	/**
+	 * Disallow backreferences that always match the empty string.
+	 */
+	noUselessBackreferenceInRegex?: RuleConfiguration;
+	/**
+	 * Disallow the concatenation of string literals.
+	 */
+	noUselessConcat?: RuleConfiguration;
 	/**
 	 * Disallow empty exports that don't change anything in a module file.
 	 */
 	noUselessEmptyExport?: RuleConfiguration;
+	/**
+	 * Disallow unnecessary escapes in regular expression literals.
+	 */
+	noUselessEscapeInRegex?: RuleConfiguration;
+	/**
+	 * Disallow String.raw on template literals without escape sequences.
+	 */
+	noUselessStringRaw?: RuleConfiguration;
 	/**
 	 * Disallow useless this aliasing.
 	 */
@@ -659,14 +791,58 @@ export interface Nursery {
 	 * Use arrow functions over function expressions.
 	 */
 	useArrowFunction?: RuleConfiguration;
+	/**
+	 * Enforce the use of as for type assertions instead of the angle-bracket syntax.
+	 */
+	useAsConstAssertion?: RuleConfiguration;
+	/**
+	 * Prefer .at() over indexing from the end with .length.
+	 */
+	useAtIndex?: RuleConfiguration;
+	/**
+	 * Require consistently using either T[] or Array<T>
+	 */
+	useConsistentArrayType?: RuleConfiguration;
+	/**
+	 * Enforce the use of new for built-in constructors, and its absence for built-in conversion functions.
+	 */
+	useConsistentBuiltinInstantiation?: RuleConfiguration;
+	/**
+	 * Enforce the consistent use of curly braces around the strings of JSX attributes and children.
+	 */
+	useConsistentCurlyBraces?: RuleConfiguration;
+	/**
+	 * Require consistent accessibility modifiers on class members.
+	 */
+	useConsistentMemberAccessibility?: RuleConfiguration;
+	/**
+	 * Enforce the use of either interface or type to define object types.
+	 */
+	useConsistentTypeDefinitions?: RuleConfiguration;
+	/**
+	 * Use Date.now() to get the number of milliseconds since the Unix Epoch.
+	 */
+	useDateNow?: RuleConfiguration;
+	/**
+	 * Require passing the caught error as the cause of a new error thrown in a catch clause.
+	 */
+	useErrorCause?: RuleConfiguration;
 	/**
 	 * Enforce all dependencies are correctly specified.
 	 */
 	useExhaustiveDependencies?: RuleConfiguration;
 	/**
-	 * Enforce get methods to always return a value.
+	 * Require that all exports are declared after all non-export statements.
 	 */
-	useGetterReturn?: RuleConfiguration;
+	useExportsLast?: RuleConfiguration;
+	/**
+	 * Enforce sorted flags without duplicates in regular expression literals.
+	 */
+	useFlatRegexFlags?: RuleConfiguration;
+	/**
+	 * Enforce that the elements with an interactive role are focusable.
+	 */
+	useFocusableInteractive?: RuleConfiguration;
 	/**
 	 * Enforce the use of import type when an import only has specifiers with type qualifier.
 	 */
@@ -675,14 +851,30 @@ export interface Nursery {
 	 * Enforce that all React hooks are being called from the Top Level component functions.
 	 */
 	useHookAtTopLevel?: RuleConfiguration;
+	/**
+	 * Enforce file extensions in relative imports.
+	 */
+	useImportExtensions?: RuleConfiguration;
+	/**
+	 * Enforce the grouping of the imports by kind.
+	 */
+	useImportGrouping?: RuleConfiguration;
 	/**
 	 * Disallows package private imports.
 	 */
 	useImportRestrictions?: RuleConfiguration;
+	/**
+	 * Promotes the use of import type for imports only used as types.
+	 */
+	useImportType?: RuleConfiguration;
 	/**
 	 * Use Array.isArray() instead of instanceof Array.
 	 */
 	useIsArray?: RuleConfiguration;
+	/**
+	 * Require a key property on the JSX elements returned by the callback of map.
+	 */
+	useJsxKeyInIterable?: RuleConfiguration;
 	/**
 	 * Require all enum members to be literal values.
 	 */
@@ -691,6 +883,46 @@ export interface Nursery {
 	 * Enforce naming conventions for everything across a codebase.
 	 */
 	useNamingConvention?: RuleConfiguration;
+	/**
+	 * Promote the use of node:assert/strict over node:assert.
+	 */
+	useNodeAssertStrict?: RuleConfiguration;
+	/**
+	 * Enforce the use of the node: protocol for Node.js built-in modules.
+	 */
+	useNodePrefixForBuiltins?: RuleConfiguration;
+	/**
+	 * Use the Number properties instead of the global ones.
+	 */
+	useNumberNamespace?: RuleConfiguration;
+	/**
+	 * Enforce the use of numeric separators in long numeric literals.
+	 */
+	useNumericSeparators?: RuleConfiguration;
+	/**
+	 * Prefer object spread over Object.assign() when constructing a new object.
+	 */
+	useObjectSpread?: RuleConfiguration;
+	/**
+	 * Enforce the use of regular expression literals instead of the RegExp constructor when possible.
+	 */
+	useRegexLiterals?: RuleConfiguration;
+	/**
+	 * Enforce using a function type instead of an interface with a single call signature.
+	 */
+	useShorthandFunctionType?: RuleConfiguration;
+	/**
+	 * Require new when throwing a built-in error.
+	 */
+	useThrowNewError?: RuleConfiguration;
+	/**
+	 * Enforce the use of trimStart() and trimEnd() over trimLeft() and trimRight().
+	 */
+	useTrimStartEnd?: RuleConfiguration;
+	/**
+	 * Enforce that the autocomplete attribute contains valid tokens.
+	 */
+	useValidAutocomplete?: RuleConfiguration;
 }
 /**
  * A list of rules that belong to this group
@@ -1003,6 +1235,35 @@ export type PossibleOptions =
 	| HooksOptions
 	| NamingConventionOptions
 	| RestrictedGlobalsOptions
+	| NumericSeparatorsOptions
+	| ConsistentArrayTypeOptions
+	| ConstantConditionOptions
+	| AwaitInLoopOptions
+	| ReExportAllOptions
+	| ImportTypeOptions
+	| ConsistentMemberAccessibilityOptions
+	| ParameterAssignOptions
+	| NodeAssertStrictOptions
+	| NodePrefixForBuiltinsOptions
+	| BarrelFileOptions
+	| ExportsLastOptions
+	| ProcessEnvOptions
+	| ConsoleOptions
+	| ConsistentCurlyBracesOptions
+	| ImplicitBooleanOptions
+	| UselessFragmentsOptions
+	| SelfClosingElementsOptions
+	| ButtonTypeOptions
+	| StaticElementInteractionsOptions
+	| LabelWithoutControlOptions
+	| DangerouslySetInnerHtmlOptions
+	| ImportExtensionsOptions
+	| ImportGroupingOptions
+	| EmptyBlockStatementsOptions
+	| ConfusingVoidTypeOptions
+	| ExplicitAnyOptions
+	| ConsistentTypeDefinitionsOptions
+	| DoubleEqualsOptions
 	| null;
 /**
  * Options for the rule `noNestedModuleImports`.
@@ -1044,6 +1305,275 @@ export interface RestrictedGlobalsOptions {
 	 */
 	deniedGlobals?: string[];
 }
+/**
+ * Options for the rule `useNumericSeparators`.
+ */
+export interface NumericSeparatorsOptions {
+	/**
+	 * The minimum number of digits that a part of a numeric literal must have to require separators.
+	 */
+	minimumDigits: number;
+}
+/**
+ * Options for the rule `useConsistentArrayType`.
+ */
+export interface ConsistentArrayTypeOptions {
+	/**
+	 * The array syntax to enforce.
+	 */
+	syntax?: ArrayTypeSyntax;
+}
+/**
+ * Options for the rule `noConstantCondition`.
+ */
+export interface ConstantConditionOptions {
+	/**
+	 * Whether the conditions of `while`, `do...while`, and `for` loops may be constant.
+	 */
+	allowConstantLoopConditions: boolean;
+}
+/**
+ * Options for the rule `noAwaitInLoop`.
+ */
+export interface AwaitInLoopOptions {
+	/**
+	 * Whether `await` expressions in the body of `for...of` loops are ignored.
+	 */
+	ignoreForOf: boolean;
+}
+/**
+ * Options for the rule `noReExportAll`.
+ */
+export interface ReExportAllOptions {
+	/**
+	 * Whether `export * as namespace from` re-exports are allowed.
+	 */
+	allowNamedExportAll: boolean;
+}
+/**
+ * Options for the rule `useImportType`.
+ */
+export interface ImportTypeOptions {
+	/**
+	 * How an import only used as types is fixed.
+	 */
+	style?: ImportTypeStyle;
+}
+/**
+ * Options for the rule `useConsistentMemberAccessibility`.
+ */
+export interface ConsistentMemberAccessibilityOptions {
+	/**
+	 * The accessibility modifiers to enforce.
+	 */
+	accessibility?: Accessibility;
+}
+/**
+ * Options for the rule `noParameterAssign`.
+ */
+export interface ParameterAssignOptions {
+	/**
+	 * Whether the assignments to a property of a parameter are allowed.
+	 */
+	props?: PropertyAssignmentMode;
+}
+/**
+ * Options for the rule `useNodeAssertStrict`.
+ */
+export interface NodeAssertStrictOptions {
+	/**
+	 * Whether the module `"node:assert"` is allowed.
+	 */
+	ignoreNodeProtocol?: boolean;
+}
+/**
+ * Options for the rule `useNodePrefixForBuiltins`.
+ */
+export interface NodePrefixForBuiltinsOptions {
+	/**
+	 * Whether the built-in modules that share their name with a popular npm package are reported.
+	 */
+	checkAmbiguousModules?: boolean;
+}
+/**
+ * Options for the rule `noBarrelFile`.
+ */
+export interface BarrelFileOptions {
+	/**
+	 * The number of re-exports that a barrel file can have.
+	 */
+	threshold: number;
+}
+/**
+ * Options for the rule `useExportsLast`.
+ */
+export interface ExportsLastOptions {
+	/**
+	 * Whether the exports of types can appear anywhere in the module.
+	 */
+	ignoreTypeExports?: boolean;
+}
+/**
+ * Options for the rule `noProcessEnv`.
+ */
+export interface ProcessEnvOptions {
+	/**
+	 * The environment variables that can be accessed.
+	 */
+	allowEnvVars?: string[];
+}
+/**
+ * Options for the rule `noConsole`.
+ */
+export interface ConsoleOptions {
+	/**
+	 * The methods of `console` that can be called.
+	 */
+	allow?: string[];
+}
+/**
+ * Options for the rule `useConsistentCurlyBraces`.
+ */
+export interface ConsistentCurlyBracesOptions {
+	/**
+	 * Whether the strings must be wrapped in curly braces.
+	 */
+	braces?: CurlyBraces;
+}
+/**
+ * Options for the rule `noImplicitBoolean`.
+ */
+export interface ImplicitBooleanOptions {
+	/**
+	 * Whether the value `true` must be written explicitly.
+	 */
+	explicitValues?: ExplicitValues;
+	/**
+	 * The attributes that are ignored.
+	 */
+	ignoredAttributes?: string[];
+}
+/**
+ * Options for the rule `noUselessFragments`.
+ */
+export interface UselessFragmentsOptions {
+	/**
+	 * Whether the fragments that only contain an expression are allowed.
+	 */
+	allowExpressions?: boolean;
+}
+/**
+ * Options for the rule `useSelfClosingElements`.
+ */
+export interface SelfClosingElementsOptions {
+	/**
+	 * Whether the HTML elements can have a closing tag without children.
+	 */
+	ignoreHtmlElements?: boolean;
+}
+/**
+ * Options for the rule `useButtonType`.
+ */
+export interface ButtonTypeOptions {
+	/**
+	 * Whether the elements with a spread attribute and no `type` are reported.
+	 */
+	strict?: boolean;
+}
+/**
+ * Options for the rule `noStaticElementInteractions`.
+ */
+export interface StaticElementInteractionsOptions {
+	/**
+	 * Additional event handlers to check.
+	 */
+	handlers?: string[];
+}
+/**
+ * Options for the rule `noLabelWithoutControl`.
+ */
+export interface LabelWithoutControlOptions {
+	/**
+	 * Whether the labels with a spread attribute are ignored.
+	 */
+	ignoreSpread?: boolean;
+}
+/**
+ * Options for the rule `noDangerouslySetInnerHtml`.
+ */
+export interface DangerouslySetInnerHtmlOptions {
+	/**
+	 * Whether the content passed to a known sanitizer is allowed.
+	 */
+	allowWithSanitizer?: boolean;
+}
+/**
+ * Options for the rule `useImportExtensions`.
+ */
+export interface ImportExtensionsOptions {
+	/**
+	 * The extensions appended by the code fix. Only the first one is used.
+	 */
+	suggestedExtensions?: string[];
+}
+/**
+ * Options for the rule `useImportGrouping`.
+ */
+export interface ImportGroupingOptions {
+	/**
+	 * The order of the groups: `"builtin"`, `"external"` and `"relative"`.
+	 */
+	groups?: string[];
+}
+/**
+ * Options for the rule `noEmptyBlockStatements`.
+ */
+export interface EmptyBlockStatementsOptions {
+	/**
+	 * Whether the body of a `catch` clause may be empty.
+	 */
+	allowEmptyCatch: boolean;
+}
+/**
+ * Options for the rule `noConfusingVoidType`.
+ */
+export interface ConfusingVoidTypeOptions {
+	/**
+	 * Whether the rule suggests replacing `void` with `undefined`.
+	 */
+	fixToUndefined: boolean;
+}
+/**
+ * Options for the rule `noExplicitAny`.
+ */
+export interface ExplicitAnyOptions {
+	/**
+	 * Whether the rule suggests replacing `any` with `unknown`.
+	 */
+	fixToUnknown: boolean;
+	/**
+	 * Whether `any` is allowed in the type of a rest parameter.
+	 */
+	ignoreRestArgs: boolean;
+}
+/**
+ * Options for the rule `useConsistentTypeDefinitions`.
+ */
+export interface ConsistentTypeDefinitionsOptions {
+	/**
+	 * How object types are defined.
+	 */
+	style: TypeDefinitionStyle;
+}
+/**
+ * Options for the rule `noDoubleEquals`.
+ */
+export interface DoubleEqualsOptions {
+	/**
+	 * Whether `==` and `!=` are allowed when comparing against `null`.
+	 */
+	ignoreNull: boolean;
+}
 export interface Hooks {
 	/**
 	* The "position" of the closure function, starting from zero.
@@ -1064,6 +1594,34 @@ export interface Hooks {
  * Supported cases for TypeScript `enum` member names.
  */
 export type EnumMemberCase = "PascalCase" | "CONSTANT_CASE" | "camelCase";
+/**
+ * Supported syntaxes for array types.
+ */
+export type ArrayTypeSyntax = "shorthand" | "generic";
+/**
+ * Supported styles for imports only used as types.
+ */
+export type ImportTypeStyle = "separated" | "inline";
+/**
+ * Supported accessibility requirements.
+ */
+export type Accessibility = "explicit" | "noPublic";
+/**
+ * Whether the assignments to a property of a parameter are allowed.
+ */
+export type PropertyAssignmentMode = "allow" | "deny";
+/**
+ * Whether the strings of JSX attributes and children are wrapped in curly braces.
+ */
+export type CurlyBraces = "never" | "always";
+/**
+ * Whether the value `true` of JSX attributes is written explicitly.
+ */
+export type ExplicitValues = "always" | "never";
+/**
+ * Supported ways of defining object types.
+ */
+export type TypeDefinitionStyle = "interface" | "type";
 export interface OpenFileParams {
 	content: string;
 	language_hint?: Language;
@@ -1191,7 +1749,6 @@ export type Category =
 	| "lint/correctness/noGlobalObjectCalls"
 	| "lint/correctness/noInnerDeclarations"
 	| "lint/correctness/noInvalidConstructorSuper"
-	| "lint/correctness/useIsNan"
 	| "lint/correctness/noNewSymbol"
 	| "lint/correctness/noPrecisionLoss"
 	| "lint/correctness/noRenderReturnValue"
@@ -1208,38 +1765,97 @@ export type Category =
 	| "lint/correctness/noUnusedVariables"
 	| "lint/correctness/noVoidElementsWithChildren"
 	| "lint/correctness/noVoidTypeReturn"
+	| "lint/correctness/useGetterReturn"
+	| "lint/correctness/useIsNan"
 	| "lint/correctness/useValidForDirection"
 	| "lint/correctness/useYield"
 	| "lint/nursery/noAccumulatingSpread"
+	| "lint/nursery/noAlert"
+	| "lint/nursery/noAriaHiddenOnFocusable"
 	| "lint/nursery/noAriaUnsupportedElements"
+	| "lint/nursery/noArrayConstructor"
+	| "lint/nursery/noAwaitInLoop"
 	| "lint/nursery/noBannedTypes"
+	| "lint/nursery/noBarrelFile"
 	| "lint/nursery/noConfusingArrow"
+	| "lint/nursery/noConfusingVoidType"
+	| "lint/nursery/noConsole"
+	| "lint/nursery/noConstantBinaryExpression"
 	| "lint/nursery/noConstantCondition"
 	| "lint/nursery/noControlCharactersInRegex"
+	| "lint/nursery/noDefaultExport"
+	| "lint/nursery/noDocumentCookie"
+	| "lint/nursery/noDocumentWrite"
+	| "lint/nursery/noDuplicateImports"
 	| "lint/nursery/noDuplicateJsonKeys"
+	| "lint/nursery/noEmptyBlockStatements"
+	| "lint/nursery/noEmptyCharacterClass"
 	| "lint/nursery/noExcessiveComplexity"
 	| "lint/nursery/noFallthroughSwitchClause"
+	| "lint/nursery/noGlobalEval"
 	| "lint/nursery/noGlobalIsFinite"
 	| "lint/nursery/noGlobalIsNan"
+	| "lint/nursery/noHeadImportInDocument"
+	| "lint/nursery/noImportTypeAsValue"
+	| "lint/nursery/noInteractiveElementToNoninteractiveRole"
+	| "lint/nursery/noLabelWithoutControl"
+	| "lint/nursery/noMisleadingCharacterClass"
+	| "lint/nursery/noMisrefactoredShorthandAssign"
+	| "lint/nursery/noNewNativeNonConstructor"
+	| "lint/nursery/noNonNullAssertedOptionalChain"
 	| "lint/nursery/noNoninteractiveTabindex"
 	| "lint/nursery/noNonoctalDecimalEscape"
+	| "lint/nursery/noProcessEnv"
+	| "lint/nursery/noReExportAll"
 	| "lint/nursery/noRedundantRoles"
 	| "lint/nursery/noSelfAssign"
+	| "lint/nursery/noStaticElementInteractions"
 	| "lint/nursery/noStaticOnlyClass"
+	| "lint/nursery/noThenProperty"
+	| "lint/nursery/noThrowLiteral"
 	| "lint/nursery/noUnsafeDeclarationMerging"
+	| "lint/nursery/noUselessBackreferenceInRegex"
+	| "lint/nursery/noUselessConcat"
 	| "lint/nursery/noUselessEmptyExport"
+	| "lint/nursery/noUselessEscapeInRegex"
+	| "lint/nursery/noUselessStringRaw"
 	| "lint/nursery/noUselessThisAlias"
 	| "lint/nursery/noVoid"
 	| "lint/nursery/useAriaPropTypes"
 	| "lint/nursery/useArrowFunction"
+	| "lint/nursery/useAsConstAssertion"
+	| "lint/nursery/useAtIndex"
+	| "lint/nursery/useConsistentArrayType"
+	| "lint/nursery/useConsistentBuiltinInstantiation"
+	| "lint/nursery/useConsistentCurlyBraces"
+	| "lint/nursery/useConsistentMemberAccessibility"
+	| "lint/nursery/useConsistentTypeDefinitions"
+	| "lint/nursery/useDateNow"
+	| "lint/nursery/useErrorCause"
 	| "lint/nursery/useExhaustiveDependencies"
-	| "lint/nursery/useGetterReturn"
+	| "lint/nursery/useExportsLast"
+	| "lint/nursery/useFlatRegexFlags"
+	| "lint/nursery/useFocusableInteractive"
 	| "lint/nursery/useGroupedTypeImport"
 	| "lint/nursery/useHookAtTopLevel"
+	| "lint/nursery/useImportExtensions"
+	| "lint/nursery/useImportGrouping"
 	| "lint/nursery/useImportRestrictions"
+	| "lint/nursery/useImportType"
 	| "lint/nursery/useIsArray"
+	| "lint/nursery/useJsxKeyInIterable"
 	| "lint/nursery/useLiteralEnumMembers"
 	| "lint/nursery/useNamingConvention"
+	| "lint/nursery/useNodeAssertStrict"
+	| "lint/nursery/useNodePrefixForBuiltins"
+	| "lint/nursery/useNumberNamespace"
+	| "lint/nursery/useNumericSeparators"
+	| "lint/nursery/useObjectSpread"
+	| "lint/nursery/useRegexLiterals"
+	| "lint/nursery/useShorthandFunctionType"
+	| "lint/nursery/useThrowNewError"
+	| "lint/nursery/useTrimStartEnd"
+	| "lint/nursery/useValidAutocomplete"
 	| "lint/performance/noDelete"
 	| "lint/security/noDangerouslySetInnerHtml"
 	| "lint/security/noDangerouslySetInnerHtmlWithChildren"